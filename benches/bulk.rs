@@ -0,0 +1,36 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use ephemeris::{Duration, Instant};
+
+fn instants(count: i64) -> Vec<Instant> {
+    (0..count)
+        .map(|i| Instant::of_epoch_second_and_adjustment(1_600_000_000 + i, i * 7_919))
+        .collect()
+}
+
+fn add_duration(c: &mut Criterion) {
+    let offset = Duration::of_seconds_and_adjustment(-37, 123_456_789);
+    let original = instants(1_000_000);
+
+    c.bench_function("add_duration_slice", |b| {
+        b.iter_batched_ref(
+            || original.clone(),
+            |instants| Instant::add_duration_slice(instants, black_box(offset)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function("add_duration_scalar", |b| {
+        b.iter_batched_ref(
+            || original.clone(),
+            |instants| {
+                for instant in instants.iter_mut() {
+                    *instant += black_box(offset);
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, add_duration);
+criterion_main!(benches);
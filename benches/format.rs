@@ -0,0 +1,23 @@
+use std::fmt::Write;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ephemeris::{Duration, Instant};
+
+fn format(c: &mut Criterion) {
+    let mut output = String::with_capacity(64);
+    let duration = Duration::of_seconds_and_adjustment(-90_061, 123_456_789);
+    c.bench_function("duration_display", |b| {
+        b.iter(|| {
+            output.clear();
+            write!(output, "{}", black_box(duration)).unwrap();
+        })
+    });
+    let instant = Instant::of_epoch_second_and_adjustment(1_196_676_930, 123_456_000);
+    c.bench_function("instant_to_epoch_string", |b| {
+        b.iter(|| black_box(instant).to_epoch_string())
+    });
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
@@ -0,0 +1,15 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ephemeris::Instant;
+
+fn parse_rfc3339(c: &mut Criterion) {
+    c.bench_function("parse_rfc3339_bytes", |b| {
+        b.iter(|| Instant::parse_rfc3339_bytes(black_box(b"2007-12-03T10:15:30.123456789+01:00")))
+    });
+    c.bench_function("parse_rfc3339_bytes_utc", |b| {
+        b.iter(|| Instant::parse_rfc3339_bytes(black_box(b"2007-12-03T10:15:30Z")))
+    });
+}
+
+criterion_group!(benches, parse_rfc3339);
+criterion_main!(benches);
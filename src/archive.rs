@@ -0,0 +1,169 @@
+//! Zero-copy [`rkyv`](https://docs.rs/rkyv) support.
+//!
+//! Both archived types share the same stable, unpadded 12 byte layout:
+//! a little-endian `i64` count of seconds, followed by a little-endian `u32` count of nanoseconds within the second.
+//! The archived types have an alignment of 1, so they may be read directly from unaligned buffers.
+
+use std::error::Error;
+use std::fmt;
+
+use rkyv::bytecheck::CheckBytes;
+use rkyv::{Archive, Deserialize, Fallible, Serialize};
+
+use crate::constants::*;
+use crate::{Duration, Instant};
+
+#[cfg(test)]
+pub mod round_trip;
+
+/// Error returned when validating an archived value whose nanosecond field is out of range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidNanosError {
+    nanos: u32,
+}
+
+impl InvalidNanosError {
+    /// Gets the out-of-range nanosecond value that was found in the archive.
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+}
+
+impl fmt::Display for InvalidNanosError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "archived nanosecond of second {} is not less than {}",
+            self.nanos, NANOSECONDS_IN_SECOND
+        )
+    }
+}
+
+impl Error for InvalidNanosError {}
+
+fn check_nanos(nanos: [u8; 4]) -> Result<(), InvalidNanosError> {
+    let nanos = u32::from_le_bytes(nanos);
+    if i64::from(nanos) < NANOSECONDS_IN_SECOND {
+        Ok(())
+    } else {
+        Err(InvalidNanosError { nanos })
+    }
+}
+
+/// The archived form of a [`Duration`](../struct.Duration.html).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct ArchivedDuration {
+    seconds: [u8; 8],
+    nanosecond_of_second: [u8; 4],
+}
+
+impl ArchivedDuration {
+    /// Gets the number of seconds in the archived duration.
+    pub fn seconds(&self) -> i64 {
+        i64::from_le_bytes(self.seconds)
+    }
+
+    /// Gets the number of nanoseconds within the second in the archived duration.
+    pub fn nano(&self) -> u32 {
+        u32::from_le_bytes(self.nanosecond_of_second)
+    }
+}
+
+impl Archive for Duration {
+    type Archived = ArchivedDuration;
+    type Resolver = ();
+
+    unsafe fn resolve(&self, _pos: usize, _resolver: (), out: *mut ArchivedDuration) {
+        out.write(ArchivedDuration {
+            seconds: self.seconds().to_le_bytes(),
+            nanosecond_of_second: self.nano().to_le_bytes(),
+        });
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Duration {
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Duration, D> for ArchivedDuration {
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Duration, D::Error> {
+        Ok(Duration::of_seconds_and_adjustment(
+            self.seconds(),
+            i64::from(self.nano()),
+        ))
+    }
+}
+
+impl<C: ?Sized> CheckBytes<C> for ArchivedDuration {
+    type Error = InvalidNanosError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const ArchivedDuration,
+        _context: &mut C,
+    ) -> Result<&'a ArchivedDuration, InvalidNanosError> {
+        check_nanos((*value).nanosecond_of_second)?;
+        Ok(&*value)
+    }
+}
+
+/// The archived form of an [`Instant`](../struct.Instant.html).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct ArchivedInstant {
+    epoch_second: [u8; 8],
+    nanosecond_of_second: [u8; 4],
+}
+
+impl ArchivedInstant {
+    /// Gets the number of seconds before or after the epoch in the archived instant.
+    pub fn epoch_second(&self) -> i64 {
+        i64::from_le_bytes(self.epoch_second)
+    }
+
+    /// Gets the number of nanoseconds within the second in the archived instant.
+    pub fn nano(&self) -> u32 {
+        u32::from_le_bytes(self.nanosecond_of_second)
+    }
+}
+
+impl Archive for Instant {
+    type Archived = ArchivedInstant;
+    type Resolver = ();
+
+    unsafe fn resolve(&self, _pos: usize, _resolver: (), out: *mut ArchivedInstant) {
+        out.write(ArchivedInstant {
+            epoch_second: self.epoch_second().to_le_bytes(),
+            nanosecond_of_second: self.nano().to_le_bytes(),
+        });
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Instant {
+    fn serialize(&self, _serializer: &mut S) -> Result<(), S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Instant, D> for ArchivedInstant {
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Instant, D::Error> {
+        Ok(Instant::of_epoch_second_and_adjustment(
+            self.epoch_second(),
+            i64::from(self.nano()),
+        ))
+    }
+}
+
+impl<C: ?Sized> CheckBytes<C> for ArchivedInstant {
+    type Error = InvalidNanosError;
+
+    unsafe fn check_bytes<'a>(
+        value: *const ArchivedInstant,
+        _context: &mut C,
+    ) -> Result<&'a ArchivedInstant, InvalidNanosError> {
+        check_nanos((*value).nanosecond_of_second)?;
+        Ok(&*value)
+    }
+}
@@ -0,0 +1,84 @@
+use proptest::prelude::*;
+use rkyv::{Deserialize, Infallible};
+
+use crate::constants::*;
+
+use crate::{Duration, Instant};
+
+fn unaligned_copy(bytes: &[u8]) -> Vec<u8> {
+    let mut unaligned = Vec::with_capacity(bytes.len() + 1);
+    unaligned.push(0xFF);
+    unaligned.extend_from_slice(bytes);
+    unaligned
+}
+
+proptest! {
+    #[test]
+    fn duration_round_trip(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let bytes = rkyv::to_bytes::<_, 16>(&duration).unwrap();
+        let archived = rkyv::check_archived_root::<Duration>(&bytes).unwrap();
+
+        prop_assert_eq!(seconds, archived.seconds());
+        prop_assert_eq!(nanos as u32, archived.nano());
+        prop_assert_eq!(duration, archived.deserialize(&mut Infallible).unwrap());
+    }
+}
+
+proptest! {
+    #[test]
+    fn instant_round_trip(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let bytes = rkyv::to_bytes::<_, 16>(&instant).unwrap();
+        let archived = rkyv::check_archived_root::<Instant>(&bytes).unwrap();
+
+        prop_assert_eq!(seconds, archived.epoch_second());
+        prop_assert_eq!(nanos as u32, archived.nano());
+        let deserialized: Instant = archived.deserialize(&mut Infallible).unwrap();
+        prop_assert_eq!(instant, deserialized);
+    }
+}
+
+proptest! {
+    #[test]
+    fn layout(instant in Just(Instant::of_epoch_second_and_adjustment(-2, 1))) {
+        let bytes = rkyv::to_bytes::<_, 16>(&instant).unwrap();
+
+        prop_assert_eq!(
+            &[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x00, 0x00, 0x00][..],
+            &bytes[..]
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn unaligned_access(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let instant_bytes = unaligned_copy(&rkyv::to_bytes::<_, 16>(&instant).unwrap());
+        let duration_bytes = unaligned_copy(&rkyv::to_bytes::<_, 16>(&duration).unwrap());
+        let archived_instant = rkyv::check_archived_root::<Instant>(&instant_bytes[1..]).unwrap();
+        let archived_duration = rkyv::check_archived_root::<Duration>(&duration_bytes[1..]).unwrap();
+
+        let deserialized_instant: Instant = archived_instant.deserialize(&mut Infallible).unwrap();
+        prop_assert_eq!(instant, deserialized_instant);
+        prop_assert_eq!(duration, archived_duration.deserialize(&mut Infallible).unwrap());
+    }
+}
+
+proptest! {
+    #[test]
+    fn reject_invalid_nanos(seconds in prop::num::i64::ANY, nanos in (NANOSECONDS_IN_SECOND as u32)..=u32::MAX) {
+        let mut instant_bytes = rkyv::to_bytes::<_, 16>(&Instant::of_epoch_second(seconds)).unwrap();
+        let mut duration_bytes = rkyv::to_bytes::<_, 16>(&Duration::of_seconds(seconds)).unwrap();
+        instant_bytes[8..].copy_from_slice(&nanos.to_le_bytes());
+        duration_bytes[8..].copy_from_slice(&nanos.to_le_bytes());
+
+        prop_assert!(rkyv::check_archived_root::<Instant>(&instant_bytes).is_err());
+        prop_assert!(rkyv::check_archived_root::<Duration>(&duration_bytes).is_err());
+    }
+}
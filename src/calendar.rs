@@ -0,0 +1,131 @@
+use core::iter::FusedIterator;
+
+use crate::civil::*;
+use crate::constants::*;
+use crate::Instant;
+
+// The first day of the Gregorian calendar in Great Britain and its colonies, 1752-09-14,
+// which followed 1752-09-02 in the Julian calendar.
+const BRITISH_CUTOVER_EPOCH_DAY: i64 = -79_366;
+
+/// A calendar system for converting between instants and dates, such as for rendering historical dates.
+///
+/// The Julian calendar has a leap year every four years, while the Gregorian calendar skips three of them every 400 years,
+/// so the two drift apart: by the Gregorian reform in 1582 the Julian calendar was 10 days behind,
+/// and Thursday 1582-10-04 (Julian) was followed by Friday 1582-10-15 (Gregorian).
+/// The difference grew to 11 days in 1700, 12 days in 1800, and 13 days from 1900 to 2099.
+///
+/// Years are numbered astronomically in every calendar, so year `0` is 1 BC, and every year starts on January 1st.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Calendar {
+    /// The Gregorian calendar, extended backwards before its introduction in 1582.
+    /// This is the calendar of ISO-8601, and of every other civil conversion in this library.
+    #[default]
+    ProlepticGregorian,
+    /// The Julian calendar, extended backwards before its introduction in 45 BC and forwards to the present.
+    Julian,
+    /// The Julian calendar until Wednesday 1752-09-02, followed by the Gregorian calendar from Thursday 1752-09-14,
+    /// as adopted by Great Britain and its colonies.
+    ///
+    /// The eleven dates in between never happened, so they are rejected when converting to an instant.
+    /// Note that Britain also started its legal year on March 25th until 1752; this is not modelled,
+    /// so dates from January 1st to March 24th take the historical year, such as 1751-02-01 rather than "1750/51".
+    MixedBritish,
+}
+
+impl Calendar {
+    // The (year, month, day) in this calendar of the given number of days from the epoch.
+    pub(crate) fn civil_of_epoch_day(self, epoch_day: i64) -> (i64, u32, u32) {
+        match self {
+            Calendar::ProlepticGregorian => civil_of_epoch_day(epoch_day),
+            Calendar::MixedBritish if epoch_day >= BRITISH_CUTOVER_EPOCH_DAY => {
+                civil_of_epoch_day(epoch_day)
+            }
+            Calendar::Julian | Calendar::MixedBritish => julian_civil_of_epoch_day(epoch_day),
+        }
+    }
+
+    // The number of days from the epoch to the given date in this calendar,
+    // or `None` if the day is out of range for the month, or skipped by the cutover.
+    pub(crate) fn epoch_day_of(self, year: i64, month: u32, day: u32) -> Option<i64> {
+        let epoch_day = match self {
+            Calendar::ProlepticGregorian => epoch_day_of(year, month, day),
+            Calendar::Julian => julian_epoch_day_of(year, month, day),
+            Calendar::MixedBritish => {
+                let epoch_day = epoch_day_of(year, month, day);
+                if epoch_day >= BRITISH_CUTOVER_EPOCH_DAY {
+                    epoch_day
+                } else {
+                    julian_epoch_day_of(year, month, day)
+                }
+            }
+        };
+        // An impossible day, such as February 30th, or a date in the cutover gap, converts back to a different date.
+        Some(epoch_day)
+            .filter(|&epoch_day| self.civil_of_epoch_day(epoch_day) == (year, month, day))
+    }
+}
+
+/// An iterator over the starts of successive calendar months, at a fixed offset from UTC.
+///
+/// This is created by [`Instant::iter_months`](struct.Instant.html#method.iter_months).
+#[derive(Clone, Debug)]
+pub struct MonthStarts {
+    year: i64,
+    month: u32,
+    step: u32,
+    offset_seconds: i32,
+    done: bool,
+}
+
+impl MonthStarts {
+    pub(crate) fn after(start: Instant, offset_seconds: i32) -> MonthStarts {
+        let (epoch_day, _) = start.to_epoch_day_and_nanos(offset_seconds);
+        let (year, month, _) = civil_of_epoch_day(epoch_day);
+        MonthStarts {
+            year,
+            month,
+            step: 1,
+            offset_seconds,
+            done: false,
+        }
+    }
+
+    /// Steps by the specified number of months at a time, such as 3 for quarters, instead of every month.
+    ///
+    /// The first month yielded is still the specified number of months after the starting month.
+    ///
+    /// # Parameters
+    ///  - `months`: the number of months to step by.
+    ///
+    /// # Panics
+    /// - if `months` is zero.
+    pub fn step_months(self, months: u32) -> MonthStarts {
+        assert!(months > 0, "step must be at least one month");
+        MonthStarts {
+            step: months,
+            ..self
+        }
+    }
+}
+
+impl Iterator for MonthStarts {
+    type Item = Instant;
+
+    fn next(&mut self) -> Option<Instant> {
+        if self.done {
+            return None;
+        }
+        let months = i64::from(self.month - 1) + i64::from(self.step);
+        self.year += months / 12;
+        self.month = (months % 12) as u32 + 1;
+
+        let start = epoch_day_of(self.year, self.month, 1)
+            .checked_mul(SECONDS_IN_DAY)
+            .and_then(|local_second| local_second.checked_sub(i64::from(self.offset_seconds)));
+        self.done = start.is_none();
+        start.map(Instant::of_epoch_second)
+    }
+}
+
+impl FusedIterator for MonthStarts {}
@@ -0,0 +1,96 @@
+//! Conversions between the proleptic Gregorian and Julian calendars and days since the epoch.
+//!
+//! Every day is treated as exactly 86,400 seconds long; no leap-second adjustment is performed.
+
+use crate::constants::*;
+
+#[cfg(test)]
+pub mod round_trip;
+
+// Days from 0000-03-01 to the epoch.
+// Counting years from March puts the leap day at the end of the year, which keeps the month arithmetic regular.
+const DAYS_FROM_MARCH_ZERO_TO_EPOCH: i64 = EPOCH_OFFSET_FROM_ZERO_DAYS - (31 + 29);
+
+// Days from 0000-03-01 in the Julian calendar to the epoch.
+// The Julian calendar was two days behind the Gregorian at that point, as neither had yet skipped a century leap day.
+const JULIAN_DAYS_FROM_MARCH_ZERO_TO_EPOCH: i64 = DAYS_FROM_MARCH_ZERO_TO_EPOCH + 2;
+
+pub fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// The number of days from the epoch to the given (valid) date.
+pub fn epoch_day_of(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(YEARS_IN_LEAP_YEAR_EPICYCLE);
+    let year_of_era = year.rem_euclid(YEARS_IN_LEAP_YEAR_EPICYCLE);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * DAYS_IN_YEAR + year_of_era / YEARS_IN_LEAP_YEAR_CYCLE
+        - year_of_era / YEARS_IN_CENTURY
+        + day_of_year;
+    era * DAYS_IN_LEAP_YEAR_EPICYCLE + day_of_era - DAYS_FROM_MARCH_ZERO_TO_EPOCH
+}
+
+// The (year, month, day) of the given number of days from the epoch.
+pub fn civil_of_epoch_day(epoch_day: i64) -> (i64, u32, u32) {
+    let day = epoch_day + DAYS_FROM_MARCH_ZERO_TO_EPOCH;
+    let era = day.div_euclid(DAYS_IN_LEAP_YEAR_EPICYCLE);
+    let day_of_era = day.rem_euclid(DAYS_IN_LEAP_YEAR_EPICYCLE);
+    let year_of_era = (day_of_era - day_of_era / (DAYS_IN_LEAP_YEAR_CYCLE - 1)
+        + day_of_era / DAYS_IN_LONG_LEAP_YEAR_CYCLE
+        - day_of_era / (DAYS_IN_LEAP_YEAR_EPICYCLE - 1))
+        / DAYS_IN_YEAR;
+    let day_of_year = day_of_era
+        - (year_of_era * DAYS_IN_YEAR + year_of_era / YEARS_IN_LEAP_YEAR_CYCLE
+            - year_of_era / YEARS_IN_CENTURY);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = era * YEARS_IN_LEAP_YEAR_EPICYCLE + year_of_era + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// The number of days from the epoch to the given (valid) date in the proleptic Julian calendar.
+pub fn julian_epoch_day_of(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let cycle = year.div_euclid(YEARS_IN_LEAP_YEAR_CYCLE);
+    let year_of_cycle = year.rem_euclid(YEARS_IN_LEAP_YEAR_CYCLE);
+    let month_from_march = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    cycle * DAYS_IN_LEAP_YEAR_CYCLE + year_of_cycle * DAYS_IN_YEAR + day_of_year
+        - JULIAN_DAYS_FROM_MARCH_ZERO_TO_EPOCH
+}
+
+// The (year, month, day) in the proleptic Julian calendar of the given number of days from the epoch.
+pub fn julian_civil_of_epoch_day(epoch_day: i64) -> (i64, u32, u32) {
+    let day = epoch_day + JULIAN_DAYS_FROM_MARCH_ZERO_TO_EPOCH;
+    let cycle = day.div_euclid(DAYS_IN_LEAP_YEAR_CYCLE);
+    let day_of_cycle = day.rem_euclid(DAYS_IN_LEAP_YEAR_CYCLE);
+    // The leap day is the last day of the cycle, which would otherwise count as the start of a fifth year.
+    let year_of_cycle =
+        (day_of_cycle - day_of_cycle / (DAYS_IN_LEAP_YEAR_CYCLE - 1)) / DAYS_IN_YEAR;
+    let day_of_year = day_of_cycle - year_of_cycle * DAYS_IN_YEAR;
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    } as u32;
+    let year = cycle * YEARS_IN_LEAP_YEAR_CYCLE + year_of_cycle + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -0,0 +1,99 @@
+use proptest::prelude::*;
+
+use crate::civil::*;
+
+proptest! {
+    #[test]
+    fn epoch_day_round_trip(epoch_day in -10_000_000_000i64..10_000_000_000) {
+        let (year, month, day) = civil_of_epoch_day(epoch_day);
+
+        prop_assert!((1..=12).contains(&month));
+        prop_assert!(day >= 1 && day <= days_in_month(year, month));
+        prop_assert_eq!(epoch_day, epoch_day_of(year, month, day));
+    }
+}
+
+proptest! {
+    #[test]
+    fn next_epoch_day_is_next_date(epoch_day in -10_000_000_000i64..10_000_000_000) {
+        let (year, month, day) = civil_of_epoch_day(epoch_day);
+
+        let expected = if day < days_in_month(year, month) {
+            (year, month, day + 1)
+        } else if month < 12 {
+            (year, month + 1, 1)
+        } else {
+            (year + 1, 1, 1)
+        };
+        prop_assert_eq!(expected, civil_of_epoch_day(epoch_day + 1));
+    }
+}
+
+proptest! {
+    #[test]
+    fn known_dates((epoch_day, date) in prop_oneof![
+        Just((0, (1970, 1, 1))),
+        Just((-1, (1969, 12, 31))),
+        Just((11_016, (2000, 2, 29))),
+        Just((-719_528, (0, 1, 1))),
+        Just((-719_529, (-1, 12, 31))),
+        Just((2_932_896, (9999, 12, 31))),
+    ]) {
+        prop_assert_eq!(date, civil_of_epoch_day(epoch_day));
+        prop_assert_eq!(epoch_day, epoch_day_of(date.0, date.1, date.2));
+    }
+}
+
+proptest! {
+    #[test]
+    fn julian_epoch_day_round_trip(epoch_day in -10_000_000_000i64..10_000_000_000) {
+        let (year, month, day) = julian_civil_of_epoch_day(epoch_day);
+
+        prop_assert!((1..=12).contains(&month));
+        prop_assert!((1..=31).contains(&day));
+        prop_assert_eq!(epoch_day, julian_epoch_day_of(year, month, day));
+    }
+}
+
+proptest! {
+    #[test]
+    fn julian_next_epoch_day_is_next_date(epoch_day in -10_000_000_000i64..10_000_000_000) {
+        let (year, month, day) = julian_civil_of_epoch_day(epoch_day);
+
+        // Every fourth year is a leap year, with no exception for centuries.
+        let days_in_month = match month {
+            2 if year % 4 == 0 => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        let expected = if day < days_in_month {
+            (year, month, day + 1)
+        } else if month < 12 {
+            (year, month + 1, 1)
+        } else {
+            (year + 1, 1, 1)
+        };
+        prop_assert_eq!(expected, julian_civil_of_epoch_day(epoch_day + 1));
+    }
+}
+
+proptest! {
+    #[test]
+    fn julian_known_dates((julian, gregorian) in prop_oneof![
+        // The Gregorian reform: Julian 1582-10-05 would have been Gregorian 1582-10-15.
+        Just(((1582, 10, 5), (1582, 10, 15))),
+        Just(((1582, 10, 4), (1582, 10, 14))),
+        Just(((1752, 9, 3), (1752, 9, 14))),
+        Just(((1900, 2, 29), (1900, 3, 13))),
+        Just(((1969, 12, 19), (1970, 1, 1))),
+        Just(((200, 3, 1), (200, 3, 1))),
+        Just(((0, 3, 1), (0, 2, 28))),
+        Just(((-100, 2, 29), (-100, 2, 26))),
+    ]) {
+        let epoch_day = epoch_day_of(gregorian.0, gregorian.1, gregorian.2);
+
+        prop_assert_eq!(julian, julian_civil_of_epoch_day(epoch_day));
+        prop_assert_eq!(epoch_day, julian_epoch_day_of(julian.0, julian.1, julian.2));
+    }
+}
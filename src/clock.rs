@@ -0,0 +1,70 @@
+use crate::Instant;
+
+#[cfg(test)]
+pub mod fixed;
+
+/// A source of the current instant.
+///
+/// Like `java.time.Clock`, this lets code that needs the current time take it as a parameter,
+/// so that tests can substitute a [`FixedClock`](struct.FixedClock.html) for the [`SystemClock`](struct.SystemClock.html).
+pub trait Clock {
+    /// Gets the current instant.
+    fn instant(&self) -> Instant;
+}
+
+/// A clock reading the system clock, through [`Instant::now`](struct.Instant.html#method.now).
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// A clock reading JavaScript's `Date.now()`, for browser builds, where the system clock is unavailable.
+///
+/// `Date.now()` is in whole milliseconds, and like the system clock may jump if the user changes the time.
+/// The monotonic `performance.now()` is not used, as it is only reachable through `web-sys`,
+/// and is relative to the page load rather than the epoch.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct JsClock;
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl Clock for JsClock {
+    fn instant(&self) -> Instant {
+        Instant::from_js_millis(js_sys::Date::now())
+            .expect("Date.now() is a finite time value within the safe range")
+    }
+}
+
+/// A clock that always returns the same instant, such as for tests.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FixedClock {
+    instant: Instant,
+}
+
+impl FixedClock {
+    /// Obtains a clock that always returns the specified instant.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant the clock returns.
+    pub fn new(instant: Instant) -> FixedClock {
+        FixedClock { instant }
+    }
+}
+
+impl Clock for FixedClock {
+    fn instant(&self) -> Instant {
+        self.instant
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn instant(&self) -> Instant {
+        (**self).instant()
+    }
+}
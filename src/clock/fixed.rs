@@ -0,0 +1,51 @@
+use proptest::prelude::*;
+
+use crate::{Clock, Duration, FixedClock, Instant};
+
+fn by_value(clock: impl Clock) -> Instant {
+    clock.instant()
+}
+
+proptest! {
+    #[test]
+    fn fixed_clock(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let clock = FixedClock::new(instant);
+
+        prop_assert_eq!(instant, clock.instant());
+        let shared: &dyn Clock = &clock;
+        prop_assert_eq!(instant, by_value(shared));
+    }
+}
+
+proptest! {
+    #[test]
+    fn elapsed_and_remaining((instant, now, elapsed, remaining) in prop_oneof![
+        // Past, future, and equal.
+        Just((Instant::of_epoch_second(1_000), Instant::of_epoch_second(1_090), Duration::of_seconds(90), Duration::of_seconds(-90))),
+        Just((Instant::of_epoch_second(1_090), Instant::of_epoch_second(1_000), Duration::of_seconds(-90), Duration::of_seconds(90))),
+        Just((Instant::of_epoch_second(1_000), Instant::of_epoch_second(1_000), Duration::ZERO, Duration::ZERO)),
+        Just((Instant::of_epoch_second_and_adjustment(0, 500_000_000), Instant::EPOCH, Duration::of_millis(-500), Duration::of_millis(500))),
+        // Saturating near the extremes.
+        Just((Instant::MIN, Instant::MAX, Duration::MAX, Duration::MIN)),
+        Just((Instant::MAX, Instant::MIN, Duration::MIN, Duration::MAX)),
+        Just((Instant::MIN, Instant::EPOCH, Duration::MAX, Duration::MIN)),
+        Just((Instant::MIN, Instant::of_epoch_second(-1), Duration::of_seconds(i64::MAX), Duration::of_seconds(-i64::MAX))),
+    ]) {
+        let clock = FixedClock::new(now);
+
+        prop_assert_eq!(elapsed, instant.elapsed(&clock));
+        prop_assert_eq!(remaining, instant.remaining_until(&clock));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn elapsed_std() {
+    let start = Instant::now();
+
+    let elapsed = start.elapsed_std();
+
+    assert!(elapsed >= Duration::ZERO, "{}", elapsed);
+    assert!(elapsed < Duration::of_seconds(60), "{}", elapsed);
+}
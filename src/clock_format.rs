@@ -0,0 +1,92 @@
+use core::fmt;
+
+use crate::constants::*;
+use crate::digits::{pad_without_precision, Digits};
+use crate::seconds_nanos::*;
+use crate::Duration;
+
+/// A duration rendered as clock time, such as `01:23:45.678`, as used by video tooling and SRT subtitles.
+///
+/// This is created by [`Duration::format_clock`](struct.Duration.html#method.format_clock).
+/// Hours are at least two digits, and are never rolled over into days, so 100 hours is `100:00:00`.
+/// Negative durations have a single leading minus, as in `-00:01:30`.
+///
+/// By default the fraction of the second is omitted when zero, and otherwise written without trailing zeros;
+/// [`fraction_digits`](#method.fraction_digits) fixes the number of digits instead.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ClockFormat {
+    duration: Duration,
+    fraction_digits: Option<u32>,
+}
+
+impl ClockFormat {
+    pub(crate) fn new(duration: Duration) -> ClockFormat {
+        ClockFormat {
+            duration,
+            fraction_digits: None,
+        }
+    }
+
+    /// Fixes the number of digits in the fraction of the second, such as 3 for the milliseconds of `00:00:01.500`.
+    ///
+    /// Digits beyond those requested are truncated, not rounded, so the seconds shown never exceed the duration.
+    /// Zero digits omits the fraction (and the decimal point) entirely.
+    ///
+    /// # Parameters
+    ///  - `digits`: the number of fraction digits, from 0 to 9.
+    ///
+    /// # Panics
+    /// - if `digits` is greater than 9.
+    pub fn fraction_digits(self, digits: u32) -> ClockFormat {
+        assert!(digits <= 9, "fraction digits must be at most 9");
+        ClockFormat {
+            fraction_digits: Some(digits),
+            ..self
+        }
+    }
+
+    fn clock_digits(&self) -> Digits {
+        let (negative, magnitude_seconds, magnitude_nanos) =
+            sign_and_magnitude(self.duration.seconds(), self.duration.nano());
+        let hours = magnitude_seconds / SECONDS_IN_HOUR as u64;
+        let minutes = magnitude_seconds % SECONDS_IN_HOUR as u64 / SECONDS_IN_MINUTE as u64;
+        let seconds = magnitude_seconds % SECONDS_IN_MINUTE as u64;
+
+        let mut digits = Digits::new();
+        if negative {
+            digits.push(b'-');
+        }
+        if hours < 10 {
+            digits.push(b'0');
+        }
+        digits.push_u64(hours);
+        digits.push(b':');
+        digits.push_2(minutes as u32);
+        digits.push(b':');
+        digits.push_2(seconds as u32);
+        match self.fraction_digits {
+            None if magnitude_nanos == 0 => {}
+            None => {
+                digits.push(b'.');
+                digits.push_fraction(magnitude_nanos);
+            }
+            Some(0) => {}
+            Some(count) => {
+                digits.push(b'.');
+                digits.push_fraction_digits(magnitude_nanos, count);
+            }
+        }
+        digits
+    }
+}
+
+impl fmt::Display for ClockFormat {
+    /// Formats the duration as clock time, such as `01:23:45.678`.
+    ///
+    /// Width, fill, and alignment are honored as for a string.
+    /// A precision is ignored, rather than truncating the text; use [`fraction_digits`](#method.fraction_digits)
+    /// to fix the number of fraction digits.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        pad_without_precision(f, self.clock_digits().as_str())
+    }
+}
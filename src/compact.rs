@@ -0,0 +1,138 @@
+//! A variable-length encoding of durations for bandwidth-constrained protocols.
+//!
+//! The first byte holds a 3-bit unit tag in its high bits, a continuation bit, and the low 4 bits of the amount.
+//! Each following byte holds a continuation bit in its high bit and the next 7 bits of the amount.
+//! The amount is zigzag encoded, so that small negative amounts are as short as small positive ones.
+
+use core::ops::Deref;
+
+use crate::seconds_nanos::*;
+use crate::{DecodeError, Unit};
+
+// Unit tags are the position of the unit in this table.
+const UNITS: [Unit; 7] = [
+    Unit::Nanos,
+    Unit::Micros,
+    Unit::Millis,
+    Unit::Seconds,
+    Unit::Minutes,
+    Unit::Hours,
+    Unit::Days,
+];
+
+// The most negative duration is -2^63 seconds, a little over -2^92 nanoseconds, so 94 bits when zigzag encoded:
+// 4 bits in the first byte, and 7 in each of the 13 after it.
+const LENGTH_MAX: usize = 14;
+
+const FIRST_PAYLOAD_BITS: u32 = 4;
+const PAYLOAD_BITS: u32 = 7;
+const FIRST_CONTINUATION: u8 = 0x10;
+const CONTINUATION: u8 = 0x80;
+
+/// The bytes of a compact encoding, held inline without allocating.
+///
+/// This is created by [`Duration::to_compact`](struct.Duration.html#method.to_compact),
+/// and dereferences to the encoded bytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CompactBytes {
+    bytes: [u8; LENGTH_MAX],
+    len: u8,
+}
+
+impl CompactBytes {
+    /// Gets the maximum length, in bytes, of any compact encoding.
+    pub const fn len_max() -> usize {
+        LENGTH_MAX
+    }
+}
+
+impl Deref for CompactBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+}
+
+impl AsRef<[u8]> for CompactBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+// Encodes a normalized seconds/nanos pair in the coarsest unit that represents it exactly.
+pub fn encode(seconds: i64, nanos: u32) -> CompactBytes {
+    let total = total_nanos(seconds, nanos);
+    let (tag, unit) = UNITS
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, unit)| total % i128::from(unit.nanos()) == 0)
+        .expect("every value is a whole number of nanoseconds");
+    let amount = total / i128::from(unit.nanos());
+    let mut remaining = ((amount << 1) ^ (amount >> 127)) as u128;
+
+    let mut encoded = CompactBytes {
+        bytes: [0; LENGTH_MAX],
+        len: 1,
+    };
+    encoded.bytes[0] = (tag as u8) << 5 | (remaining & 0x0F) as u8;
+    remaining >>= FIRST_PAYLOAD_BITS;
+    if remaining != 0 {
+        encoded.bytes[0] |= FIRST_CONTINUATION;
+    }
+    while remaining != 0 {
+        let mut byte = (remaining & 0x7F) as u8;
+        remaining >>= PAYLOAD_BITS;
+        if remaining != 0 {
+            byte |= CONTINUATION;
+        }
+        encoded.bytes[usize::from(encoded.len)] = byte;
+        encoded.len += 1;
+    }
+    encoded
+}
+
+// Decodes an encoding in any unit, requiring the bytes to hold exactly one value with no redundant trailing bytes.
+pub fn decode(bytes: &[u8]) -> Result<(i64, u32), DecodeError> {
+    let first = *bytes.first().ok_or(DecodeError::InvalidLength {
+        expected: 1,
+        actual: 0,
+    })?;
+    let unit = *UNITS
+        .get(usize::from(first >> 5))
+        .ok_or(DecodeError::UnknownUnit(first >> 5))?;
+
+    let mut zigzag = u128::from(first & 0x0F);
+    let mut continues = first & FIRST_CONTINUATION != 0;
+    let mut len = 1;
+    let mut shift = FIRST_PAYLOAD_BITS;
+    while continues {
+        if len == LENGTH_MAX {
+            return Err(DecodeError::OutOfRange);
+        }
+        let byte = *bytes.get(len).ok_or(DecodeError::InvalidLength {
+            expected: len + 1,
+            actual: bytes.len(),
+        })?;
+        continues = byte & CONTINUATION != 0;
+        if !continues && byte == 0 {
+            return Err(DecodeError::InvalidPadding);
+        }
+        zigzag |= u128::from(byte & 0x7F) << shift;
+        shift += PAYLOAD_BITS;
+        len += 1;
+    }
+    if bytes.len() != len {
+        return Err(DecodeError::InvalidLength {
+            expected: len,
+            actual: bytes.len(),
+        });
+    }
+
+    let amount = (zigzag >> 1) as i128 ^ -((zigzag & 1) as i128);
+    amount
+        .checked_mul(i128::from(unit.nanos()))
+        .and_then(of_total_nanos_checked)
+        .ok_or(DecodeError::OutOfRange)
+}
@@ -1,16 +1,22 @@
+pub const NANOSECONDS_IN_MICROSECOND: i64 =
+    NANOSECONDS_IN_MILLISECOND / MICROSECONDS_IN_MILLISECOND;
 pub const NANOSECONDS_IN_MILLISECOND: i64 = NANOSECONDS_IN_SECOND / MILLISECONDS_IN_SECOND;
 pub const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
 pub const NANOSECONDS_IN_MINUTE: i64 = SECONDS_IN_MINUTE * NANOSECONDS_IN_SECOND;
 pub const NANOSECONDS_IN_HOUR: i64 = MINUTES_IN_HOUR * NANOSECONDS_IN_MINUTE;
 pub const NANOSECONDS_IN_DAY: i64 = HOURS_IN_DAY * NANOSECONDS_IN_HOUR;
+pub const MICROSECONDS_IN_MILLISECOND: i64 = 1_000;
+pub const MICROSECONDS_IN_SECOND: i64 = MICROSECONDS_IN_MILLISECOND * MILLISECONDS_IN_SECOND;
 pub const MILLISECONDS_IN_SECOND: i64 = 1_000;
 pub const SECONDS_IN_MINUTE: i64 = 60;
 pub const SECONDS_IN_HOUR: i64 = SECONDS_IN_MINUTE * MINUTES_IN_HOUR;
 pub const SECONDS_IN_DAY: i64 = SECONDS_IN_HOUR * HOURS_IN_DAY;
 pub const MINUTES_IN_HOUR: i64 = 60;
+#[allow(dead_code)]
 pub const MINUTES_IN_DAY: i64 = MINUTES_IN_HOUR * HOURS_IN_DAY;
 pub const HOURS_IN_DAY: i64 = 24;
 
+#[allow(dead_code)]
 pub const MAX_INSTANT_YEAR: i64 = 1_000_000_000;
 pub const DAYS_IN_YEAR: i64 = 365;
 pub const DAYS_IN_LONG_YEAR: i64 = 366;
@@ -24,6 +30,13 @@ pub const YEARS_IN_LEAP_YEAR_EPICYCLE: i64 = 4 * YEARS_IN_LONG_LEAP_YEAR_CYCLE;
 pub const DAYS_IN_LEAP_YEAR_EPICYCLE: i64 =
     (YEARS_IN_LEAP_YEAR_EPICYCLE / YEARS_IN_LONG_LEAP_YEAR_CYCLE) * DAYS_IN_LONG_LEAP_YEAR_CYCLE
         + 1;
+// The estimated lengths of years and months, as used by `java.time.temporal.ChronoUnit`.
+// The Gregorian calendar has 146,097 days in each 400 year cycle, so the average year is 365.2425 days,
+// or exactly 31,556,952 seconds. The average month is a twelfth of that, 30.436875 days, or exactly 2,629,746 seconds.
+pub const SECONDS_IN_ESTIMATED_YEAR: i64 =
+    DAYS_IN_LEAP_YEAR_EPICYCLE * SECONDS_IN_DAY / YEARS_IN_LEAP_YEAR_EPICYCLE;
+pub const SECONDS_IN_ESTIMATED_MONTH: i64 = SECONDS_IN_ESTIMATED_YEAR / MONTHS_IN_YEAR;
+pub const MONTHS_IN_YEAR: i64 = 12;
 pub const EPOCH_OFFSET_FROM_ZERO_YEARS: i64 = 1970;
 pub const EPOCH_OFFSET_FROM_ZERO_DAYS: i64 = (2000 / YEARS_IN_LEAP_YEAR_EPICYCLE)
     * DAYS_IN_LEAP_YEAR_EPICYCLE
@@ -31,8 +44,27 @@ pub const EPOCH_OFFSET_FROM_ZERO_DAYS: i64 = (2000 / YEARS_IN_LEAP_YEAR_EPICYCLE
     // Leap years from 1970 to 2000:
     // 1972, 1976, 1980, 1984, 1988, 1992, 1996
     - 7;
+#[allow(dead_code)]
 pub const EPOCH_OFFSET_FROM_ZERO_SECONDS: i64 = EPOCH_OFFSET_FROM_ZERO_DAYS * SECONDS_IN_DAY;
+#[allow(dead_code)]
 pub const YEARS_IN_DECADE: i64 = 10;
 pub const YEARS_IN_CENTURY: i64 = 100;
+#[allow(dead_code)]
 pub const YEARS_IN_MILLENNIUM: i64 = 1000;
 pub const DAYS_IN_WEEK_ISO: i64 = 7;
+
+// Enough for the hours of the longest duration, 2562047788015215.
+pub const FIXED_ISO_HOUR_DIGITS: usize = 16;
+
+// Enough for every biased epoch second, up to `u64::MAX`, 18446744073709551615.
+pub const SORTABLE_SECONDS_DIGITS: usize = 20;
+
+// The largest integer a JavaScript number holds exactly, `Number.MAX_SAFE_INTEGER`.
+#[cfg_attr(not(feature = "wasm"), allow(dead_code))]
+pub const JS_MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+// The most fractional bits of fixed point seconds, so that the fraction times a billion fits in an `i128`.
+pub const FIXED_POINT_FRACTIONAL_BITS_MAX: u32 = 96;
+
+// The largest offset from UTC, as for `java.time.ZoneOffset`, which keeps the hours of an offset to two digits.
+pub const OFFSET_SECONDS_MAX: i64 = 18 * SECONDS_IN_HOUR;
@@ -0,0 +1,44 @@
+use crate::seconds_nanos::*;
+use crate::Duration;
+
+#[cfg(test)]
+pub mod utilization;
+
+/// The processor time used by a process or thread, split into time in user code and time in the system (kernel).
+///
+/// This only combines durations that have already been measured, such as from `getrusage`,
+/// for comparing against the elapsed wall-clock time.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct CpuTime {
+    /// The time spent running user code.
+    pub user: Duration,
+    /// The time spent in the system on behalf of the user code.
+    pub system: Duration,
+}
+
+impl CpuTime {
+    /// Gets the total of the user and system time.
+    ///
+    /// # Panics
+    /// - if the total would overflow a duration.
+    pub fn total(&self) -> Duration {
+        self.user + self.system
+    }
+
+    /// Gets the processor time as a fraction of the elapsed wall-clock time, such as `1.5` for 150%.
+    ///
+    /// Work spread across several processors may use more processor time than wall-clock time, giving more than `1.0`.
+    /// A zero wall-clock time gives `f64::INFINITY`, unless the processor time is also zero, which gives `0.0`.
+    ///
+    /// # Parameters
+    ///  - `wall`: the elapsed wall-clock time.
+    pub fn utilization(&self, wall: Duration) -> f64 {
+        let total = total_nanos(self.user.seconds(), self.user.nano())
+            + total_nanos(self.system.seconds(), self.system.nano());
+        if total == 0 {
+            0.0
+        } else {
+            total as f64 / total_nanos(wall.seconds(), wall.nano()) as f64
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use proptest::prelude::*;
+
+use crate::{CpuTime, Duration};
+
+proptest! {
+    #[test]
+    fn total((user, system, expected) in prop_oneof![
+        Just((Duration::ZERO, Duration::ZERO, Duration::ZERO)),
+        Just((Duration::of_millis(1_500), Duration::of_millis(250), Duration::of_millis(1_750))),
+        Just((Duration::of_nanos(999_999_999), Duration::of_nanos(1), Duration::of_seconds(1))),
+    ]) {
+        prop_assert_eq!(expected, CpuTime { user, system }.total());
+    }
+}
+
+proptest! {
+    #[test]
+    fn utilization((user, system, wall, expected) in prop_oneof![
+        Just((Duration::of_millis(750), Duration::of_millis(250), Duration::of_seconds(2), 0.5)),
+        Just((Duration::of_seconds(3), Duration::of_seconds(1), Duration::of_seconds(2), 2.0)),
+        Just((Duration::of_millis(1), Duration::ZERO, Duration::of_millis(4), 0.25)),
+        Just((Duration::ZERO, Duration::ZERO, Duration::of_seconds(1), 0.0)),
+        Just((Duration::ZERO, Duration::ZERO, Duration::ZERO, 0.0)),
+        Just((Duration::of_nanos(1), Duration::ZERO, Duration::ZERO, f64::INFINITY)),
+        // The total is never formed as a duration, so it cannot overflow.
+        Just((Duration::MAX, Duration::MAX, Duration::MAX, 2.0)),
+    ]) {
+        prop_assert_eq!(expected, CpuTime { user, system }.utilization(wall));
+    }
+}
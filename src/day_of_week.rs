@@ -0,0 +1,129 @@
+use crate::constants::*;
+
+#[cfg(test)]
+pub mod cycle;
+
+// The first of January 1970 was a Thursday.
+const EPOCH_DAY_OF_WEEK: i64 = 3;
+
+const DAYS: [DayOfWeek; 7] = [
+    DayOfWeek::Monday,
+    DayOfWeek::Tuesday,
+    DayOfWeek::Wednesday,
+    DayOfWeek::Thursday,
+    DayOfWeek::Friday,
+    DayOfWeek::Saturday,
+    DayOfWeek::Sunday,
+];
+
+/// A day of the week, such as Tuesday.
+///
+/// Like `java.time.DayOfWeek`, the days are ordered by the ISO-8601 standard, from Monday to Sunday.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DayOfWeek {
+    /// Monday, day 1.
+    Monday,
+    /// Tuesday, day 2.
+    Tuesday,
+    /// Wednesday, day 3.
+    Wednesday,
+    /// Thursday, day 4.
+    Thursday,
+    /// Friday, day 5.
+    Friday,
+    /// Saturday, day 6.
+    Saturday,
+    /// Sunday, day 7.
+    Sunday,
+}
+
+impl DayOfWeek {
+    /// Obtains a DayOfWeek from its ISO-8601 number, from 1 (Monday) to 7 (Sunday).
+    ///
+    /// # Parameters
+    ///  - `day_of_week`: the number of the day.
+    ///
+    /// # Panics
+    /// - if `day_of_week` is not from 1 to 7.
+    pub fn of(day_of_week: u32) -> DayOfWeek {
+        assert!(
+            (1..=7).contains(&day_of_week),
+            "day of week must be from 1 to 7"
+        );
+        DAYS[day_of_week as usize - 1]
+    }
+
+    /// Gets the ISO-8601 number of this day, from 1 (Monday) to 7 (Sunday).
+    pub fn value(self) -> u32 {
+        self as u32 + 1
+    }
+
+    /// Gets the day of the week that is the specified number of days after this one.
+    ///
+    /// # Parameters
+    ///  - `days`: the number of days to add, positive or negative.
+    pub fn plus(self, days: i64) -> DayOfWeek {
+        DAYS[(self as i64 + days.rem_euclid(DAYS_IN_WEEK_ISO)).rem_euclid(DAYS_IN_WEEK_ISO)
+            as usize]
+    }
+
+    /// Checks if this day is Saturday or Sunday.
+    pub fn is_weekend(self) -> bool {
+        self >= DayOfWeek::Saturday
+    }
+
+    pub(crate) fn of_epoch_day(epoch_day: i64) -> DayOfWeek {
+        DayOfWeek::Monday.plus(epoch_day.rem_euclid(DAYS_IN_WEEK_ISO) + EPOCH_DAY_OF_WEEK)
+    }
+}
+
+// Counts the weekdays (Monday to Friday) from the start day, inclusive, to the end day, exclusive.
+pub fn weekdays_between(start_epoch_day: i64, end_epoch_day: i64) -> i64 {
+    let days = end_epoch_day - start_epoch_day;
+    let (weeks, remainder) = (days / DAYS_IN_WEEK_ISO, days % DAYS_IN_WEEK_ISO);
+    let first = DayOfWeek::of_epoch_day(start_epoch_day);
+    let partial = (0..remainder)
+        .filter(|&offset| !first.plus(offset).is_weekend())
+        .count() as i64;
+    weeks * 5 + partial
+}
+
+// Counts the days that fall on the day of the week from the start day, inclusive, to the end day, exclusive.
+pub fn day_of_week_between(
+    start_epoch_day: i64,
+    end_epoch_day: i64,
+    day_of_week: DayOfWeek,
+) -> i64 {
+    let days = end_epoch_day - start_epoch_day;
+    let (weeks, remainder) = (days / DAYS_IN_WEEK_ISO, days % DAYS_IN_WEEK_ISO);
+    // How many days after the start the first such day is, from 0 to 6.
+    let first = (day_of_week as i64 - DayOfWeek::of_epoch_day(start_epoch_day) as i64)
+        .rem_euclid(DAYS_IN_WEEK_ISO);
+    weeks + i64::from(first < remainder)
+}
+
+// Numbers the week containing a day of a month or year, counting the day from 1, as `java.time.temporal.WeekFields` does.
+// Week 1 is the first week with at least the minimal number of days in the period; any days before it are in week 0.
+pub fn week_of_period(
+    day_of_period: u32,
+    day_of_week: DayOfWeek,
+    first_day_of_week: DayOfWeek,
+    minimal_days_in_first_week: u8,
+) -> u32 {
+    assert!(
+        (1..=7).contains(&minimal_days_in_first_week),
+        "minimal days in first week must be from 1 to 7"
+    );
+    let day_of_period = i64::from(day_of_period);
+    // How far into its week the day is, from 0 to 6, and so the first day of the period that starts a week, from 0.
+    let localized_day_of_week =
+        (day_of_week as i64 - first_day_of_week as i64).rem_euclid(DAYS_IN_WEEK_ISO);
+    let first_week_start = (day_of_period - 1 - localized_day_of_week).rem_euclid(DAYS_IN_WEEK_ISO);
+    // Week 1 starts at that first week start if it leaves enough days before it, or a week earlier otherwise.
+    let offset = if first_week_start + 1 > i64::from(minimal_days_in_first_week) {
+        DAYS_IN_WEEK_ISO - first_week_start
+    } else {
+        -first_week_start
+    };
+    ((DAYS_IN_WEEK_ISO + offset + day_of_period - 1) / DAYS_IN_WEEK_ISO) as u32
+}
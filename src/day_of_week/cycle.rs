@@ -0,0 +1,55 @@
+use proptest::prelude::*;
+
+use crate::day_of_week::weekdays_between;
+use crate::DayOfWeek;
+
+proptest! {
+    #[test]
+    fn of_epoch_day((epoch_day, expected) in prop_oneof![
+        Just((0, DayOfWeek::Thursday)),
+        Just((-1, DayOfWeek::Wednesday)),
+        Just((-4, DayOfWeek::Sunday)),
+        Just((4, DayOfWeek::Monday)),
+        // 2000-01-01
+        Just((10_957, DayOfWeek::Saturday)),
+        // 2024-02-29
+        Just((19_782, DayOfWeek::Thursday)),
+        // 1900-01-01
+        Just((-25_567, DayOfWeek::Monday)),
+    ]) {
+        prop_assert_eq!(expected, DayOfWeek::of_epoch_day(epoch_day));
+    }
+}
+
+proptest! {
+    #[test]
+    fn value_round_trip(value in 1..=7u32) {
+        prop_assert_eq!(value, DayOfWeek::of(value).value());
+    }
+}
+
+proptest! {
+    #[test]
+    fn plus(day in 1..=7u32, days in prop::num::i64::ANY) {
+        let expected = (i64::from(day) - 1 + days.rem_euclid(7)).rem_euclid(7) + 1;
+
+        prop_assert_eq!(expected as u32, DayOfWeek::of(day).plus(days).value());
+    }
+}
+
+#[test]
+#[should_panic(expected = "day of week must be from 1 to 7")]
+fn of_zero() {
+    DayOfWeek::of(0);
+}
+
+proptest! {
+    #[test]
+    fn weekdays_between_matches_counting(start in -100_000i64..100_000, length in 0..60i64) {
+        let expected = (start..start + length)
+            .filter(|&day| !DayOfWeek::of_epoch_day(day).is_weekend())
+            .count() as i64;
+
+        prop_assert_eq!(expected, weekdays_between(start, start + length));
+    }
+}
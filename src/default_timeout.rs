@@ -0,0 +1,73 @@
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::constants::*;
+use crate::Duration;
+
+#[cfg(test)]
+pub mod concurrency;
+
+static DEFAULT_TIMEOUT: DefaultTimeout = DefaultTimeout::new(DefaultTimeout::INITIAL);
+
+/// Gets the process-wide default timeout, which libraries may read and applications configure.
+///
+/// This starts as [`DefaultTimeout::INITIAL`](struct.DefaultTimeout.html#associatedconstant.INITIAL).
+pub fn default_timeout() -> &'static DefaultTimeout {
+    &DEFAULT_TIMEOUT
+}
+
+/// A timeout that may be read and replaced from any thread, without locking.
+///
+/// The timeout is stored as a single atomic count of nanoseconds, so it can only hold durations from zero
+/// to `u64::MAX` nanoseconds (about 584 years): setting a negative duration stores zero,
+/// and setting a longer duration stores that maximum.
+#[derive(Debug)]
+pub struct DefaultTimeout {
+    nanos: AtomicU64,
+}
+
+impl DefaultTimeout {
+    /// The initial value of the process-wide [`default_timeout`](fn.default_timeout.html), 30 seconds.
+    pub const INITIAL: Duration = Duration::of_seconds(30);
+
+    /// Creates a timeout holding the specified duration.
+    ///
+    /// # Parameters
+    ///  - `timeout`: the initial timeout, which is clamped as for [`set`](#method.set).
+    pub const fn new(timeout: Duration) -> DefaultTimeout {
+        DefaultTimeout {
+            nanos: AtomicU64::new(encode(timeout)),
+        }
+    }
+
+    /// Gets the current timeout.
+    pub fn get(&self) -> Duration {
+        decode(self.nanos.load(Ordering::Relaxed))
+    }
+
+    /// Replaces the timeout, clamping it to between zero and `u64::MAX` nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `timeout`: the new timeout.
+    pub fn set(&self, timeout: Duration) {
+        self.nanos.store(encode(timeout), Ordering::Relaxed);
+    }
+}
+
+const fn encode(timeout: Duration) -> u64 {
+    if timeout.is_negative() {
+        return 0;
+    }
+    let nanos = timeout.seconds() as i128 * NANOSECONDS_IN_SECOND as i128 + timeout.nano() as i128;
+    if nanos > u64::MAX as i128 {
+        u64::MAX
+    } else {
+        nanos as u64
+    }
+}
+
+fn decode(nanos: u64) -> Duration {
+    Duration::of_seconds_and_adjustment(
+        (nanos / NANOSECONDS_IN_SECOND as u64) as i64,
+        (nanos % NANOSECONDS_IN_SECOND as u64) as i64,
+    )
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use std::thread;
+
+use proptest::prelude::*;
+
+use crate::{default_timeout, DefaultTimeout, Duration};
+
+proptest! {
+    #[test]
+    fn set_get(seconds in 0..1_000_000_000i64, nanos in 0..1_000_000_000i64) {
+        let timeout = DefaultTimeout::new(Duration::ZERO);
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        timeout.set(duration);
+
+        prop_assert_eq!(duration, timeout.get());
+    }
+}
+
+proptest! {
+    #[test]
+    fn set_clamps((duration, expected) in prop_oneof![
+        Just((Duration::of_nanos(-1), Duration::ZERO)),
+        Just((Duration::MIN, Duration::ZERO)),
+        Just((Duration::MAX, Duration::of_seconds_and_adjustment(18_446_744_073, 709_551_615))),
+        Just((Duration::of_seconds_and_adjustment(18_446_744_073, 709_551_615), Duration::of_seconds_and_adjustment(18_446_744_073, 709_551_615))),
+        Just((Duration::of_seconds_and_adjustment(18_446_744_073, 709_551_616), Duration::of_seconds_and_adjustment(18_446_744_073, 709_551_615))),
+    ]) {
+        prop_assert_eq!(expected, DefaultTimeout::new(duration).get());
+    }
+}
+
+#[test]
+fn concurrent_set_get_is_consistent() {
+    let values: Vec<Duration> = (1..=8)
+        .map(|index| Duration::of_seconds_and_adjustment(index * 1_000_003, index * 111_111_111))
+        .collect();
+    let timeout = Arc::new(DefaultTimeout::new(values[0]));
+
+    let threads: Vec<_> = values
+        .iter()
+        .map(|&value| {
+            let timeout = Arc::clone(&timeout);
+            let values = values.clone();
+            thread::spawn(move || {
+                for _ in 0..10_000 {
+                    timeout.set(value);
+                    let read = timeout.get();
+                    assert!(values.contains(&read), "torn read {:?}", read);
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert!(values.contains(&timeout.get()));
+}
+
+#[test]
+fn global_default_timeout() {
+    assert_eq!(DefaultTimeout::INITIAL, Duration::of_seconds(30));
+
+    let previous = default_timeout().get();
+    default_timeout().set(Duration::of_millis(1_500));
+    assert_eq!(Duration::of_millis(1_500), default_timeout().get());
+    default_timeout().set(previous);
+}
@@ -0,0 +1,169 @@
+//! Fast numeric formatting into a stack buffer, bypassing the generic `core::fmt` integer machinery.
+
+use core::fmt::{self, Write};
+use core::str;
+
+const DIGIT_PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+// Enough for the longest rendering of any value in this crate.
+const CAPACITY: usize = 64;
+
+pub struct Digits {
+    bytes: [u8; CAPACITY],
+    len: usize,
+}
+
+impl Digits {
+    pub fn new() -> Digits {
+        Digits {
+            bytes: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    // Only ASCII may be pushed, which keeps the buffer valid UTF-8.
+    pub fn push(&mut self, byte: u8) {
+        debug_assert!(byte.is_ascii());
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    pub fn push_str(&mut self, text: &str) {
+        debug_assert!(text.is_ascii());
+        self.bytes[self.len..self.len + text.len()].copy_from_slice(text.as_bytes());
+        self.len += text.len();
+    }
+
+    pub fn push_u64(&mut self, mut value: u64) {
+        // Write the digits right-aligned into a scratch buffer, two at a time, then copy them over.
+        let mut scratch = [0u8; 20];
+        let mut start = scratch.len();
+        while value >= 100 {
+            let pair = (value % 100) as usize * 2;
+            value /= 100;
+            start -= 2;
+            scratch[start..start + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+        }
+        if value >= 10 {
+            let pair = value as usize * 2;
+            start -= 2;
+            scratch[start..start + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+        } else {
+            start -= 1;
+            scratch[start] = b'0' + value as u8;
+        }
+        let digits = &scratch[start..];
+        self.bytes[self.len..self.len + digits.len()].copy_from_slice(digits);
+        self.len += digits.len();
+    }
+
+    // Pushes a value left-padded with zeros to at least `width` digits.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub fn push_u64_padded(&mut self, value: u64, width: usize) {
+        let start = self.len;
+        self.push_u64(value);
+        let written = self.len - start;
+        if written < width {
+            let padding = width - written;
+            self.bytes.copy_within(start..self.len, start + padding);
+            self.bytes[start..start + padding].fill(b'0');
+            self.len += padding;
+        }
+    }
+
+    // Pushes a value below 100 as exactly two digits.
+    pub fn push_2(&mut self, value: u32) {
+        debug_assert!(value < 100);
+        let pair = value as usize * 2;
+        self.bytes[self.len..self.len + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+        self.len += 2;
+    }
+
+    // Pushes a value below 10,000 as exactly four digits.
+    pub fn push_4(&mut self, value: u32) {
+        debug_assert!(value < 10_000);
+        self.push_2(value / 100);
+        self.push_2(value % 100);
+    }
+
+    // Pushes nanoseconds as the nine digits of a fraction of a second.
+    pub fn push_9(&mut self, nanos: u32) {
+        debug_assert!(nanos < 1_000_000_000);
+        let (first, rest) = (nanos / 100_000_000, nanos % 100_000_000);
+        self.push(b'0' + first as u8);
+        self.push_2(rest / 1_000_000);
+        self.push_2(rest / 10_000 % 100);
+        self.push_2(rest / 100 % 100);
+        self.push_2(rest % 100);
+    }
+
+    // Pushes a normalized seconds/nanos pair as signed seconds with exactly nine fraction digits, such as `-0.500000000`.
+    #[cfg(any(feature = "defmt", test))]
+    pub fn push_fixed_seconds(&mut self, seconds: i64, nanos: u32) {
+        let (negative, seconds, nanos) = crate::seconds_nanos::sign_and_magnitude(seconds, nanos);
+        if negative {
+            self.push(b'-');
+        }
+        self.push_u64(seconds);
+        self.push(b'.');
+        self.push_9(nanos);
+    }
+
+    // Pushes (non-zero) nanoseconds as a fraction of a second, without trailing zeros.
+    pub fn push_fraction(&mut self, nanos: u32) {
+        self.push_9(nanos);
+        while self.bytes[self.len - 1] == b'0' {
+            self.len -= 1;
+        }
+    }
+
+    // Pushes nanoseconds as exactly `count` (1 to 9) leading digits of a fraction of a second, truncating the rest.
+    pub fn push_fraction_digits(&mut self, nanos: u32, count: u32) {
+        debug_assert!((1..=9).contains(&count));
+        self.push_9(nanos);
+        self.len -= 9 - count as usize;
+    }
+
+    // Pushes (non-zero) nanoseconds as a fraction of a second, in as many groups of three digits as needed.
+    pub fn push_fraction_groups(&mut self, nanos: u32) {
+        self.push_9(nanos);
+        if nanos % 1_000_000 == 0 {
+            self.len -= 6;
+        } else if nanos % 1_000 == 0 {
+            self.len -= 3;
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only ASCII is ever pushed into the buffer.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+}
+
+// Writes the (ASCII) text honoring the width, fill, and alignment of the formatter, as `Formatter::pad` does,
+// but without truncating to the precision, for types that interpret the precision themselves.
+pub fn pad_without_precision(f: &mut fmt::Formatter, text: &str) -> fmt::Result {
+    let padding = match f.width() {
+        Some(width) if width > text.len() => width - text.len(),
+        _ => return f.write_str(text),
+    };
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
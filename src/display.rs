@@ -0,0 +1,107 @@
+use core::fmt;
+
+use crate::digits::pad_without_precision;
+use crate::{Duration, Instant};
+
+// Writes a value for `Debug` as its human-readable rendering followed by its raw fields,
+// such as `Duration(PT1S, seconds=1, nanos=0)`.
+// The alternate form puts each on its own line, indented as derived implementations do.
+pub fn debug_with_fields(
+    f: &mut fmt::Formatter,
+    name: &str,
+    rendering: &dyn fmt::Display,
+    fields: &[(&str, &dyn fmt::Display)],
+) -> fmt::Result {
+    if f.alternate() {
+        writeln!(f, "{}(", name)?;
+        writeln!(f, "    {},", rendering)?;
+        for (field, value) in fields {
+            writeln!(f, "    {}={},", field, value)?;
+        }
+        f.write_str(")")
+    } else {
+        write!(f, "{}({}", name, rendering)?;
+        for (field, value) in fields {
+            write!(f, ", {}={}", field, value)?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// An instant rendered lazily as an ISO-8601 timestamp at an offset from UTC, such as `2007-12-03T11:15:30+01:00`.
+///
+/// This is created by [`Instant::display`](struct.Instant.html#method.display).
+/// The rendering is only computed when written, and never allocates,
+/// so it costs next to nothing when passed to a logging macro whose level is disabled.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InstantDisplay {
+    instant: Instant,
+    offset_seconds: i32,
+}
+
+impl InstantDisplay {
+    pub(crate) fn new(instant: Instant, offset_seconds: i32) -> InstantDisplay {
+        InstantDisplay {
+            instant,
+            offset_seconds,
+        }
+    }
+}
+
+impl fmt::Display for InstantDisplay {
+    /// Formats the instant at the offset, such as `2007-12-03T11:15:30+01:00`.
+    ///
+    /// Width, fill, alignment, and precision are honored as for `Instant`'s `Display`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fraction_digits = f.precision().map(|precision| precision.min(9) as u32);
+        pad_without_precision(
+            f,
+            self.instant
+                .iso_digits(fraction_digits, self.offset_seconds)
+                .as_str(),
+        )
+    }
+}
+
+/// A duration rendered lazily as an ISO-8601 duration, such as `PT8M20.345S`.
+///
+/// This is created by [`Duration::display`](struct.Duration.html#method.display).
+/// The rendering is only computed when written, and never allocates.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DurationDisplay {
+    duration: Duration,
+}
+
+impl DurationDisplay {
+    pub(crate) fn new(duration: Duration) -> DurationDisplay {
+        DurationDisplay { duration }
+    }
+}
+
+impl fmt::Display for DurationDisplay {
+    /// Formats the duration, such as `PT8M20.345S`.
+    ///
+    /// Width, fill, alignment, and precision (as the fraction digits of the seconds) are honored
+    /// as for `Duration`'s `Display`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.duration, f)
+    }
+}
+
+/// A way of rendering a duration as text, for [`Duration::format`](struct.Duration.html#method.format).
+///
+/// Each style is also available from its own method on `Duration`; this gathers them in one place.
+/// The examples are for a duration of 90.5 seconds.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DurationStyle {
+    /// An ISO-8601 duration, as for `Display`, such as `PT1M30.5S`.
+    Iso8601,
+    /// Clock time, as for [`Duration::format_clock`](struct.Duration.html#method.format_clock), such as `00:01:30.5`.
+    Clock,
+    /// An English phrase relative to the present, as for [`Duration::humanize`](struct.Duration.html#method.humanize),
+    /// such as `in 2 minutes`.
+    Human,
+    /// Plain decimal seconds, as for [`Duration::to_decimal_seconds`](struct.Duration.html#method.to_decimal_seconds),
+    /// such as `90.5`.
+    DecimalSeconds,
+}
@@ -0,0 +1,1637 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::TryFromIntError;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+use crate::compact;
+use crate::constants::*;
+use crate::digits::{pad_without_precision, Digits};
+use crate::display::debug_with_fields;
+use crate::parse::{parse_clock, parse_decimal_seconds, parse_fixed_iso, parse_iso_duration};
+use crate::rounding::*;
+use crate::seconds_nanos::*;
+use crate::wire;
+use crate::{
+    ClockFormat, CompactBytes, DecodeError, DurationDisplay, ParseError, Parts, RelativeTime,
+    RelativeTimeOptions, SliceOverflowError, SplitEvenly, Tolerance, Unit,
+};
+#[cfg(feature = "std")]
+use crate::{DecomposeError, DurationStyle};
+
+#[cfg(test)]
+pub mod arithmetic;
+#[cfg(test)]
+pub mod clock;
+#[cfg(test)]
+pub mod conversion;
+#[cfg(test)]
+pub mod encoding;
+#[cfg(test)]
+pub mod extremes;
+#[cfg(test)]
+pub mod factories;
+#[cfg(test)]
+pub mod format;
+#[cfg(test)]
+pub mod invariants;
+#[cfg(test)]
+pub mod parse;
+#[cfg(test)]
+pub mod parts;
+
+/// A time-based amount of time, such as '34.5 seconds'.
+///
+/// Unlike [`std::time::Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html), this duration may be negative.
+/// A duration is stored as a number of seconds and a nanosecond adjustment, with the nanoseconds always moving
+/// the value farther along the timeline: -0.5 seconds is stored as -1 seconds and 500,000,000 nanoseconds.
+///
+/// Converting from a bare integer (`From<i64>`, `From<i32>`, and `TryFrom<u64>`) always interprets it as whole seconds.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Duration {
+    seconds: i64,
+    nanosecond_of_second: u32,
+}
+
+impl Duration {
+    /// Constant for a duration of zero.
+    pub const ZERO: Duration = Duration {
+        seconds: 0,
+        nanosecond_of_second: 0,
+    };
+
+    /// Constant for the most negative duration.
+    pub const MIN: Duration = Duration {
+        seconds: i64::MIN,
+        nanosecond_of_second: 0,
+    };
+
+    /// Constant for the most positive duration.
+    pub const MAX: Duration = Duration {
+        seconds: i64::MAX,
+        nanosecond_of_second: NANOSECONDS_IN_SECOND as u32 - 1,
+    };
+
+    /// Obtains a Duration representing an estimated number of years, such as for a cache lifetime of "1 year".
+    ///
+    /// A year of the calendar varies in length, so this uses the average Gregorian year of 365.2425 days,
+    /// exactly 31,556,952 seconds, as `java.time.temporal.ChronoUnit.YEARS` estimates it.
+    /// Adding the result to an instant will not in general land on the same date of a later year.
+    ///
+    /// # Parameters
+    ///  - `years`: the number of years, positive or negative.
+    ///
+    /// # Panics
+    /// - if the number of years would overflow the duration.
+    pub fn of_years_estimated(years: i64) -> Duration {
+        Duration::checked_of_years_estimated(years).expect("years would overflow duration")
+    }
+
+    /// Obtains a Duration representing an estimated number of years, or `None` if the duration would overflow.
+    ///
+    /// See [`of_years_estimated`](#method.of_years_estimated) for details.
+    ///
+    /// # Parameters
+    ///  - `years`: the number of years, positive or negative.
+    pub fn checked_of_years_estimated(years: i64) -> Option<Duration> {
+        Duration::of_unit_seconds(years, SECONDS_IN_ESTIMATED_YEAR)
+    }
+
+    /// Obtains a Duration representing an estimated number of months, such as for a cache lifetime of "1 month".
+    ///
+    /// A month of the calendar varies in length, so this uses a twelfth of the average Gregorian year, 30.436875 days,
+    /// exactly 2,629,746 seconds, as `java.time.temporal.ChronoUnit.MONTHS` estimates it.
+    /// Adding the result to an instant will not in general land on the same day of a later month.
+    ///
+    /// # Parameters
+    ///  - `months`: the number of months, positive or negative.
+    ///
+    /// # Panics
+    /// - if the number of months would overflow the duration.
+    pub fn of_months_estimated(months: i64) -> Duration {
+        Duration::checked_of_months_estimated(months).expect("months would overflow duration")
+    }
+
+    /// Obtains a Duration representing an estimated number of months, or `None` if the duration would overflow.
+    ///
+    /// See [`of_months_estimated`](#method.of_months_estimated) for details.
+    ///
+    /// # Parameters
+    ///  - `months`: the number of months, positive or negative.
+    pub fn checked_of_months_estimated(months: i64) -> Option<Duration> {
+        Duration::of_unit_seconds(months, SECONDS_IN_ESTIMATED_MONTH)
+    }
+
+    /// Obtains a Duration representing a number of standard weeks of seven 24 hour days.
+    ///
+    /// # Parameters
+    ///  - `weeks`: the number of weeks, positive or negative.
+    ///
+    /// # Panics
+    /// - if the number of weeks would overflow the duration.
+    pub fn of_weeks(weeks: i64) -> Duration {
+        Duration::checked_of_weeks(weeks).expect("weeks would overflow duration")
+    }
+
+    /// Obtains a Duration representing a number of standard weeks, or `None` if the duration would overflow.
+    ///
+    /// See [`of_weeks`](#method.of_weeks) for details.
+    ///
+    /// # Parameters
+    ///  - `weeks`: the number of weeks, positive or negative.
+    pub fn checked_of_weeks(weeks: i64) -> Option<Duration> {
+        Duration::of_unit_seconds(weeks, DAYS_IN_WEEK_ISO * SECONDS_IN_DAY)
+    }
+
+    /// Obtains a Duration representing a number of standard 24 hour days.
+    ///
+    /// # Parameters
+    ///  - `days`: the number of days, positive or negative.
+    ///
+    /// # Panics
+    /// - if the number of days would overflow the duration.
+    pub fn of_days(days: i64) -> Duration {
+        Duration::checked_of_days(days).expect("days would overflow duration")
+    }
+
+    /// Obtains a Duration representing a number of standard 24 hour days, or `None` if the duration would overflow.
+    ///
+    /// See [`of_days`](#method.of_days) for details.
+    ///
+    /// # Parameters
+    ///  - `days`: the number of days, positive or negative.
+    pub fn checked_of_days(days: i64) -> Option<Duration> {
+        Duration::of_unit_seconds(days, SECONDS_IN_DAY)
+    }
+
+    /// Obtains a Duration representing a number of standard hours.
+    ///
+    /// # Parameters
+    ///  - `hours`: the number of hours, positive or negative.
+    ///
+    /// # Panics
+    /// - if the number of hours would overflow the duration.
+    pub fn of_hours(hours: i64) -> Duration {
+        Duration::checked_of_hours(hours).expect("hours would overflow duration")
+    }
+
+    /// Obtains a Duration representing a number of standard hours, or `None` if the duration would overflow.
+    ///
+    /// See [`of_hours`](#method.of_hours) for details.
+    ///
+    /// # Parameters
+    ///  - `hours`: the number of hours, positive or negative.
+    pub fn checked_of_hours(hours: i64) -> Option<Duration> {
+        Duration::of_unit_seconds(hours, SECONDS_IN_HOUR)
+    }
+
+    /// Obtains a Duration representing a number of standard minutes.
+    ///
+    /// # Parameters
+    ///  - `minutes`: the number of minutes, positive or negative.
+    ///
+    /// # Panics
+    /// - if the number of minutes would overflow the duration.
+    pub fn of_minutes(minutes: i64) -> Duration {
+        Duration::checked_of_minutes(minutes).expect("minutes would overflow duration")
+    }
+
+    /// Obtains a Duration representing a number of standard minutes, or `None` if the duration would overflow.
+    ///
+    /// See [`of_minutes`](#method.of_minutes) for details.
+    ///
+    /// # Parameters
+    ///  - `minutes`: the number of minutes, positive or negative.
+    pub fn checked_of_minutes(minutes: i64) -> Option<Duration> {
+        Duration::of_unit_seconds(minutes, SECONDS_IN_MINUTE)
+    }
+
+    /// Obtains a Duration representing a number of seconds.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the number of seconds, positive or negative.
+    pub const fn of_seconds(seconds: i64) -> Duration {
+        Duration {
+            seconds,
+            nanosecond_of_second: 0,
+        }
+    }
+
+    /// Obtains a Duration representing a number of seconds and an adjustment in nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the number of seconds, positive or negative.
+    ///  - `nano_adjustment`: the nanosecond adjustment to the number of seconds, positive or negative.
+    ///
+    /// # Panics
+    /// - if the adjusted amount of seconds would overflow the duration.
+    pub fn of_seconds_and_adjustment(seconds: i64, nano_adjustment: i64) -> Duration {
+        Duration::checked_of_seconds_and_adjustment(seconds, nano_adjustment)
+            .expect("seconds would overflow duration")
+    }
+
+    /// Obtains a Duration representing a number of seconds and an adjustment in nanoseconds,
+    /// or `None` if the adjusted amount of seconds would overflow the duration.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the number of seconds, positive or negative.
+    ///  - `nano_adjustment`: the nanosecond adjustment to the number of seconds, positive or negative.
+    pub fn checked_of_seconds_and_adjustment(
+        seconds: i64,
+        nano_adjustment: i64,
+    ) -> Option<Duration> {
+        of_seconds_and_adjustment_checked(seconds, nano_adjustment)
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Obtains a Duration representing a number of milliseconds.
+    ///
+    /// # Parameters
+    ///  - `milliseconds`: the number of milliseconds, positive or negative.
+    pub fn of_millis(milliseconds: i64) -> Duration {
+        Duration::of_seconds_and_nanos(seconds_and_nanos_of_units(
+            milliseconds,
+            MILLISECONDS_IN_SECOND,
+        ))
+    }
+
+    /// Obtains a Duration representing a number of milliseconds held in an `i32`, such as a Java `int` timeout.
+    ///
+    /// This is the same as [`of_millis`](#method.of_millis), widening the count.
+    ///
+    /// # Parameters
+    ///  - `milliseconds`: the number of milliseconds, positive or negative.
+    pub fn of_millis_i32(milliseconds: i32) -> Duration {
+        Duration::of_millis(i64::from(milliseconds))
+    }
+
+    /// Obtains a Duration representing a number of microseconds.
+    ///
+    /// # Parameters
+    ///  - `microseconds`: the number of microseconds, positive or negative.
+    pub fn of_micros(microseconds: i64) -> Duration {
+        Duration::of_seconds_and_nanos(seconds_and_nanos_of_units(
+            microseconds,
+            MICROSECONDS_IN_SECOND,
+        ))
+    }
+
+    /// Obtains a Duration representing a number of nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `nanoseconds`: the number of nanoseconds, positive or negative.
+    pub fn of_nanos(nanoseconds: i64) -> Duration {
+        Duration::of_seconds_and_nanos(seconds_and_nanos(nanoseconds))
+    }
+
+    /// Obtains the period of a frequency in hertz, such as `PT0.016666667S` for 60 Hz,
+    /// or `None` if the frequency is not positive (or is NaN), or so low that the period would overflow.
+    ///
+    /// The period is rounded to the nearest nanosecond, so frequencies above 2 GHz give `Duration::ZERO`,
+    /// as does an infinite frequency.
+    ///
+    /// # Parameters
+    ///  - `hz`: the frequency, in cycles per second.
+    pub fn from_rate_per_second(hz: f64) -> Option<Duration> {
+        if hz.is_nan() || hz <= 0.0 {
+            return None;
+        }
+        let period = 1.0 / hz;
+        // The largest representable period, less half a nanosecond for rounding, is just under 2^63 seconds.
+        if period >= 9_223_372_036_854_775_808.0 {
+            return None;
+        }
+        // The period is positive, so truncating casts floor it; the f64 has fewer significant digits than
+        // the nanoseconds of periods above 2^53 nanoseconds, which then have no fraction.
+        let seconds = period as u64;
+        let nanos = ((period - seconds as f64) * NANOSECONDS_IN_SECOND as f64 + 0.5) as i64;
+        Duration::of_seconds(seconds as i64).checked_add(Duration::of_nanos(nanos))
+    }
+
+    /// Obtains a Duration by parsing an ISO-8601 duration, such as `PT8M20.345S`.
+    ///
+    /// This accepts the forms of java.time's `Duration.parse`: `PnDTnHnMn.nS`, where each of the days, hours, minutes,
+    /// and seconds may be omitted (but not all of them, and the `T` must be followed by at least one),
+    /// and the letters may be upper or lower case. Days are exactly 24 hours.
+    /// Each component may have its own sign, as produced by `Display` (such as `PT-1M-0.5S`),
+    /// and the whole duration may have a leading sign, which negates the total of the components.
+    /// The two signs combine, so `-PT1H` and `PT-1H` are both negative one hour, while `-PT-1H` is positive one hour.
+    /// The seconds may have a fraction of up to nine digits, after a `.` or `,`, which takes the sign of the seconds.
+    ///
+    /// # Parameters
+    ///  - `text`: the duration to parse.
+    ///
+    /// # Errors
+    /// - if the text is not an ISO-8601 duration in the accepted form.
+    /// - if the text has years or months (such as `P1Y` or `P2M`), which have no exact duration,
+    ///   with a kind of `CalendarDesignator`; see [`of_years_estimated`](#method.of_years_estimated)
+    ///   and [`of_months_estimated`](#method.of_months_estimated) for estimates.
+    /// - if any component, or the total, would overflow.
+    pub fn parse(text: &str) -> Result<Duration, ParseError> {
+        parse_iso_duration(text.as_bytes()).map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Obtains a Duration by parsing plain decimal seconds, such as `8.5` or `-0.000000001`.
+    ///
+    /// The seconds may have a leading `+` or `-`, and are followed by an optional fraction of up to nine digits.
+    /// The sign applies to the whole value, so `-0.5` is minus half a second.
+    /// This is exact, unlike going through `f64`, and is the inverse of
+    /// [`to_decimal_seconds`](#method.to_decimal_seconds).
+    ///
+    /// # Parameters
+    ///  - `text`: the seconds to parse.
+    ///
+    /// # Errors
+    /// - if the text is not a valid number of seconds.
+    /// - if the duration would overflow.
+    pub fn from_decimal_seconds(text: &str) -> Result<Duration, ParseError> {
+        parse_decimal_seconds(text.as_bytes()).map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Obtains a Duration by parsing clock time, such as `01:23:45.678`.
+    ///
+    /// The accepted forms are `MM:SS` and `H:MM:SS`, each with an optional fraction of the second of up to nine digits,
+    /// and an optional leading `-` for negative durations.
+    /// Hours may have any number of digits, and are not limited to a day; minutes and seconds must be at most 59.
+    ///
+    /// # Parameters
+    ///  - `text`: the clock time to parse.
+    ///
+    /// # Errors
+    /// - if the text is not in one of the accepted forms.
+    /// - if the minutes or seconds are greater than 59.
+    /// - if the duration would overflow.
+    pub fn parse_clock(text: &str) -> Result<Duration, ParseError> {
+        parse_clock(text.as_bytes()).map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Obtains a Duration by parsing the fixed-width rendering of [`to_fixed_iso_string`](#method.to_fixed_iso_string),
+    /// such as `PT0000000000000001H05M03.250000000S`.
+    ///
+    /// Every field must have exactly its fixed width, and a negative duration has a single leading `-`.
+    ///
+    /// # Parameters
+    ///  - `text`: the fixed-width duration to parse.
+    ///
+    /// # Errors
+    /// - if the text is not in the fixed-width form.
+    /// - if the minutes or seconds are greater than 59.
+    /// - if the duration would overflow.
+    pub fn parse_fixed_iso(text: &str) -> Result<Duration, ParseError> {
+        parse_fixed_iso(text.as_bytes()).map(Duration::of_seconds_and_nanos)
+    }
+
+    fn of_unit_seconds(amount: i64, seconds_in_unit: i64) -> Option<Duration> {
+        amount
+            .checked_mul(seconds_in_unit)
+            .map(Duration::of_seconds)
+    }
+
+    // Used by the `duration!` macro, which calls this in a const item so that a literal out of range fails to compile.
+    #[doc(hidden)]
+    pub const fn __of_literal_nanos(total_nanos: i128) -> Duration {
+        let seconds = total_nanos.div_euclid(NANOSECONDS_IN_SECOND as i128);
+        if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+            panic!("duration literal is out of range");
+        }
+        Duration {
+            seconds: seconds as i64,
+            nanosecond_of_second: total_nanos.rem_euclid(NANOSECONDS_IN_SECOND as i128) as u32,
+        }
+        .debug_assert_valid()
+    }
+
+    fn of_seconds_and_nanos(seconds_and_nanos: (i64, u32)) -> Duration {
+        let (seconds, nanos) = seconds_and_nanos;
+        Duration {
+            seconds,
+            nanosecond_of_second: nanos,
+        }
+        .debug_assert_valid()
+    }
+
+    // Checks, in debug builds only, that the nanosecond of second is less than one second,
+    // which every constructor and operation must maintain; every other method relies on it.
+    pub(crate) const fn debug_assert_valid(self) -> Duration {
+        debug_assert!(
+            self.nanosecond_of_second < NANOSECONDS_IN_SECOND as u32,
+            "nanosecond of second out of range"
+        );
+        self
+    }
+
+    /// Gets the number of seconds in this duration.
+    ///
+    /// This is the whole seconds part of the duration, with [`nano()`] always moving the value farther along the timeline.
+    ///
+    /// [`nano()`]: struct.Duration.html#method.nano
+    pub const fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Gets the number of nanoseconds within the second in this duration.
+    ///
+    /// This is always positive, and is added to [`seconds()`].
+    ///
+    /// [`seconds()`]: struct.Duration.html#method.seconds
+    pub const fn nano(&self) -> u32 {
+        self.nanosecond_of_second
+    }
+
+    /// Checks if this duration is zero length.
+    pub const fn is_zero(&self) -> bool {
+        self.seconds == 0 && self.nanosecond_of_second == 0
+    }
+
+    /// Checks if this duration is negative, excluding zero.
+    pub const fn is_negative(&self) -> bool {
+        self.seconds < 0
+    }
+
+    /// Gets the number of whole days in the magnitude of this duration.
+    ///
+    /// The `*_part` accessors break the magnitude of the duration down into components, as for custom formatting;
+    /// check [`is_negative`](#method.is_negative) for the sign, so `PT-1.3S` has a seconds part of 1
+    /// and a millis part of 300.
+    pub fn days_part(&self) -> u64 {
+        self.magnitude().0 / SECONDS_IN_DAY as u64
+    }
+
+    /// Gets the hours part of the magnitude of this duration, from 0 to 23.
+    pub fn hours_part(&self) -> u32 {
+        (self.magnitude().0 % SECONDS_IN_DAY as u64 / SECONDS_IN_HOUR as u64) as u32
+    }
+
+    /// Gets the minutes part of the magnitude of this duration, from 0 to 59.
+    pub fn minutes_part(&self) -> u32 {
+        (self.magnitude().0 % SECONDS_IN_HOUR as u64 / SECONDS_IN_MINUTE as u64) as u32
+    }
+
+    /// Gets the seconds part of the magnitude of this duration, from 0 to 59.
+    pub fn seconds_part(&self) -> u32 {
+        (self.magnitude().0 % SECONDS_IN_MINUTE as u64) as u32
+    }
+
+    /// Gets the whole milliseconds of the fraction of the second of the magnitude of this duration, from 0 to 999.
+    pub fn millis_part(&self) -> u32 {
+        self.magnitude().1 / NANOSECONDS_IN_MILLISECOND as u32
+    }
+
+    /// Gets the whole microseconds of the fraction of the second of the magnitude of this duration, from 0 to 999,999.
+    pub fn micros_part(&self) -> u32 {
+        self.magnitude().1 / NANOSECONDS_IN_MICROSECOND as u32
+    }
+
+    /// Gets the nanoseconds of the fraction of the second of the magnitude of this duration, from 0 to 999,999,999.
+    ///
+    /// Unlike [`nano`](#method.nano), this is of the magnitude, so `PT-1.3S` has a nanos part of 300,000,000.
+    pub fn nanos_part(&self) -> u32 {
+        self.magnitude().1
+    }
+
+    /// Gets an iterator over the non-zero components of the magnitude of this duration, from days to nanoseconds.
+    ///
+    /// See [`Parts`](struct.Parts.html) for how the components are split.
+    pub fn parts(&self) -> Parts {
+        Parts::of(*self)
+    }
+
+    /// Checks if this duration is within the tolerance of another, such as for assertions in tests.
+    ///
+    /// # Parameters
+    ///  - `other`: the duration to compare to.
+    ///  - `tolerance`: the largest difference allowed, inclusive.
+    pub fn approx_eq(&self, other: Duration, tolerance: Tolerance) -> bool {
+        tolerance.allows(self.seconds_and_nanos(), other.seconds_and_nanos())
+    }
+
+    fn magnitude(&self) -> (u64, u32) {
+        let (_, seconds, nanos) = sign_and_magnitude(self.seconds, self.nanosecond_of_second);
+        (seconds, nanos)
+    }
+
+    /// Converts this duration to a number of seconds, with the nanoseconds as the fraction.
+    ///
+    /// Large durations lose precision, as an `f64` only has 53 bits of significand.
+    pub fn as_secs_f64(&self) -> f64 {
+        total_nanos(self.seconds, self.nanosecond_of_second) as f64 / NANOSECONDS_IN_SECOND as f64
+    }
+
+    /// Converts this duration, as a period, to a frequency in hertz, such as 60.0 for `PT0.016666667S`.
+    ///
+    /// This is the reciprocal of [`as_secs_f64`](#method.as_secs_f64), so a zero duration gives `f64::INFINITY`,
+    /// and a negative duration gives a negative rate.
+    pub fn as_rate_per_second(&self) -> f64 {
+        // Dividing into the nanoseconds directly avoids rounding the seconds first.
+        NANOSECONDS_IN_SECOND as f64 / total_nanos(self.seconds, self.nanosecond_of_second) as f64
+    }
+
+    /// Obtains a Duration from a number of seconds in binary fixed point, such as for DSP-style code avoiding floats.
+    ///
+    /// The value is in Q-format: the low `fractional_bits` bits are the fraction of a second,
+    /// so `0x8000` with 16 fractional bits is half a second. Negative values are in two's complement as usual.
+    /// The fraction is rounded to the nearest nanosecond, with ties rounding to the even nanosecond.
+    ///
+    /// # Parameters
+    ///  - `value`: the seconds, in fixed point.
+    ///  - `fractional_bits`: the number of bits of the value that are the fraction, up to 96.
+    ///
+    /// # Panics
+    /// - if `fractional_bits` is more than 96.
+    /// - if the seconds would overflow a duration.
+    pub fn from_fixed_point(value: i128, fractional_bits: u32) -> Duration {
+        assert!(
+            fractional_bits <= FIXED_POINT_FRACTIONAL_BITS_MAX,
+            "fractional bits must be at most 96"
+        );
+        Duration::checked_from_fixed_point(value, fractional_bits)
+            .expect("fixed point would overflow duration")
+    }
+
+    /// Obtains a Duration from a number of seconds in binary fixed point,
+    /// or `None` if `fractional_bits` is more than 96, or the seconds would overflow a duration.
+    ///
+    /// See [`from_fixed_point`](#method.from_fixed_point) for the format.
+    ///
+    /// # Parameters
+    ///  - `value`: the seconds, in fixed point.
+    ///  - `fractional_bits`: the number of bits of the value that are the fraction, up to 96.
+    pub fn checked_from_fixed_point(value: i128, fractional_bits: u32) -> Option<Duration> {
+        if fractional_bits > FIXED_POINT_FRACTIONAL_BITS_MAX {
+            return None;
+        }
+        let seconds = i64::try_from(value >> fractional_bits).ok()?;
+        let fraction = value & ((1 << fractional_bits) - 1);
+        // The fraction is less than 2^96, so multiplying by a billion (less than 2^30) cannot overflow.
+        let nanos = divide_rounding(
+            fraction * i128::from(NANOSECONDS_IN_SECOND),
+            1 << fractional_bits,
+            RoundingMode::HalfEven,
+        );
+        Duration::of_seconds(seconds).checked_add(Duration::of_nanos(nanos as i64))
+    }
+
+    /// Converts this duration to a number of seconds in binary fixed point, such as for DSP-style code avoiding floats.
+    ///
+    /// See [`from_fixed_point`](#method.from_fixed_point) for the format.
+    /// The nanoseconds are rounded to the nearest fraction, with ties rounding to the even fraction,
+    /// so with fewer than 30 fractional bits (where a step is longer than a nanosecond) some precision is lost.
+    /// With 30 or more, converting back gives the same duration.
+    ///
+    /// # Parameters
+    ///  - `fractional_bits`: the number of bits of the result that are the fraction, up to 96.
+    ///
+    /// # Panics
+    /// - if `fractional_bits` is more than 96.
+    /// - if the result would overflow an `i128`, which depends on both the duration and the fractional bits;
+    ///   every duration fits with up to 64 fractional bits.
+    pub fn to_fixed_point(&self, fractional_bits: u32) -> i128 {
+        assert!(
+            fractional_bits <= FIXED_POINT_FRACTIONAL_BITS_MAX,
+            "fractional bits must be at most 96"
+        );
+        self.checked_to_fixed_point(fractional_bits)
+            .expect("duration would overflow fixed point")
+    }
+
+    /// Converts this duration to a number of seconds in binary fixed point,
+    /// or `None` if `fractional_bits` is more than 96, or the result would overflow an `i128`.
+    ///
+    /// See [`to_fixed_point`](#method.to_fixed_point) for details.
+    ///
+    /// # Parameters
+    ///  - `fractional_bits`: the number of bits of the result that are the fraction, up to 96.
+    pub fn checked_to_fixed_point(&self, fractional_bits: u32) -> Option<i128> {
+        if fractional_bits > FIXED_POINT_FRACTIONAL_BITS_MAX {
+            return None;
+        }
+        let fraction = divide_rounding(
+            i128::from(self.nanosecond_of_second) << fractional_bits,
+            i128::from(NANOSECONDS_IN_SECOND),
+            RoundingMode::HalfEven,
+        );
+        i128::from(self.seconds)
+            .checked_mul(1 << fractional_bits)
+            .and_then(|seconds| seconds.checked_add(fraction))
+    }
+
+    /// Converts this duration to the total length in milliseconds.
+    ///
+    /// Any nanoseconds beyond the millisecond are dropped.
+    /// As the nanoseconds always move the value farther along the timeline, this rounds toward negative infinity,
+    /// so `PT-0.0005S` is -1 millisecond; use [`to_millis_rounding`](#method.to_millis_rounding) for other behavior.
+    ///
+    /// # Panics
+    /// - if the number of milliseconds would overflow an `i64`.
+    pub fn to_millis(&self) -> i64 {
+        self.to_millis_rounding(RoundingMode::Floor)
+    }
+
+    /// Converts this duration to the total length in milliseconds, rounding any sub-millisecond nanoseconds as specified.
+    ///
+    /// # Parameters
+    ///  - `mode`: how to round a duration that is not a whole number of milliseconds.
+    ///
+    /// # Panics
+    /// - if the number of milliseconds would overflow an `i64`.
+    pub fn to_millis_rounding(&self, mode: RoundingMode) -> i64 {
+        self.checked_to_millis_rounding(mode)
+            .expect("millis would overflow i64")
+    }
+
+    /// Converts this duration to the total length in milliseconds, rounding toward negative infinity,
+    /// or `None` if the number of milliseconds would overflow an `i64`.
+    ///
+    /// See [`to_millis`](#method.to_millis) for details.
+    pub fn checked_to_millis(&self) -> Option<i64> {
+        self.checked_to_millis_rounding(RoundingMode::Floor)
+    }
+
+    /// Converts this duration to the total length in milliseconds as an `i32`, such as for a Java `int` timeout,
+    /// or `None` if the number of milliseconds would overflow an `i32`, about 24.8 days.
+    ///
+    /// This rounds toward negative infinity, as for [`to_millis`](#method.to_millis).
+    pub fn to_millis_i32(&self) -> Option<i32> {
+        self.checked_to_millis()
+            .and_then(|millis| i32::try_from(millis).ok())
+    }
+
+    /// Converts this duration to the total length in milliseconds, rounding any sub-millisecond nanoseconds as specified,
+    /// or `None` if the number of milliseconds would overflow an `i64`.
+    ///
+    /// # Parameters
+    ///  - `mode`: how to round a duration that is not a whole number of milliseconds.
+    pub fn checked_to_millis_rounding(&self, mode: RoundingMode) -> Option<i64> {
+        i64::try_from(divide_rounding(
+            total_nanos(self.seconds, self.nanosecond_of_second),
+            i128::from(NANOSECONDS_IN_MILLISECOND),
+            mode,
+        ))
+        .ok()
+    }
+
+    /// Converts this duration to the total length in milliseconds, rounding half-way values to the even millisecond.
+    ///
+    /// This is "banker's rounding", the usual default for financial calculations,
+    /// and is symmetric for negative durations: `PT-0.0005S` rounds to 0 milliseconds, and `PT-0.0015S` to -2.
+    ///
+    /// # Panics
+    /// - if the number of milliseconds would overflow an `i64`.
+    pub fn to_millis_banker(&self) -> i64 {
+        self.to_millis_rounding(RoundingMode::HalfEven)
+    }
+
+    /// Gets the number of whole weeks of seven 24 hour days in this duration.
+    ///
+    /// Like the other `num_*` accessors, which follow the naming of chrono's `TimeDelta`,
+    /// this truncates toward zero, so `PT-0.5S` is zero whole units of any size.
+    pub fn num_weeks(&self) -> i64 {
+        self.num_units(DAYS_IN_WEEK_ISO * NANOSECONDS_IN_DAY) as i64
+    }
+
+    /// Gets the number of whole standard 24 hour days in this duration, truncating toward zero.
+    pub fn num_days(&self) -> i64 {
+        self.num_units(NANOSECONDS_IN_DAY) as i64
+    }
+
+    /// Gets the number of whole hours in this duration, truncating toward zero.
+    pub fn num_hours(&self) -> i64 {
+        self.num_units(NANOSECONDS_IN_HOUR) as i64
+    }
+
+    /// Gets the number of whole minutes in this duration, truncating toward zero.
+    pub fn num_minutes(&self) -> i64 {
+        self.num_units(NANOSECONDS_IN_MINUTE) as i64
+    }
+
+    /// Gets the number of whole seconds in this duration, truncating toward zero.
+    ///
+    /// This differs from [`seconds`](#method.seconds) for negative durations with a fraction,
+    /// so `PT-1.5S` is -1 whole seconds, but is stored as -2 seconds and 500,000,000 nanoseconds.
+    pub fn num_seconds(&self) -> i64 {
+        // The truncated seconds are never farther from zero than the stored seconds, so they always fit.
+        self.num_units(NANOSECONDS_IN_SECOND) as i64
+    }
+
+    /// Gets the number of whole milliseconds in this duration, truncating toward zero.
+    ///
+    /// This differs from [`to_millis`](#method.to_millis), which rounds toward negative infinity,
+    /// only for negative durations with a fraction of a millisecond.
+    ///
+    /// # Panics
+    /// - if the number of milliseconds would overflow an `i64`.
+    pub fn num_milliseconds(&self) -> i64 {
+        self.to_millis_rounding(RoundingMode::Down)
+    }
+
+    /// Gets the number of whole microseconds in this duration, truncating toward zero,
+    /// or `None` if the number would overflow an `i64`.
+    pub fn num_microseconds(&self) -> Option<i64> {
+        i64::try_from(self.num_units(NANOSECONDS_IN_MICROSECOND)).ok()
+    }
+
+    /// Gets the total number of nanoseconds in this duration, or `None` if the number would overflow an `i64`.
+    pub fn num_nanoseconds(&self) -> Option<i64> {
+        i64::try_from(self.num_units(1)).ok()
+    }
+
+    /// Splits this duration into the whole number of the unit it contains, and the remainder, such as `PT76H` into 3 days and `PT4H`.
+    ///
+    /// The count truncates toward zero, as for the `num_*` accessors,
+    /// so the remainder has the same sign as the duration, and is shorter than the unit.
+    ///
+    /// # Parameters
+    ///  - `unit`: the unit to count.
+    ///
+    /// # Panics
+    /// - if the count would overflow an `i64`, which is only possible for units shorter than a second.
+    pub fn to_unit_and_remainder(self, unit: Unit) -> (i64, Duration) {
+        self.checked_to_unit_and_remainder(unit)
+            .expect("count of unit would overflow i64")
+    }
+
+    /// Splits this duration into the whole number of the unit it contains, and the remainder,
+    /// or `None` if the count would overflow an `i64`.
+    ///
+    /// See [`to_unit_and_remainder`](#method.to_unit_and_remainder) for details.
+    ///
+    /// # Parameters
+    ///  - `unit`: the unit to count.
+    pub fn checked_to_unit_and_remainder(self, unit: Unit) -> Option<(i64, Duration)> {
+        let total = total_nanos(self.seconds, self.nanosecond_of_second);
+        let unit_nanos = i128::from(unit.nanos());
+        let count = i64::try_from(total / unit_nanos).ok()?;
+        // The remainder is shorter than a day, so it always fits.
+        Some((count, Duration::of_nanos((total % unit_nanos) as i64)))
+    }
+
+    /// Returns a copy of this duration truncated toward zero to a whole number of the unit, such as `PT1H30M` to `PT1H` in hours.
+    ///
+    /// This is `self` less the remainder of [`to_unit_and_remainder`](#method.to_unit_and_remainder),
+    /// so a negative duration is truncated toward zero as well, as `PT-1.5S` to `PT-1S` in seconds.
+    ///
+    /// This matches java.time's `Duration.truncatedTo`, except for negative durations a fraction of a second short
+    /// of a whole number of days, such as `PT-23H59M59.5S` (stored as -86,400 seconds and 500,000,000 nanoseconds):
+    /// java.time truncates those toward negative infinity, as `PT-24H` in hours, where this gives `PT-23H`.
+    ///
+    /// # Parameters
+    ///  - `unit`: the unit to truncate to.
+    pub fn truncated_to(self, unit: Unit) -> Duration {
+        let unit_nanos = i128::from(unit.nanos());
+        // Truncating toward zero never increases the length, so the result is always in range.
+        Duration::of_seconds_and_nanos(
+            of_total_nanos_checked(
+                total_nanos(self.seconds, self.nanosecond_of_second) / unit_nanos * unit_nanos,
+            )
+            .expect("truncation is toward zero"),
+        )
+    }
+
+    /// Splits this duration over the units, from largest to smallest, such as `PT90M` into 1 hour and 30 minutes.
+    ///
+    /// Each unit takes as many whole units as fit in what the larger units left over,
+    /// so the last unit takes the rest, truncated to its resolution.
+    /// A negative duration has the same sign on every count, so `PT-90M` is -1 hour and -30 minutes;
+    /// a unit may have a count of zero.
+    ///
+    /// # Parameters
+    ///  - `units`: the units to split the duration over, from largest to smallest.
+    ///
+    /// # Errors
+    /// - if there are no units.
+    /// - if any unit is not smaller than the one before it.
+    /// - if the count of the first unit would overflow an `i64`.
+    #[cfg(feature = "std")]
+    pub fn decompose(&self, units: &[Unit]) -> Result<Vec<(Unit, i64)>, DecomposeError> {
+        if units.is_empty() {
+            return Err(DecomposeError::NoUnits);
+        }
+        if let Some(index) = units.windows(2).position(|pair| pair[0] <= pair[1]) {
+            return Err(DecomposeError::UnitsOutOfOrder(index + 1));
+        }
+        let mut remaining = total_nanos(self.seconds, self.nanosecond_of_second);
+        units
+            .iter()
+            .map(|&unit| {
+                let unit_nanos = i128::from(unit.nanos());
+                let count = remaining / unit_nanos;
+                remaining %= unit_nanos;
+                i64::try_from(count)
+                    .map(|count| (unit, count))
+                    .map_err(|_| DecomposeError::Overflow)
+            })
+            .collect()
+    }
+
+    fn num_units(&self, nanoseconds_in_unit: i64) -> i128 {
+        total_nanos(self.seconds, self.nanosecond_of_second) / i128::from(nanoseconds_in_unit)
+    }
+
+    /// Converts this duration to a standard library duration, or `None` if it is negative.
+    ///
+    /// Every non-negative duration fits, as the standard library duration holds up to `u64::MAX` seconds.
+    pub fn to_std(&self) -> Option<core::time::Duration> {
+        if self.is_negative() {
+            None
+        } else {
+            Some(core::time::Duration::new(
+                self.seconds as u64,
+                self.nanosecond_of_second,
+            ))
+        }
+    }
+
+    /// Packs this duration into a single `u128`, such as for storing in an atomic or passing across FFI.
+    ///
+    /// The seconds are stored in the high 64 bits, biased by 2<sup>63</sup>,
+    /// and the nanoseconds in the low 32 bits, with the bits between them always zero.
+    /// The bias means that comparing the packed values gives the same ordering as comparing the durations.
+    pub const fn to_bits(self) -> u128 {
+        to_bits(self.seconds, self.nanosecond_of_second)
+    }
+
+    /// Unpacks a duration from the representation produced by [`to_bits`](#method.to_bits).
+    ///
+    /// # Errors
+    /// - if any of the padding bits between the seconds and the nanoseconds are set.
+    /// - if the nanoseconds are not less than one second.
+    pub const fn from_bits(bits: u128) -> Result<Duration, DecodeError> {
+        match from_bits(bits) {
+            Ok((seconds, nanosecond_of_second)) => Ok(Duration {
+                seconds,
+                nanosecond_of_second,
+            }
+            .debug_assert_valid()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Gets a hash of this duration that is stable across platforms, versions of this library, and languages, such as for checksums shared with another service.
+    ///
+    /// This is the 64-bit FNV-1a hash of the [`wire`](wire/index.html) encoding of the duration.
+    /// Unlike the `Hash` implementation, the result is part of the documented format, and will not change,
+    /// so the same duration maps to the same shard or bucket in every process and run.
+    /// It is not a cryptographic hash, and offers no resistance to deliberately colliding inputs.
+    pub fn canonical_hash(&self) -> u64 {
+        wire::canonical_hash(self.seconds, self.nanosecond_of_second)
+    }
+
+    /// Encodes this duration in a compact, variable-length form, such as for protocols with a tight byte budget.
+    ///
+    /// The first byte holds a 3-bit unit tag (nanoseconds through days), and the amount follows as a varint.
+    /// The coarsest unit that represents the duration exactly is used,
+    /// so `PT1H` takes a single byte while `PT1.000000001S` takes five.
+    /// No encoding is longer than [`CompactBytes::len_max`](struct.CompactBytes.html#method.len_max).
+    pub fn to_compact(&self) -> CompactBytes {
+        compact::encode(self.seconds, self.nanosecond_of_second)
+    }
+
+    /// Decodes a duration from the form produced by [`to_compact`](#method.to_compact).
+    ///
+    /// Any unit is accepted, not only the coarsest, and decoding is exact.
+    ///
+    /// # Errors
+    /// - if the input is empty, ends part way through the amount, or has bytes after the amount.
+    /// - if the unit tag is not a known unit.
+    /// - if the amount has redundant trailing zero bytes.
+    /// - if the duration would overflow.
+    pub fn from_compact(bytes: &[u8]) -> Result<Duration, DecodeError> {
+        compact::decode(bytes).map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns a copy of this duration with the specified duration added, or `None` if the result would overflow.
+    ///
+    /// # Parameters
+    ///  - `other`: the duration to add, positive or negative.
+    pub fn checked_add(self, other: Duration) -> Option<Duration> {
+        add_seconds_and_nanos_checked(self.seconds_and_nanos(), other.seconds_and_nanos())
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns a copy of this duration with the specified duration added, saturating at the minimum or maximum duration.
+    ///
+    /// # Parameters
+    ///  - `other`: the duration to add, positive or negative.
+    pub fn saturating_add(self, other: Duration) -> Duration {
+        self.checked_add(other).unwrap_or(if other.is_negative() {
+            Duration::MIN
+        } else {
+            Duration::MAX
+        })
+    }
+
+    /// Returns a copy of this duration with the specified duration subtracted, or `None` if the result would overflow.
+    ///
+    /// # Parameters
+    ///  - `other`: the duration to subtract, positive or negative.
+    pub fn checked_sub(self, other: Duration) -> Option<Duration> {
+        sub_seconds_and_nanos_checked(self.seconds_and_nanos(), other.seconds_and_nanos())
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns a copy of this duration with the specified duration subtracted, saturating at the minimum or maximum duration.
+    ///
+    /// # Parameters
+    ///  - `other`: the duration to subtract, positive or negative.
+    pub fn saturating_sub(self, other: Duration) -> Duration {
+        self.checked_sub(other).unwrap_or(if other.is_negative() {
+            Duration::MAX
+        } else {
+            Duration::MIN
+        })
+    }
+
+    /// Adds the specified duration to every duration in the slice, in place.
+    ///
+    /// This either updates every element or, if any element would overflow, leaves the slice unchanged.
+    /// The update is a single pass that only records whether any element overflowed, rather than stopping at it,
+    /// so the common case runs without per-element overflow branches; the slice is restored when one did overflow.
+    ///
+    /// # Parameters
+    ///  - `durations`: the durations to update.
+    ///  - `offset`: the duration to add, positive or negative.
+    ///
+    /// # Errors
+    /// - if adding the offset would overflow any element, identifying the first such element.
+    pub fn add_duration_slice(
+        durations: &mut [Duration],
+        offset: Duration,
+    ) -> Result<(), SliceOverflowError> {
+        add_to_slice_checked(
+            durations,
+            offset.seconds_and_nanos(),
+            |duration| duration.seconds_and_nanos(),
+            Duration::of_seconds_and_nanos,
+        )
+    }
+
+    /// Returns the exact product of this duration and the scalar in nanoseconds, or `None` if it would overflow `i128`.
+    ///
+    /// This is the exact-arithmetic primitive behind the duration multiply variants.
+    /// The product is only out of range of `i128` for durations longer than about 584 years multiplied by a similarly large scalar.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply the duration by, positive or negative.
+    pub fn checked_mul_i128_nanos(self, scalar: i64) -> Option<i128> {
+        total_nanos(self.seconds, self.nanosecond_of_second).checked_mul(i128::from(scalar))
+    }
+
+    /// Returns the exact product of this duration and the scalar in nanoseconds.
+    ///
+    /// This is the exact-arithmetic primitive behind the duration multiply variants.
+    /// Unlike `checked_mul`, the product does not need to fit in a duration,
+    /// so when a multiply overflows the caller can inspect by how much before deciding to saturate, report an error, or store the wider value.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply the duration by, positive or negative.
+    ///
+    /// # Panics
+    /// - if the product would overflow `i128`, which requires a duration longer than about 584 years multiplied by a similarly large scalar.
+    pub fn mul_i128_nanos(self, scalar: i64) -> i128 {
+        self.checked_mul_i128_nanos(scalar)
+            .expect("overflow when multiplying duration nanoseconds")
+    }
+
+    /// Returns a copy of this duration multiplied by the scalar, or `None` if the result would overflow.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply the duration by, positive or negative.
+    pub fn checked_mul(self, scalar: i64) -> Option<Duration> {
+        self.checked_mul_i128_nanos(scalar)
+            .and_then(of_total_nanos_checked)
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns a copy of this duration multiplied by the scalar, saturating at the minimum or maximum duration.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply the duration by, positive or negative.
+    pub fn saturating_mul(self, scalar: i64) -> Duration {
+        self.checked_mul(scalar)
+            .unwrap_or(if self.is_negative() == (scalar < 0) {
+                Duration::MAX
+            } else {
+                Duration::MIN
+            })
+    }
+
+    /// Returns this duration multiplied by the scalar, plus the addend, or `None` if the result would overflow.
+    ///
+    /// The computation is performed with a single overflow check on the final result,
+    /// so an intermediate product that alone would overflow a duration is fine as long as the addend brings it back in range.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply the duration by, positive or negative.
+    ///  - `addend`: the duration to add to the product, positive or negative.
+    pub fn checked_mul_add(self, scalar: i64, addend: Duration) -> Option<Duration> {
+        self.checked_mul_i128_nanos(scalar)
+            .and_then(|product| {
+                product.checked_add(total_nanos(addend.seconds, addend.nanosecond_of_second))
+            })
+            .and_then(of_total_nanos_checked)
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns this duration multiplied by the scalar, plus the addend.
+    ///
+    /// This is useful for accumulating weighted totals, such as `count * unit + running_total`,
+    /// as only the final result needs to be in range.
+    ///
+    /// # Parameters
+    ///  - `scalar`: the value to multiply the duration by, positive or negative.
+    ///  - `addend`: the duration to add to the product, positive or negative.
+    ///
+    /// # Panics
+    /// - if the result would overflow the duration.
+    pub fn mul_add(self, scalar: i64, addend: Duration) -> Duration {
+        self.checked_mul_add(scalar, addend)
+            .expect("overflow when multiplying and adding duration")
+    }
+
+    /// Interpolates linearly from `a` to `b`, as `a + (b - a) * t`, or `None` if `t` is not finite or the result would overflow.
+    ///
+    /// A `t` of 0 gives exactly `a` and a `t` of 1 exactly `b`, and `a` may be longer than `b`.
+    /// A `t` outside of 0 to 1 extrapolates beyond the endpoints; use [`lerp_clamped`](#method.lerp_clamped) to stay between them.
+    /// The difference is taken in whole nanoseconds, and the result is rounded to the nearest nanosecond, with halves away from zero.
+    /// Differences over about 104 days lose precision, as an `f64` only has 53 bits of significand.
+    ///
+    /// # Parameters
+    ///  - `a`: the duration at a `t` of 0.
+    ///  - `b`: the duration at a `t` of 1.
+    ///  - `t`: the fraction of the way from `a` to `b`.
+    pub fn checked_lerp(a: Duration, b: Duration, t: f64) -> Option<Duration> {
+        if !t.is_finite() {
+            return None;
+        }
+        of_total_nanos_checked(Duration::lerp_total_nanos(a, b, t))
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Interpolates linearly from `a` to `b`, as `a + (b - a) * t`, such as for easing an animation.
+    ///
+    /// See [`checked_lerp`](#method.checked_lerp) for the exact endpoints and rounding;
+    /// a `t` outside of 0 to 1 extrapolates beyond the endpoints.
+    ///
+    /// # Parameters
+    ///  - `a`: the duration at a `t` of 0.
+    ///  - `b`: the duration at a `t` of 1.
+    ///  - `t`: the fraction of the way from `a` to `b`.
+    ///
+    /// # Panics
+    /// - if `t` is not finite.
+    /// - if the result would overflow the duration.
+    pub fn lerp(a: Duration, b: Duration, t: f64) -> Duration {
+        assert!(t.is_finite(), "interpolation fraction must be finite");
+        Duration::checked_lerp(a, b, t).expect("overflow when interpolating duration")
+    }
+
+    /// Interpolates linearly from `a` to `b`, as for [`lerp`](#method.lerp), but with `t` clamped to 0 to 1,
+    /// so that the result is always between the endpoints, and can never overflow.
+    ///
+    /// # Parameters
+    ///  - `a`: the duration at a `t` of 0 or less.
+    ///  - `b`: the duration at a `t` of 1 or more.
+    ///  - `t`: the fraction of the way from `a` to `b`.
+    ///
+    /// # Panics
+    /// - if `t` is NaN.
+    pub fn lerp_clamped(a: Duration, b: Duration, t: f64) -> Duration {
+        assert!(!t.is_nan(), "interpolation fraction must not be NaN");
+        let (start, end) = (
+            total_nanos(a.seconds, a.nanosecond_of_second),
+            total_nanos(b.seconds, b.nanosecond_of_second),
+        );
+        // Rounding the difference to an `f64` may overshoot an endpoint by a little, which could overflow at the ends of the range.
+        let nanos = Duration::lerp_total_nanos(a, b, t.clamp(0.0, 1.0))
+            .clamp(start.min(end), start.max(end));
+        Duration::of_seconds_and_nanos(
+            of_total_nanos_checked(nanos).expect("interpolation is between the endpoints"),
+        )
+    }
+
+    fn lerp_total_nanos(a: Duration, b: Duration, t: f64) -> i128 {
+        let (start, end) = (
+            total_nanos(a.seconds, a.nanosecond_of_second),
+            total_nanos(b.seconds, b.nanosecond_of_second),
+        );
+        if t == 1.0 {
+            return end;
+        }
+        let offset = (end - start) as f64 * t;
+        // Casts truncate toward zero (and saturate), so shifting by a half first rounds halves away from zero.
+        let offset = if offset < 0.0 {
+            offset - 0.5
+        } else {
+            offset + 0.5
+        } as i128;
+        start.saturating_add(offset)
+    }
+
+    /// Returns a copy of this duration with the length negated, or `None` if the result would overflow.
+    ///
+    /// As the range of durations is asymmetric, this is only `None` for `Duration::MIN`.
+    pub fn checked_neg(self) -> Option<Duration> {
+        Duration::ZERO.checked_sub(self)
+    }
+
+    /// Returns a copy of this duration with the length negated, saturating at the maximum duration.
+    ///
+    /// As the range of durations is asymmetric, this is only different from negation for `Duration::MIN`.
+    pub fn saturating_neg(self) -> Duration {
+        self.checked_neg().unwrap_or(Duration::MAX)
+    }
+
+    /// Checks if this duration is a whole multiple of the unit, such as a flush interval that must be a multiple of the tick,
+    /// or `None` if the unit is zero.
+    ///
+    /// This is the case exactly when `self % unit` is zero, for positive and negative durations and units alike.
+    ///
+    /// # Parameters
+    ///  - `unit`: the duration to check against, positive or negative.
+    pub fn is_multiple_of(&self, unit: Duration) -> Option<bool> {
+        self.checked_rem(unit).map(|remainder| remainder.is_zero())
+    }
+
+    /// Returns the smallest multiple of the unit that is no less than this duration,
+    /// or `None` if the unit is zero or negative, or the result would overflow.
+    ///
+    /// This rounds towards positive infinity, so `PT-0.5S` with a unit of `PT0.3S` gives `PT-0.3S`.
+    /// For non-positive durations this is the same as `self - self % unit`,
+    /// as the remainder (like integer `%`) takes the sign of this duration.
+    ///
+    /// # Parameters
+    ///  - `unit`: the positive duration to align to.
+    pub fn next_multiple_of(&self, unit: Duration) -> Option<Duration> {
+        let unit = unit.positive_unit_nanos()?;
+        let total = total_nanos(self.seconds, self.nanosecond_of_second);
+        let remainder = total.rem_euclid(unit);
+        let aligned = if remainder == 0 {
+            total
+        } else {
+            total - remainder + unit
+        };
+        of_total_nanos_checked(aligned).map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns the largest multiple of the unit that is no greater than this duration,
+    /// or `None` if the unit is zero or negative, or the result would overflow.
+    ///
+    /// This rounds towards negative infinity, so `PT-0.5S` with a unit of `PT0.3S` gives `PT-0.6S`.
+    /// For non-negative durations this is the same as `self - self % unit`, but not for negative durations:
+    /// the remainder (like integer `%`) takes the sign of this duration, so `self - self % unit` rounds towards zero,
+    /// giving `PT-0.3S` here, which is [`next_multiple_of`](#method.next_multiple_of).
+    /// Rounding towards negative infinity keeps every bucket of [`Instant::truncated_key`](struct.Instant.html#method.truncated_key)
+    /// the same length on both sides of the epoch.
+    ///
+    /// # Parameters
+    ///  - `unit`: the positive duration to align to.
+    pub fn prev_multiple_of(&self, unit: Duration) -> Option<Duration> {
+        let unit = unit.positive_unit_nanos()?;
+        let total = total_nanos(self.seconds, self.nanosecond_of_second);
+        of_total_nanos_checked(total - total.rem_euclid(unit)).map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Returns the remainder of dividing this duration by another, or `None` if the divisor is zero.
+    ///
+    /// As for integer `%`, the remainder has the sign of this duration (the quotient is truncated towards zero).
+    ///
+    /// # Parameters
+    ///  - `divisor`: the duration to divide by, positive or negative.
+    pub fn checked_rem(self, divisor: Duration) -> Option<Duration> {
+        let divisor = total_nanos(divisor.seconds, divisor.nanosecond_of_second);
+        if divisor == 0 {
+            return None;
+        }
+        // The magnitude of the remainder is less than that of the divisor, so it always fits.
+        of_total_nanos_checked(total_nanos(self.seconds, self.nanosecond_of_second) % divisor)
+            .map(Duration::of_seconds_and_nanos)
+    }
+
+    /// Splits this duration into the number of equal chunks, such as for dividing work between workers,
+    /// or `None` if the number of parts is zero.
+    ///
+    /// The chunks sum exactly to this duration: the nanoseconds that do not divide evenly are spread
+    /// one each over the first chunks, so no two chunks differ by more than one nanosecond.
+    /// A negative duration splits into negative chunks, mirroring its magnitude.
+    ///
+    /// # Parameters
+    ///  - `parts`: the number of chunks to split into.
+    pub fn split_evenly(self, parts: u32) -> Option<SplitEvenly> {
+        if parts == 0 {
+            None
+        } else {
+            Some(SplitEvenly::of(self, parts))
+        }
+    }
+
+    fn seconds_and_nanos(self) -> (i64, u32) {
+        (self.seconds, self.nanosecond_of_second)
+    }
+
+    fn positive_unit_nanos(self) -> Option<i128> {
+        Some(total_nanos(self.seconds, self.nanosecond_of_second)).filter(|&nanos| nanos > 0)
+    }
+
+    /// Describes this duration as a signed offset from the present, such as "in 5 minutes" or "3 hours ago".
+    ///
+    /// Positive durations are in the future ("in"), and negative durations in the past ("ago").
+    /// Use `to_string()` on the result for the English phrase.
+    /// This uses the default [`RelativeTimeOptions`](struct.RelativeTimeOptions.html).
+    pub fn humanize(&self) -> RelativeTime {
+        self.humanize_with(&RelativeTimeOptions::default())
+    }
+
+    /// Describes this duration as a signed offset from the present, using the specified thresholds.
+    ///
+    /// # Parameters
+    ///  - `options`: the thresholds for "just now" and each unit.
+    pub fn humanize_with(&self, options: &RelativeTimeOptions) -> RelativeTime {
+        RelativeTime::of_duration(*self, options)
+    }
+
+    /// Formats this duration as clock time, such as `01:23:45.678`, rather than as ISO-8601.
+    ///
+    /// See [`ClockFormat`](struct.ClockFormat.html) for the rendering, and for configuring the fraction digits.
+    /// The result is the inverse of [`parse_clock`](#method.parse_clock).
+    pub fn format_clock(&self) -> ClockFormat {
+        ClockFormat::new(*self)
+    }
+
+    /// Formats this duration lazily as an ISO-8601 duration, such as `PT8M20.345S`.
+    ///
+    /// Nothing is computed until the result is written, so this is cheap to pass to a logging macro that may discard it.
+    /// The rendering, including width, fill, alignment, and precision, is as for `Display`.
+    pub fn display(&self) -> DurationDisplay {
+        DurationDisplay::new(*self)
+    }
+
+    /// Gets the maximum length, in bytes, of the output of [`format_into`](#method.format_into)
+    /// (and of [`to_iso_full`](#method.to_iso_full)), such as for sizing a fixed buffer.
+    pub const fn formatted_len_max() -> usize {
+        // PT-2562047788015214H-59M-59.999999999S
+        38
+    }
+
+    /// Formats this duration as an ISO-8601 duration, such as `PT8M20.345S`, into the writer.
+    ///
+    /// Only the hours, minutes, and seconds components are used - days are never output, as they are not exact.
+    /// Zero components are omitted, and a zero duration is `PT0S`.
+    /// Negative durations carry the sign on each component, as in `PT-1M-1.5S`.
+    ///
+    /// The whole rendering is written with a single call, so a writer that fails receives nothing.
+    ///
+    /// # Parameters
+    ///  - `w`: the writer to format into.
+    pub fn format_into<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        self.write_iso(w, false)
+    }
+
+    /// Formats this duration as an ISO-8601 duration that always includes the hours, minutes, and seconds components,
+    /// such as `PT1H0M0S`.
+    ///
+    /// Some consumers - notably XML schemas built on `xs:duration` patterns, and other schema-strict validators -
+    /// reject the compact form produced by `Display` (which omits zero components, as in `PT1H`).
+    /// They also reject a sign on each component, so unlike `Display`, a negative duration has a single leading minus
+    /// before its magnitude, as in `-PT0H1M1.5S` rather than `PT0H-1M-1.5S`. Both forms parse back to the same value.
+    #[cfg(feature = "std")]
+    pub fn to_iso_full(&self) -> String {
+        let mut formatted = String::new();
+        self.write_iso(&mut formatted, true)
+            .expect("formatting to a string cannot fail");
+        formatted
+    }
+
+    /// Formats this duration in the specified style, such as `PT1M30.5S` or `00:01:30.5`.
+    ///
+    /// This is a single entry point for the formats that are otherwise available from their own methods;
+    /// see [`DurationStyle`](enum.DurationStyle.html) for an example of each.
+    ///
+    /// # Parameters
+    ///  - `style`: the rendering to use.
+    #[cfg(feature = "std")]
+    pub fn format(&self, style: DurationStyle) -> String {
+        match style {
+            DurationStyle::Iso8601 => self.to_string(),
+            DurationStyle::Clock => self.format_clock().to_string(),
+            DurationStyle::Human => self.humanize().to_string(),
+            DurationStyle::DecimalSeconds => self.to_decimal_seconds(),
+        }
+    }
+
+    /// Formats this duration as plain decimal seconds, such as `8.5` for `PT8.5S`, or `-0.000000001` for `PT-0.000000001S`.
+    ///
+    /// Trailing zeros in the fraction are dropped, as is the fraction entirely for a whole number of seconds.
+    /// Negative durations carry the sign on the whole value.
+    /// This is exact, unlike going through `f64`, and is parsed by [`from_decimal_seconds`](#method.from_decimal_seconds).
+    #[cfg(feature = "std")]
+    pub fn to_decimal_seconds(&self) -> String {
+        let (negative, seconds, nanos) =
+            sign_and_magnitude(self.seconds, self.nanosecond_of_second);
+        let mut digits = Digits::new();
+        if negative {
+            digits.push(b'-');
+        }
+        digits.push_u64(seconds);
+        if nanos > 0 {
+            digits.push(b'.');
+            digits.push_fraction(nanos);
+        }
+        digits.as_str().to_owned()
+    }
+
+    /// Formats this duration as the time left on a countdown, such as `01:30:00` or `00:30`.
+    ///
+    /// This is clock time as for [`format_clock`](#method.format_clock), with the hours omitted when zero.
+    /// Hours are never rolled over into days, so two days is `48:00:00`.
+    /// Negative durations, when the countdown has passed, are clamped to `00:00`.
+    /// A fraction of a second rounds up, so `00:00` is shown only once no time is left.
+    #[cfg(feature = "std")]
+    pub fn to_countdown(&self) -> String {
+        let remaining_seconds = if self.is_negative() {
+            0
+        } else {
+            self.seconds as u64 + u64::from(self.nanosecond_of_second > 0)
+        };
+        let hours = remaining_seconds / SECONDS_IN_HOUR as u64;
+        let minutes = remaining_seconds % SECONDS_IN_HOUR as u64 / SECONDS_IN_MINUTE as u64;
+        let seconds = remaining_seconds % SECONDS_IN_MINUTE as u64;
+
+        let mut digits = Digits::new();
+        if hours > 0 {
+            if hours < 10 {
+                digits.push(b'0');
+            }
+            digits.push_u64(hours);
+            digits.push(b':');
+        }
+        digits.push_2(minutes as u32);
+        digits.push(b':');
+        digits.push_2(seconds as u32);
+        digits.as_str().to_owned()
+    }
+
+    /// Formats this duration as an ISO-8601 duration with every field present at a fixed width,
+    /// such as `PT0000000000000001H05M03.250000000S`, for column-aligned output.
+    ///
+    /// The field widths are stable: 16 digits of hours (enough for the longest duration),
+    /// 2 each of minutes and seconds, and 9 of the fraction of the second, always zero padded.
+    /// Negative durations have a single leading minus, as in `-PT0000000000000000H01M30.000000000S`,
+    /// rather than the per-component signs of `Display`.
+    ///
+    /// Because of the fixed widths, comparing the strings of non-negative durations gives the same ordering
+    /// as comparing the durations. This is parsed by [`parse_fixed_iso`](#method.parse_fixed_iso).
+    #[cfg(feature = "std")]
+    pub fn to_fixed_iso_string(&self) -> String {
+        let (negative, magnitude_seconds, magnitude_nanos) =
+            sign_and_magnitude(self.seconds, self.nanosecond_of_second);
+        let hours = magnitude_seconds / SECONDS_IN_HOUR as u64;
+        let minutes = magnitude_seconds % SECONDS_IN_HOUR as u64 / SECONDS_IN_MINUTE as u64;
+        let seconds = magnitude_seconds % SECONDS_IN_MINUTE as u64;
+
+        let mut digits = Digits::new();
+        if negative {
+            digits.push(b'-');
+        }
+        digits.push_str("PT");
+        digits.push_u64_padded(hours, FIXED_ISO_HOUR_DIGITS);
+        digits.push(b'H');
+        digits.push_2(minutes as u32);
+        digits.push(b'M');
+        digits.push_2(seconds as u32);
+        digits.push(b'.');
+        digits.push_9(magnitude_nanos);
+        digits.push(b'S');
+        digits.as_str().to_owned()
+    }
+
+    fn write_iso<W: fmt::Write>(&self, w: &mut W, all_components: bool) -> fmt::Result {
+        w.write_str(self.iso_digits(all_components, None).as_str())
+    }
+
+    // The ISO-8601 rendering, with the seconds always written with exactly `fraction_digits` digits if specified,
+    // rounding the magnitude to the nearest, as for the fraction digits of `Instant`.
+    fn iso_digits(&self, all_components: bool, fraction_digits: Option<u32>) -> Digits {
+        let mut digits = Digits::new();
+        if self.is_zero() && !all_components && fraction_digits.is_none() {
+            digits.push_str("PT0S");
+            return digits;
+        }
+
+        // Like java.time, each component carries the sign of the whole duration,
+        // so `PT-1M-0.5S` is minus a minute and minus half a second, and parses back to the same value.
+        // The full form is for `xs:duration`, which only allows a single leading sign, as in `-PT0H1M0.5S`.
+        let (negative, mut magnitude, mut nanos) =
+            sign_and_magnitude(self.seconds, self.nanosecond_of_second);
+        let leading_sign = negative && all_components;
+        let negative = negative && !all_components;
+        if let Some(count) = fraction_digits {
+            let unit = 10u32.pow(9 - count);
+            nanos = (nanos + unit / 2) / unit * unit;
+            // The magnitude is at most 2^63 seconds, so carrying into it cannot overflow.
+            if nanos == NANOSECONDS_IN_SECOND as u32 {
+                magnitude += 1;
+                nanos = 0;
+            }
+        }
+        let hours = magnitude / SECONDS_IN_HOUR as u64;
+        let minutes = magnitude % SECONDS_IN_HOUR as u64 / SECONDS_IN_MINUTE as u64;
+        let seconds = magnitude % SECONDS_IN_MINUTE as u64;
+        let push_component = |digits: &mut Digits, value: u64, has_fraction: bool| {
+            if negative && (value != 0 || has_fraction) {
+                digits.push(b'-');
+            }
+            digits.push_u64(value);
+        };
+
+        if leading_sign {
+            digits.push(b'-');
+        }
+        digits.push_str("PT");
+        if hours != 0 || all_components {
+            push_component(&mut digits, hours, false);
+            digits.push(b'H');
+        }
+        if minutes != 0 || all_components {
+            push_component(&mut digits, minutes, false);
+            digits.push(b'M');
+        }
+        if seconds == 0 && nanos == 0 && !all_components && fraction_digits.is_none() {
+            return digits;
+        }
+
+        push_component(&mut digits, seconds, nanos > 0);
+        match fraction_digits {
+            None if nanos == 0 => {}
+            None => {
+                digits.push(b'.');
+                digits.push_fraction(nanos);
+            }
+            Some(0) => {}
+            Some(count) => {
+                digits.push(b'.');
+                digits.push_fraction_digits(nanos, count);
+            }
+        }
+        digits.push(b'S');
+        digits
+    }
+}
+
+impl From<i64> for Duration {
+    /// Converts a whole number of **seconds** to a duration, as [`of_seconds`](#method.of_seconds).
+    fn from(seconds: i64) -> Duration {
+        Duration::of_seconds(seconds)
+    }
+}
+
+impl From<i32> for Duration {
+    /// Converts a whole number of **seconds** to a duration, as [`of_seconds`](#method.of_seconds).
+    fn from(seconds: i32) -> Duration {
+        Duration::of_seconds(i64::from(seconds))
+    }
+}
+
+impl TryFrom<u64> for Duration {
+    type Error = TryFromIntError;
+
+    /// Converts a whole number of **seconds** to a duration, as [`of_seconds`](#method.of_seconds).
+    ///
+    /// # Errors
+    /// - if the number of seconds is greater than `i64::MAX`.
+    fn try_from(seconds: u64) -> Result<Duration, TryFromIntError> {
+        i64::try_from(seconds).map(Duration::of_seconds)
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseError;
+
+    /// Parses an ISO-8601 duration, as [`parse`](#method.parse).
+    fn from_str(text: &str) -> Result<Duration, ParseError> {
+        Duration::parse(text)
+    }
+}
+
+impl fmt::Display for Duration {
+    /// Formats this duration as an ISO-8601 duration, such as `PT8M20.345S`.
+    ///
+    /// See [`format_into`](#method.format_into) for details.
+    /// Width, fill, and alignment are honored as for a string, so `{:>12}` right-aligns the duration.
+    ///
+    /// A precision fixes the number of fraction digits of the seconds, as for `Instant`'s `Display`,
+    /// so `{:.3}` writes `PT1M0.000S` for a minute, and `PT1.235S` for 1.2345 seconds, rounding to the nearest.
+    /// The seconds are always written with a precision, even when zero.
+    /// Precisions above 9 are treated as 9, since nanoseconds are the finest resolution available.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fraction_digits = f.precision().map(|precision| precision.min(9) as u32);
+        pad_without_precision(f, self.iso_digits(false, fraction_digits).as_str())
+    }
+}
+
+impl fmt::Debug for Duration {
+    /// Formats this duration as its ISO-8601 rendering followed by its raw fields,
+    /// such as `Duration(PT2H30M, seconds=9000, nanos=0)`.
+    ///
+    /// The alternate form (`{:#?}`) puts each on its own line.
+    /// This replaced the derived `Debug`, so code matching the old `Duration { seconds: .. }` output must be updated.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_with_fields(
+            f,
+            "Duration",
+            self,
+            &[
+                ("seconds", &self.seconds),
+                ("nanos", &self.nanosecond_of_second),
+            ],
+        )
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        self.checked_add(other)
+            .expect("overflow when adding durations")
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        self.checked_sub(other)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Duration {
+    type Output = Duration;
+
+    fn mul(self, scalar: i64) -> Duration {
+        self.checked_mul(scalar)
+            .expect("overflow when multiplying duration")
+    }
+}
+
+impl MulAssign<i64> for Duration {
+    fn mul_assign(&mut self, scalar: i64) {
+        *self = *self * scalar;
+    }
+}
+
+impl Rem for Duration {
+    type Output = Duration;
+
+    /// Gets the remainder of dividing this duration by another, with the sign of this duration.
+    fn rem(self, divisor: Duration) -> Duration {
+        self.checked_rem(divisor)
+            .expect("division of duration by zero")
+    }
+}
+
+impl RemAssign for Duration {
+    fn rem_assign(&mut self, divisor: Duration) {
+        *self = *self % divisor;
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        self.checked_neg().expect("overflow when negating duration")
+    }
+}
+
+impl Duration {
+    // Renders the duration for `defmt`, as a seconds-only ISO-8601 duration, such as `PT-0.500000000S`.
+    #[cfg(any(feature = "defmt", test))]
+    pub(crate) fn defmt_digits(&self) -> Digits {
+        let mut digits = Digits::new();
+        digits.push_str("PT");
+        digits.push_fixed_seconds(self.seconds, self.nanosecond_of_second);
+        digits.push(b'S');
+        digits
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Duration {
+    /// Writes the duration as a seconds-only ISO-8601 duration, such as `PT-0.500000000S`.
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.defmt_digits().as_str())
+    }
+}
@@ -0,0 +1,577 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::Duration;
+
+fn total_nanos(duration: Duration) -> i128 {
+    i128::from(duration.seconds()) * i128::from(NANOSECONDS_IN_SECOND) + i128::from(duration.nano())
+}
+
+proptest! {
+    #[test]
+    fn add_carries_nanos(first in Just(600_000_000), second in Just(600_000_000)) {
+        let sum = Duration::of_seconds_and_adjustment(0, first) + Duration::of_seconds_and_adjustment(0, second);
+
+        prop_assert_eq!(Duration::of_seconds_and_adjustment(1, 200_000_000), sum);
+        prop_assert_eq!(1, sum.seconds());
+        prop_assert_eq!(200_000_000, sum.nano());
+    }
+}
+
+prop_compose! {
+    fn nanos_summing_near_second()
+        (first in 0..NANOSECONDS_IN_SECOND, offset in -1_000i64..=1_000) -> (i64, i64)
+        {
+            let second = (NANOSECONDS_IN_SECOND - first + offset).clamp(0, NANOSECONDS_IN_SECOND - 1);
+            (first, second)
+        }
+}
+
+proptest! {
+    #[test]
+    fn add_nanos_around_second_boundary(seconds in -1_000_000i64..1_000_000, (first, second) in nanos_summing_near_second()) {
+        let augend = Duration::of_seconds_and_adjustment(seconds, first);
+        let addend = Duration::of_seconds_and_adjustment(-seconds, second);
+
+        let sum = augend + addend;
+
+        prop_assert!(sum.nano() < NANOSECONDS_IN_SECOND as u32);
+        prop_assert_eq!(i128::from(first + second), total_nanos(sum));
+    }
+}
+
+proptest! {
+    #[test]
+    fn add(first_seconds in prop::num::i64::ANY, first_nanos in 0..NANOSECONDS_IN_SECOND, second_seconds in prop::num::i64::ANY, second_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let augend = Duration::of_seconds_and_adjustment(first_seconds, first_nanos);
+        let addend = Duration::of_seconds_and_adjustment(second_seconds, second_nanos);
+
+        let expected = total_nanos(augend) + total_nanos(addend);
+        let in_range = (total_nanos(Duration::MIN)..=total_nanos(Duration::MAX)).contains(&expected);
+
+        match augend.checked_add(addend) {
+            Some(sum) => {
+                prop_assert!(in_range);
+                prop_assert!(sum.nano() < NANOSECONDS_IN_SECOND as u32);
+                prop_assert_eq!(expected, total_nanos(sum));
+            },
+            None => prop_assert!(!in_range),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_carry_back_into_range(first in Just(Duration::of_seconds_and_adjustment(i64::MIN, 600_000_000)), second in Just(Duration::of_seconds_and_adjustment(-1, 600_000_000))) {
+        let sum = first + second;
+
+        prop_assert_eq!(Duration::of_seconds_and_adjustment(i64::MIN, 200_000_000), sum);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when adding durations")]
+    fn add_overflow(first in Just(Duration::MAX), second in Just(Duration::of_nanos(1))) {
+        let _sum = first + second;
+    }
+}
+
+proptest! {
+    #[test]
+    fn sub(first_seconds in prop::num::i64::ANY, first_nanos in 0..NANOSECONDS_IN_SECOND, second_seconds in prop::num::i64::ANY, second_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let minuend = Duration::of_seconds_and_adjustment(first_seconds, first_nanos);
+        let subtrahend = Duration::of_seconds_and_adjustment(second_seconds, second_nanos);
+
+        let expected = total_nanos(minuend) - total_nanos(subtrahend);
+        let in_range = (total_nanos(Duration::MIN)..=total_nanos(Duration::MAX)).contains(&expected);
+
+        match minuend.checked_sub(subtrahend) {
+            Some(difference) => {
+                prop_assert!(in_range);
+                prop_assert!(difference.nano() < NANOSECONDS_IN_SECOND as u32);
+                prop_assert_eq!(expected, total_nanos(difference));
+            },
+            None => prop_assert!(!in_range),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when subtracting durations")]
+    fn sub_underflow(first in Just(Duration::MIN), second in Just(Duration::of_nanos(1))) {
+        let _difference = first - second;
+    }
+}
+
+proptest! {
+    #[test]
+    fn neg(seconds in (i64::MIN + 1)..=i64::MAX, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let negated = -duration;
+
+        prop_assert_eq!(-total_nanos(duration), total_nanos(negated));
+        prop_assert_eq!(duration, -negated);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when negating duration")]
+    fn neg_overflow(duration in Just(Duration::MIN)) {
+        let _negated = -duration;
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, scalar in prop::num::i64::ANY) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let expected = total_nanos(duration).checked_mul(i128::from(scalar));
+        let in_range = expected.is_some_and(|expected| (total_nanos(Duration::MIN)..=total_nanos(Duration::MAX)).contains(&expected));
+
+        match duration.checked_mul(scalar) {
+            Some(product) => {
+                prop_assert!(in_range);
+                prop_assert_eq!(expected.unwrap(), total_nanos(product));
+            },
+            None => prop_assert!(!in_range),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul_small_scalar(seconds in -1_000_000i64..1_000_000, nanos in 0..NANOSECONDS_IN_SECOND, scalar in -1_000_000i64..1_000_000) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(total_nanos(duration) * i128::from(scalar), total_nanos(duration * scalar));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when multiplying duration")]
+    fn mul_overflow(duration in Just(Duration::MIN), scalar in Just(-1)) {
+        let _product = duration * scalar;
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul_i128_nanos(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, scalar in prop::num::i64::ANY) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let expected = total_nanos(duration).checked_mul(i128::from(scalar));
+        prop_assert_eq!(expected, duration.checked_mul_i128_nanos(scalar));
+        if let Some(expected) = expected {
+            prop_assert_eq!(expected, duration.mul_i128_nanos(scalar));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul_i128_nanos_beyond_i64((duration, scalar, expected) in prop_oneof![
+        Just((Duration::of_seconds(10), i64::MAX, 92_233_720_368_547_758_070_000_000_000i128)),
+        Just((Duration::of_nanos(-3), i64::MIN, 27_670_116_110_564_327_424i128)),
+        Just((Duration::MAX, 2, 18_446_744_073_709_551_615_999_999_998i128)),
+        Just((Duration::MIN, -1, 9_223_372_036_854_775_808_000_000_000i128)),
+    ]) {
+        prop_assert!(!(i128::from(i64::MIN)..=i128::from(i64::MAX)).contains(&expected));
+        prop_assert_eq!(expected, duration.mul_i128_nanos(scalar));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when multiplying duration nanoseconds")]
+    fn mul_i128_nanos_overflow(duration in Just(Duration::MAX), scalar in Just(i64::MAX)) {
+        let _product = duration.mul_i128_nanos(scalar);
+    }
+}
+
+proptest! {
+    #[test]
+    fn saturating_add((first, second, expected) in prop_oneof![
+        Just((Duration::of_seconds(1), Duration::of_seconds(-2), Duration::of_seconds(-1))),
+        Just((Duration::MAX, Duration::of_nanos(1), Duration::MAX)),
+        Just((Duration::MIN, Duration::of_nanos(-1), Duration::MIN)),
+    ]) {
+        prop_assert_eq!(expected, first.saturating_add(second));
+    }
+}
+
+proptest! {
+    #[test]
+    fn saturating_sub((first, second, expected) in prop_oneof![
+        Just((Duration::of_seconds(1), Duration::of_seconds(-2), Duration::of_seconds(3))),
+        Just((Duration::MIN, Duration::of_nanos(1), Duration::MIN)),
+        Just((Duration::MAX, Duration::of_nanos(-1), Duration::MAX)),
+    ]) {
+        prop_assert_eq!(expected, first.saturating_sub(second));
+    }
+}
+
+proptest! {
+    #[test]
+    fn saturating_mul((duration, scalar, expected) in prop_oneof![
+        Just((Duration::of_seconds(-2), 3, Duration::of_seconds(-6))),
+        Just((Duration::MAX, 2, Duration::MAX)),
+        Just((Duration::MIN, 2, Duration::MIN)),
+        Just((Duration::MIN, -2, Duration::MAX)),
+        Just((Duration::MAX, -2, Duration::MIN)),
+    ]) {
+        prop_assert_eq!(expected, duration.saturating_mul(scalar));
+    }
+}
+
+proptest! {
+    #[test]
+    fn checked_neg((duration, expected) in prop_oneof![
+        Just((Duration::of_seconds(-2), Some(Duration::of_seconds(2)))),
+        Just((Duration::of_millis(1_500), Some(Duration::of_millis(-1_500)))),
+        Just((Duration::of_nanos(-1), Some(Duration::of_nanos(1)))),
+        Just((Duration::ZERO, Some(Duration::ZERO))),
+        Just((Duration::MAX, Some(Duration::of_seconds_and_adjustment(i64::MIN, 1)))),
+        Just((Duration::MIN, None)),
+    ]) {
+        prop_assert_eq!(expected, duration.checked_neg());
+    }
+}
+
+proptest! {
+    #[test]
+    fn saturating_neg((duration, expected) in prop_oneof![
+        Just((Duration::of_seconds(-2), Duration::of_seconds(2))),
+        Just((Duration::MIN, Duration::MAX)),
+    ]) {
+        prop_assert_eq!(expected, duration.saturating_neg());
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul_add(seconds in -1_000_000i64..1_000_000, nanos in 0..NANOSECONDS_IN_SECOND, scalar in -1_000_000i64..1_000_000, addend_seconds in prop::num::i64::ANY, addend_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let addend = Duration::of_seconds_and_adjustment(addend_seconds, addend_nanos);
+
+        let expected = total_nanos(duration) * i128::from(scalar) + total_nanos(addend);
+        let in_range = (total_nanos(Duration::MIN)..=total_nanos(Duration::MAX)).contains(&expected);
+
+        match duration.checked_mul_add(scalar, addend) {
+            Some(result) => {
+                prop_assert!(in_range);
+                prop_assert_eq!(expected, total_nanos(result));
+                prop_assert_eq!(result, duration.mul_add(scalar, addend));
+            },
+            None => prop_assert!(!in_range),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul_add_product_overflow_brought_into_range(seconds in Just(i64::MAX / 2 + 1), scalar in Just(2), addend_seconds in Just(-10)) {
+        let duration = Duration::of_seconds(seconds);
+        let addend = Duration::of_seconds(addend_seconds);
+
+        prop_assert_eq!(None, duration.checked_mul(scalar));
+        prop_assert_eq!(Duration::of_seconds(i64::MAX - 9), duration.mul_add(scalar, addend));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when multiplying and adding duration")]
+    fn mul_add_overflow(duration in Just(Duration::MAX), scalar in Just(i64::MAX), addend in Just(Duration::MIN)) {
+        let _result = duration.mul_add(scalar, addend);
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_duration_slice_matches_scalar(durations in prop::collection::vec((prop::num::i64::ANY, 0..NANOSECONDS_IN_SECOND), 0..20), offset_seconds in prop::num::i64::ANY, offset_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let original: Vec<Duration> = durations.into_iter().map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos)).collect();
+        let offset = Duration::of_seconds_and_adjustment(offset_seconds, offset_nanos);
+        let mut durations = original.clone();
+
+        let scalar: Option<Vec<Duration>> = original.iter().map(|duration| duration.checked_add(offset)).collect();
+
+        match (scalar, Duration::add_duration_slice(&mut durations, offset)) {
+            (Some(expected), Ok(())) => prop_assert_eq!(expected, durations),
+            (None, Err(error)) => {
+                prop_assert_eq!(&original, &durations);
+                prop_assert!(original[error.index()].checked_add(offset).is_none());
+            },
+            (scalar, result) => prop_assert!(false, "scalar {:?} but slice {:?}", scalar, result),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_duration_slice_carry_at_extremes((original, offset, expected) in Just((
+        vec![Duration::MIN, Duration::of_nanos(-1)],
+        Duration::of_seconds_and_adjustment(0, 999_999_999),
+        vec![Duration::of_seconds_and_adjustment(i64::MIN, 999_999_999), Duration::of_nanos(999_999_998)],
+    ))) {
+        let mut durations = original;
+
+        prop_assert_eq!(Ok(()), Duration::add_duration_slice(&mut durations, offset));
+        prop_assert_eq!(expected, durations);
+    }
+}
+
+proptest! {
+    #[test]
+    fn multiples((millis, unit_millis, is_multiple, next, prev) in prop_oneof![
+        Just((900, 300, true, 900, 900)),
+        Just((1_000, 300, false, 1_200, 900)),
+        Just((0, 300, true, 0, 0)),
+        Just((-500, 300, false, -300, -600)),
+        Just((-600, 300, true, -600, -600)),
+        Just((-1_300, 1_000, false, -1_000, -2_000)),
+        Just((60_000, 1_000, true, 60_000, 60_000)),
+        Just((1, 1_000, false, 1_000, 0)),
+    ]) {
+        let duration = Duration::of_millis(millis);
+        let unit = Duration::of_millis(unit_millis);
+
+        prop_assert_eq!(Some(is_multiple), duration.is_multiple_of(unit));
+        prop_assert_eq!(Some(Duration::of_millis(next)), duration.next_multiple_of(unit));
+        prop_assert_eq!(Some(Duration::of_millis(prev)), duration.prev_multiple_of(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn multiples_bracket(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64, unit_nanos in 1..1_000_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let unit = Duration::of_nanos(unit_nanos);
+
+        if let Some(next) = duration.next_multiple_of(unit) {
+            prop_assert!(next >= duration && next - duration < unit);
+            prop_assert_eq!(Some(true), next.is_multiple_of(unit));
+        }
+        if let Some(prev) = duration.prev_multiple_of(unit) {
+            prop_assert!(prev <= duration && duration - prev < unit);
+            prop_assert_eq!(Some(true), prev.is_multiple_of(unit));
+        }
+        prop_assert_eq!(duration.is_multiple_of(unit), Some((duration % unit).is_zero()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn multiples_overflow((duration, unit, next, prev) in prop_oneof![
+        Just((Duration::MAX, Duration::of_seconds(7), None, Some(Duration::of_seconds(i64::MAX)))),
+        Just((Duration::MIN, Duration::of_seconds(7), Some(Duration::of_seconds(-i64::MAX)), None)),
+        Just((Duration::MAX, Duration::MAX, Some(Duration::MAX), Some(Duration::MAX))),
+    ]) {
+        prop_assert_eq!(next, duration.next_multiple_of(unit));
+        prop_assert_eq!(prev, duration.prev_multiple_of(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn multiples_of_invalid_unit((unit, is_multiple) in prop_oneof![
+        Just((Duration::ZERO, None)),
+        Just((Duration::of_millis(-300), Some(true))),
+        Just((Duration::of_millis(-400), Some(false))),
+    ]) {
+        let duration = Duration::of_millis(-900);
+
+        prop_assert_eq!(is_multiple, duration.is_multiple_of(unit));
+        prop_assert_eq!(None, duration.next_multiple_of(unit));
+        prop_assert_eq!(None, duration.prev_multiple_of(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn multiples_against_rem((millis, unit_millis) in prop_oneof![
+        Just((1_000, 300)),
+        Just((-500, 300)),
+        Just((-1_300, 1_000)),
+    ]) {
+        let duration = Duration::of_millis(millis);
+        let unit = Duration::of_millis(unit_millis);
+        let toward_zero = duration - duration % unit;
+
+        // `%` truncates toward zero, so `self - self % unit` is the multiple toward zero.
+        let expected = if millis < 0 { duration.next_multiple_of(unit) } else { duration.prev_multiple_of(unit) };
+        prop_assert_eq!(expected, Some(toward_zero));
+    }
+}
+
+proptest! {
+    #[test]
+    fn rem((millis, divisor_millis, expected) in prop_oneof![
+        Just((1_000, 300, 100)),
+        Just((-1_000, 300, -100)),
+        Just((1_000, -300, 100)),
+        Just((-1_300, 1_000, -300)),
+        Just((900, 300, 0)),
+    ]) {
+        let mut duration = Duration::of_millis(millis);
+        let divisor = Duration::of_millis(divisor_millis);
+
+        prop_assert_eq!(Duration::of_millis(expected), duration % divisor);
+        duration %= divisor;
+        prop_assert_eq!(Duration::of_millis(expected), duration);
+    }
+}
+
+proptest! {
+    #[test]
+    fn rem_extremes((duration, divisor, expected) in prop_oneof![
+        Just((Duration::MIN, Duration::of_nanos(-1), Some(Duration::ZERO))),
+        Just((Duration::MIN, Duration::MAX, Some(Duration::of_nanos(-1)))),
+        Just((Duration::MAX, Duration::ZERO, None)),
+    ]) {
+        prop_assert_eq!(expected, duration.checked_rem(divisor));
+    }
+}
+
+#[test]
+#[should_panic(expected = "division of duration by zero")]
+fn rem_by_zero() {
+    let _ = Duration::of_seconds(1) % Duration::ZERO;
+}
+
+proptest! {
+    #[test]
+    fn split_evenly((duration, parts, expected) in prop_oneof![
+        Just((Duration::of_micros(1), 4, vec![250, 250, 250, 250])),
+        Just((Duration::of_nanos(10), 3, vec![4, 3, 3])),
+        Just((Duration::of_nanos(-10), 3, vec![-4, -3, -3])),
+        Just((Duration::of_nanos(2), 5, vec![1, 1, 0, 0, 0])),
+        Just((Duration::of_nanos(-2), 5, vec![-1, -1, 0, 0, 0])),
+        Just((Duration::ZERO, 2, vec![0, 0])),
+    ]) {
+        let chunks = duration.split_evenly(parts).unwrap();
+
+        prop_assert_eq!(expected.len(), chunks.len());
+        prop_assert_eq!(expected, chunks.map(|chunk| total_nanos(chunk) as i64).collect::<Vec<_>>());
+    }
+}
+
+proptest! {
+    #[test]
+    fn split_evenly_reassembles(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, parts in 1..1_000u32) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let chunks = duration.split_evenly(parts).unwrap().map(total_nanos).collect::<Vec<_>>();
+
+        prop_assert_eq!(parts as usize, chunks.len());
+        prop_assert_eq!(total_nanos(duration), chunks.iter().sum::<i128>());
+        let (largest, smallest) = (chunks.iter().map(|chunk| chunk.abs()).max().unwrap(), chunks.iter().map(|chunk| chunk.abs()).min().unwrap());
+        prop_assert!(largest - smallest <= 1);
+        prop_assert!(chunks.iter().all(|chunk| chunk.signum() != -total_nanos(duration).signum()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn split_evenly_extremes((duration, parts) in prop_oneof![
+        Just((Duration::MIN, 1)),
+        Just((Duration::MAX, 1)),
+        Just((Duration::MIN, 1_000_000)),
+        Just((Duration::MAX, 7)),
+    ]) {
+        let mut chunks = duration.split_evenly(parts).unwrap();
+
+        if parts == 1 {
+            prop_assert_eq!(Some(duration), chunks.next());
+            prop_assert_eq!(None, chunks.next());
+        } else {
+            let first = chunks.next().unwrap();
+            prop_assert_eq!(total_nanos(duration), total_nanos(first) + chunks.map(total_nanos).sum::<i128>());
+        }
+    }
+}
+
+#[test]
+fn split_evenly_zero_parts() {
+    assert!(Duration::of_seconds(1).split_evenly(0).is_none());
+}
+
+proptest! {
+    #[test]
+    fn lerp((a, b, t, expected) in prop_oneof![
+        Just((Duration::ZERO, Duration::of_seconds(10), 0.5, Duration::of_seconds(5))),
+        Just((Duration::ZERO, Duration::of_seconds(10), 0.25, Duration::of_millis(2_500))),
+        // Reversed endpoints interpolate downward.
+        Just((Duration::of_seconds(10), Duration::ZERO, 0.25, Duration::of_millis(7_500))),
+        Just((Duration::of_seconds(-1), Duration::of_seconds(1), 0.5, Duration::ZERO)),
+        // Extrapolation beyond the endpoints.
+        Just((Duration::ZERO, Duration::of_seconds(10), 1.5, Duration::of_seconds(15))),
+        Just((Duration::ZERO, Duration::of_seconds(10), -0.5, Duration::of_seconds(-5))),
+        // Halves of a nanosecond round away from zero.
+        Just((Duration::ZERO, Duration::of_nanos(1), 0.5, Duration::of_nanos(1))),
+        Just((Duration::ZERO, Duration::of_nanos(-1), 0.5, Duration::of_nanos(-1))),
+    ]) {
+        prop_assert_eq!(expected, Duration::lerp(a, b, t));
+        prop_assert_eq!(Some(expected), Duration::checked_lerp(a, b, t));
+    }
+}
+
+proptest! {
+    #[test]
+    fn lerp_endpoints_are_exact(a in prop::num::i64::ANY, b in prop::num::i64::ANY, a_nanos in 0..1_000_000_000i64, b_nanos in 0..1_000_000_000i64) {
+        let a = Duration::of_seconds_and_adjustment(a, a_nanos);
+        let b = Duration::of_seconds_and_adjustment(b, b_nanos);
+
+        prop_assert_eq!(a, Duration::lerp(a, b, 0.0));
+        prop_assert_eq!(b, Duration::lerp(a, b, 1.0));
+        prop_assert_eq!(a, Duration::lerp_clamped(a, b, -1.0));
+        prop_assert_eq!(b, Duration::lerp_clamped(a, b, 2.0));
+    }
+}
+
+proptest! {
+    #[test]
+    fn lerp_clamped_stays_between((a, b) in prop_oneof![
+        Just((Duration::MIN, Duration::MAX)),
+        Just((Duration::MAX, Duration::MIN)),
+        Just((Duration::of_seconds(i64::MAX - 1), Duration::MAX)),
+    ], t in prop_oneof![Just(f64::NEG_INFINITY), Just(f64::INFINITY), -2.0..2.0f64, Just(1.0 - f64::EPSILON)]) {
+        let result = Duration::lerp_clamped(a, b, t);
+
+        prop_assert!(a.min(b) <= result && result <= a.max(b));
+    }
+}
+
+proptest! {
+    #[test]
+    fn checked_lerp_none((a, b, t) in prop_oneof![
+        Just((Duration::ZERO, Duration::of_seconds(1), f64::NAN)),
+        Just((Duration::ZERO, Duration::of_seconds(1), f64::INFINITY)),
+        Just((Duration::ZERO, Duration::MAX, 2.0)),
+        Just((Duration::MAX, Duration::MIN, 1.5)),
+    ]) {
+        prop_assert_eq!(None, Duration::checked_lerp(a, b, t));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "interpolation fraction must be finite")]
+    fn lerp_not_finite(t in prop_oneof![Just(f64::NAN), Just(f64::INFINITY), Just(f64::NEG_INFINITY)]) {
+        let _duration = Duration::lerp(Duration::ZERO, Duration::of_seconds(1), t);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "interpolation fraction must not be NaN")]
+    fn lerp_clamped_nan(t in Just(f64::NAN)) {
+        let _duration = Duration::lerp_clamped(Duration::ZERO, Duration::of_seconds(1), t);
+    }
+}
@@ -0,0 +1,130 @@
+use proptest::prelude::*;
+
+use crate::{Duration, ParseErrorKind};
+
+proptest! {
+    #[test]
+    fn format_clock((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, "00:00:00")),
+        Just((5, 0, "00:00:05")),
+        Just((5_025, 678_000_000, "01:23:45.678")),
+        Just((86_400, 0, "24:00:00")),
+        Just((360_000, 0, "100:00:00")),
+        Just((-90, 0, "-00:01:30")),
+        Just((-1, 500_000_000, "-00:00:00.5")),
+        Just((-3_601, 999_999_999, "-01:00:00.000000001")),
+        Just((i64::MAX, 999_999_999, "2562047788015215:30:07.999999999")),
+        Just((i64::MIN, 0, "-2562047788015215:30:08")),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.format_clock().to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_clock_fraction_digits((seconds, nanos, digits, expected) in prop_oneof![
+        Just((5_025, 678_000_000, 3, "01:23:45.678")),
+        Just((5_025, 0, 3, "01:23:45.000")),
+        Just((5_025, 678_900_000, 3, "01:23:45.678")),
+        Just((5_025, 678_900_000, 0, "01:23:45")),
+        Just((5_025, 1, 9, "01:23:45.000000001")),
+        Just((-2, 999_000_000, 2, "-00:00:01.00")),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.format_clock().fraction_digits(digits).to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_clock_padding((format, expected) in prop_oneof![
+        Just((format!("{:>10}", Duration::of_minutes(90).format_clock()), "  01:30:00")),
+        Just((format!("{:*<10}", Duration::of_minutes(90).format_clock()), "01:30:00**")),
+        Just((format!("{:.2}", Duration::of_minutes(90).format_clock()), "01:30:00")),
+        Just((format!("{:>12.2}", Duration::of_millis(5_025_678).format_clock()), "01:23:45.678")),
+    ]) {
+        prop_assert_eq!(expected, format);
+    }
+}
+
+#[test]
+#[should_panic(expected = "fraction digits must be at most 9")]
+fn format_clock_fraction_digits_too_many() {
+    Duration::ZERO.format_clock().fraction_digits(10);
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_countdown((duration, expected) in prop_oneof![
+        Just((Duration::of_minutes(90), "01:30:00")),
+        Just((Duration::of_seconds(30), "00:30")),
+        Just((Duration::of_minutes(59) + Duration::of_seconds(59), "59:59")),
+        Just((Duration::of_hours(1), "01:00:00")),
+        Just((Duration::of_hours(48), "48:00:00")),
+        Just((Duration::of_hours(100), "100:00:00")),
+        Just((Duration::ZERO, "00:00")),
+        Just((Duration::of_nanos(1), "00:01")),
+        Just((Duration::of_millis(59_500), "01:00")),
+        Just((Duration::of_seconds(-30), "00:00")),
+        Just((Duration::MIN, "00:00")),
+        Just((Duration::MAX, "2562047788015215:30:08")),
+    ]) {
+        prop_assert_eq!(expected, duration.to_countdown());
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_clock((text, seconds, nanos) in prop_oneof![
+        Just(("00:05", 5, 0)),
+        Just(("0:05.25", 5, 250_000_000)),
+        Just(("59:59", 3_599, 0)),
+        Just(("1:23:45", 5_025, 0)),
+        Just(("01:23:45.678", 5_025, 678_000_000)),
+        Just(("100:00:00", 360_000, 0)),
+        Just(("-01:30", -90, 0)),
+        Just(("-00:00:00.5", -1, 500_000_000)),
+        Just(("-0:00", 0, 0)),
+    ]) {
+        prop_assert_eq!(Ok(Duration::of_seconds_and_adjustment(seconds, nanos)), Duration::parse_clock(text));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_clock_invalid((text, kind, position) in prop_oneof![
+        Just(("1:75:00", ParseErrorKind::FieldOutOfRange, 2)),
+        Just(("1:00:60", ParseErrorKind::FieldOutOfRange, 5)),
+        Just(("60:00", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("100:00", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("5", ParseErrorKind::UnexpectedEnd, 1)),
+        Just(("1:5", ParseErrorKind::UnexpectedEnd, 3)),
+        Just(("+1:00", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("1:00:00:00", ParseErrorKind::TrailingCharacters, 7)),
+        Just(("1:00:00.", ParseErrorKind::UnexpectedEnd, 8)),
+        Just(("2562047788015216:00:00", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("99999999999999999999:00:00", ParseErrorKind::FieldOutOfRange, 0)),
+    ]) {
+        let error = Duration::parse_clock(text).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn clock_round_trip(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(duration), Duration::parse_clock(&duration.format_clock().to_string()));
+        prop_assert_eq!(
+            Ok(duration),
+            Duration::parse_clock(&duration.format_clock().fraction_digits(9).to_string())
+        );
+    }
+}
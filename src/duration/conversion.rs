@@ -0,0 +1,454 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+#[cfg(feature = "std")]
+use crate::DecomposeError;
+use crate::{Duration, RoundingMode, Unit};
+
+proptest! {
+    #[test]
+    fn to_millis_floors(millis in -1_000_000_000_000i64..1_000_000_000_000, sub_millis in 0..NANOSECONDS_IN_MILLISECOND) {
+        let duration = Duration::of_millis(millis) + Duration::of_nanos(sub_millis);
+
+        prop_assert_eq!(millis, duration.to_millis());
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_millis_rounding_modes((nanos, expected) in prop_oneof![
+        // The table from `java.math.RoundingMode`, in milliseconds:
+        //            Up, Down, Ceiling, Floor, HalfUp, HalfDown, HalfEven
+        Just((5_500_000, [6, 5, 6, 5, 6, 5, 6])),
+        Just((2_500_000, [3, 2, 3, 2, 3, 2, 2])),
+        Just((1_600_000, [2, 1, 2, 1, 2, 2, 2])),
+        Just((1_100_000, [2, 1, 2, 1, 1, 1, 1])),
+        Just((1_000_000, [1, 1, 1, 1, 1, 1, 1])),
+        Just((-1_000_000, [-1, -1, -1, -1, -1, -1, -1])),
+        Just((-1_100_000, [-2, -1, -1, -2, -1, -1, -1])),
+        Just((-1_600_000, [-2, -1, -1, -2, -2, -2, -2])),
+        Just((-2_500_000, [-3, -2, -2, -3, -3, -2, -2])),
+        Just((-5_500_000, [-6, -5, -5, -6, -6, -5, -6])),
+    ]) {
+        let duration = Duration::of_nanos(nanos);
+        let modes = [
+            RoundingMode::Up,
+            RoundingMode::Down,
+            RoundingMode::Ceiling,
+            RoundingMode::Floor,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+        ];
+
+        for (mode, expected) in modes.iter().zip(expected.iter()) {
+            prop_assert_eq!(*expected, duration.to_millis_rounding(*mode), "{:?}", mode);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_millis_banker_examples((nanos, expected) in prop_oneof![
+        Just((-500_000, 0)),
+        Just((-1_500_000, -2)),
+        Just((500_000, 0)),
+        Just((1_500_000, 2)),
+        Just((-499_999, 0)),
+        Just((-500_001, -1)),
+    ]) {
+        prop_assert_eq!(expected, Duration::of_nanos(nanos).to_millis_banker());
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_millis_banker_half_way(millis in -1_000_000_000_000i64..1_000_000_000_000) {
+        let duration = Duration::of_millis(millis) + Duration::of_nanos(NANOSECONDS_IN_MILLISECOND / 2);
+        let expected = if millis % 2 == 0 { millis } else { millis + 1 };
+
+        prop_assert_eq!(expected, duration.to_millis_banker());
+        prop_assert_eq!(-expected, (-duration).to_millis_banker());
+    }
+}
+
+#[test]
+fn to_millis_banker_every_half_way_near_zero() {
+    for millis in -100_000i64..100_000 {
+        let duration =
+            Duration::of_millis(millis) + Duration::of_nanos(NANOSECONDS_IN_MILLISECOND / 2);
+        let expected = if millis % 2 == 0 { millis } else { millis + 1 };
+
+        assert_eq!(expected, duration.to_millis_banker(), "{}", duration);
+        assert_eq!(-expected, (-duration).to_millis_banker(), "{}", -duration);
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_millis_banker_not_half_way(millis in -1_000_000_000_000i64..1_000_000_000_000, sub_millis in 1..NANOSECONDS_IN_MILLISECOND) {
+        prop_assume!(sub_millis != NANOSECONDS_IN_MILLISECOND / 2);
+        let duration = Duration::of_millis(millis) + Duration::of_nanos(sub_millis);
+        let expected = if sub_millis < NANOSECONDS_IN_MILLISECOND / 2 { millis } else { millis + 1 };
+
+        prop_assert_eq!(expected, duration.to_millis_banker());
+        prop_assert_eq!(-expected, (-duration).to_millis_banker());
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "millis would overflow i64")]
+    fn to_millis_overflow(duration in prop_oneof![Just(Duration::MAX), Just(Duration::MIN)]) {
+        duration.to_millis();
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_std(seconds in 0..=i64::MAX, nanos in 0..1_000_000_000u32) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, i64::from(nanos));
+
+        prop_assert_eq!(Some(core::time::Duration::new(seconds as u64, nanos)), duration.to_std());
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_std_negative(duration in prop_oneof![Just(Duration::MIN), Just(Duration::of_nanos(-1)), Just(Duration::of_seconds(-5))]) {
+        prop_assert_eq!(None, duration.to_std());
+    }
+}
+
+proptest! {
+    #[test]
+    fn as_secs_f64((duration, expected) in prop_oneof![
+        Just((Duration::ZERO, 0.0)),
+        Just((Duration::of_millis(1_500), 1.5)),
+        Just((Duration::of_millis(-1_500), -1.5)),
+        Just((Duration::of_nanos(-1), -0.000_000_001)),
+        Just((Duration::MAX, 9_223_372_036_854_775_808.0)),
+    ]) {
+        prop_assert_eq!(expected, duration.as_secs_f64());
+    }
+}
+
+proptest! {
+    #[test]
+    fn as_rate_per_second((duration, expected) in prop_oneof![
+        Just((Duration::of_millis(500), 2.0)),
+        Just((Duration::of_millis(-500), -2.0)),
+        Just((Duration::of_nanos(1), 1_000_000_000.0)),
+        Just((Duration::ZERO, f64::INFINITY)),
+    ]) {
+        prop_assert_eq!(expected, duration.as_rate_per_second());
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_rate_per_second((hz, expected) in prop_oneof![
+        Just((60.0, Some(Duration::of_nanos(16_666_667)))),
+        Just((1.0, Some(Duration::of_seconds(1)))),
+        Just((0.5, Some(Duration::of_seconds(2)))),
+        Just((3.0, Some(Duration::of_nanos(333_333_333)))),
+        Just((1_000_000_000.0, Some(Duration::of_nanos(1)))),
+        Just((3_000_000_000.0, Some(Duration::ZERO))),
+        Just((f64::INFINITY, Some(Duration::ZERO))),
+        Just((1.0 / 4_611_686_018_427_387_904.0, Some(Duration::of_seconds(4_611_686_018_427_387_904)))),
+        Just((1.0 / 9_223_372_036_854_775_808.0, None)),
+        Just((0.0, None)),
+        Just((-0.0, None)),
+        Just((-60.0, None)),
+        Just((f64::NAN, None)),
+    ]) {
+        prop_assert_eq!(expected, Duration::from_rate_per_second(hz));
+    }
+}
+
+proptest! {
+    #[test]
+    fn rate_round_trip(hz in 0.001..1_000_000.0f64) {
+        let period = Duration::from_rate_per_second(hz).unwrap();
+
+        // Within half a nanosecond, allowing for the precision of the floating point period.
+        prop_assert!((period.as_secs_f64() - 1.0 / hz).abs() <= 0.5e-9 + 1e-15 / hz, "{} {}", hz, period);
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_fixed_point((duration, fractional_bits, expected) in prop_oneof![
+        Just((Duration::of_millis(500), 16, Some(0x8000))),
+        Just((Duration::of_millis(1_500), 16, Some(0x1_8000))),
+        Just((Duration::of_millis(-500), 16, Some(-0x8000))),
+        Just((Duration::of_millis(-1_500), 16, Some(-0x1_8000))),
+        Just((Duration::of_seconds(3), 0, Some(3))),
+        Just((Duration::of_nanos(1), 16, Some(0))),
+        Just((Duration::of_nanos(1), 30, Some(1))),
+        Just((Duration::of_nanos(1), 31, Some(2))),
+        // Ties round to even.
+        Just((Duration::of_millis(250), 1, Some(0))),
+        Just((Duration::of_millis(750), 1, Some(2))),
+        Just((Duration::ZERO, 96, Some(0))),
+        Just((Duration::MAX, 64, Some(170_141_183_460_469_231_731_687_303_697_437_361_654))),
+        Just((Duration::MIN, 64, Some(i128::MIN))),
+        Just((Duration::of_seconds(-(1 << 31)), 96, Some(i128::MIN))),
+        Just((Duration::MAX, 65, None)),
+        Just((Duration::MIN, 65, None)),
+        Just((Duration::of_seconds(1 << 31), 96, None)),
+    ]) {
+        prop_assert_eq!(expected, duration.checked_to_fixed_point(fractional_bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_fixed_point((value, fractional_bits, expected) in prop_oneof![
+        Just((0x8000, 16, Some(Duration::of_millis(500)))),
+        Just((-0x8000, 16, Some(Duration::of_millis(-500)))),
+        Just((-0x1_8000, 16, Some(Duration::of_millis(-1_500)))),
+        Just((3, 0, Some(Duration::of_seconds(3)))),
+        Just((1, 30, Some(Duration::of_nanos(1)))),
+        Just((1, 31, Some(Duration::ZERO))),
+        // Ties round to even.
+        Just((1, 10, Some(Duration::of_nanos(976_562)))),
+        Just((3, 10, Some(Duration::of_nanos(2_929_688)))),
+        Just(((1 << 30) - 1, 30, Some(Duration::of_nanos(999_999_999)))),
+        // Rounds up into the next second.
+        Just(((1 << 64) - 1, 64, Some(Duration::of_seconds(1)))),
+        Just((i128::MIN, 64, Some(Duration::MIN))),
+        Just((i128::MAX, 0, None)),
+        Just((i128::MAX, 64, None)),
+    ]) {
+        prop_assert_eq!(expected, Duration::checked_from_fixed_point(value, fractional_bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn fixed_point_round_trips(seconds in -(1i64 << 29)..(1 << 29), nanos in 0..NANOSECONDS_IN_SECOND, fractional_bits in 30..=96u32) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(duration, Duration::from_fixed_point(duration.to_fixed_point(fractional_bits), fractional_bits));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "duration would overflow fixed point")]
+    fn to_fixed_point_overflow(duration in Just(Duration::MAX)) {
+        duration.to_fixed_point(96);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "fractional bits must be at most 96")]
+    fn fixed_point_fractional_bits_max(fractional_bits in 97..=u32::MAX) {
+        Duration::ZERO.to_fixed_point(fractional_bits);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "fractional bits must be at most 96")]
+    fn from_fixed_point_fractional_bits_max(fractional_bits in 97..=u32::MAX) {
+        Duration::from_fixed_point(0, fractional_bits);
+    }
+}
+
+proptest! {
+    #[test]
+    fn checked_fixed_point_fractional_bits_max(value in prop::num::i128::ANY, fractional_bits in prop_oneof![Just(97), Just(128), Just(200), Just(u32::MAX)]) {
+        prop_assert_eq!(None, Duration::checked_from_fixed_point(value, fractional_bits));
+        prop_assert_eq!(None, Duration::ZERO.checked_to_fixed_point(fractional_bits));
+        prop_assert_eq!(None, Duration::MAX.checked_to_fixed_point(fractional_bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn num_units((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, (0, 0, 0, 0, 0, 0, Some(0), Some(0)))),
+        Just((694_861, 1_001_000, (1, 8, 193, 11_581, 694_861, 694_861_001, Some(694_861_001_001), Some(694_861_001_001_000)))),
+        Just((-694_862, 998_999_000, (-1, -8, -193, -11_581, -694_861, -694_861_001, Some(-694_861_001_001), Some(-694_861_001_001_000)))),
+        Just((-1, 999_999_999, (0, 0, 0, 0, 0, 0, Some(0), Some(-1)))),
+        Just((-1, 500_000_000, (0, 0, 0, 0, 0, -500, Some(-500_000), Some(-500_000_000)))),
+        Just((i64::MAX, 999_999_999, (15_250_284_452_471, 106_751_991_167_300, 2_562_047_788_015_215, 153_722_867_280_912_930, i64::MAX, 0, None, None))),
+        Just((i64::MIN, 0, (-15_250_284_452_471, -106_751_991_167_300, -2_562_047_788_015_215, -153_722_867_280_912_930, i64::MIN, 0, None, None))),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let (weeks, days, hours, minutes, whole_seconds, millis, micros, total_nanos) = expected;
+        prop_assert_eq!(
+            (weeks, days, hours, minutes, whole_seconds, micros, total_nanos),
+            (
+                duration.num_weeks(),
+                duration.num_days(),
+                duration.num_hours(),
+                duration.num_minutes(),
+                duration.num_seconds(),
+                duration.num_microseconds(),
+                duration.num_nanoseconds(),
+            )
+        );
+        if micros.is_some() {
+            prop_assert_eq!(millis, duration.num_milliseconds());
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn num_units_match_to_methods(seconds in -1_000_000_000_000i64..1_000_000_000_000, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(duration.to_millis_rounding(RoundingMode::Down), duration.num_milliseconds());
+        if !duration.is_negative() {
+            prop_assert_eq!(duration.to_millis(), duration.num_milliseconds());
+            prop_assert_eq!(duration.seconds(), duration.num_seconds());
+        }
+        if let Some(nanos) = duration.num_nanoseconds() {
+            prop_assert_eq!(duration, Duration::of_nanos(nanos));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "millis would overflow i64")]
+    fn num_milliseconds_overflow(duration in prop_oneof![Just(Duration::MAX), Just(Duration::MIN)]) {
+        duration.num_milliseconds();
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_unit_and_remainder((duration, unit, expected) in prop_oneof![
+        Just((Duration::of_hours(76), Unit::Days, (3, Duration::of_hours(4)))),
+        Just((Duration::of_hours(-76), Unit::Days, (-3, Duration::of_hours(-4)))),
+        Just((Duration::of_millis(-1_500), Unit::Seconds, (-1, Duration::of_millis(-500)))),
+        Just((Duration::of_minutes(59), Unit::Hours, (0, Duration::of_minutes(59)))),
+        Just((Duration::MIN, Unit::Seconds, (i64::MIN, Duration::ZERO))),
+        Just((Duration::MAX, Unit::Seconds, (i64::MAX, Duration::of_nanos(999_999_999)))),
+    ]) {
+        prop_assert_eq!(Some(expected), duration.checked_to_unit_and_remainder(unit));
+        prop_assert_eq!(expected, duration.to_unit_and_remainder(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_unit_and_remainder_recombines(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, unit in prop_oneof![Just(Unit::Seconds), Just(Unit::Minutes), Just(Unit::Hours), Just(Unit::Days)]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let (count, remainder) = duration.to_unit_and_remainder(unit);
+        prop_assert!(-unit.duration() < remainder && remainder < unit.duration());
+        prop_assert!(remainder == Duration::ZERO || remainder.is_negative() == duration.is_negative());
+        prop_assert_eq!(Some(duration), unit.duration().checked_mul_add(count, remainder));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "count of unit would overflow i64")]
+    fn to_unit_and_remainder_overflow(duration in Just(Duration::MAX), unit in Just(Unit::Nanos)) {
+        let _split = duration.to_unit_and_remainder(unit);
+    }
+}
+
+proptest! {
+    #[test]
+    fn truncated_to((duration, unit, expected) in prop_oneof![
+        Just((Duration::of_minutes(90), Unit::Hours, Duration::of_hours(1))),
+        Just((Duration::of_millis(-1_500), Unit::Seconds, Duration::of_seconds(-1))),
+        Just((Duration::of_hours(-76), Unit::Days, Duration::of_days(-3))),
+        // java.time gives `PT-24H` here.
+        Just((Duration::of_seconds_and_adjustment(-86_400, 500_000_000), Unit::Hours, Duration::of_hours(-23))),
+        Just((Duration::MIN, Unit::Days, Duration::of_days(-106_751_991_167_300))),
+        Just((Duration::MAX, Unit::Nanos, Duration::MAX)),
+    ]) {
+        prop_assert_eq!(expected, duration.truncated_to(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn truncated_to_leaves_remainder(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, unit in prop_oneof![Just(Unit::Seconds), Just(Unit::Minutes), Just(Unit::Hours), Just(Unit::Days)]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(duration - duration.to_unit_and_remainder(unit).1, duration.truncated_to(unit));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn decompose((duration, units, expected) in prop_oneof![
+        Just((Duration::of_minutes(90), vec![Unit::Hours, Unit::Minutes], vec![(Unit::Hours, 1), (Unit::Minutes, 30)])),
+        Just((Duration::of_minutes(-90), vec![Unit::Hours, Unit::Minutes], vec![(Unit::Hours, -1), (Unit::Minutes, -30)])),
+        // The last unit drops what is left below its resolution.
+        Just((Duration::of_millis(-90_500), vec![Unit::Hours, Unit::Minutes, Unit::Seconds], vec![(Unit::Hours, 0), (Unit::Minutes, -1), (Unit::Seconds, -30)])),
+        // Units may be skipped.
+        Just((Duration::of_hours(76) + Duration::of_millis(1_500), vec![Unit::Days, Unit::Minutes, Unit::Millis], vec![(Unit::Days, 3), (Unit::Minutes, 240), (Unit::Millis, 1_500)])),
+        Just((Duration::MIN, vec![Unit::Days, Unit::Nanos], vec![(Unit::Days, -106_751_991_167_300), (Unit::Nanos, -55_808_000_000_000)])),
+    ]) {
+        prop_assert_eq!(Ok(expected), duration.decompose(&units));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn decompose_single_unit(seconds in -1_000_000_000i64..1_000_000_000, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(vec![(Unit::Days, duration.num_days())]), duration.decompose(&[Unit::Days]));
+        prop_assert_eq!(Ok(vec![(Unit::Hours, duration.num_hours())]), duration.decompose(&[Unit::Hours]));
+        prop_assert_eq!(Ok(vec![(Unit::Minutes, duration.num_minutes())]), duration.decompose(&[Unit::Minutes]));
+        prop_assert_eq!(Ok(vec![(Unit::Seconds, duration.num_seconds())]), duration.decompose(&[Unit::Seconds]));
+        prop_assert_eq!(Ok(vec![(Unit::Millis, duration.num_milliseconds())]), duration.decompose(&[Unit::Millis]));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn decompose_errors((duration, units, expected) in prop_oneof![
+        Just((Duration::ZERO, vec![], DecomposeError::NoUnits)),
+        Just((Duration::ZERO, vec![Unit::Minutes, Unit::Hours], DecomposeError::UnitsOutOfOrder(1))),
+        Just((Duration::ZERO, vec![Unit::Days, Unit::Hours, Unit::Hours], DecomposeError::UnitsOutOfOrder(2))),
+        Just((Duration::MAX, vec![Unit::Nanos], DecomposeError::Overflow)),
+    ]) {
+        prop_assert_eq!(Err(expected), duration.decompose(&units));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_millis_i32((duration, expected) in prop_oneof![
+        Just((Duration::of_seconds(1), Some(1_000))),
+        Just((Duration::of_days(30), None)),
+        Just((Duration::of_days(-30), None)),
+        Just((Duration::of_millis(i64::from(i32::MAX)), Some(i32::MAX))),
+        Just((Duration::of_millis(i64::from(i32::MAX) + 1), None)),
+        Just((Duration::of_millis(i64::from(i32::MIN)), Some(i32::MIN))),
+        Just((Duration::of_millis(i64::from(i32::MIN)) - Duration::of_nanos(1), None)),
+        Just((Duration::of_nanos(-1), Some(-1))),
+        Just((Duration::MAX, None)),
+    ]) {
+        prop_assert_eq!(expected, duration.to_millis_i32());
+    }
+}
+
+proptest! {
+    #[test]
+    fn millis_i32_round_trip(millis in prop::num::i32::ANY) {
+        let duration = Duration::of_millis_i32(millis);
+
+        prop_assert_eq!(Duration::of_millis(i64::from(millis)), duration);
+        prop_assert_eq!(Some(millis), duration.to_millis_i32());
+    }
+}
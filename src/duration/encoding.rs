@@ -0,0 +1,158 @@
+use proptest::prelude::*;
+
+use crate::{CompactBytes, DecodeError, Duration};
+
+fn durations() -> impl Strategy<Value = Duration> {
+    prop_oneof![
+        Just(Duration::MIN),
+        Just(Duration::ZERO),
+        Just(Duration::MAX),
+        (prop::num::i64::ANY, 0..1_000_000_000i64)
+            .prop_map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos)),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn bits_round_trip(duration in durations()) {
+        prop_assert_eq!(Ok(duration), Duration::from_bits(duration.to_bits()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn bits_preserve_order(duration in durations(), other in durations()) {
+        prop_assert_eq!(duration.cmp(&other), duration.to_bits().cmp(&other.to_bits()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn bits_known_values((duration, bits) in prop_oneof![
+        Just((Duration::MIN, 0)),
+        Just((Duration::ZERO, 1 << 127)),
+        Just((Duration::MAX, u128::MAX << 64 | 999_999_999)),
+        Just((Duration::of_nanos(-1), (u128::from(u64::MAX >> 1) << 64) | 999_999_999)),
+    ]) {
+        prop_assert_eq!(bits, duration.to_bits());
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_bits_invalid_padding(padding in 1..=u32::MAX) {
+        let bits = Duration::ZERO.to_bits() | u128::from(padding) << 32;
+
+        prop_assert_eq!(Err(DecodeError::InvalidPadding), Duration::from_bits(bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_bits_invalid_nanos(nanos in 1_000_000_000..=u32::MAX) {
+        let bits = Duration::ZERO.to_bits() | u128::from(nanos);
+
+        prop_assert_eq!(Err(DecodeError::InvalidNanos(nanos)), Duration::from_bits(bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn compact_round_trip(duration in durations()) {
+        let encoded = duration.to_compact();
+
+        prop_assert!(encoded.len() <= CompactBytes::len_max());
+        prop_assert_eq!(Ok(duration), Duration::from_compact(&encoded));
+    }
+}
+
+proptest! {
+    #[test]
+    fn compact_known_values((duration, bytes) in prop_oneof![
+        Just((Duration::ZERO, &[0xC0][..])),
+        Just((Duration::of_hours(1), &[0xA2][..])),
+        Just((Duration::of_hours(-1), &[0xA1][..])),
+        Just((Duration::of_days(1), &[0xC2][..])),
+        Just((Duration::of_minutes(90), &[0x94, 0x0B][..])),
+        Just((Duration::of_seconds(1), &[0x62][..])),
+        Just((Duration::of_millis(1_500), &[0x58, 0xBB, 0x01][..])),
+        Just((Duration::of_nanos(1), &[0x02][..])),
+        Just((Duration::of_seconds_and_adjustment(1, 1), &[0x12, 0xC0, 0xB2, 0xCD, 0x3B][..])),
+    ]) {
+        prop_assert_eq!(bytes, &duration.to_compact()[..]);
+        prop_assert_eq!(Ok(duration), Duration::from_compact(bytes));
+    }
+}
+
+#[test]
+fn compact_coarse_units_are_shorter() {
+    let hour = Duration::of_hours(1).to_compact();
+    let second_and_nano = Duration::of_seconds_and_adjustment(1, 1).to_compact();
+
+    assert!(
+        hour.len() < second_and_nano.len(),
+        "{:?} {:?}",
+        hour,
+        second_and_nano
+    );
+    assert!(second_and_nano.len() <= 5);
+}
+
+#[test]
+fn compact_len_max_is_tight() {
+    assert_eq!(
+        CompactBytes::len_max(),
+        Duration::of_seconds_and_adjustment(i64::MIN, 1)
+            .to_compact()
+            .len()
+    );
+}
+
+proptest! {
+    #[test]
+    fn compact_finer_units_decode((bytes, duration) in prop_oneof![
+        Just((&[0x78, 0x07][..], Duration::of_minutes(1))),
+        Just((&[0x58, 0x07][..], Duration::of_millis(60))),
+    ]) {
+        prop_assert_eq!(Ok(duration), Duration::from_compact(bytes));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_compact_invalid((bytes, error) in prop_oneof![
+        Just((&[][..], DecodeError::InvalidLength { expected: 1, actual: 0 })),
+        Just((&[0xE0][..], DecodeError::UnknownUnit(7))),
+        Just((&[0x12][..], DecodeError::InvalidLength { expected: 2, actual: 1 })),
+        Just((&[0x12, 0x80][..], DecodeError::InvalidLength { expected: 3, actual: 2 })),
+        Just((&[0xA2, 0x00][..], DecodeError::InvalidLength { expected: 1, actual: 2 })),
+        Just((&[0x12, 0x00][..], DecodeError::InvalidPadding)),
+        Just((&[0x12, 0x80, 0x00][..], DecodeError::InvalidPadding)),
+        Just((&[0xDF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F][..], DecodeError::OutOfRange)),
+        Just((&[0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..], DecodeError::OutOfRange)),
+    ]) {
+        prop_assert_eq!(Err(error), Duration::from_compact(bytes));
+    }
+}
+
+proptest! {
+    #[test]
+    fn canonical_hash_is_stable(duration in durations()) {
+        // Rebuilding the value, rather than copying it, must not change its hash.
+        let rebuilt = Duration::of_seconds_and_adjustment(duration.seconds(), i64::from(duration.nano()));
+
+        prop_assert_eq!(duration.canonical_hash(), duration.canonical_hash());
+        prop_assert_eq!(duration.canonical_hash(), rebuilt.canonical_hash());
+    }
+}
+
+proptest! {
+    #[test]
+    fn canonical_hash_ignores_construction((duration, other) in prop_oneof![
+        Just((Duration::of_millis(1_500), Duration::of_seconds_and_adjustment(1, 500_000_000))),
+        Just((Duration::of_nanos(-1), Duration::of_seconds_and_adjustment(-1, 999_999_999))),
+        Just((Duration::of_minutes(1), Duration::of_seconds(60))),
+    ]) {
+        prop_assert_eq!(duration.canonical_hash(), other.canonical_hash());
+    }
+}
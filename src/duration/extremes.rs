@@ -0,0 +1,89 @@
+use proptest::prelude::*;
+
+use crate::testing::*;
+use crate::{Duration, RoundingMode};
+
+fn edges() -> impl Strategy<Value = i64> {
+    proptest::sample::select(I64_EDGES.to_vec())
+}
+
+fn extremes() -> impl Strategy<Value = Duration> {
+    prop_oneof![
+        Just(Duration::MIN),
+        Just(Duration::of_seconds_and_adjustment(i64::MIN, 1)),
+        Just(Duration::of_nanos(-1)),
+        Just(Duration::ZERO),
+        Just(Duration::of_seconds_and_adjustment(i64::MAX, 0)),
+        Just(Duration::MAX),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn constructors_never_panic(value in edges()) {
+        let _seconds = Duration::of_seconds(value);
+        let _millis = Duration::of_millis(value);
+        let _micros = Duration::of_micros(value);
+        let _nanos = Duration::of_nanos(value);
+    }
+}
+
+proptest! {
+    #[test]
+    fn constructors_panic_only_when_checked_is_none(value in edges(), adjustment in edges()) {
+        assert_panics_only_when_none(|| Duration::of_weeks(value), Duration::checked_of_weeks(value));
+        assert_panics_only_when_none(|| Duration::of_days(value), Duration::checked_of_days(value));
+        assert_panics_only_when_none(|| Duration::of_hours(value), Duration::checked_of_hours(value));
+        assert_panics_only_when_none(|| Duration::of_minutes(value), Duration::checked_of_minutes(value));
+        assert_panics_only_when_none(
+            || Duration::of_seconds_and_adjustment(value, adjustment),
+            Duration::checked_of_seconds_and_adjustment(value, adjustment),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_seconds_and_adjustment_edges((seconds, adjustment, expected) in prop_oneof![
+        Just((0, i64::MIN, Some((-9_223_372_037, 145_224_192)))),
+        Just((0, i64::MAX, Some((9_223_372_036, 854_775_807)))),
+        Just((i64::MIN, i64::MIN, None)),
+        Just((i64::MAX, i64::MAX, None)),
+        Just((i64::MIN, i64::MAX, Some((i64::MIN + 9_223_372_036, 854_775_807)))),
+        Just((i64::MAX, -1, Some((i64::MAX - 1, 999_999_999)))),
+        Just((i64::MIN, -1, None)),
+    ]) {
+        let duration = Duration::checked_of_seconds_and_adjustment(seconds, adjustment);
+
+        prop_assert_eq!(expected, duration.map(|duration| (duration.seconds(), duration.nano())));
+    }
+}
+
+proptest! {
+    #[test]
+    fn conversions_panic_only_when_checked_is_none(duration in extremes(), scalar in edges()) {
+        assert_panics_only_when_none(|| duration.to_millis(), duration.checked_to_millis());
+        for mode in [RoundingMode::Up, RoundingMode::Down, RoundingMode::Ceiling, RoundingMode::Floor, RoundingMode::HalfEven] {
+            assert_panics_only_when_none(|| duration.to_millis_rounding(mode), duration.checked_to_millis_rounding(mode));
+        }
+        assert_panics_only_when_none(|| duration * scalar, duration.checked_mul(scalar));
+        assert_panics_only_when_none(|| -duration, duration.checked_neg());
+        assert_panics_only_when_none(|| duration + Duration::MAX, duration.checked_add(Duration::MAX));
+        assert_panics_only_when_none(|| duration - Duration::MAX, duration.checked_sub(Duration::MAX));
+        assert_panics_only_when_none(|| duration % Duration::of_nanos(scalar), duration.checked_rem(Duration::of_nanos(scalar)));
+        assert_panics_only_when_none(|| duration.mul_add(scalar, Duration::MIN), duration.checked_mul_add(scalar, Duration::MIN));
+    }
+}
+
+proptest! {
+    #[test]
+    fn conversions_never_panic(duration in extremes()) {
+        let _std = duration.to_std();
+        let _seconds = duration.num_seconds();
+        let _micros = duration.num_microseconds();
+        let _nanos = duration.num_nanoseconds();
+        let _saturated = duration.saturating_neg();
+        let _parts = duration.parts().count();
+        let _float = duration.as_secs_f64();
+    }
+}
@@ -0,0 +1,273 @@
+use proptest::prelude::*;
+
+use std::convert::TryFrom;
+
+use crate::constants::*;
+
+use crate::{duration, Duration};
+
+proptest! {
+    #[test]
+    fn of_seconds(seconds in prop::num::i64::ANY) {
+        let duration = Duration::of_seconds(seconds);
+
+        prop_assert_eq!(0, duration.nano());
+        prop_assert_eq!(seconds, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_seconds_and_adjustment(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(nanos, duration.nano() as i64);
+        prop_assert_eq!(seconds, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_seconds_and_adjustment_negative(seconds in (i64::MIN + 1)..=i64::MAX, nanos in 1..NANOSECONDS_IN_SECOND) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, -nanos);
+
+        prop_assert_eq!(NANOSECONDS_IN_SECOND - nanos, duration.nano() as i64);
+        prop_assert_eq!(seconds - 1, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "seconds would overflow duration")]
+    fn of_seconds_and_adjustment_overflow(seconds in Just(i64::MAX), nanoseconds in Just(NANOSECONDS_IN_SECOND)) {
+        let _duration = Duration::of_seconds_and_adjustment(seconds, nanoseconds);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "seconds would overflow duration")]
+    fn of_seconds_and_adjustment_underflow(seconds in Just(i64::MIN), nanoseconds in Just(-1)) {
+        let _duration = Duration::of_seconds_and_adjustment(seconds, nanoseconds);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_weeks(weeks in (i64::MIN / SECONDS_IN_DAY / DAYS_IN_WEEK_ISO)..=(i64::MAX / SECONDS_IN_DAY / DAYS_IN_WEEK_ISO)) {
+        let duration = Duration::of_weeks(weeks);
+
+        prop_assert_eq!(Duration::of_days(weeks * DAYS_IN_WEEK_ISO), duration);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "weeks would overflow duration")]
+    fn of_weeks_overflow(weeks in (i64::MAX / SECONDS_IN_DAY / DAYS_IN_WEEK_ISO + 1)..=i64::MAX) {
+        let _duration = Duration::of_weeks(weeks);
+    }
+}
+
+proptest! {
+    #[test]
+    fn estimated_lengths_are_pinned(count in prop_oneof![Just(1i64), Just(-1), Just(12), Just(400)]) {
+        // These are the exact lengths java.time estimates, which must never drift.
+        prop_assert_eq!(Duration::of_seconds(count * 31_556_952), Duration::of_years_estimated(count));
+        prop_assert_eq!(Duration::of_seconds(count * 2_629_746), Duration::of_months_estimated(count));
+        prop_assert_eq!(Duration::of_years_estimated(count), Duration::of_months_estimated(count * 12));
+        // 400 Gregorian years are 146,097 days.
+        prop_assert_eq!(Duration::of_days(146_097 * count), Duration::of_years_estimated(400 * count));
+    }
+}
+
+proptest! {
+    #[test]
+    fn estimated_at_limits(years in prop_oneof![Just(i64::MAX / 31_556_952), Just(i64::MIN / 31_556_952)]) {
+        prop_assert_eq!(Some(Duration::of_seconds(years * 31_556_952)), Duration::checked_of_years_estimated(years));
+        prop_assert_eq!(None, Duration::checked_of_years_estimated(years + years.signum()));
+        prop_assert_eq!(Some(Duration::of_seconds(years * 31_556_952)), Duration::checked_of_months_estimated(years * 12));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "years would overflow duration")]
+    fn of_years_estimated_overflow(years in prop_oneof![Just(i64::MAX / 31_556_952 + 1), Just(i64::MIN)]) {
+        let _duration = Duration::of_years_estimated(years);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "months would overflow duration")]
+    fn of_months_estimated_overflow(months in prop_oneof![Just(i64::MAX / 2_629_746 + 1), Just(i64::MIN)]) {
+        let _duration = Duration::of_months_estimated(months);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_days(days in (i64::MIN / SECONDS_IN_DAY)..=(i64::MAX / SECONDS_IN_DAY)) {
+        let duration = Duration::of_days(days);
+
+        prop_assert_eq!(0, duration.nano());
+        prop_assert_eq!(days * SECONDS_IN_DAY, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "days would overflow duration")]
+    fn of_days_overflow(days in (i64::MAX / SECONDS_IN_DAY + 1)..=i64::MAX) {
+        let _duration = Duration::of_days(days);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_hours(hours in (i64::MIN / SECONDS_IN_HOUR)..=(i64::MAX / SECONDS_IN_HOUR)) {
+        let duration = Duration::of_hours(hours);
+
+        prop_assert_eq!(0, duration.nano());
+        prop_assert_eq!(hours * SECONDS_IN_HOUR, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "hours would overflow duration")]
+    fn of_hours_underflow(hours in i64::MIN..(i64::MIN / SECONDS_IN_HOUR)) {
+        let _duration = Duration::of_hours(hours);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_minutes(minutes in (i64::MIN / SECONDS_IN_MINUTE)..=(i64::MAX / SECONDS_IN_MINUTE)) {
+        let duration = Duration::of_minutes(minutes);
+
+        prop_assert_eq!(0, duration.nano());
+        prop_assert_eq!(minutes * SECONDS_IN_MINUTE, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "minutes would overflow duration")]
+    fn of_minutes_overflow(minutes in (i64::MAX / SECONDS_IN_MINUTE + 1)..=i64::MAX) {
+        let _duration = Duration::of_minutes(minutes);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_millis(millis in prop::num::i64::ANY) {
+        let duration = Duration::of_millis(millis);
+
+        let (seconds, nanos) = if millis >= 0 || millis % MILLISECONDS_IN_SECOND == 0 {
+            (millis / MILLISECONDS_IN_SECOND, millis % MILLISECONDS_IN_SECOND * NANOSECONDS_IN_MILLISECOND)
+        } else {
+            (millis / MILLISECONDS_IN_SECOND - 1, millis % MILLISECONDS_IN_SECOND * NANOSECONDS_IN_MILLISECOND + NANOSECONDS_IN_SECOND)
+        };
+
+        prop_assert_eq!(nanos as u32, duration.nano());
+        prop_assert_eq!(seconds, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_nanos(nanos in prop::num::i64::ANY) {
+        let duration = Duration::of_nanos(nanos);
+
+        let (seconds, nanos) = if nanos >= 0 || nanos % NANOSECONDS_IN_SECOND == 0 {
+            (nanos / NANOSECONDS_IN_SECOND, nanos % NANOSECONDS_IN_SECOND)
+        } else {
+            (nanos / NANOSECONDS_IN_SECOND - 1, nanos % NANOSECONDS_IN_SECOND + NANOSECONDS_IN_SECOND)
+        };
+
+        prop_assert_eq!(nanos as u32, duration.nano());
+        prop_assert_eq!(seconds, duration.seconds());
+    }
+}
+
+proptest! {
+    #[test]
+    fn sub_second_factories_at_extremes((value, millis, micros, nanos) in prop_oneof![
+        Just((i64::MIN, (-9_223_372_036_854_776, 192_000_000), (-9_223_372_036_855, 224_192_000), (-9_223_372_037, 145_224_192))),
+        Just((i64::MIN + 1, (-9_223_372_036_854_776, 193_000_000), (-9_223_372_036_855, 224_193_000), (-9_223_372_037, 145_224_193))),
+        Just((-1, (-1, 999_000_000), (-1, 999_999_000), (-1, 999_999_999))),
+        Just((i64::MAX - 1, (9_223_372_036_854_775, 806_000_000), (9_223_372_036_854, 775_806_000), (9_223_372_036, 854_775_806))),
+        Just((i64::MAX, (9_223_372_036_854_775, 807_000_000), (9_223_372_036_854, 775_807_000), (9_223_372_036, 854_775_807))),
+    ]) {
+        let parts = |duration: Duration| (duration.seconds(), duration.nano());
+
+        prop_assert_eq!(millis, parts(Duration::of_millis(value)));
+        prop_assert_eq!(micros, parts(Duration::of_micros(value)));
+        prop_assert_eq!(nanos, parts(Duration::of_nanos(value)));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_i64_is_seconds(seconds in prop::num::i64::ANY) {
+        prop_assert_eq!(Duration::of_seconds(seconds), Duration::from(seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_i32_is_seconds(seconds in prop::num::i32::ANY) {
+        prop_assert_eq!(Duration::of_seconds(i64::from(seconds)), Duration::from(seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn try_from_u64_is_seconds(seconds in 0..=i64::MAX as u64) {
+        prop_assert_eq!(Ok(Duration::of_seconds(seconds as i64)), Duration::try_from(seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn try_from_u64_overflow(seconds in i64::MAX as u64 + 1..=u64::MAX) {
+        prop_assert!(Duration::try_from(seconds).is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_micros(micros in prop::num::i64::ANY) {
+        let duration = Duration::of_micros(micros);
+
+        prop_assert_eq!(
+            i128::from(micros) * 1_000,
+            i128::from(duration.seconds()) * 1_000_000_000 + i128::from(duration.nano())
+        );
+    }
+}
+
+const TIMEOUT: Duration = duration!(2 h, 30 min);
+const NEGATIVE: Duration = duration!(-1 s, 500 ms);
+const EVERY_UNIT: Duration = duration!(1 d, 2 h, 3 min, 4 s, 5 ms, 6 us, 7 ns,);
+const LONGEST: Duration = duration!(9_223_372_036_854_775_807 s, 999_999_999 ns);
+const SHORTEST: Duration = duration!(-9_223_372_036_854_775_808 s);
+
+proptest! {
+    #[test]
+    fn duration_macro((duration, expected) in prop_oneof![
+        Just((TIMEOUT, Duration::of_minutes(150))),
+        Just((NEGATIVE, Duration::of_millis(-1_500))),
+        Just((EVERY_UNIT, Duration::of_seconds_and_adjustment(93_784, 5_006_007))),
+        Just((LONGEST, Duration::MAX)),
+        Just((SHORTEST, Duration::MIN)),
+        Just((duration!(0 ns), Duration::ZERO)),
+        Just((duration!(-0 s), Duration::ZERO)),
+        Just((duration!(90 min, 30 min), Duration::of_hours(2))),
+        Just((duration!(-1 ns), Duration::of_nanos(-1))),
+    ]) {
+        prop_assert_eq!(expected, duration);
+    }
+}
@@ -0,0 +1,444 @@
+use std::fmt;
+
+use proptest::prelude::*;
+
+use crate::testing::FixedWriter;
+#[cfg(feature = "std")]
+use crate::DurationStyle;
+use crate::{Duration, ParseErrorKind};
+
+proptest! {
+    #[test]
+    fn display((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, "PT0S")),
+        Just((1, 0, "PT1S")),
+        Just((60, 0, "PT1M")),
+        Just((-60, 0, "PT-1M")),
+        Just((3_600, 0, "PT1H")),
+        Just((-3_600, 0, "PT-1H")),
+        Just((5_400, 0, "PT1H30M")),
+        Just((86_400, 0, "PT24H")),
+        Just((59, 1, "PT59.000000001S")),
+        Just((500, 345_000_000, "PT8M20.345S")),
+        Just((-1, 500_000_000, "PT-0.5S")),
+        Just((-61, 500_000_000, "PT-1M-0.5S")),
+        Just((-62, 500_000_000, "PT-1M-1.5S")),
+        Just((-3_601, 999_999_999, "PT-1H-0.000000001S")),
+        Just((-3_660, 500_000_000, "PT-1H-59.5S")),
+        Just((-86_400, 1, "PT-23H-59M-59.999999999S")),
+        Just((i64::MAX, 999_999_999, "PT2562047788015215H30M7.999999999S")),
+        Just((i64::MIN, 0, "PT-2562047788015215H-30M-8S")),
+        Just((i64::MIN, 1, "PT-2562047788015215H-30M-7.999999999S")),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.to_string());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_iso_full((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, "PT0H0M0S")),
+        Just((3_600, 0, "PT1H0M0S")),
+        Just((60, 0, "PT0H1M0S")),
+        Just((1, 0, "PT0H0M1S")),
+        Just((5_400, 0, "PT1H30M0S")),
+        Just((500, 345_000_000, "PT0H8M20.345S")),
+        Just((-1, 500_000_000, "-PT0H0M0.5S")),
+        Just((-3_600, 0, "-PT1H0M0S")),
+        Just((-62, 500_000_000, "-PT0H1M1.5S")),
+        Just((i64::MIN, 0, "-PT2562047788015215H30M8S")),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.to_iso_full());
+        prop_assert_eq!(Ok(duration), Duration::parse(expected));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_iso_full_of_hours(hours in Just(1)) {
+        prop_assert_eq!("PT1H0M0S", Duration::of_hours(hours).to_iso_full());
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn implements_defmt_format() {
+    fn assert_format<T: defmt::Format>() {}
+
+    assert_format::<Duration>();
+}
+
+proptest! {
+    #[test]
+    fn defmt_rendering((duration, expected) in prop_oneof![
+        Just((Duration::ZERO, "PT0.000000000S")),
+        Just((Duration::of_millis(1_500), "PT1.500000000S")),
+        Just((Duration::of_millis(-500), "PT-0.500000000S")),
+        Just((Duration::of_nanos(-1), "PT-0.000000001S")),
+        Just((Duration::of_seconds_and_adjustment(-2, 250_000_000), "PT-1.750000000S")),
+        Just((Duration::MAX, "PT9223372036854775807.999999999S")),
+        Just((Duration::MIN, "PT-9223372036854775808.000000000S")),
+    ]) {
+        let digits = duration.defmt_digits();
+
+        prop_assert_eq!(expected, digits.as_str());
+    }
+}
+
+// The original rendering through `core::fmt`'s integer formatting, as a reference for the digit buffer.
+fn reference_iso(duration: Duration, all_components: bool) -> String {
+    if duration.is_zero() && !all_components {
+        return "PT0S".to_owned();
+    }
+    if all_components && duration.is_negative() {
+        let magnitude =
+            -(i128::from(duration.seconds()) * 1_000_000_000 + i128::from(duration.nano()));
+        let (seconds, nanos) = (magnitude / 1_000_000_000, magnitude % 1_000_000_000);
+        let mut formatted = format!(
+            "-PT{}H{}M{}",
+            seconds / 3_600,
+            seconds % 3_600 / 60,
+            seconds % 60
+        );
+        if nanos > 0 {
+            formatted += &format!(".{}", format!("{:09}", nanos).trim_end_matches('0'));
+        }
+        return formatted + "S";
+    }
+    let (effective_seconds, directed_nanos) = if duration.seconds() < 0 && duration.nano() > 0 {
+        (duration.seconds() + 1, 1_000_000_000 - duration.nano())
+    } else {
+        (duration.seconds(), duration.nano())
+    };
+    let (hours, minutes, seconds) = (
+        effective_seconds / 3_600,
+        effective_seconds % 3_600 / 60,
+        effective_seconds % 60,
+    );
+
+    let mut formatted = "PT".to_owned();
+    if hours != 0 || all_components {
+        formatted += &format!("{}H", hours);
+    }
+    if minutes != 0 || all_components {
+        formatted += &format!("{}M", minutes);
+    }
+    if seconds == 0 && directed_nanos == 0 && !all_components {
+        return formatted;
+    }
+    if seconds == 0 && duration.seconds() < 0 && directed_nanos > 0 {
+        formatted += "-0";
+    } else {
+        formatted += &format!("{}", seconds);
+    }
+    if directed_nanos > 0 {
+        formatted += &format!(
+            ".{}",
+            format!("{:09}", directed_nanos).trim_end_matches('0')
+        );
+    }
+    formatted + "S"
+}
+
+fn spread_durations(count: usize) -> impl Iterator<Item = Duration> {
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    (0..count).map(move |index| {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        // Spread the magnitudes across every digit count, and the nanos across every trailing zero count.
+        let seconds = (state as i64) >> (index % 64);
+        let nanos = (state >> 32) as i64 % 1_000_000_000 / 10i64.pow((index % 10) as u32);
+        Duration::of_seconds_and_adjustment(seconds, nanos * 10i64.pow((index % 10) as u32))
+    })
+}
+
+#[test]
+fn display_matches_reference() {
+    for duration in
+        spread_durations(200_000).chain(vec![Duration::MIN, Duration::MAX, Duration::ZERO])
+    {
+        assert_eq!(reference_iso(duration, false), duration.to_string());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn to_iso_full_matches_reference() {
+    for duration in
+        spread_durations(200_000).chain(vec![Duration::MIN, Duration::MAX, Duration::ZERO])
+    {
+        assert_eq!(reference_iso(duration, true), duration.to_iso_full());
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_matches_reference_for_any(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(reference_iso(duration, false), duration.to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_into_fixed_buffer(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let mut writer = FixedWriter::<38>::new();
+
+        prop_assert_eq!(Ok(()), duration.format_into(&mut writer));
+        prop_assert_eq!(duration.to_string(), writer.as_str());
+        prop_assert!(writer.as_str().len() <= Duration::formatted_len_max());
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_into_overflow_writes_nothing(duration in Just(Duration::of_seconds_and_adjustment(500, 345_000_000))) {
+        let mut writer = FixedWriter::<8>::new();
+
+        prop_assert_eq!(Err(fmt::Error), duration.format_into(&mut writer));
+        prop_assert_eq!("", writer.as_str());
+    }
+}
+
+#[test]
+fn formatted_len_max_is_tight() {
+    let longest = Duration::of_seconds_and_adjustment(-9_223_372_036_854_770_400, 1);
+
+    assert_eq!(
+        Duration::formatted_len_max(),
+        longest.to_string().len(),
+        "{}",
+        longest
+    );
+    assert!(Duration::MIN.to_string().len() <= Duration::formatted_len_max());
+    assert!(Duration::MAX.to_string().len() <= Duration::formatted_len_max());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn formatted_len_max_covers_to_iso_full() {
+    for duration in [
+        Duration::MIN,
+        Duration::of_seconds_and_adjustment(-9_223_372_036_854_770_400, 1),
+        Duration::MAX,
+        Duration::ZERO,
+    ] {
+        assert!(
+            duration.to_iso_full().len() <= Duration::formatted_len_max(),
+            "{}",
+            duration
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_padding((format, expected) in prop_oneof![
+        Just((format!("{:>12}", Duration::of_minutes(1)), "        PT1M")),
+        Just((format!("{:12}", Duration::of_minutes(1)), "PT1M        ")),
+        Just((format!("{:<12}", Duration::of_minutes(-1)), "PT-1M       ")),
+        Just((format!("{:^12}", Duration::of_minutes(1)), "    PT1M    ")),
+        Just((format!("{:0>12}", Duration::of_minutes(1)), "00000000PT1M")),
+        Just((format!("{:*<12}", Duration::of_millis(1_500)), "PT1.5S******")),
+        Just((format!("{:>4}", Duration::of_millis(1_500)), "PT1.5S")),
+    ]) {
+        prop_assert_eq!(expected, format);
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_without_flags_matches_format_into(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let mut writer = FixedWriter::<38>::new();
+        duration.format_into(&mut writer).unwrap();
+
+        prop_assert_eq!(writer.as_str(), format!("{}", duration));
+        prop_assert_eq!(reference_iso(duration, false), format!("{}", duration));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_fixed_iso_string((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, "PT0000000000000000H00M00.000000000S")),
+        Just((303, 250_000_000, "PT0000000000000000H05M03.250000000S")),
+        Just((360_000, 0, "PT0000000000000100H00M00.000000000S")),
+        Just((-90, 0, "-PT0000000000000000H01M30.000000000S")),
+        Just((-1, 500_000_000, "-PT0000000000000000H00M00.500000000S")),
+        Just((i64::MAX, 999_999_999, "PT2562047788015215H30M07.999999999S")),
+        Just((i64::MIN, 0, "-PT2562047788015215H30M08.000000000S")),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.to_fixed_iso_string());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn fixed_iso_round_trip(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(duration), Duration::parse_fixed_iso(&duration.to_fixed_iso_string()));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn fixed_iso_string_order_matches_duration_order(
+        seconds in 0..=i64::MAX,
+        nanos in 0..1_000_000_000i64,
+        other_seconds in prop_oneof![0..=i64::MAX, 0..1_000_000i64],
+        other_nanos in 0..1_000_000_000i64,
+    ) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let other = Duration::of_seconds_and_adjustment(other_seconds, other_nanos);
+
+        prop_assert_eq!(
+            duration.cmp(&other),
+            duration.to_fixed_iso_string().cmp(&other.to_fixed_iso_string())
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_fixed_iso_invalid((text, kind, position) in prop_oneof![
+        Just(("PT1H05M03.250000000S", ParseErrorKind::InvalidCharacter, 3)),
+        Just(("PT0000000000000000H5M03.250000000S", ParseErrorKind::InvalidCharacter, 20)),
+        Just(("PT0000000000000000H60M00.000000000S", ParseErrorKind::FieldOutOfRange, 19)),
+        Just(("PT0000000000000000H00M03.25S", ParseErrorKind::InvalidCharacter, 27)),
+        Just(("PT0000000000000000H00M03S", ParseErrorKind::InvalidCharacter, 24)),
+        Just(("PT2562047788015216H00M00.000000000S", ParseErrorKind::FieldOutOfRange, 2)),
+        Just(("PT0000000000000000H00M00.000000000SS", ParseErrorKind::TrailingCharacters, 35)),
+        Just(("+PT0000000000000000H00M00.000000000S", ParseErrorKind::InvalidCharacter, 0)),
+    ]) {
+        let error = Duration::parse_fixed_iso(text).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_decimal_seconds((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, "0")),
+        Just((8, 500_000_000, "8.5")),
+        Just((8, 0, "8")),
+        Just((0, 1, "0.000000001")),
+        Just((-1, 999_999_999, "-0.000000001")),
+        Just((-1, 500_000_000, "-0.5")),
+        Just((-9, 500_000_000, "-8.5")),
+        Just((-8, 0, "-8")),
+        Just((i64::MAX, 999_999_999, "9223372036854775807.999999999")),
+        Just((i64::MIN, 0, "-9223372036854775808")),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.to_decimal_seconds());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn decimal_seconds_round_trip(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(duration), Duration::from_decimal_seconds(&duration.to_decimal_seconds()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_precision((duration, precision, expected) in prop_oneof![
+        Just((Duration::of_minutes(1), 2, "PT1M0.00S")),
+        Just((Duration::of_minutes(1), 0, "PT1M0S")),
+        Just((Duration::of_seconds(9_000), 3, "PT2H30M0.000S")),
+        Just((Duration::ZERO, 3, "PT0.000S")),
+        Just((Duration::of_nanos(123_456_789), 6, "PT0.123457S")),
+        Just((Duration::of_nanos(123_456_789), 12, "PT0.123456789S")),
+        Just((Duration::of_millis(1_500), 0, "PT2S")),
+        Just((Duration::of_millis(59_999), 2, "PT1M0.00S")),
+        Just((Duration::of_millis(-1_500), 3, "PT-1.500S")),
+        Just((Duration::of_millis(-1_500), 0, "PT-2S")),
+        Just((Duration::of_nanos(-1), 3, "PT0.000S")),
+        Just((Duration::of_minutes(-1), 1, "PT-1M0.0S")),
+        Just((Duration::MAX, 0, "PT2562047788015215H30M8S")),
+    ]) {
+        prop_assert_eq!(expected, format!("{:.*}", precision, duration));
+        prop_assert_eq!(expected, format!("{:.*}", precision, duration.display()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_precision_with_width((format, expected) in prop_oneof![
+        Just((format!("{:>12.2}", Duration::of_minutes(1)), "   PT1M0.00S")),
+        Just((format!("{:*<12.3}", Duration::of_seconds(9_000).display()), "PT2H30M0.000S")),
+        Just((format!("{:-^10.1}", Duration::of_millis(1_250)), "--PT1.3S--")),
+    ]) {
+        prop_assert_eq!(expected, format);
+    }
+}
+
+proptest! {
+    #[test]
+    fn lazy_display_matches_display(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(format!("{}", duration), format!("{}", duration.display()));
+        prop_assert_eq!(format!("{:*^48}", duration), format!("{:*^48}", duration.display()));
+        prop_assert_eq!(format!("{:.4}", duration), format!("{:.4}", duration.display()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn debug((duration, expected) in prop_oneof![
+        Just((Duration::ZERO, "Duration(PT0S, seconds=0, nanos=0)")),
+        Just((Duration::of_minutes(150), "Duration(PT2H30M, seconds=9000, nanos=0)")),
+        Just((Duration::of_millis(-500), "Duration(PT-0.5S, seconds=-1, nanos=500000000)")),
+    ]) {
+        prop_assert_eq!(expected, format!("{:?}", duration));
+    }
+}
+
+#[test]
+fn debug_alternate() {
+    assert_eq!(
+        "Duration(\n    PT2H30M,\n    seconds=9000,\n    nanos=0,\n)",
+        format!("{:#?}", Duration::of_minutes(150))
+    );
+    assert_eq!(
+        "Some(\n    Duration(\n        PT1S,\n        seconds=1,\n        nanos=0,\n    ),\n)",
+        format!("{:#?}", Some(Duration::of_seconds(1)))
+    );
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn format_styles((style, expected) in prop_oneof![
+        Just((DurationStyle::Iso8601, "PT1M30.5S")),
+        Just((DurationStyle::Clock, "00:01:30.5")),
+        Just((DurationStyle::Human, "in 2 minutes")),
+        Just((DurationStyle::DecimalSeconds, "90.5")),
+    ]) {
+        prop_assert_eq!(expected, Duration::of_millis(90_500).format(style));
+    }
+}
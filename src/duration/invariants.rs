@@ -0,0 +1,123 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+use crate::testing::I64_EDGES;
+use crate::{Duration, RoundingMode, Unit};
+
+// Durations weighted towards the edges of the range, where a carry or borrow is most likely to escape.
+fn durations() -> impl Strategy<Value = Duration> {
+    prop_oneof![
+        Just(Duration::MIN),
+        Just(Duration::ZERO),
+        Just(Duration::MAX),
+        (
+            prop::sample::select(I64_EDGES.to_vec()),
+            prop_oneof![Just(0), Just(1), Just(999_999_999)]
+        )
+            .prop_map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos)),
+        (prop::num::i64::ANY, 0..NANOSECONDS_IN_SECOND)
+            .prop_map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos)),
+        (-1_000_000i64..1_000_000, 0..NANOSECONDS_IN_SECOND)
+            .prop_map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos)),
+    ]
+}
+
+fn is_valid(duration: Duration) -> bool {
+    duration.nano() < NANOSECONDS_IN_SECOND as u32
+}
+
+proptest! {
+    #[test]
+    fn constructors_are_valid(amount in prop::num::i64::ANY, adjustment in prop::num::i64::ANY, fixed_point in prop::num::i128::ANY, fractional_bits in 0..=96u32) {
+        let constructed = [
+            Duration::checked_of_weeks(amount),
+            Duration::checked_of_days(amount),
+            Duration::checked_of_hours(amount),
+            Duration::checked_of_minutes(amount),
+            Some(Duration::of_seconds(amount)),
+            Duration::checked_of_seconds_and_adjustment(amount, adjustment),
+            Some(Duration::of_millis(amount)),
+            Some(Duration::of_millis_i32(amount as i32)),
+            Some(Duration::of_micros(amount)),
+            Some(Duration::of_nanos(amount)),
+            Duration::checked_from_fixed_point(fixed_point, fractional_bits),
+        ];
+        for duration in constructed.iter().flatten() {
+            prop_assert!(is_valid(*duration), "{:?}", duration);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn arithmetic_is_valid(duration in durations(), other in durations(), scalar in prop::num::i64::ANY) {
+        let results = [
+            duration.checked_add(other),
+            Some(duration.saturating_add(other)),
+            duration.checked_sub(other),
+            Some(duration.saturating_sub(other)),
+            duration.checked_mul(scalar),
+            Some(duration.saturating_mul(scalar)),
+            duration.checked_mul_add(scalar, other),
+            duration.checked_neg(),
+            Some(duration.saturating_neg()),
+            duration.checked_rem(other),
+            duration.checked_to_unit_and_remainder(Unit::Hours).map(|(_, remainder)| remainder),
+        ];
+        for result in results.iter().flatten() {
+            prop_assert!(is_valid(*result), "{:?}", result);
+        }
+        if other > Duration::ZERO {
+            for multiple in duration.next_multiple_of(other).iter().chain(duration.prev_multiple_of(other).iter()) {
+                prop_assert!(is_valid(*multiple), "{:?}", multiple);
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn split_chunks_are_valid(duration in durations(), parts in 1..1_000u32) {
+        for chunk in duration.split_evenly(parts).unwrap() {
+            prop_assert!(is_valid(chunk), "{:?}", chunk);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn conversions_round_trip_valid(duration in durations()) {
+        prop_assert_eq!(Ok(duration), Duration::from_bits(duration.to_bits()));
+        prop_assert_eq!(Ok(duration), Duration::from_compact(&duration.to_compact()));
+        if let Some(millis) = duration.checked_to_millis_rounding(RoundingMode::Floor) {
+            prop_assert!(is_valid(Duration::of_millis(millis)));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_then_sub_is_identity(duration in durations(), other in durations()) {
+        if let Some(sum) = duration.checked_add(other) {
+            prop_assert_eq!(Some(duration), sum.checked_sub(other));
+            prop_assert_eq!(Some(sum), other.checked_add(duration));
+        }
+        if let Some(difference) = duration.checked_sub(other) {
+            prop_assert_eq!(Some(duration), difference.checked_add(other));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn neg_and_mul_identities(duration in durations()) {
+        prop_assert_eq!(Some(duration), duration.checked_mul(1));
+        prop_assert_eq!(Some(Duration::ZERO), duration.checked_mul(0));
+        prop_assert_eq!(Some(Duration::ZERO), duration.checked_sub(duration));
+        if let Some(negated) = duration.checked_neg() {
+            prop_assert_eq!(Some(duration), negated.checked_neg());
+            prop_assert_eq!(Some(negated), duration.checked_mul(-1));
+            prop_assert_eq!(Some(Duration::ZERO), duration.checked_add(negated));
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+use crate::{Duration, ParseErrorKind};
+
+// Expected values are from java.time's `Duration.parse`.
+proptest! {
+    #[test]
+    fn parse((text, seconds, nanos) in prop_oneof![
+        Just(("PT0S", 0, 0)),
+        Just(("PT8M20.345S", 500, 345_000_000)),
+        Just(("P2DT3H", 183_600, 0)),
+        Just(("PT-1M-0.5S", -61, 500_000_000)),
+        Just(("pt1h", 3_600, 0)),
+        Just(("p1dt1h1m1s", 90_061, 0)),
+        Just(("P1D", 86_400, 0)),
+        Just(("PT1.S", 1, 0)),
+        Just(("PT1,5S", 1, 500_000_000)),
+        Just(("PT-0.5S", -1, 500_000_000)),
+        Just(("PT+1H", 3_600, 0)),
+        Just(("P-1DT25H", 3_600, 0)),
+        Just(("PT1H30M", 5_400, 0)),
+        Just(("PT1H-60M", 0, 0)),
+        Just(("PT0.000000001S", 0, 1)),
+        Just(("PT-0.000000001S", -1, 999_999_999)),
+        Just(("P106751991167300DT15H30M7.999999999S", i64::MAX, 999_999_999)),
+        Just(("PT-2562047788015215H-30M-8S", i64::MIN, 0)),
+        Just(("PT9223372036854775807S", i64::MAX, 0)),
+        Just(("PT-9223372036854775808S", i64::MIN, 0)),
+    ]) {
+        let expected = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(expected), Duration::parse(text));
+        prop_assert_eq!(Ok(expected), text.parse::<Duration>());
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_whole_duration_sign((text, seconds, nanos) in prop_oneof![
+        Just(("PT1H", 3_600, 0)),
+        Just(("+PT1H", 3_600, 0)),
+        Just(("-PT1H", -3_600, 0)),
+        Just(("PT-1H", -3_600, 0)),
+        Just(("+PT-1H", -3_600, 0)),
+        Just(("-PT-1H", 3_600, 0)),
+        Just(("-PT1H-1M", -3_540, 0)),
+        Just(("-PT-9223372036854775807S", i64::MAX, 0)),
+        Just(("-PT-1M-0.5S", 60, 500_000_000)),
+    ]) {
+        prop_assert_eq!(Ok(Duration::of_seconds_and_adjustment(seconds, nanos)), Duration::parse(text));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_invalid((text, kind, position) in prop_oneof![
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("P", ParseErrorKind::UnexpectedEnd, 1)),
+        Just(("PT", ParseErrorKind::UnexpectedEnd, 2)),
+        Just(("P1DT", ParseErrorKind::UnexpectedEnd, 4)),
+        Just(("-P", ParseErrorKind::UnexpectedEnd, 2)),
+        Just(("PT1M1H", ParseErrorKind::InvalidCharacter, 5)),
+        Just(("PT1H1H", ParseErrorKind::InvalidCharacter, 5)),
+        Just(("P1DT1S1M", ParseErrorKind::TrailingCharacters, 6)),
+        Just(("PT1.5M", ParseErrorKind::InvalidCharacter, 5)),
+        Just(("PT1.5H", ParseErrorKind::InvalidCharacter, 5)),
+        Just(("PT1.0000000001S", ParseErrorKind::FieldOutOfRange, 13)),
+        Just(("PT1S2", ParseErrorKind::TrailingCharacters, 4)),
+        Just(("1PT1S", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("--PT1S", ParseErrorKind::InvalidCharacter, 1)),
+        Just(("PT 1S", ParseErrorKind::InvalidCharacter, 2)),
+        Just(("PT1D", ParseErrorKind::InvalidCharacter, 3)),
+        Just(("P1H", ParseErrorKind::InvalidCharacter, 2)),
+        // Years and months have no exact duration.
+        Just(("P1Y", ParseErrorKind::CalendarDesignator, 2)),
+        Just(("P2M", ParseErrorKind::CalendarDesignator, 2)),
+        Just(("-P-1y2M3DT4H", ParseErrorKind::CalendarDesignator, 4)),
+        Just(("p10m", ParseErrorKind::CalendarDesignator, 3)),
+        Just(("PT9223372036854775808S", ParseErrorKind::FieldOutOfRange, 2)),
+        Just(("PT-9223372036854775809S", ParseErrorKind::FieldOutOfRange, 2)),
+        Just(("PT99999999999999999999999S", ParseErrorKind::FieldOutOfRange, 2)),
+        Just(("P106751991167301D", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("PT9223372036854775807S1", ParseErrorKind::TrailingCharacters, 22)),
+        // Negating the most negative duration overflows.
+        Just(("-PT-9223372036854775808S", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("-PT-2562047788015215H-30M-8S", ParseErrorKind::FieldOutOfRange, 0)),
+    ]) {
+        let error = Duration::parse(text).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips_display_for_whole_seconds(seconds in prop::num::i64::ANY) {
+        let duration = Duration::of_seconds(seconds);
+
+        prop_assert_eq!(Ok(duration), Duration::parse(&duration.to_string()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_round_trips_display(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(duration), Duration::parse(&duration.to_string()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_decimal_seconds((text, seconds, nanos) in prop_oneof![
+        Just(("0", 0, 0)),
+        Just(("8.5", 8, 500_000_000)),
+        Just(("8.500000000", 8, 500_000_000)),
+        Just(("+8", 8, 0)),
+        Just(("-0.000000001", -1, 999_999_999)),
+        Just(("-8.5", -9, 500_000_000)),
+        Just(("-0", 0, 0)),
+        Just(("9223372036854775807.999999999", i64::MAX, 999_999_999)),
+        Just(("-9223372036854775808", i64::MIN, 0)),
+    ]) {
+        prop_assert_eq!(Ok(Duration::of_seconds_and_adjustment(seconds, nanos)), Duration::from_decimal_seconds(text));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_decimal_seconds_errors((text, kind, position) in prop_oneof![
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("8.", ParseErrorKind::UnexpectedEnd, 2)),
+        Just(("PT8S", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("8.5s", ParseErrorKind::TrailingCharacters, 3)),
+        Just(("1e3", ParseErrorKind::TrailingCharacters, 1)),
+        Just(("8.0000000001", ParseErrorKind::FieldOutOfRange, 11)),
+        Just(("-9223372036854775808.5", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("9223372036854775808", ParseErrorKind::FieldOutOfRange, 0)),
+    ]) {
+        let error = Duration::from_decimal_seconds(text).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
+
+#[test]
+fn parse_round_trips_display_around_boundaries() {
+    // Every component carries its own sign, so the boundaries where a component becomes zero are the risky ones.
+    let boundaries = [
+        0,
+        SECONDS_IN_MINUTE,
+        SECONDS_IN_HOUR,
+        SECONDS_IN_HOUR + SECONDS_IN_MINUTE,
+        SECONDS_IN_DAY,
+        i64::MAX / SECONDS_IN_HOUR * SECONDS_IN_HOUR,
+    ];
+    for boundary in boundaries {
+        for offset in -2..=2 {
+            for seconds in [boundary + offset, -boundary + offset, -boundary - offset] {
+                for nanos in [0, 1, 500_000_000, 999_999_999] {
+                    let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+                    assert_eq!(
+                        Ok(duration),
+                        Duration::parse(&duration.to_string()),
+                        "{}",
+                        duration
+                    );
+                }
+            }
+        }
+    }
+    for duration in [Duration::MIN, Duration::MAX] {
+        assert_eq!(Ok(duration), Duration::parse(&duration.to_string()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_calendar_designator_names_estimates(text in prop_oneof![Just("P1Y"), Just("P1M")]) {
+        let message = Duration::parse(text).unwrap_err().to_string();
+
+        prop_assert!(message.contains("Duration::of_years_estimated"), "{}", message);
+        prop_assert!(message.contains("Duration::of_months_estimated"), "{}", message);
+    }
+}
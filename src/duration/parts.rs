@@ -0,0 +1,84 @@
+use proptest::prelude::*;
+
+use crate::{Duration, Unit};
+
+proptest! {
+    #[test]
+    fn part_accessors((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, (false, 0, 0, 0, 0, 0, 0, 0))),
+        Just((93_784, 5_006_007, (false, 1, 2, 3, 4, 5, 5_006, 5_006_007))),
+        Just((-2, 700_000_000, (true, 0, 0, 0, 1, 300, 300_000, 300_000_000))),
+        Just((-93_785, 994_993_993, (true, 1, 2, 3, 4, 5, 5_006, 5_006_007))),
+        Just((-1, 999_999_999, (true, 0, 0, 0, 0, 0, 0, 1))),
+        Just((i64::MAX, 999_999_999, (false, 106_751_991_167_300, 15, 30, 7, 999, 999_999, 999_999_999))),
+        Just((i64::MIN, 0, (true, 106_751_991_167_300, 15, 30, 8, 0, 0, 0))),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, (
+            duration.is_negative(),
+            duration.days_part(),
+            duration.hours_part(),
+            duration.minutes_part(),
+            duration.seconds_part(),
+            duration.millis_part(),
+            duration.micros_part(),
+            duration.nanos_part(),
+        ));
+    }
+}
+
+#[test]
+fn parts_of_negative() {
+    let duration = Duration::of_millis(-1_300);
+
+    assert!(duration.is_negative());
+    assert_eq!(
+        vec![(Unit::Seconds, 1), (Unit::Millis, 300)],
+        duration.parts().collect::<Vec<_>>()
+    );
+}
+
+proptest! {
+    #[test]
+    fn parts((seconds, nanos, expected) in prop_oneof![
+        Just((0, 0, vec![])),
+        Just((86_400, 0, vec![(Unit::Days, 1)])),
+        Just((93_784, 5_006_007, vec![
+            (Unit::Days, 1),
+            (Unit::Hours, 2),
+            (Unit::Minutes, 3),
+            (Unit::Seconds, 4),
+            (Unit::Millis, 5),
+            (Unit::Micros, 6),
+            (Unit::Nanos, 7),
+        ])),
+        Just((3_601, 2_300_000, vec![(Unit::Hours, 1), (Unit::Seconds, 1), (Unit::Millis, 2), (Unit::Micros, 300)])),
+        Just((-1, 999_999_999, vec![(Unit::Nanos, 1)])),
+        Just((i64::MIN, 0, vec![
+            (Unit::Days, 106_751_991_167_300),
+            (Unit::Hours, 15),
+            (Unit::Minutes, 30),
+            (Unit::Seconds, 8),
+        ])),
+    ]) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, duration.parts().collect::<Vec<_>>());
+    }
+}
+
+proptest! {
+    #[test]
+    fn parts_sum_to_magnitude(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+        let magnitude = i128::from(duration.seconds()) * 1_000_000_000 + i128::from(duration.nano());
+
+        let total: i128 = duration
+            .parts()
+            .map(|(unit, amount)| i128::from(amount) * i128::from(unit.duration().seconds() * 1_000_000_000 + i64::from(unit.duration().nano())))
+            .sum();
+
+        prop_assert_eq!(magnitude.abs(), total);
+    }
+}
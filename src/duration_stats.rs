@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+
+use crate::seconds_nanos::*;
+use crate::{Duration, DurationSum};
+
+#[cfg(test)]
+pub mod summary;
+
+/// Accumulates durations for summary statistics, such as for reporting latencies.
+///
+/// The total is kept by a [`DurationSum`](struct.DurationSum.html), so it cannot overflow part way,
+/// and the durations are retained for [`percentile`](#method.percentile).
+/// Each [`push`](#method.push) only appends; the durations are sorted when next needed for a percentile,
+/// so a run of pushes followed by percentiles sorts once.
+/// Two statistics are equal if they have the same durations, in whatever order they were added.
+#[derive(Clone, Debug, Default)]
+pub struct DurationStats {
+    durations: Vec<Duration>,
+    // Whether a duration may have been pushed out of order since the last sort.
+    unsorted: bool,
+    sum: DurationSum,
+}
+
+impl DurationStats {
+    /// Obtains empty statistics, with no durations.
+    pub const fn new() -> DurationStats {
+        DurationStats {
+            durations: Vec::new(),
+            unsorted: false,
+            sum: DurationSum::new(),
+        }
+    }
+
+    /// Adds a duration to the statistics.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to add, positive or negative.
+    pub fn push(&mut self, duration: Duration) {
+        self.unsorted |= self.durations.last().is_some_and(|&last| last > duration);
+        self.durations.push(duration);
+        self.sum.add(duration);
+    }
+
+    /// Gets the number of durations.
+    pub fn count(&self) -> usize {
+        self.durations.len()
+    }
+
+    /// Gets the shortest (most negative) duration, or `None` if there are no durations.
+    pub fn min(&self) -> Option<Duration> {
+        if self.unsorted {
+            self.durations.iter().min().copied()
+        } else {
+            self.durations.first().copied()
+        }
+    }
+
+    /// Gets the longest duration, or `None` if there are no durations.
+    pub fn max(&self) -> Option<Duration> {
+        if self.unsorted {
+            self.durations.iter().max().copied()
+        } else {
+            self.durations.last().copied()
+        }
+    }
+
+    /// Gets the total of the durations, which is zero if there are no durations.
+    ///
+    /// The total itself may be outside the range of `Duration`; see [`DurationSum::total`](struct.DurationSum.html#method.total).
+    pub fn sum(&self) -> DurationSum {
+        self.sum
+    }
+
+    /// Gets the arithmetic mean of the durations, or `None` if there are no durations.
+    ///
+    /// The mean is truncated toward negative infinity to the nanosecond.
+    /// It is always in range, even when the total is not.
+    pub fn mean(&self) -> Option<Duration> {
+        if self.durations.is_empty() {
+            return None;
+        }
+        let mean = self
+            .sum
+            .total_i128_nanos()
+            .div_euclid(self.durations.len() as i128);
+        of_total_nanos_checked(mean)
+            .map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, i64::from(nanos)))
+    }
+
+    /// Gets the duration at the specified percentile,
+    /// or `None` if there are no durations or `percentile` is not from 0 to 100, including if it is NaN.
+    ///
+    /// This uses the nearest-rank method: the result is the smallest duration that is at least `percentile` percent
+    /// of the durations, so it is always one of the durations, and a percentile of zero gives the minimum.
+    /// The durations are sorted first if any were pushed out of order since the last sort.
+    ///
+    /// # Parameters
+    ///  - `percentile`: the percentile, from 0 to 100.
+    pub fn percentile(&mut self, percentile: f64) -> Option<Duration> {
+        if !(0.0..=100.0).contains(&percentile) {
+            return None;
+        }
+        self.sort();
+        let rank = (percentile / 100.0 * self.durations.len() as f64).ceil() as usize;
+        self.durations.get(rank.max(1) - 1).copied()
+    }
+
+    fn sort(&mut self) {
+        if self.unsorted {
+            self.durations.sort_unstable();
+            self.unsorted = false;
+        }
+    }
+
+    fn sorted_durations(&self) -> Cow<'_, [Duration]> {
+        if self.unsorted {
+            let mut sorted = self.durations.clone();
+            sorted.sort_unstable();
+            Cow::Owned(sorted)
+        } else {
+            Cow::Borrowed(&self.durations)
+        }
+    }
+}
+
+impl PartialEq for DurationStats {
+    fn eq(&self, other: &DurationStats) -> bool {
+        self.durations.len() == other.durations.len()
+            && self.sum == other.sum
+            && self.sorted_durations() == other.sorted_durations()
+    }
+}
+
+impl Eq for DurationStats {}
+
+impl From<&[Duration]> for DurationStats {
+    fn from(durations: &[Duration]) -> DurationStats {
+        let mut sorted = durations.to_vec();
+        sorted.sort_unstable();
+        DurationStats {
+            sum: durations.iter().copied().collect(),
+            durations: sorted,
+            unsorted: false,
+        }
+    }
+}
+
+impl Extend<Duration> for DurationStats {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, durations: I) {
+        durations
+            .into_iter()
+            .for_each(|duration| self.push(duration));
+    }
+}
@@ -0,0 +1,107 @@
+use proptest::prelude::*;
+
+use crate::{Duration, DurationStats};
+
+proptest! {
+    #[test]
+    fn empty(percentile in 0.0..=100.0f64) {
+        let mut stats = DurationStats::new();
+
+        prop_assert_eq!(0, stats.count());
+        prop_assert_eq!(None, stats.min());
+        prop_assert_eq!(None, stats.max());
+        prop_assert_eq!(Some(Duration::ZERO), stats.sum().total());
+        prop_assert_eq!(None, stats.mean());
+        prop_assert_eq!(None, stats.percentile(percentile));
+    }
+}
+
+proptest! {
+    #[test]
+    fn summary((durations, min, max, sum, mean) in prop_oneof![
+        Just((vec![Duration::of_seconds(3)], Duration::of_seconds(3), Duration::of_seconds(3), Some(Duration::of_seconds(3)), Duration::of_seconds(3))),
+        Just((
+            vec![Duration::of_millis(30), Duration::of_millis(-10), Duration::of_millis(20)],
+            Duration::of_millis(-10), Duration::of_millis(30), Some(Duration::of_millis(40)), Duration::of_nanos(13_333_333),
+        )),
+        Just((
+            vec![Duration::of_nanos(-2), Duration::of_nanos(-1), Duration::of_nanos(-1)],
+            Duration::of_nanos(-2), Duration::of_nanos(-1), Some(Duration::of_nanos(-4)), Duration::of_nanos(-2),
+        )),
+        // The total overflows `i64` nanoseconds, but not `Duration`.
+        Just((
+            vec![Duration::of_seconds(9_000_000_000), Duration::of_seconds(9_000_000_000)],
+            Duration::of_seconds(9_000_000_000), Duration::of_seconds(9_000_000_000),
+            Some(Duration::of_seconds(18_000_000_000)), Duration::of_seconds(9_000_000_000),
+        )),
+        // The total overflows `Duration`, but the mean does not.
+        Just((
+            vec![Duration::MAX, Duration::MAX, Duration::ZERO],
+            Duration::ZERO, Duration::MAX, None, Duration::of_seconds_and_adjustment(6_148_914_691_236_517_205, 333_333_332),
+        )),
+    ]) {
+        let mut pushed = DurationStats::new();
+        pushed.extend(durations.iter().copied());
+        let converted = DurationStats::from(&durations[..]);
+
+        for stats in [pushed, converted].iter() {
+            prop_assert_eq!(durations.len(), stats.count());
+            prop_assert_eq!(Some(min), stats.min());
+            prop_assert_eq!(Some(max), stats.max());
+            prop_assert_eq!(sum, stats.sum().total());
+            prop_assert_eq!(Some(mean), stats.mean());
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn percentile((percentile, expected) in prop_oneof![
+        Just((0.0, -5)),
+        Just((10.0, -5)),
+        Just((10.1, 10)),
+        Just((20.0, 10)),
+        Just((50.0, 40)),
+        Just((50.1, 60)),
+        Just((90.0, 90)),
+        Just((99.9, 100)),
+        Just((100.0, 100)),
+    ]) {
+        let mut stats = DurationStats::new();
+        for millis in [100, 40, -5, 20, 60, 10, 90, 30, 80, 70].iter() {
+            stats.push(Duration::of_millis(*millis));
+        }
+
+        prop_assert_eq!(Some(Duration::of_millis(expected)), stats.percentile(percentile));
+    }
+}
+
+proptest! {
+    #[test]
+    fn percentile_out_of_range(percentile in prop_oneof![Just(-0.1), Just(100.1), Just(f64::NAN), Just(f64::INFINITY)]) {
+        prop_assert_eq!(None, DurationStats::from(&[Duration::ZERO][..]).percentile(percentile));
+    }
+}
+
+proptest! {
+    #[test]
+    fn order_of_pushes(millis in prop::collection::vec(-1_000i64..1_000, 0..20)) {
+        let durations: Vec<Duration> = millis.iter().map(|&millis| Duration::of_millis(millis)).collect();
+        let mut pushed = DurationStats::new();
+        pushed.extend(durations.iter().copied());
+        let mut converted = DurationStats::from(&durations[..]);
+
+        prop_assert_eq!(&converted, &pushed);
+        prop_assert_eq!(converted.min(), pushed.min());
+        prop_assert_eq!(converted.max(), pushed.max());
+        for percentile in [0.0, 25.0, 50.0, 99.0, 100.0].iter() {
+            prop_assert_eq!(converted.percentile(*percentile), pushed.percentile(*percentile));
+        }
+        // Pushing after a percentile leaves the durations to be sorted again.
+        pushed.push(Duration::of_millis(-2_000));
+        converted.push(Duration::of_millis(-2_000));
+        prop_assert_eq!(&converted, &pushed);
+        prop_assert_eq!(Some(Duration::of_millis(-2_000)), pushed.min());
+        prop_assert_eq!(Some(Duration::of_millis(-2_000)), pushed.percentile(0.0));
+    }
+}
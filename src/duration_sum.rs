@@ -0,0 +1,68 @@
+use core::iter::FromIterator;
+
+use crate::seconds_nanos::*;
+use crate::Duration;
+
+#[cfg(test)]
+pub mod accumulate;
+
+/// Accumulates a running total of durations without overflowing part way.
+///
+/// Summing with [`Duration::checked_add`](struct.Duration.html#method.checked_add) fails as soon as the
+/// running total leaves the range of `Duration`, even if later values would bring it back.
+/// This keeps the total as 128-bit nanoseconds instead, so only the final total needs to be in range.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DurationSum {
+    total_nanos: i128,
+}
+
+impl DurationSum {
+    /// Obtains an empty sum, with a total of zero.
+    pub const fn new() -> DurationSum {
+        DurationSum { total_nanos: 0 }
+    }
+
+    /// Adds a duration to the running total.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to add, positive or negative.
+    ///
+    /// # Panics
+    /// - if the running total would overflow 128-bit nanoseconds,
+    ///   which takes billions of durations close to the maximum.
+    pub fn add(&mut self, duration: Duration) {
+        self.total_nanos = self
+            .total_nanos
+            .checked_add(total_nanos(duration.seconds(), duration.nano()))
+            .expect("duration sum would overflow");
+    }
+
+    /// Gets the total as a duration.
+    ///
+    /// Returns `None` if the total is outside the range of `Duration`.
+    pub fn total(&self) -> Option<Duration> {
+        of_total_nanos_checked(self.total_nanos)
+            .map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, i64::from(nanos)))
+    }
+
+    /// Gets the total as a number of nanoseconds, which is never out of range.
+    pub const fn total_i128_nanos(&self) -> i128 {
+        self.total_nanos
+    }
+}
+
+impl Extend<Duration> for DurationSum {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, durations: I) {
+        durations
+            .into_iter()
+            .for_each(|duration| self.add(duration));
+    }
+}
+
+impl FromIterator<Duration> for DurationSum {
+    fn from_iter<I: IntoIterator<Item = Duration>>(durations: I) -> DurationSum {
+        let mut sum = DurationSum::new();
+        sum.extend(durations);
+        sum
+    }
+}
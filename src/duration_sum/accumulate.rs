@@ -0,0 +1,37 @@
+use proptest::prelude::*;
+
+use crate::{Duration, DurationSum};
+
+proptest! {
+    #[test]
+    fn total((durations, expected) in prop_oneof![
+        Just((vec![], Some(Duration::ZERO))),
+        Just((vec![Duration::of_seconds(1), Duration::of_nanos(-1)], Some(Duration::of_nanos(999_999_999)))),
+        Just((vec![Duration::MAX, Duration::MIN], Some(Duration::of_nanos(-1)))),
+        // The running total leaves the range of `Duration`, but the final total does not.
+        Just((vec![Duration::MAX, Duration::MAX, Duration::MIN, Duration::MIN], Some(Duration::of_nanos(-2)))),
+        Just((vec![Duration::MAX, Duration::of_seconds(1), Duration::of_seconds(-2)], Some(Duration::MAX - Duration::of_seconds(1)))),
+        Just((vec![Duration::MIN, Duration::of_nanos(-1), Duration::of_nanos(1)], Some(Duration::MIN))),
+        Just((vec![Duration::MAX, Duration::of_nanos(1)], None)),
+        Just((vec![Duration::MIN, Duration::of_nanos(-1)], None)),
+    ]) {
+        let sum: DurationSum = durations.iter().copied().collect();
+        prop_assert_eq!(expected, sum.total());
+    }
+}
+
+proptest! {
+    #[test]
+    fn total_i128_nanos((durations, expected) in prop_oneof![
+        Just((vec![], 0)),
+        Just((vec![Duration::of_seconds(-1), Duration::of_nanos(1)], -999_999_999)),
+        Just((vec![Duration::MAX, Duration::of_nanos(1)], i128::from(i64::MAX) * 1_000_000_000 + 1_000_000_000)),
+        Just((vec![Duration::MIN, Duration::MIN], i128::from(i64::MIN) * 2_000_000_000)),
+    ]) {
+        let mut sum = DurationSum::new();
+        for duration in durations {
+            sum.add(duration);
+        }
+        prop_assert_eq!(expected, sum.total_i128_nanos());
+    }
+}
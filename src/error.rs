@@ -0,0 +1,347 @@
+use core::fmt;
+
+use crate::{Duration, DurationRange, TimestampUnit};
+
+/// Error returned when decoding a binary representation of a value fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// The input was not the length the encoding requires.
+    InvalidLength {
+        /// The number of bytes the encoding requires.
+        expected: usize,
+        /// The number of bytes that were provided.
+        actual: usize,
+    },
+    /// The leading version byte was not a version this library understands.
+    UnknownVersion(u8),
+    /// The nanosecond of second was not less than one second.
+    InvalidNanos(u32),
+    /// Bits that the encoding reserves as zero were set.
+    InvalidPadding,
+    /// The unit tag of a compact encoding was not a unit this library understands.
+    UnknownUnit(u8),
+    /// The decoded value was outside the range of the type.
+    OutOfRange,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::InvalidLength { expected, actual } => {
+                write!(f, "expected {} bytes, got {}", expected, actual)
+            }
+            DecodeError::UnknownVersion(version) => {
+                write!(f, "unknown encoding version {}", version)
+            }
+            DecodeError::InvalidNanos(nanos) => write!(
+                f,
+                "nanosecond of second {} is not less than 1000000000",
+                nanos
+            ),
+            DecodeError::InvalidPadding => write!(f, "reserved padding bits are not zero"),
+            DecodeError::UnknownUnit(tag) => write!(f, "unknown unit tag {}", tag),
+            DecodeError::OutOfRange => write!(f, "decoded value is out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Error returned when applying an offset to a slice of values would overflow for at least one element.
+///
+/// When this is returned, none of the elements have been changed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SliceOverflowError {
+    index: usize,
+}
+
+impl SliceOverflowError {
+    pub(crate) fn new(index: usize) -> SliceOverflowError {
+        SliceOverflowError { index }
+    }
+
+    /// Gets the index of an element that would have overflowed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for SliceOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "overflow when offsetting element {} of slice",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceOverflowError {}
+
+/// Error returned when a duration is outside the bounds of a [`DurationRange`](struct.DurationRange.html).
+///
+/// This carries the offending duration and the bounds, for rendering messages such as for configuration errors.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutOfRangeError {
+    value: Duration,
+    range: DurationRange,
+}
+
+impl OutOfRangeError {
+    pub(crate) fn new(value: Duration, range: DurationRange) -> OutOfRangeError {
+        OutOfRangeError { value, range }
+    }
+
+    /// Gets the duration that was outside the range.
+    pub fn value(&self) -> Duration {
+        self.value
+    }
+
+    /// Gets the range the duration was outside of.
+    pub fn range(&self) -> DurationRange {
+        self.range
+    }
+}
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "duration {} is outside the range {} to {} (inclusive)",
+            self.value,
+            self.range.min(),
+            self.range.max()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRangeError {}
+
+/// Error returned when a civil (calendar) field is outside of its valid range, such as February 29th of 2023.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CivilFieldError {
+    field: CivilField,
+    value: i64,
+}
+
+/// A field of a civil date and time, as identified by a [`CivilFieldError`](struct.CivilFieldError.html).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CivilField {
+    /// The year, where a format only supports a limited range of years.
+    Year,
+    /// The month of the year, from 1 to 12.
+    Month,
+    /// The day of the month, from 1 to the length of the month.
+    Day,
+    /// The hour of the day, from 0 to 23.
+    Hour,
+    /// The minute of the hour, from 0 to 59.
+    Minute,
+    /// The second of the minute, from 0 to 59.
+    Second,
+    /// The nanosecond of the second, from 0 to 999,999,999.
+    Nanosecond,
+}
+
+impl CivilFieldError {
+    pub(crate) fn new(field: CivilField, value: i64) -> CivilFieldError {
+        CivilFieldError { field, value }
+    }
+
+    /// Gets the field that was out of range.
+    pub fn field(&self) -> CivilField {
+        self.field
+    }
+
+    /// Gets the value of the field that was out of range.
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+impl fmt::Display for CivilFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let field = match self.field {
+            CivilField::Year => "year",
+            CivilField::Month => "month",
+            CivilField::Day => "day of month",
+            CivilField::Hour => "hour",
+            CivilField::Minute => "minute",
+            CivilField::Second => "second",
+            CivilField::Nanosecond => "nanosecond",
+        };
+        write!(f, "{} {} is out of range", field, self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CivilFieldError {}
+
+/// Error returned when an instant is too far from the epoch to count in the units of an `i64` timestamp.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimestampOverflowError {
+    unit: TimestampUnit,
+}
+
+impl TimestampOverflowError {
+    pub(crate) fn new(unit: TimestampUnit) -> TimestampOverflowError {
+        TimestampOverflowError { unit }
+    }
+
+    /// Gets the unit of the timestamp that would have overflowed.
+    pub fn unit(&self) -> TimestampUnit {
+        self.unit
+    }
+}
+
+impl fmt::Display for TimestampOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unit = match self.unit {
+            TimestampUnit::Second => "seconds",
+            TimestampUnit::Millisecond => "milliseconds",
+            TimestampUnit::Microsecond => "microseconds",
+            TimestampUnit::Nanosecond => "nanoseconds",
+        };
+        write!(f, "instant would overflow a timestamp in {}", unit)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TimestampOverflowError {}
+
+/// Error returned when a JavaScript time value, such as from `Date.getTime()`, cannot be converted to an instant.
+#[cfg(feature = "wasm")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JsMillisError {
+    /// The value was `NaN` or infinite, as for an invalid `Date`.
+    NotFinite,
+    /// The value was further from the epoch than `Number.MAX_SAFE_INTEGER` milliseconds,
+    /// beyond which JavaScript numbers cannot hold every whole millisecond.
+    OutOfRange,
+}
+
+#[cfg(feature = "wasm")]
+impl fmt::Display for JsMillisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsMillisError::NotFinite => write!(f, "JavaScript time value is not a finite number"),
+            JsMillisError::OutOfRange => write!(
+                f,
+                "JavaScript time value is outside of the safe integer range"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "wasm")]
+impl std::error::Error for JsMillisError {}
+
+/// Error returned when a duration cannot be decomposed into the requested units,
+/// by [`Duration::decompose`](struct.Duration.html#method.decompose).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum DecomposeError {
+    /// No units were requested.
+    NoUnits,
+    /// The unit at the index was not smaller than the unit before it.
+    UnitsOutOfOrder(usize),
+    /// The count of the largest unit would overflow an `i64`.
+    Overflow,
+}
+
+impl fmt::Display for DecomposeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecomposeError::NoUnits => f.write_str("no units to decompose into"),
+            DecomposeError::UnitsOutOfOrder(index) => {
+                write!(f, "unit {} is not smaller than the unit before it", index)
+            }
+            DecomposeError::Overflow => f.write_str("count of the largest unit would overflow i64"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecomposeError {}
+
+/// Error returned when arithmetic through [`Temporal`](trait.Temporal.html) or
+/// [`TemporalAmount`](trait.TemporalAmount.html) fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TemporalError {
+    /// The result would be outside the range of its type.
+    Overflow,
+}
+
+impl fmt::Display for TemporalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemporalError::Overflow => f.write_str("temporal arithmetic would overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TemporalError {}
+
+/// Error returned when parsing text into a value fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+}
+
+/// The reason parsing failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input ended before the value was complete.
+    UnexpectedEnd,
+    /// A character was found that is not allowed at its position.
+    InvalidCharacter,
+    /// The value was complete, but more input followed it.
+    TrailingCharacters,
+    /// A field was well formed, but outside of its valid range, such as a 13th month.
+    FieldOutOfRange,
+    /// A duration had years or months, which have no exact length;
+    /// see `Duration::of_years_estimated` and `Duration::of_months_estimated` for estimates.
+    CalendarDesignator,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, position: usize) -> ParseError {
+        ParseError { kind, position }
+    }
+
+    /// Gets the reason parsing failed.
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Gets the byte offset in the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self.kind {
+            ParseErrorKind::UnexpectedEnd => "unexpected end of input",
+            ParseErrorKind::InvalidCharacter => "invalid character",
+            ParseErrorKind::TrailingCharacters => "unexpected trailing characters",
+            ParseErrorKind::FieldOutOfRange => "field out of range",
+            ParseErrorKind::CalendarDesignator => {
+                "years and months have no exact duration, use Duration::of_years_estimated or Duration::of_months_estimated"
+            }
+        };
+        write!(f, "{} at position {}", reason, self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
@@ -1,17 +1,67 @@
-use std::i64;
+#[cfg(feature = "std")]
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, AddAssign, RangeInclusive, Sub, SubAssign};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::calendar::MonthStarts;
+use crate::civil::*;
 use crate::constants::*;
+use crate::day_of_week::{day_of_week_between, week_of_period, weekdays_between};
+use crate::digits::{pad_without_precision, Digits};
+use crate::display::debug_with_fields;
+use crate::parse::{parse_decimal_seconds, parse_flexible, parse_rfc3339, parse_sortable};
 use crate::seconds_nanos::*;
+use crate::wire;
+#[cfg(feature = "wasm")]
+use crate::JsMillisError;
+#[cfg(feature = "std")]
+use crate::SystemClock;
+use crate::{
+    Calendar, CivilField, CivilFieldError, Clock, DayOfWeek, DecodeError, Duration, InstantDisplay,
+    ParseError, RelativeTime, RelativeTimeOptions, SliceOverflowError, TimestampOverflowError,
+    TimestampUnit, Tolerance, Unit,
+};
 
+#[cfg(test)]
+pub mod arithmetic;
+#[cfg(test)]
+pub mod calendar;
+#[cfg(test)]
+pub mod encoding;
+#[cfg(test)]
+pub mod extremes;
 #[cfg(test)]
 pub mod factories;
+#[cfg(test)]
+pub mod format;
+#[cfg(test)]
+pub mod invariants;
+#[cfg(all(test, feature = "wasm"))]
+pub mod js;
+#[cfg(test)]
+pub mod parse;
+#[cfg(test)]
+pub mod search;
+#[cfg(all(test, feature = "std"))]
+pub mod system_time;
+#[cfg(test)]
+pub mod timestamp;
+
+const VERSIONED_BYTES_VERSION: u8 = 1;
+const VERSIONED_BYTES_LENGTH: usize = 13;
 
 /// An instantaneous point in time along the timeline.
 ///
 /// This is explicitly a TAI instant.
 /// This means that, among other things, the civil time an instant maps to
 /// (a normal calendar date and time) changes over time, as leap seconds are added to the civil clock.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Instant {
     epoch_second: i64,
     nanosecond_of_second: u32,
@@ -36,20 +86,54 @@ impl Instant {
         nanosecond_of_second: NANOSECONDS_IN_SECOND as u32 - 1,
     };
 
+    /// Obtains the current instant from the system clock.
+    ///
+    /// This is the system clock's count of seconds since the Unix epoch, taken as is:
+    /// as with the civil conversions, no leap-second adjustment is made.
+    /// The system clock may be adjusted, so successive calls are not guaranteed to increase;
+    /// see [`MonotonicInstantSource`](struct.MonotonicInstantSource.html) when they must.
+    #[cfg(feature = "std")]
+    pub fn now() -> Instant {
+        Instant::from_system_time_lossy(SystemTime::now())
+    }
+
+    /// Obtains an Instant from a standard library system time, saturating at the minimum or maximum instant.
+    ///
+    /// This is the system time's count of seconds since the Unix epoch, taken as is, as for [`now`](#method.now).
+    /// System times are usually UTC-based with leap seconds smeared or repeated by the operating system,
+    /// so this is not a TAI conversion, and two system times a leap second apart may differ by more than a second
+    /// of real elapsed time. Only system times beyond the range of instants (which some platforms allow) are changed,
+    /// to the nearest end of the range.
+    ///
+    /// # Parameters
+    ///  - `time`: the system time to convert.
+    #[cfg(feature = "std")]
+    pub fn from_system_time_lossy(time: SystemTime) -> Instant {
+        Instant::of_total_nanos_saturating(system_time_nanos(time))
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn of_total_nanos_saturating(total: i128) -> Instant {
+        match of_total_nanos_checked(total) {
+            Some(seconds_and_nanos) => Instant::of_epoch_second_and_nanos(seconds_and_nanos),
+            None if total < 0 => Instant::MIN,
+            None => Instant::MAX,
+        }
+    }
+
     /// Obtains an Instant using milliseconds since '1970-01-01 00:00:00.000Z'.
     ///
-    /// The seconds and fractional seconds are calculated from the provided milliseconds.
+    /// The seconds and fractional seconds are calculated from the provided milliseconds,
+    /// with milliseconds before the epoch borrowing from the seconds, so the nanosecond of second is never negative.
+    /// Every `i64` is in range, as even `i64::MIN` milliseconds is only a few hundred million years from the epoch.
     ///
     /// # Parameters
     ///  - `epoch_milliseconds`: the milliseconds since the epoch.
     pub fn of_epoch_milli(epoch_milliseconds: i64) -> Instant {
-        let (seconds, remainder) = (
-            epoch_milliseconds / MILLISECONDS_IN_SECOND,
-            epoch_milliseconds % MILLISECONDS_IN_SECOND,
-        );
-        let nanoseconds = remainder * NANOSECONDS_IN_MILLISECOND;
-        Instant::of_epoch_second_and_adjustment_checked(seconds, nanoseconds)
-            .expect("milliseconds would overflow instant")
+        Instant::of_epoch_second_and_nanos(seconds_and_nanos_of_units(
+            epoch_milliseconds,
+            MILLISECONDS_IN_SECOND,
+        ))
     }
 
     /// Obtains an Instant using seconds since '1970-01-01 00:00:00Z'.
@@ -69,20 +153,135 @@ impl Instant {
     /// # Panics
     /// - if the adjusted amount of seconds would be before the minimum instant, or after the maximum instant.
     pub fn of_epoch_second_and_adjustment(epoch_seconds: i64, nano_adjustment: i64) -> Instant {
-        Instant::of_epoch_second_and_adjustment_checked(epoch_seconds, nano_adjustment)
+        Instant::checked_of_epoch_second_and_adjustment(epoch_seconds, nano_adjustment)
             .expect("seconds would overflow instant")
     }
 
-    fn of_epoch_second_and_adjustment_checked(
-        seconds: i64,
+    /// Obtains an Instant using seconds and an adjustment in nanoseconds since '1970-01-01 00:00:00.000000000Z',
+    /// or `None` if the adjusted amount of seconds would be before the minimum instant, or after the maximum instant.
+    ///
+    /// # Parameters
+    ///  - `epoch_seconds`: the seconds since the epoch.
+    ///  - `nano_adjustment`: the adjustment amount from the given second.
+    pub fn checked_of_epoch_second_and_adjustment(
+        epoch_seconds: i64,
         nano_adjustment: i64,
     ) -> Option<Instant> {
-        of_seconds_and_adjustment_checked(seconds, nano_adjustment).map(|(seconds, nanos)| {
-            Instant {
-                epoch_second: seconds,
-                nanosecond_of_second: nanos,
-            }
-        })
+        of_seconds_and_adjustment_checked(epoch_seconds, nano_adjustment)
+            .map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Obtains an Instant by parsing an RFC 3339 timestamp, such as `2007-12-03T10:15:30.123Z`.
+    ///
+    /// This is the same as [`parse_rfc3339_bytes`](#method.parse_rfc3339_bytes), for text.
+    ///
+    /// Unlike java.time's `Instant.parse`, this follows RFC 3339, except that a second of `60`,
+    /// which RFC 3339 allows for a leap second, is rejected. Years have exactly four digits,
+    /// so the signed years that `Display` writes outside of 0000 to 9999 (such as `+10000-01-01T00:00:00Z`) are rejected,
+    /// as are a second of `60` and an hour of `24`, which java.time adjusts, and a `.` without digits after it.
+    /// In turn, this accepts a space between the date and time, and offsets beyond `±18:00`, which java.time rejects.
+    ///
+    /// # Parameters
+    ///  - `text`: the timestamp to parse.
+    ///
+    /// # Errors
+    /// - if the text is not a valid RFC 3339 timestamp.
+    pub fn parse_rfc3339(text: &str) -> Result<Instant, ParseError> {
+        Instant::parse_rfc3339_bytes(text.as_bytes())
+    }
+
+    /// Obtains an Instant by parsing an RFC 3339 timestamp held as bytes, such as `b"2007-12-03T10:15:30.123Z"`.
+    ///
+    /// The date and time are separated by `T`, `t`, or a space, followed by an offset of `Z`, `z`, or `±HH:MM`.
+    /// The fraction of the second is optional, and may have up to nine digits.
+    /// Leap seconds (a second of `60`) are rejected, and - as with the other civil conversions -
+    /// every day is treated as 86,400 seconds long.
+    ///
+    /// This does not allocate, and callers holding bytes do not need to validate them as UTF-8 first.
+    ///
+    /// # Parameters
+    ///  - `bytes`: the timestamp to parse.
+    ///
+    /// # Errors
+    /// - if the bytes are not a valid RFC 3339 timestamp.
+    pub fn parse_rfc3339_bytes(bytes: &[u8]) -> Result<Instant, ParseError> {
+        parse_rfc3339(bytes).map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Obtains an Instant by parsing fractional seconds since the epoch, such as `1718453421.123456789`,
+    /// as printed by `date +%s.%N`.
+    ///
+    /// The seconds may have a leading `+` or `-`, and are followed by an optional fraction of up to nine digits.
+    /// The sign applies to the whole value, so `-0.5` is half a second before the epoch
+    /// (an epoch second of `-1` and 500,000,000 nanoseconds).
+    /// This is the inverse of [`to_unix_string`](#method.to_unix_string).
+    ///
+    /// # Parameters
+    ///  - `text`: the seconds to parse.
+    ///
+    /// # Errors
+    /// - if the text is not a valid number of seconds.
+    /// - if the seconds are outside the range of instants.
+    pub fn parse_unix(text: &str) -> Result<Instant, ParseError> {
+        parse_decimal_seconds(text.as_bytes()).map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Obtains an Instant by parsing the sortable key produced by [`to_sortable_string`](#method.to_sortable_string),
+    /// such as `09223372038573229229.123456789`.
+    ///
+    /// The key must have exactly its fixed width: twenty digits of biased seconds, a `.`, and nine digits of nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `text`: the sortable key to parse.
+    ///
+    /// # Errors
+    /// - if the text is not in the fixed-width form.
+    /// - if the biased seconds are greater than `u64::MAX`.
+    pub fn from_sortable_string(text: &str) -> Result<Instant, ParseError> {
+        parse_sortable(text.as_bytes()).map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Obtains an Instant by parsing a date and time in one of several common formats,
+    /// such as scraped from the output of commands.
+    ///
+    /// This is a pragmatic alternative to the strict [`parse_rfc3339`](#method.parse_rfc3339).
+    /// The formats are tried in this order, and the first that matches is used:
+    ///  1. ISO-8601 with an offset, as accepted by [`parse_rfc3339`](#method.parse_rfc3339),
+    ///     such as `2006-01-02T15:04:05.123-07:00`.
+    ///  2. ISO-8601 without an offset, such as `2006-01-02 15:04:05` or `2006-01-02T15:04:05.123`.
+    ///  3. `asctime` output, such as `Mon Jan  2 15:04:05 2006`, where the day may be padded with a space or a zero.
+    ///  4. `date` output in UTC, such as `Mon Jan  2 15:04:05 UTC 2006`, where the zone is `UTC` or `GMT`.
+    ///
+    /// Names of days and months are English and case-sensitive, and the day of the week is not checked against the date.
+    /// Formats without an offset or zone are treated as being in the given offset.
+    ///
+    /// # Parameters
+    ///  - `text`: the date and time to parse.
+    ///  - `offset_seconds`: the offset from UTC for formats without an offset or zone.
+    ///
+    /// # Errors
+    /// - if the text is not in any of the formats, or has a field out of range.
+    pub fn parse_flexible(text: &str, offset_seconds: i32) -> Result<Instant, ParseError> {
+        parse_flexible(text.as_bytes(), offset_seconds).map(Instant::of_epoch_second_and_nanos)
+    }
+
+    fn of_epoch_second_and_nanos(seconds_and_nanos: (i64, u32)) -> Instant {
+        let (epoch_second, nanos) = seconds_and_nanos;
+        Instant {
+            epoch_second,
+            nanosecond_of_second: nanos,
+        }
+        .debug_assert_valid()
+    }
+
+    // Checks, in debug builds only, that the nanosecond of second is less than one second,
+    // which every constructor and operation must maintain; every other method relies on it.
+    pub(crate) const fn debug_assert_valid(self) -> Instant {
+        debug_assert!(
+            self.nanosecond_of_second < NANOSECONDS_IN_SECOND as u32,
+            "nanosecond of second out of range"
+        );
+        self
     }
 
     /// Gets the number of seconds before or after the epoch.
@@ -98,4 +297,1829 @@ impl Instant {
     pub fn nano(&self) -> u32 {
         self.nanosecond_of_second
     }
+
+    /// Packs this instant into a single `u128`, such as for storing in an atomic or passing across FFI.
+    ///
+    /// The epoch second is stored in the high 64 bits, biased by 2<sup>63</sup>,
+    /// and the nanoseconds in the low 32 bits, with the bits between them always zero.
+    /// The bias means that comparing the packed values gives the same ordering as comparing the instants.
+    pub const fn to_bits(self) -> u128 {
+        to_bits(self.epoch_second, self.nanosecond_of_second)
+    }
+
+    /// Unpacks an instant from the representation produced by [`to_bits`](#method.to_bits).
+    ///
+    /// # Errors
+    /// - if any of the padding bits between the epoch second and the nanoseconds are set.
+    /// - if the nanoseconds are not less than one second.
+    pub const fn from_bits(bits: u128) -> Result<Instant, DecodeError> {
+        match from_bits(bits) {
+            Ok((epoch_second, nanosecond_of_second)) => Ok(Instant {
+                epoch_second,
+                nanosecond_of_second,
+            }
+            .debug_assert_valid()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Gets a hash of this instant that is stable across platforms, versions of this library, and languages, such as for checksums shared with another service.
+    ///
+    /// This is the 64-bit FNV-1a hash of the [`wire`](wire/index.html) encoding of the instant.
+    /// Unlike the `Hash` implementation, the result is part of the documented format, and will not change.
+    pub fn canonical_hash(&self) -> u64 {
+        wire::canonical_hash(self.epoch_second, self.nanosecond_of_second)
+    }
+
+    /// Converts this instant to the number of nanoseconds since the epoch.
+    ///
+    /// Only instants from `1677-09-21T00:12:43.145224192Z` to `2262-04-11T23:47:16.854775807Z` (inclusive) fit;
+    /// use [`checked_to_epoch_nanos`](#method.checked_to_epoch_nanos) for instants that may be outside that range.
+    ///
+    /// # Panics
+    /// - if the number of nanoseconds would overflow an `i64`.
+    pub fn to_epoch_nanos(&self) -> i64 {
+        self.checked_to_epoch_nanos()
+            .expect("nanos would overflow i64")
+    }
+
+    /// Converts this instant to the number of nanoseconds since the epoch,
+    /// or `None` if the number of nanoseconds would overflow an `i64`.
+    pub fn checked_to_epoch_nanos(&self) -> Option<i64> {
+        i64::try_from(total_nanos(self.epoch_second, self.nanosecond_of_second)).ok()
+    }
+
+    /// Obtains an Instant from an integer timestamp since the epoch, in the specified unit.
+    ///
+    /// Every timestamp is in range, as even nanoseconds only span a few centuries.
+    ///
+    /// # Parameters
+    ///  - `value`: the number of units since the epoch.
+    ///  - `unit`: the unit of the timestamp.
+    pub fn from_timestamp(value: i64, unit: TimestampUnit) -> Instant {
+        of_timestamp(value, unit.per_second())
+    }
+
+    /// Obtains Instants from a slice of integer timestamps since the epoch, all in the specified unit.
+    ///
+    /// This is the same as calling [`from_timestamp`](#method.from_timestamp) for each element,
+    /// but picks the conversion once for the whole slice.
+    ///
+    /// # Parameters
+    ///  - `values`: the numbers of units since the epoch.
+    ///  - `unit`: the unit of the timestamps.
+    #[cfg(feature = "std")]
+    pub fn from_timestamp_slice(values: &[i64], unit: TimestampUnit) -> Vec<Instant> {
+        // Matching outside the loop lets each loop divide by a constant, which compiles to a multiply and shift.
+        match unit {
+            TimestampUnit::Second => values.iter().map(|&value| of_timestamp(value, 1)).collect(),
+            TimestampUnit::Millisecond => values
+                .iter()
+                .map(|&value| of_timestamp(value, MILLISECONDS_IN_SECOND))
+                .collect(),
+            TimestampUnit::Microsecond => values
+                .iter()
+                .map(|&value| of_timestamp(value, MICROSECONDS_IN_SECOND))
+                .collect(),
+            TimestampUnit::Nanosecond => values
+                .iter()
+                .map(|&value| of_timestamp(value, NANOSECONDS_IN_SECOND))
+                .collect(),
+        }
+    }
+
+    /// Encodes a column of instants as the first instant and the nanoseconds between each instant and the next, such as for compressing timestamps in storage.
+    ///
+    /// For a sorted column at a regular interval the deltas are small and repetitive, so they compress well,
+    /// but the instants do not need to be sorted, as a delta may be negative.
+    /// The column is reconstructed by [`delta_decode`](#method.delta_decode).
+    /// An empty column has no first instant, so encodes to `None`.
+    ///
+    /// # Parameters
+    ///  - `instants`: the instants to encode, usually in increasing order.
+    ///
+    /// # Errors
+    /// - if the nanoseconds from any instant to the next would overflow an `i64`, about 292 years, identifying the later instant.
+    #[cfg(feature = "std")]
+    pub fn delta_encode(
+        instants: &[Instant],
+    ) -> Result<Option<(Instant, Vec<i64>)>, SliceOverflowError> {
+        let first = match instants.first() {
+            Some(&first) => first,
+            None => return Ok(None),
+        };
+        let deltas = instants
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| {
+                pair[1]
+                    .checked_duration_since(pair[0])
+                    .and_then(|delta| {
+                        i64::try_from(total_nanos(delta.seconds(), delta.nano())).ok()
+                    })
+                    .ok_or_else(|| SliceOverflowError::new(index + 1))
+            })
+            .collect::<Result<Vec<i64>, SliceOverflowError>>()?;
+        Ok(Some((first, deltas)))
+    }
+
+    /// Decodes a column of instants from the first instant and the nanoseconds between each instant and the next,
+    /// as produced by [`delta_encode`](#method.delta_encode).
+    ///
+    /// The result starts with the first instant, so it has one more element than the deltas.
+    ///
+    /// # Parameters
+    ///  - `first`: the first instant of the column.
+    ///  - `deltas`: the nanoseconds from each instant to the next, positive or negative.
+    ///
+    /// # Errors
+    /// - if applying a delta would overflow the range of instants, identifying the delta.
+    #[cfg(feature = "std")]
+    pub fn delta_decode(
+        first: Instant,
+        deltas: &[i64],
+    ) -> Result<Vec<Instant>, SliceOverflowError> {
+        let mut instants = Vec::with_capacity(deltas.len() + 1);
+        instants.push(first);
+        let mut current = first;
+        for (index, &delta) in deltas.iter().enumerate() {
+            current = current
+                .checked_add(Duration::of_nanos(delta))
+                .ok_or_else(|| SliceOverflowError::new(index))?;
+            instants.push(current);
+        }
+        Ok(instants)
+    }
+
+    /// Converts this instant to an integer timestamp since the epoch, in the specified unit.
+    ///
+    /// Any fraction of a unit is truncated toward negative infinity,
+    /// so that timestamps are ordered the same as the instants, including before the epoch.
+    ///
+    /// # Parameters
+    ///  - `unit`: the unit of the timestamp.
+    ///
+    /// # Errors
+    /// - if the number of units would overflow an `i64`.
+    pub fn to_timestamp(&self, unit: TimestampUnit) -> Result<i64, TimestampOverflowError> {
+        let per_second = unit.per_second();
+        let units = i128::from(self.epoch_second) * i128::from(per_second)
+            + i128::from(
+                i64::from(self.nanosecond_of_second) / (NANOSECONDS_IN_SECOND / per_second),
+            );
+        i64::try_from(units).map_err(|_| TimestampOverflowError::new(unit))
+    }
+
+    /// Converts this instant to the seconds and nanoseconds of a POSIX `struct timespec`, as `(tv_sec, tv_nsec)`.
+    ///
+    /// As POSIX requires, `tv_nsec` is never negative, so instants before the epoch borrow from the seconds:
+    /// half a second before the epoch is `(-1, 500_000_000)`.
+    /// As with the system time conversions, the seconds are taken as is, with no leap-second adjustment,
+    /// so this is not a TAI conversion: POSIX clocks count UTC seconds, ignoring leap seconds.
+    pub fn to_timespec(&self) -> (i64, i64) {
+        (self.epoch_second, i64::from(self.nanosecond_of_second))
+    }
+
+    /// Obtains an Instant from the seconds and nanoseconds of a POSIX `struct timespec`,
+    /// or `None` if the nanoseconds are not in `0..1_000_000_000`.
+    ///
+    /// This is the inverse of [`to_timespec`](#method.to_timespec), and has the same caveat about leap seconds.
+    ///
+    /// # Parameters
+    ///  - `tv_sec`: the seconds since the epoch.
+    ///  - `tv_nsec`: the nanoseconds after the second, which must be non-negative.
+    pub fn from_timespec(tv_sec: i64, tv_nsec: i64) -> Option<Instant> {
+        u32::try_from(tv_nsec)
+            .ok()
+            .filter(|&nanos| nanos < NANOSECONDS_IN_SECOND as u32)
+            .map(|nanos| Instant::of_epoch_second_and_nanos((tv_sec, nanos)))
+    }
+
+    /// Obtains an Instant from a JavaScript time value, the milliseconds since the epoch of `Date.getTime()`.
+    ///
+    /// Any fraction of a millisecond is kept, floored to the nanosecond,
+    /// so values before the epoch floor toward negative infinity, and the order of values is kept.
+    /// As with the system time conversions, there is no leap-second adjustment.
+    ///
+    /// # Parameters
+    ///  - `millis`: the milliseconds since the epoch.
+    ///
+    /// # Errors
+    /// - if `millis` is `NaN` or infinite, as for an invalid `Date`.
+    /// - if `millis` is further from the epoch than `Number.MAX_SAFE_INTEGER`.
+    #[cfg(feature = "wasm")]
+    pub fn from_js_millis(millis: f64) -> Result<Instant, JsMillisError> {
+        if !millis.is_finite() {
+            return Err(JsMillisError::NotFinite);
+        }
+        if millis.abs() > JS_MAX_SAFE_INTEGER as f64 {
+            return Err(JsMillisError::OutOfRange);
+        }
+        let whole_millis = millis.floor();
+        // The fraction is never negative, so the cast floors it.
+        let sub_milli_nanos = (((millis - whole_millis) * NANOSECONDS_IN_MILLISECOND as f64)
+            as i64)
+            .min(NANOSECONDS_IN_MILLISECOND - 1);
+        let whole_millis = whole_millis as i64;
+        Ok(Instant::of_epoch_second_and_adjustment(
+            whole_millis.div_euclid(MILLISECONDS_IN_SECOND),
+            whole_millis.rem_euclid(MILLISECONDS_IN_SECOND) * NANOSECONDS_IN_MILLISECOND
+                + sub_milli_nanos,
+        ))
+    }
+
+    /// Converts this instant to a JavaScript time value, the milliseconds since the epoch for `new Date(millis)`.
+    ///
+    /// Any fraction of a millisecond is truncated toward negative infinity,
+    /// so half a millisecond before the epoch is `-1.0`, and instants keep their order (though not their distinctness).
+    /// A `Date` only holds up to 8.64e15 milliseconds either side of the epoch,
+    /// and past `Number.MAX_SAFE_INTEGER` the result is rounded to the nearest number.
+    #[cfg(feature = "wasm")]
+    pub fn to_js_millis(&self) -> f64 {
+        let millis = i128::from(self.epoch_second) * i128::from(MILLISECONDS_IN_SECOND)
+            + i128::from(i64::from(self.nanosecond_of_second) / NANOSECONDS_IN_MILLISECOND);
+        millis as f64
+    }
+
+    /// Returns a copy of this instant with the specified duration added,
+    /// or `None` if the result would be outside the range of instants.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to add, positive or negative.
+    pub fn checked_add(self, duration: Duration) -> Option<Instant> {
+        add_seconds_and_nanos_checked(
+            (self.epoch_second, self.nanosecond_of_second),
+            (duration.seconds(), duration.nano()),
+        )
+        .map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Returns a copy of this instant with the specified duration added, saturating at the minimum or maximum instant.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to add, positive or negative.
+    pub fn saturating_add(self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .unwrap_or(if duration.is_negative() {
+                Instant::MIN
+            } else {
+                Instant::MAX
+            })
+    }
+
+    /// Returns a copy of this instant with the specified duration subtracted,
+    /// or `None` if the result would be outside the range of instants.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to subtract, positive or negative.
+    pub fn checked_sub(self, duration: Duration) -> Option<Instant> {
+        sub_seconds_and_nanos_checked(
+            (self.epoch_second, self.nanosecond_of_second),
+            (duration.seconds(), duration.nano()),
+        )
+        .map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Returns a copy of this instant with the specified duration subtracted, saturating at the minimum or maximum instant.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to subtract, positive or negative.
+    pub fn saturating_sub(self, duration: Duration) -> Instant {
+        self.checked_sub(duration)
+            .unwrap_or(if duration.is_negative() {
+                Instant::MAX
+            } else {
+                Instant::MIN
+            })
+    }
+
+    /// Adds the specified duration to every instant in the slice, in place, such as to correct for clock skew.
+    ///
+    /// This either updates every element or, if any element would overflow, leaves the slice unchanged.
+    /// The update is a single pass that only records whether any element overflowed, rather than stopping at it,
+    /// so the common case runs without per-element overflow branches; the slice is restored when one did overflow.
+    ///
+    /// # Parameters
+    ///  - `instants`: the instants to update.
+    ///  - `offset`: the duration to add, positive or negative.
+    ///
+    /// # Errors
+    /// - if adding the offset would overflow any element, identifying the first such element.
+    pub fn add_duration_slice(
+        instants: &mut [Instant],
+        offset: Duration,
+    ) -> Result<(), SliceOverflowError> {
+        add_to_slice_checked(
+            instants,
+            (offset.seconds(), offset.nano()),
+            |instant| (instant.epoch_second, instant.nanosecond_of_second),
+            Instant::of_epoch_second_and_nanos,
+        )
+    }
+
+    /// Gets the elapsed duration from an earlier instant to this one, or `Duration::ZERO` if `earlier` is actually later.
+    ///
+    /// Unlike subtracting instants, this never produces a negative duration.
+    /// This makes it the safe default for "time since start" measurements,
+    /// where a backwards step of the clock should not be reported as negative elapsed time.
+    /// A span too large to represent saturates at `Duration::MAX`.
+    ///
+    /// # Parameters
+    ///  - `earlier`: the instant the elapsed time is measured from.
+    pub fn duration_since_or_zero(&self, earlier: Instant) -> Duration {
+        if earlier > *self {
+            Duration::ZERO
+        } else {
+            self.checked_duration_since(earlier)
+                .unwrap_or(Duration::MAX)
+        }
+    }
+
+    /// Estimates how far a remote clock is ahead of the local clock, from a single request and response.
+    ///
+    /// This is the simple NTP-style estimate `remote - (local_now - round_trip / 2)`,
+    /// which assumes that the remote read its clock halfway through the round trip,
+    /// meaning the request and the response took equally long. Any asymmetry in the network path
+    /// (or time spent by the remote before or after reading its clock) is an error of up to half the round trip.
+    /// A positive result means the remote clock is ahead; odd nanoseconds of the round trip are truncated when halved.
+    ///
+    /// # Parameters
+    ///  - `local_now`: the local time when the response was received.
+    ///  - `remote`: the remote time, as carried by the response.
+    ///  - `round_trip`: the local time from sending the request to receiving the response.
+    ///
+    /// # Panics
+    /// - if `round_trip` is negative.
+    /// - if the estimate would overflow a duration.
+    pub fn estimate_skew(local_now: Instant, remote: Instant, round_trip: Duration) -> Duration {
+        assert!(!round_trip.is_negative(), "round trip must not be negative");
+        let skew = total_nanos(remote.epoch_second, remote.nanosecond_of_second)
+            - total_nanos(local_now.epoch_second, local_now.nanosecond_of_second)
+            + total_nanos(round_trip.seconds(), round_trip.nano()) / 2;
+        of_total_nanos_checked(skew)
+            .map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, i64::from(nanos)))
+            .expect("skew would overflow duration")
+    }
+
+    /// Gets the first tick strictly after `now`, on the grid of ticks every `period` starting at this instant,
+    /// such as for a fixed-rate scheduler.
+    ///
+    /// This is the smallest `self + n * period` later than `now`, for `n` of zero or more,
+    /// so a `now` exactly on a tick gets the following tick, and a `now` before this instant gets this instant.
+    /// The tick count is computed exactly, so a tick is never skipped or repeated however far `now` is from the start.
+    ///
+    /// # Parameters
+    ///  - `now`: the instant the tick must be after.
+    ///  - `period`: the time between ticks.
+    ///
+    /// # Panics
+    /// - if `period` is not positive.
+    /// - if the tick would be after the maximum instant.
+    pub fn next_tick_after(&self, now: Instant, period: Duration) -> Instant {
+        self.checked_next_tick_after(now, period)
+            .expect("next tick would overflow instant")
+    }
+
+    /// Gets the first tick strictly after `now`, on the grid of ticks every `period` starting at this instant,
+    /// or `None` if the tick would be after the maximum instant.
+    ///
+    /// See [`next_tick_after`](#method.next_tick_after) for details.
+    ///
+    /// # Parameters
+    ///  - `now`: the instant the tick must be after.
+    ///  - `period`: the time between ticks.
+    ///
+    /// # Panics
+    /// - if `period` is not positive.
+    pub fn checked_next_tick_after(&self, now: Instant, period: Duration) -> Option<Instant> {
+        assert!(period > Duration::ZERO, "period must be positive");
+        let phase = total_nanos(self.epoch_second, self.nanosecond_of_second);
+        let period = total_nanos(period.seconds(), period.nano());
+        let elapsed = total_nanos(now.epoch_second, now.nanosecond_of_second) - phase;
+        let ticks = if elapsed < 0 { 0 } else { elapsed / period + 1 };
+        of_total_nanos_checked(phase + ticks * period).map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Gets the absolute duration between this instant and another, regardless of which is earlier.
+    ///
+    /// A span too large to represent saturates at `Duration::MAX`.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to measure to.
+    pub fn abs_diff(&self, other: Instant) -> Duration {
+        if other > *self {
+            other.duration_since_or_zero(*self)
+        } else {
+            self.duration_since_or_zero(other)
+        }
+    }
+
+    /// Gets the start of the bucket of the given length containing this instant, such as for grouping instants by minute
+    /// as the keys of a `BTreeMap`.
+    ///
+    /// Buckets are aligned to the epoch, so each key is the largest multiple of the unit since the epoch
+    /// that is no later than this instant; instants before the epoch are rounded towards the past as well.
+    /// Any two instants in the same bucket produce equal keys, which are identical in every field
+    /// (including [`to_bits`](#method.to_bits)), and keys are ordered consistently with the instants themselves,
+    /// so if `a <= b` then `a.truncated_key(unit) <= b.truncated_key(unit)`.
+    ///
+    /// # Parameters
+    ///  - `unit`: the positive length of the buckets.
+    ///
+    /// # Panics
+    /// - if `unit` is zero or negative.
+    /// - if the start of the bucket would be before the minimum instant.
+    pub fn truncated_key(&self, unit: Duration) -> Instant {
+        assert!(unit > Duration::ZERO, "unit must be positive");
+        Duration::of_seconds_and_adjustment(self.epoch_second, i64::from(self.nanosecond_of_second))
+            .prev_multiple_of(unit)
+            .map(|since_epoch| {
+                Instant::of_epoch_second_and_nanos((since_epoch.seconds(), since_epoch.nano()))
+            })
+            .expect("bucket would overflow instant")
+    }
+
+    /// Returns a copy of this instant with the nanosecond of second replaced, keeping the epoch second,
+    /// such as for aligning samples to a fixed phase within each second.
+    ///
+    /// # Parameters
+    ///  - `nanos`: the new nanosecond of second, from 0 to 999,999,999.
+    ///
+    /// # Errors
+    /// - if `nanos` is not less than one second.
+    pub fn with_nanos_of_second(&self, nanos: u32) -> Result<Instant, CivilFieldError> {
+        if nanos < NANOSECONDS_IN_SECOND as u32 {
+            Ok(Instant::of_epoch_second_and_nanos((
+                self.epoch_second,
+                nanos,
+            )))
+        } else {
+            Err(CivilFieldError::new(
+                CivilField::Nanosecond,
+                i64::from(nanos),
+            ))
+        }
+    }
+
+    /// Truncates this instant toward the past to the start of its second, dropping the nanosecond of second.
+    pub fn floor_to_second(&self) -> Instant {
+        Instant::of_epoch_second(self.epoch_second)
+    }
+
+    /// Rounds this instant to the nearest whole second, with half a second rounding up to the following second.
+    ///
+    /// # Panics
+    /// - if the rounded instant would be after the maximum instant.
+    pub fn round_to_nearest_second(&self) -> Instant {
+        if self.nanosecond_of_second < NANOSECONDS_IN_SECOND as u32 / 2 {
+            self.floor_to_second()
+        } else {
+            self.epoch_second
+                .checked_add(1)
+                .map(Instant::of_epoch_second)
+                .expect("rounding would overflow instant")
+        }
+    }
+
+    /// Checks if this instant and another display the same when truncated to the unit at a fixed offset from UTC,
+    /// such as for collapsing log lines that only differ below the precision they are shown at.
+    ///
+    /// Both instants are truncated towards the past in local time, so the instants are equal exactly
+    /// when they fall in the same second, minute, or day, as shown on a clock at the offset.
+    /// The offset only matters for units longer than its granularity,
+    /// such as days for any offset, or hours for `+05:30`.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to compare to.
+    ///  - `precision`: the smallest unit that is displayed.
+    ///  - `offset_seconds`: the offset from UTC at which the instants are displayed, such as `3600` for `+01:00`.
+    pub fn display_eq(&self, other: Instant, precision: Unit, offset_seconds: i32) -> bool {
+        let truncated = |instant: Instant| {
+            (total_nanos(instant.epoch_second, instant.nanosecond_of_second)
+                + i128::from(offset_seconds) * i128::from(NANOSECONDS_IN_SECOND))
+            .div_euclid(i128::from(precision.nanos()))
+        };
+        truncated(*self) == truncated(other)
+    }
+
+    /// Checks if this instant is within the tolerance of another, such as for assertions in tests.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to compare to.
+    ///  - `tolerance`: the largest difference allowed, inclusive.
+    pub fn approx_eq(&self, other: Instant, tolerance: Tolerance) -> bool {
+        tolerance.allows(
+            (self.epoch_second, self.nanosecond_of_second),
+            (other.epoch_second, other.nanosecond_of_second),
+        )
+    }
+
+    /// Gets the time remaining until this instant, as a deadline, for APIs that take a standard library timeout.
+    ///
+    /// This is the difference from `now` to this instant, converted with [`Duration::to_std`](struct.Duration.html#method.to_std),
+    /// or `None` if the deadline has been reached (it is not after `now`), so that a timer should fire immediately.
+    /// A deadline so far in the future that the difference overflows a duration gives `std::time::Duration::MAX`.
+    ///
+    /// # Parameters
+    ///  - `now`: the current instant.
+    #[cfg(feature = "std")]
+    pub fn timeout_from(&self, now: Instant) -> Option<std::time::Duration> {
+        if *self <= now {
+            return None;
+        }
+        match self.checked_duration_since(now) {
+            Some(remaining) => remaining.to_std(),
+            None => Some(std::time::Duration::MAX),
+        }
+    }
+
+    /// Gets the time elapsed from this instant to the current instant of the clock,
+    /// which is negative if this instant is in the future.
+    ///
+    /// A span too large to represent saturates at `Duration::MIN` or `Duration::MAX`, rather than panicking.
+    ///
+    /// # Parameters
+    ///  - `clock`: the clock to read the current instant from.
+    pub fn elapsed(&self, clock: &impl Clock) -> Duration {
+        clock.instant().saturating_duration_since(*self)
+    }
+
+    /// Gets the time elapsed from this instant to the current instant of the system clock,
+    /// which is negative if this instant is in the future.
+    ///
+    /// This is [`elapsed`](#method.elapsed) with a [`SystemClock`](struct.SystemClock.html).
+    #[cfg(feature = "std")]
+    pub fn elapsed_std(&self) -> Duration {
+        self.elapsed(&SystemClock)
+    }
+
+    /// Gets the time remaining from the current instant of the clock until this instant,
+    /// which is negative if this instant is in the past.
+    ///
+    /// A span too large to represent saturates at `Duration::MIN` or `Duration::MAX`, rather than panicking.
+    ///
+    /// # Parameters
+    ///  - `clock`: the clock to read the current instant from.
+    pub fn remaining_until(&self, clock: &impl Clock) -> Duration {
+        self.saturating_duration_since(clock.instant())
+    }
+
+    /// Gets the duration from another instant to this one, as `self - other`,
+    /// or `None` if the difference would overflow a duration, as when the instants are more than half the range apart.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to subtract, earlier or later.
+    pub fn checked_sub_instant(self, other: Instant) -> Option<Duration> {
+        self.checked_duration_since(other)
+    }
+
+    /// Gets how long to wait for this instant, as a deadline, from the current instant of the clock,
+    /// capped at the longest interval between polls, as a standard library timeout for an OS call.
+    ///
+    /// The time remaining is clamped between zero and `max`, so an expired deadline gives zero,
+    /// and a deadline too far in the future for the difference to be a duration gives `max`.
+    /// This never panics: a negative `max` is treated as zero.
+    ///
+    /// # Parameters
+    ///  - `clock`: the clock to read the current instant from.
+    ///  - `max`: the longest time to wait before polling again.
+    pub fn poll_timeout(&self, clock: &impl Clock, max: Duration) -> core::time::Duration {
+        let max = max.max(Duration::ZERO);
+        let now = clock.instant();
+        let remaining = match self.checked_sub_instant(now) {
+            Some(remaining) => remaining.clamp(Duration::ZERO, max),
+            None if *self > now => max,
+            None => Duration::ZERO,
+        };
+        remaining.to_std().unwrap_or(core::time::Duration::ZERO)
+    }
+
+    fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+        self.checked_duration_since(earlier)
+            .unwrap_or(if *self > earlier {
+                Duration::MAX
+            } else {
+                Duration::MIN
+            })
+    }
+
+    pub(crate) fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        sub_seconds_and_nanos_checked(
+            (self.epoch_second, self.nanosecond_of_second),
+            (earlier.epoch_second, earlier.nanosecond_of_second),
+        )
+        .map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, i64::from(nanos)))
+    }
+
+    /// Obtains an Instant from a packed MS-DOS date and time, as used by zip archives and FAT filesystems.
+    ///
+    /// The date packs the years since 1980 in bits 15-9, the month in bits 8-5, and the day of the month in bits 4-0;
+    /// the time packs the hour in bits 15-11, the minute in bits 10-5, and half the second in bits 4-0.
+    /// These are local times, with no record of their offset from UTC, so the offset must be supplied.
+    ///
+    /// # Parameters
+    ///  - `date`: the packed date.
+    ///  - `time`: the packed time.
+    ///  - `offset_seconds`: the offset from UTC at which the date and time were recorded, such as `3600` for `+01:00`.
+    ///
+    /// # Errors
+    /// - if any packed field is out of range, such as a month of 0 or 13, a day of 0, or a second of 60 or more.
+    pub fn from_dos_datetime(
+        date: u16,
+        time: u16,
+        offset_seconds: i32,
+    ) -> Result<Instant, CivilFieldError> {
+        let (date, time) = (u32::from(date), u32::from(time));
+        Instant::try_of_offset(
+            1980 + (date >> 9) as i32,
+            (date >> 5) & 0x0F,
+            date & 0x1F,
+            time >> 11,
+            (time >> 5) & 0x3F,
+            (time & 0x1F) * 2,
+            0,
+            offset_seconds,
+        )
+    }
+
+    /// Packs this instant as an MS-DOS date and time, as used by zip archives and FAT filesystems.
+    ///
+    /// The format only has a resolution of two seconds, so the time is always truncated towards the past to an even second,
+    /// and any fraction of the second is dropped. Be aware that some tools, such as Info-ZIP's `zip`,
+    /// instead round odd seconds up, so `08:30:45` is stored here as `08:30:44`, but by them as `08:30:46`.
+    /// See [`from_dos_datetime`](#method.from_dos_datetime) for the layout.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which to record the date and time, such as `3600` for `+01:00`.
+    ///
+    /// # Errors
+    /// - if the year is before 1980 or after 2107, as observed at the offset.
+    pub fn to_dos_datetime(&self, offset_seconds: i32) -> Result<(u16, u16), CivilFieldError> {
+        let (epoch_day, nano_of_day) = self.to_epoch_day_and_nanos(offset_seconds);
+        let (year, month, day) = civil_of_epoch_day(epoch_day);
+        if !(1980..=2107).contains(&year) {
+            return Err(CivilFieldError::new(CivilField::Year, year));
+        }
+        let second_of_day = (nano_of_day / NANOSECONDS_IN_SECOND as u64) as u32;
+        let hour = second_of_day / SECONDS_IN_HOUR as u32;
+        let minute = second_of_day / SECONDS_IN_MINUTE as u32 % MINUTES_IN_HOUR as u32;
+        let second = second_of_day % SECONDS_IN_MINUTE as u32;
+        let date = ((year - 1980) as u32) << 9 | month << 5 | day;
+        let time = hour << 11 | minute << 5 | (second / 2);
+        Ok((date as u16, time as u16))
+    }
+
+    /// Obtains an Instant from a day number and the nanoseconds within that day, as observed at a fixed offset from UTC.
+    ///
+    /// This is the inverse of [`to_epoch_day_and_nanos`](#method.to_epoch_day_and_nanos).
+    ///
+    /// # Parameters
+    ///  - `epoch_day`: the number of days since '1970-01-01', negative for earlier days.
+    ///  - `nano_of_day`: the nanoseconds since the start of the day.
+    ///  - `offset_seconds`: the offset from UTC at which the day is observed, such as `3600` for `+01:00`.
+    ///
+    /// # Panics
+    /// - if `nano_of_day` is not less than the length of a day.
+    /// - if the instant would be before the minimum instant, or after the maximum instant.
+    pub fn from_epoch_day_and_nanos(
+        epoch_day: i64,
+        nano_of_day: u64,
+        offset_seconds: i32,
+    ) -> Instant {
+        assert!(
+            nano_of_day < NANOSECONDS_IN_DAY as u64,
+            "nano of day must be less than one day"
+        );
+        Instant::checked_from_epoch_day_and_nanos(epoch_day, nano_of_day, offset_seconds)
+            .expect("epoch day would overflow instant")
+    }
+
+    /// Obtains an Instant from a day number and the nanoseconds within that day, as observed at a fixed offset from UTC,
+    /// or `None` if `nano_of_day` is not less than the length of a day,
+    /// or the instant would be before the minimum instant, or after the maximum instant.
+    ///
+    /// See [`from_epoch_day_and_nanos`](#method.from_epoch_day_and_nanos) for details.
+    ///
+    /// # Parameters
+    ///  - `epoch_day`: the number of days since '1970-01-01', negative for earlier days.
+    ///  - `nano_of_day`: the nanoseconds since the start of the day.
+    ///  - `offset_seconds`: the offset from UTC at which the day is observed, such as `3600` for `+01:00`.
+    pub fn checked_from_epoch_day_and_nanos(
+        epoch_day: i64,
+        nano_of_day: u64,
+        offset_seconds: i32,
+    ) -> Option<Instant> {
+        if nano_of_day >= NANOSECONDS_IN_DAY as u64 {
+            return None;
+        }
+        let epoch_second = i128::from(epoch_day) * i128::from(SECONDS_IN_DAY)
+            + i128::from(nano_of_day / NANOSECONDS_IN_SECOND as u64)
+            - i128::from(offset_seconds);
+        i64::try_from(epoch_second).ok().map(|epoch_second| {
+            Instant::of_epoch_second_and_nanos((
+                epoch_second,
+                (nano_of_day % NANOSECONDS_IN_SECOND as u64) as u32,
+            ))
+        })
+    }
+
+    /// Obtains an Instant from a civil date and time in UTC, such as `Instant::of_utc(2007, 12, 3, 10, 15, 30, 0)`.
+    ///
+    /// Months and days count from 1, as on a calendar.
+    /// As with the other civil conversions, every day is treated as 86,400 seconds long,
+    /// so there is no leap-second adjustment, and a second of `60` is rejected.
+    ///
+    /// # Parameters
+    ///  - `year`: the proleptic Gregorian year, such as `2007`, or `0` for 1 BC.
+    ///  - `month`: the month of the year, from 1 to 12.
+    ///  - `day`: the day of the month, from 1 to the length of the month.
+    ///  - `hour`: the hour of the day, from 0 to 23.
+    ///  - `minute`: the minute of the hour, from 0 to 59.
+    ///  - `second`: the second of the minute, from 0 to 59.
+    ///  - `nano`: the nanosecond of the second, from 0 to 999,999,999.
+    ///
+    /// # Panics
+    /// - if any field is out of range, naming the first such field.
+    pub fn of_utc(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+    ) -> Instant {
+        Instant::try_of_utc(year, month, day, hour, minute, second, nano)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Obtains an Instant from a civil date and time in UTC,
+    /// or an error naming the first field out of range.
+    ///
+    /// See [`of_utc`](#method.of_utc) for details.
+    ///
+    /// # Errors
+    /// - if any field is out of range, naming the first such field.
+    pub fn try_of_utc(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+    ) -> Result<Instant, CivilFieldError> {
+        Instant::try_of_offset(year, month, day, hour, minute, second, nano, 0)
+    }
+
+    /// Obtains an Instant from a civil date and time observed at a fixed offset from UTC,
+    /// such as `Instant::of_offset(2007, 12, 3, 11, 15, 30, 0, 3600)` for `2007-12-03T11:15:30+01:00`.
+    ///
+    /// See [`of_utc`](#method.of_utc) for details.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the date and time are observed, such as `3600` for `+01:00`.
+    ///
+    /// # Panics
+    /// - if any field is out of range, naming the first such field.
+    #[allow(clippy::too_many_arguments)]
+    pub fn of_offset(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+        offset_seconds: i32,
+    ) -> Instant {
+        Instant::try_of_offset(year, month, day, hour, minute, second, nano, offset_seconds)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Obtains an Instant from a civil date and time observed at a fixed offset from UTC,
+    /// or an error naming the first field out of range.
+    ///
+    /// See [`of_offset`](#method.of_offset) for details.
+    ///
+    /// # Errors
+    /// - if any field is out of range, naming the first such field.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_of_offset(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nano: u32,
+        offset_seconds: i32,
+    ) -> Result<Instant, CivilFieldError> {
+        let year = i64::from(year);
+        let check = |field, value, valid: RangeInclusive<u32>| {
+            if valid.contains(&value) {
+                Ok(())
+            } else {
+                Err(CivilFieldError::new(field, i64::from(value)))
+            }
+        };
+        check(CivilField::Month, month, 1..=12)?;
+        check(CivilField::Day, day, 1..=days_in_month(year, month))?;
+        check(CivilField::Hour, hour, 0..=HOURS_IN_DAY as u32 - 1)?;
+        check(CivilField::Minute, minute, 0..=MINUTES_IN_HOUR as u32 - 1)?;
+        check(CivilField::Second, second, 0..=SECONDS_IN_MINUTE as u32 - 1)?;
+        check(
+            CivilField::Nanosecond,
+            nano,
+            0..=NANOSECONDS_IN_SECOND as u32 - 1,
+        )?;
+
+        let second_of_day = u64::from(hour) * SECONDS_IN_HOUR as u64
+            + u64::from(minute) * SECONDS_IN_MINUTE as u64
+            + u64::from(second);
+        // Even the extreme years and offsets are well within the range of instants.
+        Ok(Instant::from_epoch_day_and_nanos(
+            epoch_day_of(year, month, day),
+            second_of_day * NANOSECONDS_IN_SECOND as u64 + u64::from(nano),
+            offset_seconds,
+        ))
+    }
+
+    /// Splits this instant into a day number and the nanoseconds within that day, as observed at a fixed offset from UTC.
+    ///
+    /// Days are counted from '1970-01-01', rounding toward negative infinity,
+    /// so `1969-12-31T12:00:00Z` is day -1 with 43,200,000,000,000 nanoseconds into the day.
+    /// This is the primitive underlying any date or time type, and is directly useful as a date key.
+    /// As with the other civil conversions, every day is treated as 86,400 seconds long.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the day is observed, such as `3600` for `+01:00`.
+    pub fn to_epoch_day_and_nanos(&self, offset_seconds: i32) -> (i64, u64) {
+        let local_second = i128::from(self.epoch_second) + i128::from(offset_seconds);
+        let seconds_in_day = i128::from(SECONDS_IN_DAY);
+        (
+            local_second.div_euclid(seconds_in_day) as i64,
+            local_second.rem_euclid(seconds_in_day) as u64 * NANOSECONDS_IN_SECOND as u64
+                + u64::from(self.nanosecond_of_second),
+        )
+    }
+
+    /// Gets the date of this instant in the calendar, as observed at a fixed offset from UTC,
+    /// such as `(1582, 10, 4)` in the Julian calendar for the day before the Gregorian reform.
+    ///
+    /// The year is numbered astronomically, so year `0` is 1 BC.
+    /// See [`Calendar`](enum.Calendar.html) for how the calendars differ.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the date is observed, such as `3600` for `+01:00`.
+    ///  - `calendar`: the calendar to express the date in.
+    pub fn to_civil_date(&self, offset_seconds: i32, calendar: Calendar) -> (i64, u32, u32) {
+        calendar.civil_of_epoch_day(self.to_epoch_day_and_nanos(offset_seconds).0)
+    }
+
+    /// Obtains an Instant at the start of a date in the calendar, as observed at a fixed offset from UTC,
+    /// or an error naming the field out of range.
+    ///
+    /// This is the inverse of [`to_civil_date`](#method.to_civil_date).
+    /// As with the other civil conversions, every day is treated as 86,400 seconds long.
+    ///
+    /// # Parameters
+    ///  - `year`: the year in the calendar, such as `1582`, or `0` for 1 BC.
+    ///  - `month`: the month of the year, from 1 to 12.
+    ///  - `day`: the day of the month, from 1 to the length of the month in the calendar.
+    ///  - `offset_seconds`: the offset from UTC at which the date is observed, such as `3600` for `+01:00`.
+    ///  - `calendar`: the calendar the date is in.
+    ///
+    /// # Errors
+    /// - if the month is out of range.
+    /// - if the day is out of range for the month, or is one of the dates skipped by the cutover of
+    ///   [`Calendar::MixedBritish`](enum.Calendar.html#variant.MixedBritish).
+    pub fn try_of_civil_date(
+        year: i32,
+        month: u32,
+        day: u32,
+        offset_seconds: i32,
+        calendar: Calendar,
+    ) -> Result<Instant, CivilFieldError> {
+        if !(1..=12).contains(&month) {
+            return Err(CivilFieldError::new(CivilField::Month, i64::from(month)));
+        }
+        let epoch_day = calendar
+            .epoch_day_of(i64::from(year), month, day)
+            .ok_or_else(|| CivilFieldError::new(CivilField::Day, i64::from(day)))?;
+        // Even the extreme years and offsets are well within the range of instants.
+        Ok(Instant::from_epoch_day_and_nanos(
+            epoch_day,
+            0,
+            offset_seconds,
+        ))
+    }
+
+    /// Describes the time between this instant and a reference instant, such as "3 minutes ago" or "in 2 days".
+    ///
+    /// This is the instant counterpart of [`Duration::humanize`](struct.Duration.html#method.humanize):
+    /// the phrase is "ago" when this instant is before the reference, and "in" when it is after.
+    /// Use `to_string()` on the result for the English phrase.
+    /// This uses the default [`RelativeTimeOptions`](struct.RelativeTimeOptions.html).
+    /// The reference is a parameter, rather than the current time, so that results are reproducible.
+    ///
+    /// # Parameters
+    ///  - `reference`: the instant to describe this instant relative to, usually the current time.
+    pub fn relative_to(&self, reference: Instant) -> RelativeTime {
+        self.relative_to_with(reference, &RelativeTimeOptions::default())
+    }
+
+    /// Describes the time between this instant and a reference instant, using the specified thresholds.
+    ///
+    /// The largest unit whose threshold the difference reaches is chosen, and the amount is rounded half up.
+    ///
+    /// # Parameters
+    ///  - `reference`: the instant to describe this instant relative to, usually the current time.
+    ///  - `options`: the thresholds for "just now" and each unit.
+    pub fn relative_to_with(
+        &self,
+        reference: Instant,
+        options: &RelativeTimeOptions,
+    ) -> RelativeTime {
+        RelativeTime::between(*self, reference, options)
+    }
+
+    /// Gets the day of the week of this instant, as observed at a fixed offset from UTC.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the day is observed, such as `3600` for `+01:00`.
+    pub fn day_of_week(&self, offset_seconds: i32) -> DayOfWeek {
+        DayOfWeek::of_epoch_day(self.to_epoch_day_and_nanos(offset_seconds).0)
+    }
+
+    /// Gets the aligned week of the month of this instant, as observed at a fixed offset from UTC.
+    ///
+    /// Like `java.time`'s `ALIGNED_WEEK_OF_MONTH`, weeks are aligned to the start of the month regardless of
+    /// the day of the week, so days 1 to 7 are week 1, days 8 to 14 are week 2, and so on, up to week 5.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the date is observed, such as `3600` for `+01:00`.
+    pub fn aligned_week_of_month(&self, offset_seconds: i32) -> u32 {
+        let (_, _, day) = civil_of_epoch_day(self.to_epoch_day_and_nanos(offset_seconds).0);
+        (day - 1) / 7 + 1
+    }
+
+    /// Gets the day within the aligned week of the month of this instant, as observed at a fixed offset from UTC.
+    ///
+    /// Like `java.time`'s `ALIGNED_DAY_OF_WEEK_IN_MONTH`, this is from 1 to 7, where day 1 of the month is always 1;
+    /// see [`aligned_week_of_month`](#method.aligned_week_of_month).
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the date is observed, such as `3600` for `+01:00`.
+    pub fn aligned_day_of_week_in_month(&self, offset_seconds: i32) -> u32 {
+        let (_, _, day) = civil_of_epoch_day(self.to_epoch_day_and_nanos(offset_seconds).0);
+        (day - 1) % 7 + 1
+    }
+
+    /// Gets the week of the month of this instant, as observed at a fixed offset from UTC,
+    /// using the specified week definition.
+    ///
+    /// This matches `java.time.temporal.WeekFields.weekOfMonth()`. Weeks start on `first_day_of_week`,
+    /// and week 1 is the first week with at least `minimal_days_in_first_week` days in the month;
+    /// days before it are in week 0. For example, the US definition is Sunday with a minimum of 1 day,
+    /// so week 1 always contains the first of the month, while the ISO-8601 definition is Monday with a minimum of 4 days,
+    /// so a month starting on a Friday begins with three days of week 0.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the date is observed, such as `3600` for `+01:00`.
+    ///  - `first_day_of_week`: the day each week starts on.
+    ///  - `minimal_days_in_first_week`: the days of the month that week 1 must contain, from 1 to 7.
+    ///
+    /// # Panics
+    /// - if `minimal_days_in_first_week` is not from 1 to 7.
+    pub fn week_of_month(
+        &self,
+        offset_seconds: i32,
+        first_day_of_week: DayOfWeek,
+        minimal_days_in_first_week: u8,
+    ) -> u32 {
+        let epoch_day = self.to_epoch_day_and_nanos(offset_seconds).0;
+        let (_, _, day) = civil_of_epoch_day(epoch_day);
+        week_of_period(
+            day,
+            DayOfWeek::of_epoch_day(epoch_day),
+            first_day_of_week,
+            minimal_days_in_first_week,
+        )
+    }
+
+    /// Gets the week of the year of this instant, as observed at a fixed offset from UTC,
+    /// using the specified week definition.
+    ///
+    /// This matches `java.time.temporal.WeekFields.weekOfYear()`, numbering weeks within the year as
+    /// [`week_of_month`](#method.week_of_month) does within the month, from week 0 to week 54.
+    /// Unlike the ISO week-based year, days before week 1 are in week 0 rather than the last week of the previous year.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the date is observed, such as `3600` for `+01:00`.
+    ///  - `first_day_of_week`: the day each week starts on.
+    ///  - `minimal_days_in_first_week`: the days of the year that week 1 must contain, from 1 to 7.
+    ///
+    /// # Panics
+    /// - if `minimal_days_in_first_week` is not from 1 to 7.
+    pub fn week_of_year(
+        &self,
+        offset_seconds: i32,
+        first_day_of_week: DayOfWeek,
+        minimal_days_in_first_week: u8,
+    ) -> u32 {
+        let epoch_day = self.to_epoch_day_and_nanos(offset_seconds).0;
+        let (year, _, _) = civil_of_epoch_day(epoch_day);
+        week_of_period(
+            (epoch_day - epoch_day_of(year, 1, 1) + 1) as u32,
+            DayOfWeek::of_epoch_day(epoch_day),
+            first_day_of_week,
+            minimal_days_in_first_week,
+        )
+    }
+
+    /// Gets the start of the UTC day containing this instant, such as for bucketing events by day.
+    ///
+    /// Like the other `start_of_utc_*` methods, this floors toward the past, so an instant before the epoch
+    /// gets the start of its own day rather than of the following day, and an instant exactly on the boundary is unchanged.
+    /// As with the other civil conversions, every day is treated as 86,400 seconds long.
+    ///
+    /// # Panics
+    /// - if the start of the day would be before the minimum instant.
+    pub fn start_of_utc_day(&self) -> Instant {
+        Instant::from_epoch_day_and_nanos(self.to_epoch_day_and_nanos(0).0, 0, 0)
+    }
+
+    /// Gets the start of the UTC week containing this instant, with weeks starting on the specified day.
+    ///
+    /// # Parameters
+    ///  - `first_day`: the day each week starts on, such as Monday for ISO-8601 weeks.
+    ///
+    /// # Panics
+    /// - if the start of the week would be before the minimum instant.
+    pub fn start_of_utc_week(&self, first_day: DayOfWeek) -> Instant {
+        let epoch_day = self.to_epoch_day_and_nanos(0).0;
+        let days_into_week = (i64::from(DayOfWeek::of_epoch_day(epoch_day).value())
+            - i64::from(first_day.value()))
+        .rem_euclid(DAYS_IN_WEEK_ISO);
+        Instant::from_epoch_day_and_nanos(epoch_day - days_into_week, 0, 0)
+    }
+
+    /// Gets the start of the UTC month containing this instant, in the proleptic Gregorian calendar.
+    ///
+    /// # Panics
+    /// - if the start of the month would be before the minimum instant.
+    pub fn start_of_utc_month(&self) -> Instant {
+        let (year, month, _) = civil_of_epoch_day(self.to_epoch_day_and_nanos(0).0);
+        Instant::from_epoch_day_and_nanos(epoch_day_of(year, month, 1), 0, 0)
+    }
+
+    /// Gets the start of the UTC year containing this instant, in the proleptic Gregorian calendar.
+    ///
+    /// # Panics
+    /// - if the start of the year would be before the minimum instant.
+    pub fn start_of_utc_year(&self) -> Instant {
+        let (year, _, _) = civil_of_epoch_day(self.to_epoch_day_and_nanos(0).0);
+        Instant::from_epoch_day_and_nanos(epoch_day_of(year, 1, 1), 0, 0)
+    }
+
+    /// Converts this instant to a decimal year, as observed at a fixed offset from UTC,
+    /// such as `2023.5` for the middle of 2023, as used to plot scientific time series.
+    ///
+    /// The fraction is the time into the year divided by the length of that year,
+    /// so each day is 1/366 of a leap year, but 1/365 of a common year.
+    /// An `f64` only has 53 bits of significand, which for present-day years resolves to about 7 microseconds.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC at which the year is observed, such as `3600` for `+01:00`.
+    pub fn to_decimal_year(&self, offset_seconds: i32) -> f64 {
+        let (epoch_day, nano_of_day) = self.to_epoch_day_and_nanos(offset_seconds);
+        let (year, _, _) = civil_of_epoch_day(epoch_day);
+        let nanos_into_year = (epoch_day - epoch_day_of(year, 1, 1)) as f64
+            * NANOSECONDS_IN_DAY as f64
+            + nano_of_day as f64;
+        year as f64 + nanos_into_year / year_nanos(year) as f64
+    }
+
+    /// Obtains an Instant from a decimal year, as observed at a fixed offset from UTC,
+    /// or `None` if the year is not finite, or the instant would be out of range.
+    ///
+    /// This is the inverse of [`to_decimal_year`](#method.to_decimal_year), rounded to the nearest nanosecond,
+    /// so `2020.5` is `2020-07-02T00:00:00Z`, half of the 366 days of 2020.
+    /// As the year only has the precision of an `f64`, round trips are only exact to about 7 microseconds for present-day years.
+    ///
+    /// # Parameters
+    ///  - `year`: the year with its fraction, such as `2023.5`.
+    ///  - `offset_seconds`: the offset from UTC at which the year is observed, such as `3600` for `+01:00`.
+    pub fn from_decimal_year(year: f64, offset_seconds: i32) -> Option<Instant> {
+        // Well beyond the years of any instant, but small enough that the epoch day of the year cannot overflow.
+        if !year.is_finite() || year.abs() > 1e12 {
+            return None;
+        }
+        // Floor and round by hand, as they are not available without `std`.
+        let mut whole_year = year as i64;
+        if whole_year as f64 > year {
+            whole_year -= 1;
+        }
+        // The fraction is never negative, so adding a half and truncating rounds to the nearest.
+        let nanos_into_year =
+            ((year - whole_year as f64) * year_nanos(whole_year) as f64 + 0.5) as i128;
+        let local_nanos = i128::from(epoch_day_of(whole_year, 1, 1))
+            * i128::from(NANOSECONDS_IN_DAY)
+            + nanos_into_year;
+        of_total_nanos_checked(
+            local_nanos - i128::from(offset_seconds) * i128::from(NANOSECONDS_IN_SECOND),
+        )
+        .map(Instant::of_epoch_second_and_nanos)
+    }
+
+    /// Counts the civil days from the date of this instant to that of another, as observed at a fixed offset from UTC,
+    /// such as the number of nights of a stay.
+    ///
+    /// Only the dates matter, not the times of day, so 23:00 to 01:00 the next day is one day,
+    /// while 00:00 to 23:59 of the same day is none.
+    /// If `other` is on an earlier date the count is negative, so that reversing the instants negates the result.
+    /// See [`civil_days_touched`](#method.civil_days_touched) to count the dates a span overlaps instead.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to count to.
+    ///  - `offset_seconds`: the offset from UTC at which the dates are observed, such as `3600` for `+01:00`.
+    pub fn civil_days_until(&self, other: Instant, offset_seconds: i32) -> i64 {
+        other.to_epoch_day_and_nanos(offset_seconds).0
+            - self.to_epoch_day_and_nanos(offset_seconds).0
+    }
+
+    /// Counts the civil dates that the span from this instant to `end` overlaps, as observed at a fixed offset from UTC,
+    /// such as the number of calendar days a booking occupies.
+    ///
+    /// The span is half-open, so a date is touched if any instant from this one up to, but not including, `end` falls on it.
+    /// A non-empty span within a single day touches one date, a span ending exactly at midnight does not touch
+    /// the date starting at `end`, and an empty span touches none.
+    /// The exact length of the span is `end - self`, which may differ from the count of whole days.
+    ///
+    /// # Parameters
+    ///  - `end`: the exclusive end of the span.
+    ///  - `offset_seconds`: the offset from UTC at which the dates are observed, such as `3600` for `+01:00`.
+    ///
+    /// # Panics
+    /// - if `end` is before this instant.
+    pub fn civil_days_touched(&self, end: Instant, offset_seconds: i32) -> u64 {
+        assert!(*self <= end, "end must not be before start");
+        if *self == end {
+            return 0;
+        }
+        let first = self.to_epoch_day_and_nanos(offset_seconds).0;
+        let (end_day, end_nanos) = end.to_epoch_day_and_nanos(offset_seconds);
+        // An end at midnight excludes the whole of its own date.
+        let last = if end_nanos == 0 { end_day - 1 } else { end_day };
+        (last - first + 1) as u64
+    }
+
+    /// Counts the civil dates falling on the day of the week, from the date of the start to that of the end,
+    /// as observed at a fixed offset from UTC, such as for "how many Mondays between these dates".
+    ///
+    /// The dates form a half-open interval: the date of `start` is counted, but the date of `end` is not,
+    /// so the count is zero if `end` is not on a later date than `start`.
+    ///
+    /// # Parameters
+    ///  - `start`: the instant whose date starts the range, inclusive.
+    ///  - `end`: the instant whose date ends the range, exclusive.
+    ///  - `day_of_week`: the day of the week to count, such as `DayOfWeek::Monday`.
+    ///  - `offset_seconds`: the offset from UTC at which the dates are observed, such as `3600` for `+01:00`.
+    pub fn count_weekday_in_range(
+        start: Instant,
+        end: Instant,
+        day_of_week: DayOfWeek,
+        offset_seconds: i32,
+    ) -> i64 {
+        let start = start.to_epoch_day_and_nanos(offset_seconds).0;
+        let end = end.to_epoch_day_and_nanos(offset_seconds).0;
+        if start < end {
+            day_of_week_between(start, end, day_of_week)
+        } else {
+            0
+        }
+    }
+
+    /// Counts the business days (Monday to Friday) from the civil date of this instant to that of another,
+    /// as observed at a fixed offset from UTC, such as for SLA calculations.
+    ///
+    /// The dates form a half-open interval: the date of this instant is counted, but the date of `other` is not.
+    /// If `other` is on an earlier date the count is negative, so that reversing the instants negates the result.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to count to.
+    ///  - `offset_seconds`: the offset from UTC at which the dates are observed, such as `3600` for `+01:00`.
+    pub fn business_days_until(&self, other: Instant, offset_seconds: i32) -> i64 {
+        let start = self.to_epoch_day_and_nanos(offset_seconds).0;
+        let end = other.to_epoch_day_and_nanos(offset_seconds).0;
+        if start <= end {
+            weekdays_between(start, end)
+        } else {
+            -weekdays_between(end, start)
+        }
+    }
+
+    /// Counts the business days from the civil date of this instant to that of another, excluding holidays.
+    ///
+    /// This is as [`business_days_until`](#method.business_days_until), except that any holiday falling on a weekday
+    /// within the interval is not counted. Holidays falling on a weekend are ignored, as they are not counted anyway.
+    ///
+    /// # Parameters
+    ///  - `other`: the instant to count to.
+    ///  - `offset_seconds`: the offset from UTC at which the dates are observed, such as `3600` for `+01:00`.
+    ///  - `holidays`: the dates to exclude, as `(year, month, day)`; invalid dates are ignored.
+    #[cfg(feature = "std")]
+    pub fn business_days_until_excluding(
+        &self,
+        other: Instant,
+        offset_seconds: i32,
+        holidays: &HashSet<(i32, u8, u8)>,
+    ) -> i64 {
+        let start = self.to_epoch_day_and_nanos(offset_seconds).0;
+        let end = other.to_epoch_day_and_nanos(offset_seconds).0;
+        let (low, high) = (start.min(end), start.max(end));
+        let excluded = holidays
+            .iter()
+            .filter(|&&(year, month, day)| {
+                let (year, month, day) = (i64::from(year), u32::from(month), u32::from(day));
+                (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+            })
+            .map(|&(year, month, day)| {
+                epoch_day_of(i64::from(year), u32::from(month), u32::from(day))
+            })
+            .filter(|&epoch_day| {
+                low <= epoch_day
+                    && epoch_day < high
+                    && !DayOfWeek::of_epoch_day(epoch_day).is_weekend()
+            })
+            .count() as i64;
+        let count = weekdays_between(low, high) - excluded;
+        if start <= end {
+            count
+        } else {
+            -count
+        }
+    }
+
+    /// Iterates over the start of each month after the specified instant, as observed at a fixed offset from UTC.
+    ///
+    /// Starting from mid-January yields the first of February, then the first of March, and so on,
+    /// each at midnight at the given offset. Every month starts on its first day,
+    /// so differing month lengths and leap years never shift the results.
+    /// Use [`step_months`](struct.MonthStarts.html#method.step_months) to step by more than one month, such as for quarters.
+    /// Iteration ends when the next month start would be after the maximum instant.
+    ///
+    /// As with the other civil conversions, every day is treated as 86,400 seconds long.
+    ///
+    /// # Parameters
+    ///  - `start`: the instant to start after; its own month is never yielded.
+    ///  - `offset_seconds`: the offset from UTC at which months are observed, such as `3600` for `+01:00`.
+    pub fn iter_months(start: Instant, offset_seconds: i32) -> MonthStarts {
+        MonthStarts::after(start, offset_seconds)
+    }
+
+    /// Finds the instant in a sorted index closest to the query, returning its position and distance from the query.
+    ///
+    /// This is a binary search, so the index must be sorted in ascending order;
+    /// if it is not, some position is still returned, but it may not be the nearest.
+    /// When the query is equally distant from two instants, the earlier one is chosen,
+    /// and of any duplicate instants the first position is returned.
+    /// Distances are compared exactly, but the one returned saturates at `Duration::MAX`, as for
+    /// [`abs_diff`](#method.abs_diff).
+    ///
+    /// Returns `None` only if the index is empty.
+    ///
+    /// # Parameters
+    ///  - `index`: the instants to search, in ascending order.
+    ///  - `query`: the instant to find the nearest neighbor of.
+    pub fn nearest(index: &[Instant], query: Instant) -> Option<(usize, Duration)> {
+        let after = index.partition_point(|instant| *instant < query);
+        let position = match (after.checked_sub(1), index.get(after)) {
+            (None, None) => return None,
+            (None, Some(_)) => after,
+            (Some(before), None) => before,
+            (Some(before), Some(&later)) => {
+                let query_nanos = total_nanos(query.epoch_second, query.nanosecond_of_second);
+                let earlier = index[before];
+                let before_distance =
+                    query_nanos - total_nanos(earlier.epoch_second, earlier.nanosecond_of_second);
+                let after_distance =
+                    total_nanos(later.epoch_second, later.nanosecond_of_second) - query_nanos;
+                if before_distance <= after_distance {
+                    before
+                } else {
+                    after
+                }
+            }
+        };
+        let nearest = index[position];
+        let first = index[..position].partition_point(|instant| *instant < nearest);
+        Some((first, nearest.abs_diff(query)))
+    }
+
+    /// Finds the earliest of the instants, returning its position along with it, such as for an event loop's next wakeup.
+    ///
+    /// The instants do not need to be sorted; of any duplicates of the earliest instant, the first position is returned.
+    ///
+    /// Returns `None` only if there are no instants.
+    ///
+    /// # Parameters
+    ///  - `instants`: the instants to search, such as pending deadlines.
+    pub fn earliest(instants: &[Instant]) -> Option<(usize, Instant)> {
+        instants
+            .iter()
+            .copied()
+            .enumerate()
+            .min_by(|(_, instant), (_, other)| instant.cmp(other))
+    }
+
+    /// Finds the step between instants that are evenly spaced, such as the timestamps of regular samples.
+    ///
+    /// A slice with a uniform step can be stored as just its first instant, the step, and its length.
+    /// The step is the difference from each instant to the next; it is zero for a run of equal instants,
+    /// and negative if the instants are descending.
+    ///
+    /// Returns `None` if the slice has fewer than two instants, if the differences are not all equal,
+    /// or if a difference is too large to represent.
+    ///
+    /// # Parameters
+    ///  - `sorted`: the instants to check, in order.
+    pub fn detect_uniform_stride(sorted: &[Instant]) -> Option<Duration> {
+        let mut steps = sorted
+            .windows(2)
+            .map(|pair| pair[1].checked_duration_since(pair[0]));
+        let step = steps.next()??;
+        if steps.all(|next| next == Some(step)) {
+            Some(step)
+        } else {
+            None
+        }
+    }
+
+    /// Downsamples the instants by keeping every `factor`-th one, starting with the first, such as for a coarser level of an index.
+    ///
+    /// Positions 0, `factor`, `2 * factor`, and so on are kept, in order, so a sorted slice gives a sorted result,
+    /// of `base.len()` divided by `factor`, rounded up, instants. An empty slice gives an empty result.
+    ///
+    /// # Parameters
+    ///  - `base`: the instants to downsample, usually sorted.
+    ///  - `factor`: the number of instants each kept instant stands for.
+    ///
+    /// # Panics
+    /// - if `factor` is zero.
+    #[cfg(feature = "std")]
+    pub fn downsample_grid(base: &[Instant], factor: u32) -> Vec<Instant> {
+        assert!(factor > 0, "downsampling factor must be positive");
+        base.iter().copied().step_by(factor as usize).collect()
+    }
+
+    /// Builds a pyramid of successively coarser levels of the instants, such as for a multi-resolution index.
+    ///
+    /// The first level is a copy of the base, and each level after it is the level before downsampled
+    /// by the factor, as for [`downsample_grid`](#method.downsample_grid).
+    /// Levels continue to be built once they shrink to a single instant (or none), so there are always `levels` of them.
+    ///
+    /// # Parameters
+    ///  - `base`: the finest level of instants, usually sorted.
+    ///  - `levels`: the number of levels, including the base.
+    ///  - `factor`: the number of instants of each level that each instant of the next level stands for.
+    ///
+    /// # Panics
+    /// - if `factor` is zero.
+    #[cfg(feature = "std")]
+    pub fn build_pyramid(base: &[Instant], levels: usize, factor: u32) -> Vec<Vec<Instant>> {
+        assert!(factor > 0, "downsampling factor must be positive");
+        let mut pyramid: Vec<Vec<Instant>> = Vec::with_capacity(levels);
+        for _ in 0..levels {
+            let level = match pyramid.last() {
+                Some(finer) => Instant::downsample_grid(finer, factor),
+                None => base.to_vec(),
+            };
+            pyramid.push(level);
+        }
+        pyramid
+    }
+
+    /// Gets the maximum length, in bytes, of the output of [`format_into`](#method.format_into),
+    /// such as for sizing a fixed buffer.
+    pub const fn formatted_len_max() -> usize {
+        // -292277022657-01-27T08:29:52.000000001Z
+        39
+    }
+
+    /// Formats this instant as an ISO-8601 timestamp in UTC, such as `2007-12-03T10:15:30.123Z`, into the writer.
+    ///
+    /// This matches `java.time.Instant`: the fraction of the second is omitted when zero,
+    /// and otherwise uses as many groups of three digits as needed.
+    /// Years after 9999 are prefixed with `+`, and years before 0 with `-`.
+    /// As with the other civil conversions, every day is treated as 86,400 seconds long.
+    ///
+    /// The whole rendering is written with a single call, so a writer that fails receives nothing.
+    ///
+    /// # Parameters
+    ///  - `w`: the writer to format into.
+    pub fn format_into<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_str(self.iso_digits(None, 0).as_str())
+    }
+
+    /// Formats this instant as an RFC 3339 timestamp in UTC, with exactly the given number of fraction digits.
+    ///
+    /// The fraction is rounded half-up to the requested digits, carrying into the seconds
+    /// (and from there into the date) as needed, so `23:59:59.9999999` at 6 digits is midnight of the next day.
+    /// The one exception is [`MAX`](#associatedconstant.MAX), which has no later second to carry into,
+    /// and so is truncated instead.
+    /// Zero digits omits the fraction (and the decimal point) entirely.
+    /// This is the same rendering as `Display` with a precision, so `format!("{:.6}", instant)` is equivalent.
+    ///
+    /// # Parameters
+    ///  - `digits`: the number of fraction digits, from 0 to 9.
+    ///
+    /// # Panics
+    /// - if `digits` is greater than 9.
+    #[cfg(feature = "std")]
+    pub fn to_rfc3339_with_precision(&self, digits: u8) -> String {
+        assert!(digits <= 9, "fraction digits must be at most 9");
+        self.iso_digits(Some(u32::from(digits)), 0)
+            .as_str()
+            .to_owned()
+    }
+
+    /// Formats this instant lazily as an ISO-8601 timestamp at the given offset from UTC, such as `2007-12-03T11:15:30+01:00`.
+    ///
+    /// Nothing is computed until the result is written, so this is cheap to pass to a logging macro that may discard it.
+    /// The rendering is as for `Display`, including precision, except that the local date and time are shown
+    /// followed by the offset, which is `Z` for UTC and otherwise `+HH:MM`, or `+HH:MM:SS` if it has seconds.
+    ///
+    /// # Parameters
+    ///  - `offset_seconds`: the offset from UTC to render at, in seconds.
+    ///
+    /// # Panics
+    /// - if `offset_seconds` is more than 18 hours either side of UTC.
+    pub fn display(&self, offset_seconds: i32) -> InstantDisplay {
+        assert!(
+            offset_seconds.unsigned_abs() <= OFFSET_SECONDS_MAX as u32,
+            "offset must be within 18 hours of UTC"
+        );
+        InstantDisplay::new(*self, offset_seconds)
+    }
+
+    // Rounds half-up to the given number (0 to 9) of fraction digits,
+    // returning the instant with its nanoseconds rounded to a multiple of the last digit.
+    fn round_fraction(&self, digits: u32) -> Instant {
+        let unit = 10u32.pow(9 - digits);
+        let rounded = (self.nanosecond_of_second + unit / 2) / unit * unit;
+        if rounded < NANOSECONDS_IN_SECOND as u32 {
+            Instant::of_epoch_second_and_nanos((self.epoch_second, rounded))
+        } else {
+            self.epoch_second
+                .checked_add(1)
+                .map(Instant::of_epoch_second)
+                .unwrap_or(Instant::of_epoch_second_and_nanos((
+                    self.epoch_second,
+                    self.nanosecond_of_second / unit * unit,
+                )))
+        }
+    }
+
+    pub(crate) fn iso_digits(&self, fraction_digits: Option<u32>, offset_seconds: i32) -> Digits {
+        let rounded = fraction_digits.map(|digits| self.round_fraction(digits));
+        let instant = rounded.as_ref().unwrap_or(self);
+        let (epoch_day, nano_of_day) = instant.to_epoch_day_and_nanos(offset_seconds);
+        let (year, month, day) = civil_of_epoch_day(epoch_day);
+        let second_of_day = (nano_of_day / NANOSECONDS_IN_SECOND as u64) as u32;
+
+        let mut digits = Digits::new();
+        if year > 9_999 {
+            digits.push(b'+');
+        } else if year < 0 {
+            digits.push(b'-');
+        }
+        match year.unsigned_abs() {
+            year if year < 10_000 => digits.push_4(year as u32),
+            year => digits.push_u64(year),
+        }
+        digits.push(b'-');
+        digits.push_2(month);
+        digits.push(b'-');
+        digits.push_2(day);
+        digits.push(b'T');
+        digits.push_2(second_of_day / SECONDS_IN_HOUR as u32);
+        digits.push(b':');
+        digits.push_2(second_of_day / SECONDS_IN_MINUTE as u32 % MINUTES_IN_HOUR as u32);
+        digits.push(b':');
+        digits.push_2(second_of_day % SECONDS_IN_MINUTE as u32);
+        match fraction_digits {
+            None if instant.nanosecond_of_second == 0 => {}
+            None => {
+                digits.push(b'.');
+                digits.push_fraction_groups(instant.nanosecond_of_second);
+            }
+            Some(0) => {}
+            Some(count) => {
+                digits.push(b'.');
+                digits.push_fraction_digits(instant.nanosecond_of_second, count);
+            }
+        }
+        if offset_seconds == 0 {
+            digits.push(b'Z');
+        } else {
+            let magnitude = offset_seconds.unsigned_abs();
+            digits.push(if offset_seconds < 0 { b'-' } else { b'+' });
+            digits.push_2(magnitude / SECONDS_IN_HOUR as u32);
+            digits.push(b':');
+            digits.push_2(magnitude / SECONDS_IN_MINUTE as u32 % MINUTES_IN_HOUR as u32);
+            if magnitude % SECONDS_IN_MINUTE as u32 != 0 {
+                digits.push(b':');
+                digits.push_2(magnitude % SECONDS_IN_MINUTE as u32);
+            }
+        }
+        digits
+    }
+
+    /// Formats this instant as the (fractional) seconds since the epoch, such as `1234567890.123456789`.
+    ///
+    /// Trailing zeros in the fraction are dropped, as is the fraction entirely for a whole second.
+    /// Instants before the epoch carry the sign on the whole value, so half a second before the epoch is `-0.5`.
+    /// This is a cheap rendering of the stored fields, and does not perform any civil (calendar) conversion.
+    #[cfg(feature = "std")]
+    pub fn to_epoch_string(&self) -> String {
+        let (negative, seconds, nanos) =
+            sign_and_magnitude(self.epoch_second, self.nanosecond_of_second);
+        let mut digits = Digits::new();
+        if negative {
+            digits.push(b'-');
+        }
+        digits.push_u64(seconds);
+        if nanos > 0 {
+            digits.push(b'.');
+            digits.push_fraction(nanos);
+        }
+        digits.as_str().to_owned()
+    }
+
+    /// Formats this instant as a fixed-width ASCII key whose lexicographic order is the order of the instants,
+    /// such as for object names or document IDs in stores that sort by string.
+    ///
+    /// The key is the epoch second plus 2^63 (so that `MIN` is zero, and instants before 1970 sort first)
+    /// as twenty zero-padded decimal digits, then a `.`, then the nanosecond of second as nine digits:
+    /// the epoch is `09223372036854775808.000000000`, and `2024-06-15T12:10:21.123456789Z` is
+    /// `09223372038573229229.123456789`. Every key is 30 characters.
+    /// This scheme is part of the documented format, and will not change.
+    /// This is parsed by [`from_sortable_string`](#method.from_sortable_string).
+    #[cfg(feature = "std")]
+    pub fn to_sortable_string(&self) -> String {
+        let mut text = Digits::new();
+        text.push_u64_padded(
+            to_biased_seconds(self.epoch_second),
+            SORTABLE_SECONDS_DIGITS,
+        );
+        text.push(b'.');
+        text.push_9(self.nanosecond_of_second);
+        text.as_str().to_owned()
+    }
+
+    /// Formats this instant as the fractional seconds since the epoch, with exactly the given number of fraction digits,
+    /// such as `1718453421.123457` for 6 digits.
+    ///
+    /// As with [`to_rfc3339_with_precision`](#method.to_rfc3339_with_precision), the fraction is rounded half-up,
+    /// except at [`MAX`](#associatedconstant.MAX) where it is truncated,
+    /// and zero digits omits the fraction (and the decimal point) entirely.
+    /// Instants before the epoch carry the sign on the whole value, so half a second before the epoch is `-0.5`.
+    /// This is the inverse of [`parse_unix`](#method.parse_unix).
+    ///
+    /// # Parameters
+    ///  - `digits`: the number of fraction digits, from 0 to 9.
+    ///
+    /// # Panics
+    /// - if `digits` is greater than 9.
+    #[cfg(feature = "std")]
+    pub fn to_unix_string(&self, digits: u8) -> String {
+        assert!(digits <= 9, "fraction digits must be at most 9");
+        let rounded = self.round_fraction(u32::from(digits));
+        let (negative, seconds, nanos) =
+            sign_and_magnitude(rounded.epoch_second, rounded.nanosecond_of_second);
+        let mut text = Digits::new();
+        if negative {
+            text.push(b'-');
+        }
+        text.push_u64(seconds);
+        if digits > 0 {
+            text.push(b'.');
+            text.push_fraction_digits(nanos, u32::from(digits));
+        }
+        text.as_str().to_owned()
+    }
+
+    /// Encodes this instant as a versioned, fixed-length binary record, suitable for persisting to disk.
+    ///
+    /// The first byte is the format version, currently `1`.
+    /// Version `1` is followed by the epoch second as a little-endian `i64`,
+    /// then the nanosecond of second as a little-endian `u32`, for 13 bytes in total.
+    /// Future versions may change the payload, but will always keep the leading version byte.
+    #[cfg(feature = "std")]
+    pub fn to_versioned_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(VERSIONED_BYTES_LENGTH);
+        bytes.push(VERSIONED_BYTES_VERSION);
+        bytes.extend_from_slice(&self.epoch_second.to_le_bytes());
+        bytes.extend_from_slice(&self.nanosecond_of_second.to_le_bytes());
+        bytes
+    }
+
+    /// Decodes an instant from the binary record produced by [`to_versioned_bytes`](#method.to_versioned_bytes).
+    ///
+    /// # Errors
+    /// - if the version byte is missing, or is not a known version.
+    /// - if the input is not exactly the length the version requires.
+    /// - if the nanosecond of second is not less than one second.
+    pub fn from_versioned_bytes(bytes: &[u8]) -> Result<Instant, DecodeError> {
+        match bytes.first() {
+            None => Err(DecodeError::InvalidLength {
+                expected: VERSIONED_BYTES_LENGTH,
+                actual: 0,
+            }),
+            Some(&VERSIONED_BYTES_VERSION) => {
+                if bytes.len() != VERSIONED_BYTES_LENGTH {
+                    return Err(DecodeError::InvalidLength {
+                        expected: VERSIONED_BYTES_LENGTH,
+                        actual: bytes.len(),
+                    });
+                }
+                let mut epoch_second = [0; 8];
+                let mut nanos = [0; 4];
+                epoch_second.copy_from_slice(&bytes[1..9]);
+                nanos.copy_from_slice(&bytes[9..13]);
+                let nanos = u32::from_le_bytes(nanos);
+                if i64::from(nanos) >= NANOSECONDS_IN_SECOND {
+                    return Err(DecodeError::InvalidNanos(nanos));
+                }
+                Ok(Instant::of_epoch_second_and_nanos((
+                    i64::from_le_bytes(epoch_second),
+                    nanos,
+                )))
+            }
+            Some(&version) => Err(DecodeError::UnknownVersion(version)),
+        }
+    }
+}
+
+impl fmt::Display for Instant {
+    /// Formats this instant as an ISO-8601 timestamp in UTC, such as `2007-12-03T10:15:30.123Z`.
+    ///
+    /// See [`format_into`](#method.format_into) for details.
+    /// Width, fill, and alignment are honored as for a string, so `{:>30}` right-aligns the instant.
+    ///
+    /// Without a precision, the fraction is not trimmed of every trailing zero, as `Duration`'s `Display` does,
+    /// but written in groups of three digits, so half a second is `.500`:
+    /// this keeps the default rendering the same as `java.time.Instant.toString()`.
+    /// A precision fixes the number of fraction digits, as for
+    /// [`to_rfc3339_with_precision`](#method.to_rfc3339_with_precision), so `{:.6}` always writes microseconds.
+    /// Precisions above 9 are treated as 9, since nanoseconds are the finest resolution available.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fraction_digits = f.precision().map(|precision| precision.min(9) as u32);
+        pad_without_precision(f, self.iso_digits(fraction_digits, 0).as_str())
+    }
+}
+
+impl fmt::Debug for Instant {
+    /// Formats this instant as its ISO-8601 rendering in UTC followed by its raw fields,
+    /// such as `Instant(2024-06-15T12:10:21Z, epoch_second=1718453421, nano=0)`.
+    ///
+    /// The alternate form (`{:#?}`) puts each on its own line.
+    /// This replaced the derived `Debug`, so code matching the old `Instant { epoch_second: .. }` output must be updated.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_with_fields(
+            f,
+            "Instant",
+            self,
+            &[
+                ("epoch_second", &self.epoch_second),
+                ("nano", &self.nanosecond_of_second),
+            ],
+        )
+    }
+}
+
+impl FromStr for Instant {
+    type Err = ParseError;
+
+    /// Parses an RFC 3339 timestamp, as [`parse_rfc3339`](#method.parse_rfc3339).
+    fn from_str(text: &str) -> Result<Instant, ParseError> {
+        Instant::parse_rfc3339(text)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, duration: Duration) -> Instant {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub for Instant {
+    type Output = Duration;
+
+    /// Gets the signed duration from the other instant to this one.
+    fn sub(self, other: Instant) -> Duration {
+        self.checked_duration_since(other)
+            .expect("overflow when subtracting instants")
+    }
+}
+
+// Converts a timestamp counting units that divide a second evenly, flooring so the nanoseconds are never negative.
+#[inline(always)]
+fn of_timestamp(value: i64, per_second: i64) -> Instant {
+    Instant::of_epoch_second_and_nanos(seconds_and_nanos_of_units(value, per_second))
+}
+
+// The length of the (proleptic Gregorian) year in nanoseconds.
+fn year_nanos(year: i64) -> i64 {
+    let days = if is_leap_year(year) {
+        DAYS_IN_LONG_YEAR
+    } else {
+        DAYS_IN_YEAR
+    };
+    days * NANOSECONDS_IN_DAY
+}
+
+// The signed nanoseconds from the Unix epoch to the system time, which always fit in an `i128`.
+#[cfg(feature = "std")]
+fn system_time_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => {
+            i128::from(after.as_secs()) * i128::from(NANOSECONDS_IN_SECOND)
+                + i128::from(after.subsec_nanos())
+        }
+        Err(error) => {
+            let before = error.duration();
+            -(i128::from(before.as_secs()) * i128::from(NANOSECONDS_IN_SECOND)
+                + i128::from(before.subsec_nanos()))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialEq<SystemTime> for Instant {
+    /// Checks if this instant is the same as the system time, counting seconds since the Unix epoch as is.
+    ///
+    /// See [`from_system_time_lossy`](#method.from_system_time_lossy) for the caveats about leap seconds;
+    /// system times beyond the range of instants are never equal to any instant.
+    fn eq(&self, other: &SystemTime) -> bool {
+        total_nanos(self.epoch_second, self.nanosecond_of_second) == system_time_nanos(*other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl PartialOrd<SystemTime> for Instant {
+    /// Compares this instant to the system time, counting seconds since the Unix epoch as is.
+    ///
+    /// See [`from_system_time_lossy`](#method.from_system_time_lossy) for the caveats about leap seconds;
+    /// system times beyond the range of instants compare as later than every instant, or earlier, as appropriate.
+    fn partial_cmp(&self, other: &SystemTime) -> Option<Ordering> {
+        Some(
+            total_nanos(self.epoch_second, self.nanosecond_of_second)
+                .cmp(&system_time_nanos(*other)),
+        )
+    }
+}
+
+impl Instant {
+    // Renders the instant for `defmt`, as signed (fractional) seconds since the epoch, such as `-0.500000000`.
+    #[cfg(any(feature = "defmt", test))]
+    pub(crate) fn defmt_digits(&self) -> Digits {
+        let mut digits = Digits::new();
+        digits.push_fixed_seconds(self.epoch_second, self.nanosecond_of_second);
+        digits
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Instant {
+    /// Writes the instant as signed (fractional) seconds since the epoch, such as `-0.500000000`.
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.defmt_digits().as_str())
+    }
 }
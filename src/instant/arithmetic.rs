@@ -0,0 +1,502 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{CivilField, Duration, FixedClock, Instant};
+
+fn total_nanos(seconds: i64, nanos: u32) -> i128 {
+    i128::from(seconds) * i128::from(NANOSECONDS_IN_SECOND) + i128::from(nanos)
+}
+
+proptest! {
+    #[test]
+    fn add_duration(instant_seconds in prop::num::i64::ANY, instant_nanos in 0..NANOSECONDS_IN_SECOND, duration_seconds in prop::num::i64::ANY, duration_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(instant_seconds, instant_nanos);
+        let duration = Duration::of_seconds_and_adjustment(duration_seconds, duration_nanos);
+
+        let expected = total_nanos(instant.epoch_second(), instant.nano()) + total_nanos(duration.seconds(), duration.nano());
+        let in_range = (total_nanos(i64::MIN, 0)..=total_nanos(i64::MAX, 999_999_999)).contains(&expected);
+
+        match instant.checked_add(duration) {
+            Some(sum) => {
+                prop_assert!(in_range);
+                prop_assert_eq!(expected, total_nanos(sum.epoch_second(), sum.nano()));
+                prop_assert_eq!(sum, instant + duration);
+            },
+            None => prop_assert!(!in_range),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn sub_duration(instant_seconds in prop::num::i64::ANY, instant_nanos in 0..NANOSECONDS_IN_SECOND, duration_seconds in prop::num::i64::ANY, duration_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(instant_seconds, instant_nanos);
+        let duration = Duration::of_seconds_and_adjustment(duration_seconds, duration_nanos);
+
+        let expected = total_nanos(instant.epoch_second(), instant.nano()) - total_nanos(duration.seconds(), duration.nano());
+        let in_range = (total_nanos(i64::MIN, 0)..=total_nanos(i64::MAX, 999_999_999)).contains(&expected);
+
+        match instant.checked_sub(duration) {
+            Some(difference) => {
+                prop_assert!(in_range);
+                prop_assert_eq!(expected, total_nanos(difference.epoch_second(), difference.nano()));
+                prop_assert_eq!(difference, instant - duration);
+            },
+            None => prop_assert!(!in_range),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_duration_carries_nanos(instant in Just(Instant::of_epoch_second_and_adjustment(0, 600_000_000)), duration in Just(Duration::of_millis(600))) {
+        prop_assert_eq!(Instant::of_epoch_second_and_adjustment(1, 200_000_000), instant + duration);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when adding duration to instant")]
+    fn add_duration_overflow(instant in Just(Instant::MAX), duration in Just(Duration::of_nanos(1))) {
+        let _instant = instant + duration;
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when subtracting duration from instant")]
+    fn sub_duration_underflow(instant in Just(Instant::MIN), duration in Just(Duration::of_nanos(1))) {
+        let _instant = instant - duration;
+    }
+}
+
+proptest! {
+    #[test]
+    fn saturating_add_duration((instant, duration, expected) in prop_oneof![
+        Just((Instant::EPOCH, Duration::of_seconds(-1), Instant::of_epoch_second(-1))),
+        Just((Instant::MAX, Duration::of_nanos(1), Instant::MAX)),
+        Just((Instant::MIN, Duration::of_nanos(-1), Instant::MIN)),
+    ]) {
+        prop_assert_eq!(expected, instant.saturating_add(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn saturating_sub_duration((instant, duration, expected) in prop_oneof![
+        Just((Instant::EPOCH, Duration::of_seconds(-1), Instant::of_epoch_second(1))),
+        Just((Instant::MIN, Duration::of_nanos(1), Instant::MIN)),
+        Just((Instant::MAX, Duration::of_nanos(-1), Instant::MAX)),
+    ]) {
+        prop_assert_eq!(expected, instant.saturating_sub(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn sub_instant(first_seconds in (i64::MIN / 2)..(i64::MAX / 2), first_nanos in 0..NANOSECONDS_IN_SECOND, second_seconds in (i64::MIN / 2)..(i64::MAX / 2), second_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let first = Instant::of_epoch_second_and_adjustment(first_seconds, first_nanos);
+        let second = Instant::of_epoch_second_and_adjustment(second_seconds, second_nanos);
+
+        let duration = first - second;
+
+        prop_assert_eq!(total_nanos(first.epoch_second(), first.nano()) - total_nanos(second.epoch_second(), second.nano()), total_nanos(duration.seconds(), duration.nano()));
+        prop_assert_eq!(first, second + duration);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "overflow when subtracting instants")]
+    fn sub_instant_overflow(first in Just(Instant::MAX), second in Just(Instant::MIN)) {
+        let _duration = first - second;
+    }
+}
+
+proptest! {
+    #[test]
+    fn duration_since_or_zero(seconds in (i64::MIN / 2)..(i64::MAX / 2), nanos in 0..NANOSECONDS_IN_SECOND, elapsed_seconds in 0..(i64::MAX / 2), elapsed_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let earlier = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let elapsed = Duration::of_seconds_and_adjustment(elapsed_seconds, elapsed_nanos);
+        let later = earlier + elapsed;
+
+        prop_assert_eq!(elapsed, later.duration_since_or_zero(earlier));
+        prop_assert_eq!(Duration::ZERO, earlier.duration_since_or_zero(earlier));
+    }
+}
+
+proptest! {
+    #[test]
+    fn duration_since_or_zero_reversed(seconds in (i64::MIN / 2)..(i64::MAX / 2), nanos in 0..NANOSECONDS_IN_SECOND, elapsed_nanos in 1..NANOSECONDS_IN_SECOND) {
+        let earlier = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let later = earlier + Duration::of_nanos(elapsed_nanos);
+
+        prop_assert_eq!(Duration::ZERO, earlier.duration_since_or_zero(later));
+    }
+}
+
+proptest! {
+    #[test]
+    fn duration_since_or_zero_saturates(later in Just(Instant::MAX), earlier in Just(Instant::MIN)) {
+        prop_assert_eq!(Duration::MAX, later.duration_since_or_zero(earlier));
+    }
+}
+
+proptest! {
+    #[test]
+    fn abs_diff(seconds in (i64::MIN / 2)..(i64::MAX / 2), nanos in 0..NANOSECONDS_IN_SECOND, elapsed_seconds in 0..(i64::MAX / 2), elapsed_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let earlier = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let elapsed = Duration::of_seconds_and_adjustment(elapsed_seconds, elapsed_nanos);
+        let later = earlier + elapsed;
+
+        prop_assert_eq!(elapsed, later.abs_diff(earlier));
+        prop_assert_eq!(elapsed, earlier.abs_diff(later));
+    }
+}
+
+proptest! {
+    #[test]
+    fn abs_diff_saturates((first, second) in prop_oneof![
+        Just((Instant::MAX, Instant::MIN)),
+        Just((Instant::MIN, Instant::MAX)),
+    ]) {
+        prop_assert_eq!(Duration::MAX, first.abs_diff(second));
+    }
+}
+
+proptest! {
+    #[test]
+    fn truncated_key_shares_minute(seconds in (i64::MIN / 2)..(i64::MAX / 2)) {
+        let minute = Duration::of_minutes(1);
+        // 2024-06-15T12:34:10.25Z and 30 seconds later.
+        let first = Instant::of_epoch_second_and_adjustment(1_718_454_850, 250_000_000);
+        let second = first + Duration::of_seconds(30);
+
+        prop_assert_eq!(Instant::of_epoch_second(1_718_454_840), first.truncated_key(minute));
+        prop_assert_eq!(first.truncated_key(minute).to_bits(), second.truncated_key(minute).to_bits());
+        prop_assert_ne!(first.truncated_key(minute), (second + Duration::of_seconds(20)).truncated_key(minute));
+
+        // The same holds anywhere on the timeline.
+        let start = Instant::of_epoch_second(seconds).truncated_key(minute);
+        let end = start + Duration::of_seconds_and_adjustment(59, 999_999_999);
+        prop_assert_eq!(start, end.truncated_key(minute));
+        prop_assert_eq!(end + Duration::of_nanos(1), (end + Duration::of_nanos(1)).truncated_key(minute));
+    }
+}
+
+proptest! {
+    #[test]
+    fn truncated_key_vectors((instant, unit, expected) in prop_oneof![
+        Just((Instant::EPOCH, Duration::of_hours(1), Instant::EPOCH)),
+        Just((Instant::of_epoch_second_and_adjustment(3_599, 999_999_999), Duration::of_hours(1), Instant::EPOCH)),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 999_999_999), Duration::of_hours(1), Instant::of_epoch_second(-3_600))),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 500_000_000), Duration::of_millis(300), Instant::of_epoch_second_and_adjustment(-1, 400_000_000))),
+        Just((Instant::of_epoch_second_and_adjustment(12, 345_678_901), Duration::of_nanos(1), Instant::of_epoch_second_and_adjustment(12, 345_678_901))),
+        Just((Instant::MAX, Duration::of_seconds(1), Instant::of_epoch_second(i64::MAX))),
+        Just((Instant::MIN, Duration::of_seconds(1), Instant::MIN)),
+    ]) {
+        prop_assert_eq!(expected, instant.truncated_key(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn truncated_key_is_monotonic(first_seconds in (i64::MIN / 2)..(i64::MAX / 2), first_nanos in 0..NANOSECONDS_IN_SECOND, second_seconds in (i64::MIN / 2)..(i64::MAX / 2), second_nanos in 0..NANOSECONDS_IN_SECOND, unit_nanos in 1..(1i64 << 50)) {
+        let first = Instant::of_epoch_second_and_adjustment(first_seconds, first_nanos);
+        let second = Instant::of_epoch_second_and_adjustment(second_seconds, second_nanos);
+        let unit = Duration::of_nanos(unit_nanos);
+        let (earlier, later) = if first <= second { (first, second) } else { (second, first) };
+
+        prop_assert!(earlier.truncated_key(unit) <= later.truncated_key(unit));
+        prop_assert!(earlier.truncated_key(unit) <= earlier);
+        prop_assert_eq!(earlier.truncated_key(unit), earlier.truncated_key(unit).truncated_key(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "unit must be positive")]
+    fn truncated_key_requires_positive_unit(unit in prop_oneof![Just(Duration::ZERO), Just(Duration::of_nanos(-1))]) {
+        let _key = Instant::EPOCH.truncated_key(unit);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "bucket would overflow instant")]
+    fn truncated_key_overflow(instant in Just(Instant::MIN)) {
+        let _key = instant.truncated_key(Duration::of_seconds(7));
+    }
+}
+
+proptest! {
+    #[test]
+    fn with_nanos_of_second(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, replacement in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let replaced = instant.with_nanos_of_second(replacement as u32).unwrap();
+
+        prop_assert_eq!(seconds, replaced.epoch_second());
+        prop_assert_eq!(replacement as u32, replaced.nano());
+    }
+}
+
+proptest! {
+    #[test]
+    fn with_nanos_of_second_out_of_range(nanos in (NANOSECONDS_IN_SECOND as u32)..=u32::MAX) {
+        let error = Instant::EPOCH.with_nanos_of_second(nanos).unwrap_err();
+
+        prop_assert_eq!((CivilField::Nanosecond, i64::from(nanos)), (error.field(), error.value()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn round_to_second((seconds, nanos, floor, nearest) in prop_oneof![
+        Just((10, 0, 10, 10)),
+        Just((10, 400_000_000, 10, 10)),
+        Just((10, 499_999_999, 10, 10)),
+        Just((10, 500_000_000, 10, 11)),
+        Just((10, 600_000_000, 10, 11)),
+        Just((-1, 400_000_000, -1, -1)),
+        Just((-1, 600_000_000, -1, 0)),
+        Just((i64::MIN, 999_999_999, i64::MIN, i64::MIN + 1)),
+        Just((i64::MAX, 499_999_999, i64::MAX, i64::MAX)),
+    ]) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Instant::of_epoch_second(floor), instant.floor_to_second());
+        prop_assert_eq!(Instant::of_epoch_second(nearest), instant.round_to_nearest_second());
+    }
+}
+
+#[test]
+#[should_panic(expected = "rounding would overflow instant")]
+fn round_to_nearest_second_overflow() {
+    Instant::MAX.round_to_nearest_second();
+}
+
+// A deterministic spread of instants across the whole range, including the extremes.
+fn spread_instants(count: usize) -> Vec<Instant> {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    (0..count)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            Instant::of_epoch_second_and_adjustment(
+                (state >> 8) as i64 >> 8,
+                (state % NANOSECONDS_IN_SECOND as u64) as i64,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn add_duration_slice_million_matches_scalar() {
+    let offset = Duration::of_seconds_and_adjustment(-37, 123_456_789);
+    let original = spread_instants(1_000_000);
+    let mut instants = original.clone();
+
+    Instant::add_duration_slice(&mut instants, offset).unwrap();
+
+    for (instant, shifted) in original.iter().zip(instants.iter()) {
+        assert_eq!(*instant + offset, *shifted);
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_duration_slice_matches_scalar(instants in prop::collection::vec((prop::num::i64::ANY, 0..NANOSECONDS_IN_SECOND), 0..20), duration_seconds in prop::num::i64::ANY, duration_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let original: Vec<Instant> = instants.into_iter().map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos)).collect();
+        let offset = Duration::of_seconds_and_adjustment(duration_seconds, duration_nanos);
+        let mut instants = original.clone();
+
+        let scalar: Option<Vec<Instant>> = original.iter().map(|instant| instant.checked_add(offset)).collect();
+
+        match (scalar, Instant::add_duration_slice(&mut instants, offset)) {
+            (Some(expected), Ok(())) => prop_assert_eq!(expected, instants),
+            (None, Err(error)) => {
+                prop_assert_eq!(&original, &instants);
+                prop_assert!(original[error.index()].checked_add(offset).is_none());
+            },
+            (scalar, result) => prop_assert!(false, "scalar {:?} but slice {:?}", scalar, result),
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_duration_slice_overflow_is_atomic((offset, index) in prop_oneof![Just((Duration::of_seconds(1), 999)), Just((Duration::of_seconds(-1), 0))]) {
+        let mut original = spread_instants(1_000);
+        original.sort();
+        original[0] = Instant::MIN;
+        original[999] = Instant::MAX;
+        let mut instants = original.clone();
+
+        let result = Instant::add_duration_slice(&mut instants, offset);
+
+        prop_assert_eq!(index, result.unwrap_err().index());
+        prop_assert_eq!(original, instants);
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn timeout_from((deadline, now, expected) in prop_oneof![
+        Just((Instant::of_epoch_second_and_adjustment(1_700_000_030, 250_000_000), Instant::of_epoch_second(1_700_000_000), Some(std::time::Duration::new(30, 250_000_000)))),
+        Just((Instant::of_epoch_second(1), Instant::of_epoch_second_and_adjustment(0, 999_999_999), Some(std::time::Duration::new(0, 1)))),
+        Just((Instant::of_epoch_second(1_700_000_000), Instant::of_epoch_second(1_700_000_000), None)),
+        Just((Instant::of_epoch_second(1_699_999_999), Instant::of_epoch_second(1_700_000_000), None)),
+        Just((Instant::MIN, Instant::MAX, None)),
+        Just((Instant::MAX, Instant::MIN, Some(std::time::Duration::MAX))),
+    ]) {
+        prop_assert_eq!(expected, deadline.timeout_from(now));
+    }
+}
+
+proptest! {
+    #[test]
+    fn checked_sub_instant((instant, other, expected) in prop_oneof![
+        Just((Instant::of_epoch_second_and_adjustment(90, 500_000_000), Instant::of_epoch_second(0), Some(Duration::of_millis(90_500)))),
+        Just((Instant::of_epoch_second(0), Instant::of_epoch_second_and_adjustment(90, 500_000_000), Some(Duration::of_millis(-90_500)))),
+        Just((Instant::EPOCH, Instant::EPOCH, Some(Duration::ZERO))),
+        Just((Instant::MAX, Instant::MIN, None)),
+        Just((Instant::MIN, Instant::MAX, None)),
+    ]) {
+        prop_assert_eq!(expected, instant.checked_sub_instant(other));
+    }
+}
+
+proptest! {
+    #[test]
+    fn poll_timeout((deadline, now, max, expected) in prop_oneof![
+        // Imminent, within the longest poll interval.
+        Just((Instant::of_epoch_second_and_adjustment(1_700_000_000, 250_000_000), Instant::of_epoch_second(1_700_000_000), Duration::of_seconds(1), core::time::Duration::from_millis(250))),
+        Just((Instant::of_epoch_second(1), Instant::of_epoch_second_and_adjustment(0, 999_999_999), Duration::of_seconds(1), core::time::Duration::from_nanos(1))),
+        // Later than the longest poll interval.
+        Just((Instant::of_epoch_second(1_700_000_030), Instant::of_epoch_second(1_700_000_000), Duration::of_seconds(1), core::time::Duration::from_secs(1))),
+        // Expired, or due now.
+        Just((Instant::of_epoch_second(1_699_999_999), Instant::of_epoch_second(1_700_000_000), Duration::of_seconds(1), core::time::Duration::ZERO)),
+        Just((Instant::of_epoch_second(1_700_000_000), Instant::of_epoch_second(1_700_000_000), Duration::of_seconds(1), core::time::Duration::ZERO)),
+        Just((Instant::MIN, Instant::MAX, Duration::of_seconds(1), core::time::Duration::ZERO)),
+        // Far enough in the future that the difference overflows.
+        Just((Instant::MAX, Instant::MIN, Duration::of_seconds(1), core::time::Duration::from_secs(1))),
+        Just((Instant::MAX, Instant::MIN, Duration::MAX, core::time::Duration::new(i64::MAX as u64, 999_999_999))),
+        // No waiting at all.
+        Just((Instant::of_epoch_second(1_700_000_030), Instant::of_epoch_second(1_700_000_000), Duration::ZERO, core::time::Duration::ZERO)),
+        Just((Instant::MAX, Instant::MIN, Duration::ZERO, core::time::Duration::ZERO)),
+        Just((Instant::of_epoch_second(1_700_000_030), Instant::of_epoch_second(1_700_000_000), Duration::of_seconds(-1), core::time::Duration::ZERO)),
+    ]) {
+        prop_assert_eq!(expected, deadline.poll_timeout(&FixedClock::new(now), max));
+    }
+}
+
+proptest! {
+    #[test]
+    fn estimate_skew_symmetric_path(sent in -1_000_000_000_000i64..1_000_000_000_000, one_way_nanos in 0..10_000_000_000i64, skew_nanos in -10_000_000_000_000i64..10_000_000_000_000) {
+        let (one_way, skew) = (Duration::of_nanos(one_way_nanos), Duration::of_nanos(skew_nanos));
+        // The remote reads its clock as the request arrives, and the response takes as long to return.
+        let sent = Instant::of_epoch_second(sent);
+        let remote = sent + one_way + skew;
+        let received = sent + one_way + one_way;
+
+        prop_assert_eq!(skew, Instant::estimate_skew(received, remote, received - sent));
+    }
+}
+
+proptest! {
+    #[test]
+    fn estimate_skew((local_now, remote, round_trip, expected) in prop_oneof![
+        Just((Instant::of_epoch_second(100), Instant::of_epoch_second(100), Duration::ZERO, Duration::ZERO)),
+        Just((Instant::of_epoch_second(100), Instant::of_epoch_second(100), Duration::of_millis(200), Duration::of_millis(100))),
+        Just((Instant::of_epoch_second(100), Instant::of_epoch_second(103), Duration::of_millis(200), Duration::of_millis(3_100))),
+        Just((Instant::of_epoch_second(100), Instant::of_epoch_second(97), Duration::of_millis(200), Duration::of_millis(-2_900))),
+        Just((Instant::of_epoch_second(0), Instant::of_epoch_second(0), Duration::of_nanos(3), Duration::of_nanos(1))),
+        // A request taking 150ms and a response taking 50ms, against a clock 1s ahead, is estimated 50ms off.
+        Just((
+            Instant::of_epoch_milli(200),
+            Instant::of_epoch_milli(1_150),
+            Duration::of_millis(200),
+            Duration::of_millis(1_050),
+        )),
+        // The midpoint of the round trip is before the minimum instant, but the estimate is in range.
+        Just((Instant::MIN, Instant::MIN, Duration::MAX, Duration::of_seconds_and_adjustment(4_611_686_018_427_387_903, 999_999_999))),
+    ]) {
+        prop_assert_eq!(expected, Instant::estimate_skew(local_now, remote, round_trip));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "round trip must not be negative")]
+    fn estimate_skew_negative_round_trip(round_trip in Just(Duration::of_nanos(-1))) {
+        Instant::estimate_skew(Instant::of_epoch_second(0), Instant::of_epoch_second(0), round_trip);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "skew would overflow duration")]
+    fn estimate_skew_overflow(round_trip in Just(Duration::ZERO)) {
+        Instant::estimate_skew(Instant::MIN, Instant::MAX, round_trip);
+    }
+}
+
+proptest! {
+    #[test]
+    fn next_tick_after((phase, now, period, expected) in prop_oneof![
+        Just((Instant::of_epoch_second(10), Instant::of_epoch_second(12), Duration::of_seconds(5), Instant::of_epoch_second(15))),
+        // A `now` exactly on a tick gets the following tick.
+        Just((Instant::of_epoch_second(10), Instant::of_epoch_second(15), Duration::of_seconds(5), Instant::of_epoch_second(20))),
+        Just((Instant::of_epoch_second(10), Instant::of_epoch_second(10), Duration::of_seconds(5), Instant::of_epoch_second(15))),
+        Just((Instant::of_epoch_second(10), Instant::of_epoch_second_and_adjustment(14, 999_999_999), Duration::of_seconds(5), Instant::of_epoch_second(15))),
+        // A `now` before the phase gets the phase itself.
+        Just((Instant::of_epoch_second(10), Instant::of_epoch_second_and_adjustment(9, 999_999_999), Duration::of_seconds(5), Instant::of_epoch_second(10))),
+        Just((Instant::of_epoch_second(10), Instant::MIN, Duration::of_seconds(5), Instant::of_epoch_second(10))),
+        Just((Instant::of_epoch_second(-7), Instant::of_epoch_second(-1), Duration::of_seconds(3), Instant::of_epoch_second(2))),
+        Just((Instant::of_epoch_second(0), Instant::of_epoch_milli(2_500), Duration::of_nanos(1), Instant::of_epoch_second_and_adjustment(2, 500_000_001))),
+        Just((Instant::MIN, Instant::MAX - Duration::of_seconds(1), Duration::MAX, Instant::MAX - Duration::of_nanos(1))),
+    ]) {
+        prop_assert_eq!(expected, phase.next_tick_after(now, period));
+        prop_assert_eq!(Some(expected), phase.checked_next_tick_after(now, period));
+    }
+}
+
+proptest! {
+    #[test]
+    fn next_tick_after_is_next_on_grid(
+        phase in -(1i64 << 40)..(1i64 << 40),
+        now in -(1i64 << 40)..(1i64 << 40),
+        period in 1..(1i64 << 20),
+    ) {
+        let (phase, now, period) = (Instant::of_epoch_milli(phase), Instant::of_epoch_milli(now), Duration::of_millis(period));
+
+        let tick = phase.next_tick_after(now, period);
+
+        prop_assert!(tick > now);
+        prop_assert!(tick >= phase);
+        prop_assert_eq!(Some(true), (tick - phase).is_multiple_of(period));
+        prop_assert!(tick == phase || tick - period <= now);
+    }
+}
+
+proptest! {
+    #[test]
+    fn next_tick_after_overflow((phase, now, period) in prop_oneof![
+        Just((Instant::of_epoch_second(0), Instant::MAX, Duration::of_nanos(1))),
+        Just((Instant::MIN, Instant::MAX, Duration::MAX)),
+    ]) {
+        prop_assert_eq!(None, phase.checked_next_tick_after(now, period));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "period must be positive")]
+    fn next_tick_after_non_positive_period(period in prop_oneof![Just(Duration::ZERO), Just(Duration::of_nanos(-1))]) {
+        Instant::of_epoch_second(0).next_tick_after(Instant::of_epoch_second(0), period);
+    }
+}
@@ -0,0 +1,550 @@
+use proptest::prelude::*;
+
+use crate::{Calendar, CivilField, DayOfWeek, Duration, Instant, Unit};
+
+fn utc(text: &str) -> Instant {
+    Instant::parse_rfc3339(text).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn iter_months_from_mid_january(start in Just("2023-01-15T12:34:56Z")) {
+        let months: Vec<Instant> = Instant::iter_months(utc(start), 0).take(14).collect();
+
+        prop_assert_eq!(
+            vec![
+                utc("2023-02-01T00:00:00Z"),
+                utc("2023-03-01T00:00:00Z"),
+                utc("2023-04-01T00:00:00Z"),
+                utc("2023-05-01T00:00:00Z"),
+                utc("2023-06-01T00:00:00Z"),
+                utc("2023-07-01T00:00:00Z"),
+                utc("2023-08-01T00:00:00Z"),
+                utc("2023-09-01T00:00:00Z"),
+                utc("2023-10-01T00:00:00Z"),
+                utc("2023-11-01T00:00:00Z"),
+                utc("2023-12-01T00:00:00Z"),
+                utc("2024-01-01T00:00:00Z"),
+                utc("2024-02-01T00:00:00Z"),
+                utc("2024-03-01T00:00:00Z"),
+            ],
+            months
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn iter_months_from_end_of_month((start, expected) in prop_oneof![
+        Just(("2023-01-31T23:59:59Z", ["2023-02-01T00:00:00Z", "2023-03-01T00:00:00Z", "2023-04-01T00:00:00Z"])),
+        Just(("2024-01-31T00:00:00Z", ["2024-02-01T00:00:00Z", "2024-03-01T00:00:00Z", "2024-04-01T00:00:00Z"])),
+        Just(("2024-02-29T00:00:00Z", ["2024-03-01T00:00:00Z", "2024-04-01T00:00:00Z", "2024-05-01T00:00:00Z"])),
+        Just(("2024-02-01T00:00:00Z", ["2024-03-01T00:00:00Z", "2024-04-01T00:00:00Z", "2024-05-01T00:00:00Z"])),
+    ]) {
+        let months: Vec<Instant> = Instant::iter_months(utc(start), 0).take(3).collect();
+
+        prop_assert_eq!(expected.iter().map(|text| utc(text)).collect::<Vec<_>>(), months);
+    }
+}
+
+proptest! {
+    #[test]
+    fn iter_months_at_offset((start, offset_seconds, expected) in prop_oneof![
+        // Still January at +01:00, which starts February an hour before UTC does.
+        Just(("2023-01-31T12:00:00Z", 3_600, ["2023-01-31T23:00:00Z", "2023-02-28T23:00:00Z"])),
+        // Already February at +01:00.
+        Just(("2023-01-31T23:30:00Z", 3_600, ["2023-02-28T23:00:00Z", "2023-03-31T23:00:00Z"])),
+        // Still December at -05:00.
+        Just(("2024-01-01T04:00:00Z", -18_000, ["2024-01-01T05:00:00Z", "2024-02-01T05:00:00Z"])),
+    ]) {
+        let months: Vec<Instant> = Instant::iter_months(utc(start), offset_seconds).take(2).collect();
+
+        prop_assert_eq!(expected.iter().map(|text| utc(text)).collect::<Vec<_>>(), months);
+    }
+}
+
+proptest! {
+    #[test]
+    fn iter_months_quarters(start in Just("2023-11-15T00:00:00Z")) {
+        let quarters: Vec<Instant> = Instant::iter_months(utc(start), 0).step_months(3).take(4).collect();
+
+        prop_assert_eq!(
+            vec![
+                utc("2024-02-01T00:00:00Z"),
+                utc("2024-05-01T00:00:00Z"),
+                utc("2024-08-01T00:00:00Z"),
+                utc("2024-11-01T00:00:00Z"),
+            ],
+            quarters
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn iter_months_step_years(start in Just("1999-03-01T00:00:00Z")) {
+        let years: Vec<Instant> = Instant::iter_months(utc(start), 0).step_months(12 * 100).take(2).collect();
+
+        prop_assert_eq!(vec![utc("2099-03-01T00:00:00Z"), utc("2199-03-01T00:00:00Z")], years);
+    }
+}
+
+proptest! {
+    #[test]
+    fn iter_months_ends_at_max(start in Just(Instant::MAX)) {
+        prop_assert_eq!(None, Instant::iter_months(start, 0).next());
+        prop_assert_eq!(None, Instant::iter_months(start, 1).next());
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "step must be at least one month")]
+    fn step_months_zero(start in Just(Instant::EPOCH)) {
+        Instant::iter_months(start, 0).step_months(0);
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_epoch_day_and_nanos_vectors((text, offset_seconds, epoch_day, nano_of_day) in prop_oneof![
+        Just(("1970-01-01T00:00:00Z", 0, 0, 0)),
+        Just(("1969-12-31T12:00:00Z", 0, -1, 43_200_000_000_000)),
+        Just(("1969-12-31T23:59:59.999999999Z", 0, -1, 86_399_999_999_999)),
+        Just(("2000-02-29T01:02:03.000000004Z", 0, 11_016, 3_723_000_000_004)),
+        // Already the next day at +01:00, and still the previous day at -01:00.
+        Just(("1970-01-01T23:30:00Z", 3_600, 1, 1_800_000_000_000)),
+        Just(("1970-01-01T00:30:00Z", -3_600, -1, 84_600_000_000_000)),
+    ]) {
+        let instant = utc(text);
+
+        prop_assert_eq!((epoch_day, nano_of_day), instant.to_epoch_day_and_nanos(offset_seconds));
+        prop_assert_eq!(instant, Instant::from_epoch_day_and_nanos(epoch_day, nano_of_day, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn epoch_day_and_nanos_round_trip(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64, offset_seconds in -86_399..86_400) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let (epoch_day, nano_of_day) = instant.to_epoch_day_and_nanos(offset_seconds);
+
+        prop_assert!(nano_of_day < 86_400_000_000_000);
+        prop_assert_eq!(instant, Instant::from_epoch_day_and_nanos(epoch_day, nano_of_day, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "nano of day must be less than one day")]
+    fn from_epoch_day_and_nanos_past_end_of_day(nano_of_day in 86_400_000_000_000u64..) {
+        Instant::from_epoch_day_and_nanos(0, nano_of_day, 0);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "epoch day would overflow instant")]
+    fn from_epoch_day_and_nanos_overflow(epoch_day in prop_oneof![Just(i64::MAX / 86_400 + 1), Just(i64::MIN / 86_400 - 1)]) {
+        Instant::from_epoch_day_and_nanos(epoch_day, 0, 0);
+    }
+}
+
+fn at(epoch_day: i64, hour: i64) -> Instant {
+    Instant::of_epoch_second(epoch_day * 86_400 + hour * 3_600)
+}
+
+proptest! {
+    #[test]
+    fn day_of_week((instant, offset_seconds, expected) in prop_oneof![
+        Just((Instant::EPOCH, 0, DayOfWeek::Thursday)),
+        Just((Instant::EPOCH, -1, DayOfWeek::Wednesday)),
+        Just((at(19_783, 23), 3_600, DayOfWeek::Saturday)),
+        Just((Instant::MIN, 0, DayOfWeek::of_epoch_day(-106_751_991_167_301))),
+    ]) {
+        prop_assert_eq!(expected, instant.day_of_week(offset_seconds));
+    }
+}
+
+// 2024-03-01 (epoch day 19,783) is a Friday.
+proptest! {
+    #[test]
+    fn business_days_until((start, end, offset_seconds, expected) in prop_oneof![
+        // Friday to the following Tuesday: Friday and Monday.
+        Just((at(19_783, 12), at(19_787, 12), 0, 2)),
+        Just((at(19_787, 12), at(19_783, 12), 0, -2)),
+        // Saturday to Monday.
+        Just((at(19_784, 0), at(19_786, 0), 0, 0)),
+        // The same date.
+        Just((at(19_783, 1), at(19_783, 23), 0, 0)),
+        // Four whole weeks, from Friday to Friday.
+        Just((at(19_783, 0), at(19_811, 0), 0, 20)),
+        // Friday late in the evening is Saturday at +01:00.
+        Just((at(19_783, 23), at(19_787, 12), 3_600, 1)),
+        // Crossing the epoch.
+        Just((at(-10, 0), at(10, 0), 0, 15)),
+    ]) {
+        prop_assert_eq!(expected, start.business_days_until(end, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn business_days_until_is_antisymmetric(start in -1_000_000i64..1_000_000, end in -1_000_000i64..1_000_000) {
+        let (start, end) = (at(start, 0), at(end, 0));
+
+        prop_assert_eq!(start.business_days_until(end, 0), -end.business_days_until(start, 0));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn business_days_until_excluding((holidays, expected) in prop_oneof![
+        Just((vec![], 2)),
+        // Monday 2024-03-04.
+        Just((vec![(2024, 3, 4)], 1)),
+        // Saturday, already excluded.
+        Just((vec![(2024, 3, 2)], 2)),
+        // Tuesday, the excluded end of the interval.
+        Just((vec![(2024, 3, 5)], 2)),
+        // Both weekdays, and an invalid date.
+        Just((vec![(2024, 3, 1), (2024, 3, 4), (2024, 2, 30)], 0)),
+    ]) {
+        let holidays: std::collections::HashSet<(i32, u8, u8)> = holidays.into_iter().collect();
+
+        prop_assert_eq!(expected, at(19_783, 12).business_days_until_excluding(at(19_787, 12), 0, &holidays));
+        prop_assert_eq!(-expected, at(19_787, 12).business_days_until_excluding(at(19_783, 12), 0, &holidays));
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_eq((first, second, precision, offset_seconds, expected) in prop_oneof![
+        // 100ms apart, within one second.
+        Just(("2024-06-15T12:34:56.100Z", "2024-06-15T12:34:56.200Z", Unit::Seconds, 0, true)),
+        Just(("2024-06-15T12:34:56.100Z", "2024-06-15T12:34:56.200Z", Unit::Millis, 0, false)),
+        Just(("2024-06-15T12:34:56.100Z", "2024-06-15T12:34:56.200Z", Unit::Nanos, 0, false)),
+        // 100ms apart, across a second.
+        Just(("2024-06-15T12:34:56.950Z", "2024-06-15T12:34:57.050Z", Unit::Seconds, 0, false)),
+        Just(("2024-06-15T12:34:56.950Z", "2024-06-15T12:34:57.050Z", Unit::Minutes, 0, true)),
+        Just(("2024-06-15T12:34:56.123456Z", "2024-06-15T12:34:56.123456999Z", Unit::Micros, 0, true)),
+        // Before the epoch, truncation is still towards the past.
+        Just(("1969-12-31T23:59:59.100Z", "1969-12-31T23:59:59.900Z", Unit::Seconds, 0, true)),
+        Just(("1969-12-31T23:59:59.900Z", "1970-01-01T00:00:00.100Z", Unit::Seconds, 0, false)),
+        // The offset moves the boundaries of days, and of hours for a half-hour offset.
+        Just(("2024-06-15T20:00:00Z", "2024-06-16T01:00:00Z", Unit::Days, 0, false)),
+        Just(("2024-06-15T20:00:00Z", "2024-06-16T01:00:00Z", Unit::Days, -6 * 3600, true)),
+        Just(("2024-06-15T12:10:00Z", "2024-06-15T12:40:00Z", Unit::Hours, 0, true)),
+        Just(("2024-06-15T12:10:00Z", "2024-06-15T12:40:00Z", Unit::Hours, 19_800, false)),
+    ]) {
+        let (first, second) = (utc(first), utc(second));
+
+        prop_assert_eq!(expected, first.display_eq(second, precision, offset_seconds));
+        prop_assert_eq!(expected, second.display_eq(first, precision, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_eq_matches_rendering(seconds in -(1i64 << 35)..(1i64 << 35), nanos in 0..1_000_000_000i64, delta in 0..2_000_000_000i64) {
+        let first = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let second = first + crate::Duration::of_nanos(delta);
+        let truncated = |instant: Instant| {
+            let text = format!("{:.9}", instant);
+            text[..text.len() - 7].to_owned()
+        };
+
+        prop_assert_eq!(truncated(first) == truncated(second), first.display_eq(second, Unit::Millis, 0));
+    }
+}
+
+// The expected values are from java.time's `ALIGNED_WEEK_OF_MONTH`, `ALIGNED_DAY_OF_WEEK_IN_MONTH`,
+// and `WeekFields.of(first_day_of_week, minimal_days)`, for dates starting months and years on awkward days.
+proptest! {
+    #[test]
+    fn aligned_week_of_month((date, aligned_week, aligned_day) in prop_oneof![
+        Just(("2023-01-01", 1, 1)),
+        Just(("2024-06-02", 1, 2)),
+        Just(("2024-06-09", 2, 2)),
+        Just(("2024-06-30", 5, 2)),
+        Just(("2023-05-31", 5, 3)),
+        Just(("2021-01-03", 1, 3)),
+        Just(("2019-12-30", 5, 2)),
+    ]) {
+        let instant = utc(&format!("{}T12:00:00Z", date));
+
+        prop_assert_eq!(aligned_week, instant.aligned_week_of_month(0));
+        prop_assert_eq!(aligned_day, instant.aligned_day_of_week_in_month(0));
+    }
+}
+
+proptest! {
+    #[test]
+    fn week_of_month_and_year((date, us, iso, monday_1, saturday_7) in prop_oneof![
+        // Sunday.
+        Just(("2023-01-01", (1, 1), (0, 0), (1, 1), (0, 0))),
+        // Saturday.
+        Just(("2022-01-01", (1, 1), (0, 0), (1, 1), (1, 1))),
+        // Friday.
+        Just(("2021-01-01", (1, 1), (0, 0), (1, 1), (0, 0))),
+        Just(("2021-01-03", (2, 2), (0, 0), (1, 1), (1, 1))),
+        Just(("2024-06-01", (1, 22), (0, 22), (1, 22), (1, 22))),
+        Just(("2024-06-02", (2, 23), (0, 22), (1, 22), (1, 22))),
+        Just(("2024-06-09", (3, 24), (1, 23), (2, 23), (2, 23))),
+        Just(("2024-06-30", (6, 27), (4, 26), (5, 26), (5, 26))),
+        Just(("2024-09-01", (1, 36), (0, 35), (1, 35), (0, 35))),
+        Just(("2023-05-31", (5, 22), (5, 22), (5, 23), (4, 21))),
+        Just(("2020-12-31", (5, 53), (5, 53), (5, 53), (4, 52))),
+        Just(("2026-02-01", (1, 6), (0, 5), (1, 5), (0, 5))),
+        Just(("2019-12-30", (5, 53), (5, 53), (6, 53), (4, 52))),
+    ]) {
+        let instant = utc(&format!("{}T12:00:00Z", date));
+        let weeks = |first_day_of_week, minimal_days| (
+            instant.week_of_month(0, first_day_of_week, minimal_days),
+            instant.week_of_year(0, first_day_of_week, minimal_days),
+        );
+
+        prop_assert_eq!(us, weeks(DayOfWeek::Sunday, 1));
+        prop_assert_eq!(iso, weeks(DayOfWeek::Monday, 4));
+        prop_assert_eq!(monday_1, weeks(DayOfWeek::Monday, 1));
+        prop_assert_eq!(saturday_7, weeks(DayOfWeek::Saturday, 7));
+    }
+}
+
+proptest! {
+    #[test]
+    fn week_of_month_at_offset((instant, offset_seconds, expected) in prop_oneof![
+        // Saturday, 2024-06-01 in UTC, but Sunday, 2024-06-02 at +01:00.
+        Just(("2024-06-01T23:00:00Z", 0, 1)),
+        Just(("2024-06-01T23:00:00Z", 3_600, 2)),
+        // Sunday, 2024-06-02 in UTC, but Saturday, 2024-06-01 at -01:00.
+        Just(("2024-06-02T00:30:00Z", -3_600, 1)),
+    ]) {
+        prop_assert_eq!(expected, utc(instant).week_of_month(offset_seconds, DayOfWeek::Sunday, 1));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "minimal days in first week must be from 1 to 7")]
+    fn week_of_month_invalid_minimal_days(minimal_days in prop_oneof![Just(0u8), Just(8u8)]) {
+        utc("2024-06-01T00:00:00Z").week_of_month(0, DayOfWeek::Monday, minimal_days);
+    }
+}
+
+proptest! {
+    #[test]
+    fn civil_date_across_gregorian_reform((text, gregorian, julian) in prop_oneof![
+        Just(("1582-10-15T00:00:00Z", (1582, 10, 15), (1582, 10, 5))),
+        Just(("1582-10-14T23:59:59Z", (1582, 10, 14), (1582, 10, 4))),
+        Just(("1752-09-14T12:00:00Z", (1752, 9, 14), (1752, 9, 3))),
+        Just(("2024-06-15T12:10:21Z", (2024, 6, 15), (2024, 6, 2))),
+        Just(("0004-02-28T00:00:00Z", (4, 2, 28), (4, 3, 1))),
+    ]) {
+        let instant = utc(text);
+
+        prop_assert_eq!(gregorian, instant.to_civil_date(0, Calendar::ProlepticGregorian));
+        prop_assert_eq!(gregorian, instant.to_civil_date(0, Calendar::default()));
+        prop_assert_eq!(julian, instant.to_civil_date(0, Calendar::Julian));
+        prop_assert_eq!(instant.truncated_key(Duration::of_days(1)), Instant::try_of_civil_date(julian.0 as i32, julian.1, julian.2, 0, Calendar::Julian).unwrap());
+    }
+}
+
+proptest! {
+    #[test]
+    fn civil_date_mixed_british((text, date) in prop_oneof![
+        Just(("1582-10-15T00:00:00Z", (1582, 10, 5))),
+        Just(("1752-09-13T23:59:59Z", (1752, 9, 2))),
+        Just(("1752-09-14T00:00:00Z", (1752, 9, 14))),
+        Just(("2024-06-15T00:00:00Z", (2024, 6, 15))),
+    ]) {
+        let instant = utc(text);
+
+        prop_assert_eq!(date, instant.to_civil_date(0, Calendar::MixedBritish));
+        prop_assert_eq!(instant.truncated_key(Duration::of_days(1)), Instant::try_of_civil_date(date.0 as i32, date.1, date.2, 0, Calendar::MixedBritish).unwrap());
+    }
+}
+
+proptest! {
+    #[test]
+    fn civil_date_at_offset(offset_seconds in Just(-3_600)) {
+        let instant = utc("1582-10-15T00:30:00Z");
+
+        prop_assert_eq!((1582, 10, 4), instant.to_civil_date(offset_seconds, Calendar::Julian));
+        prop_assert_eq!(utc("1582-10-14T01:00:00Z"), Instant::try_of_civil_date(1582, 10, 4, offset_seconds, Calendar::Julian).unwrap());
+    }
+}
+
+proptest! {
+    #[test]
+    fn civil_date_out_of_range((year, month, day, calendar, field, value) in prop_oneof![
+        Just((2024, 13, 1, Calendar::Julian, CivilField::Month, 13)),
+        Just((2024, 0, 1, Calendar::ProlepticGregorian, CivilField::Month, 0)),
+        Just((1900, 2, 29, Calendar::ProlepticGregorian, CivilField::Day, 29)),
+        Just((1900, 2, 30, Calendar::Julian, CivilField::Day, 30)),
+        Just((2024, 4, 31, Calendar::Julian, CivilField::Day, 31)),
+        Just((2024, 4, 0, Calendar::MixedBritish, CivilField::Day, 0)),
+        // The dates skipped by the British cutover never happened.
+        Just((1752, 9, 3, Calendar::MixedBritish, CivilField::Day, 3)),
+        Just((1752, 9, 13, Calendar::MixedBritish, CivilField::Day, 13)),
+    ]) {
+        let error = Instant::try_of_civil_date(year, month, day, 0, calendar).unwrap_err();
+
+        prop_assert_eq!((field, value), (error.field(), error.value()));
+    }
+}
+
+#[test]
+fn julian_leap_day_of_gregorian_common_year() {
+    let instant = Instant::try_of_civil_date(1900, 2, 29, 0, Calendar::Julian).unwrap();
+
+    assert_eq!(utc("1900-03-13T00:00:00Z"), instant);
+    assert_eq!((1900, 2, 29), instant.to_civil_date(0, Calendar::Julian));
+}
+
+proptest! {
+    #[test]
+    fn start_of_utc_boundaries((text, day, monday_week, sunday_week, month, year) in prop_oneof![
+        Just(("2024-02-29T13:45:00Z", "2024-02-29T00:00:00Z", "2024-02-26T00:00:00Z", "2024-02-25T00:00:00Z", "2024-02-01T00:00:00Z", "2024-01-01T00:00:00Z")),
+        // Exactly on every boundary, so each is unchanged.
+        Just(("2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z", "2023-12-31T00:00:00Z", "2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z")),
+        Just(("2024-01-01T00:00:00.000000001Z", "2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z", "2023-12-31T00:00:00Z", "2024-01-01T00:00:00Z", "2024-01-01T00:00:00Z")),
+        Just(("2023-12-31T23:59:59.999999999Z", "2023-12-31T00:00:00Z", "2023-12-25T00:00:00Z", "2023-12-31T00:00:00Z", "2023-12-01T00:00:00Z", "2023-01-01T00:00:00Z")),
+        Just(("2024-03-01T00:00:00Z", "2024-03-01T00:00:00Z", "2024-02-26T00:00:00Z", "2024-02-25T00:00:00Z", "2024-03-01T00:00:00Z", "2024-01-01T00:00:00Z")),
+        // Before the epoch, flooring toward the past.
+        Just(("1969-12-31T23:59:59Z", "1969-12-31T00:00:00Z", "1969-12-29T00:00:00Z", "1969-12-28T00:00:00Z", "1969-12-01T00:00:00Z", "1969-01-01T00:00:00Z")),
+        Just(("1900-02-28T12:00:00Z", "1900-02-28T00:00:00Z", "1900-02-26T00:00:00Z", "1900-02-25T00:00:00Z", "1900-02-01T00:00:00Z", "1900-01-01T00:00:00Z")),
+    ]) {
+        let instant = utc(text);
+
+        prop_assert_eq!(utc(day), instant.start_of_utc_day());
+        prop_assert_eq!(utc(monday_week), instant.start_of_utc_week(DayOfWeek::Monday));
+        prop_assert_eq!(utc(sunday_week), instant.start_of_utc_week(DayOfWeek::Sunday));
+        prop_assert_eq!(utc(month), instant.start_of_utc_month());
+        prop_assert_eq!(utc(year), instant.start_of_utc_year());
+    }
+}
+
+proptest! {
+    #[test]
+    fn start_of_utc_is_floor(epoch_second in -100_000_000_000i64..100_000_000_000, nanos in 0..1_000_000_000i64) {
+        let instant = Instant::of_epoch_second_and_adjustment(epoch_second, nanos);
+
+        let starts = [
+            instant.start_of_utc_year(),
+            instant.start_of_utc_month(),
+            instant.start_of_utc_day(),
+        ];
+        prop_assert!(starts.windows(2).all(|pair| pair[0] <= pair[1]));
+        prop_assert!(starts[2] <= instant && instant - starts[2] < Duration::of_days(1));
+        let week = instant.start_of_utc_week(DayOfWeek::Wednesday);
+        prop_assert_eq!(DayOfWeek::Wednesday, week.day_of_week(0));
+        prop_assert!(week <= instant && instant - week < Duration::of_weeks(1));
+        for start in starts.iter().chain([week].iter()) {
+            prop_assert_eq!(*start, start.start_of_utc_day());
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn civil_days((start, end, offset_seconds, until, touched) in prop_oneof![
+        // Entirely within one day.
+        Just((utc("2024-03-01T09:00:00Z"), utc("2024-03-01T17:00:00Z"), 0, 0, 1)),
+        // Three nights, touching four dates.
+        Just((utc("2024-03-01T15:00:00Z"), utc("2024-03-04T11:00:00Z"), 0, 3, 4)),
+        // Ending exactly at midnight does not touch the following date.
+        Just((utc("2024-03-01T00:00:00Z"), utc("2024-03-02T00:00:00Z"), 0, 1, 1)),
+        Just((utc("2024-03-01T23:00:00Z"), utc("2024-03-02T00:00:00.000000001Z"), 0, 1, 2)),
+        // Empty.
+        Just((utc("2024-03-01T12:00:00Z"), utc("2024-03-01T12:00:00Z"), 0, 0, 0)),
+        // Across midnight at the offset, but not in UTC.
+        Just((utc("2024-03-01T22:00:00Z"), utc("2024-03-01T23:30:00Z"), 3_600, 1, 2)),
+        // Across the epoch, including February 29th.
+        Just((utc("1969-12-31T12:00:00Z"), utc("1970-01-01T12:00:00Z"), 0, 1, 2)),
+        Just((utc("2024-02-28T12:00:00Z"), utc("2024-03-01T00:00:00Z"), 0, 2, 2)),
+    ]) {
+        prop_assert_eq!(until, start.civil_days_until(end, offset_seconds));
+        prop_assert_eq!(-until, end.civil_days_until(start, offset_seconds));
+        prop_assert_eq!(touched, start.civil_days_touched(end, offset_seconds));
+    }
+}
+
+#[test]
+#[should_panic(expected = "end must not be before start")]
+fn civil_days_touched_reversed() {
+    utc("2024-03-02T00:00:00Z").civil_days_touched(utc("2024-03-01T00:00:00Z"), 0);
+}
+
+proptest! {
+    #[test]
+    fn decimal_year((text, offset_seconds, year) in prop_oneof![
+        Just(("2020-01-01T00:00:00Z", 0, 2020.0)),
+        // Half of the 366 days of 2020, but of the 365 days of 2023.
+        Just(("2020-07-02T00:00:00Z", 0, 2020.5)),
+        Just(("2023-07-02T12:00:00Z", 0, 2023.5)),
+        Just(("2023-12-31T23:00:00Z", 3_600, 2024.0)),
+        Just(("1969-01-01T00:00:00Z", 0, 1969.0)),
+        Just(("1969-07-02T12:00:00Z", 0, 1969.5)),
+        Just(("0000-07-02T00:00:00Z", 0, 0.5)),
+    ]) {
+        let instant = utc(text);
+
+        prop_assert_eq!(year, instant.to_decimal_year(offset_seconds));
+        prop_assert_eq!(Some(instant), Instant::from_decimal_year(year, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn decimal_year_round_trips_to_microseconds(epoch_second in -10_000_000_000i64..10_000_000_000, nanos in 0..1_000_000_000i64) {
+        let instant = Instant::of_epoch_second_and_adjustment(epoch_second, nanos);
+
+        let round_trip = Instant::from_decimal_year(instant.to_decimal_year(0), 0).unwrap();
+
+        prop_assert!(instant.abs_diff(round_trip) < Duration::of_micros(100));
+    }
+}
+
+proptest! {
+    #[test]
+    fn decimal_year_out_of_range(year in prop_oneof![Just(f64::NAN), Just(f64::INFINITY), Just(f64::NEG_INFINITY), Just(1e13), Just(-1e300)]) {
+        prop_assert_eq!(None, Instant::from_decimal_year(year, 0));
+    }
+}
+
+// 2024-03-04 (epoch day 19,786) is a Monday.
+proptest! {
+    #[test]
+    fn count_weekday_in_range((start, end, offset_seconds, expected) in prop_oneof![
+        // Wednesday to Wednesday four weeks later, with partial weeks at both ends.
+        Just((at(19_781, 12), at(19_809, 12), 0, 4)),
+        // Monday to Monday: the start is counted, but the end is not, even later in its day.
+        Just((at(19_786, 0), at(19_807, 0), 0, 3)),
+        Just((at(19_786, 0), at(19_807, 23), 0, 3)),
+        // Tuesday to Monday.
+        Just((at(19_787, 0), at(19_807, 0), 0, 2)),
+        // Tuesday to the following Monday.
+        Just((at(19_787, 0), at(19_793, 0), 0, 0)),
+        // The same date, and a reversed range.
+        Just((at(19_786, 1), at(19_786, 23), 0, 0)),
+        Just((at(19_809, 0), at(19_781, 0), 0, 0)),
+        // Sunday late in the evening is Monday at +01:00.
+        Just((at(19_785, 23), at(19_786, 23), 0, 0)),
+        Just((at(19_785, 23), at(19_786, 23), 3_600, 1)),
+    ]) {
+        prop_assert_eq!(expected, Instant::count_weekday_in_range(start, end, DayOfWeek::Monday, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn count_weekday_in_range_matches_scan(start in -1_000i64..1_000, days in 0i64..60, day_of_week in 1u32..=7) {
+        let day_of_week = DayOfWeek::of(day_of_week);
+
+        let expected = (start..start + days).filter(|&epoch_day| at(epoch_day, 0).day_of_week(0) == day_of_week).count() as i64;
+        prop_assert_eq!(expected, Instant::count_weekday_in_range(at(start, 0), at(start + days, 0), day_of_week, 0));
+    }
+}
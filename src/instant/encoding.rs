@@ -0,0 +1,269 @@
+use proptest::prelude::*;
+
+use crate::{CivilField, DecodeError, Instant, ParseErrorKind};
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_versioned_bytes_known_good((bytes, expected) in Just((
+        [1, 0xD2, 0x02, 0x96, 0x49, 0, 0, 0, 0, 0x15, 0xCD, 0x5B, 0x07],
+        Instant::of_epoch_second_and_adjustment(1_234_567_890, 123_456_789),
+    ))) {
+        prop_assert_eq!(Ok(expected), Instant::from_versioned_bytes(&bytes));
+        prop_assert_eq!(bytes.to_vec(), expected.to_versioned_bytes());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn versioned_bytes_round_trip(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(instant), Instant::from_versioned_bytes(&instant.to_versioned_bytes()));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_versioned_bytes_unknown_version(version in 2..=u8::MAX) {
+        let mut bytes = Instant::EPOCH.to_versioned_bytes();
+        bytes[0] = version;
+
+        prop_assert_eq!(Err(DecodeError::UnknownVersion(version)), Instant::from_versioned_bytes(&bytes));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_versioned_bytes_invalid_length(length in 0..20usize) {
+        prop_assume!(length != 13);
+        let mut bytes = [0; 20];
+        let bytes = &mut bytes[..length];
+        if let Some(version) = bytes.first_mut() {
+            *version = 1;
+        }
+
+        prop_assert_eq!(
+            Err(DecodeError::InvalidLength { expected: 13, actual: length }),
+            Instant::from_versioned_bytes(bytes)
+        );
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_versioned_bytes_invalid_nanos(nanos in 1_000_000_000..=u32::MAX) {
+        let mut bytes = Instant::EPOCH.to_versioned_bytes();
+        bytes[9..13].copy_from_slice(&nanos.to_le_bytes());
+
+        prop_assert_eq!(Err(DecodeError::InvalidNanos(nanos)), Instant::from_versioned_bytes(&bytes));
+    }
+}
+
+fn instants() -> impl Strategy<Value = Instant> {
+    prop_oneof![
+        Just(Instant::MIN),
+        Just(Instant::EPOCH),
+        Just(Instant::MAX),
+        (prop::num::i64::ANY, 0..1_000_000_000i64)
+            .prop_map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos)),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn bits_round_trip(instant in instants()) {
+        prop_assert_eq!(Ok(instant), Instant::from_bits(instant.to_bits()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn bits_preserve_order(instant in instants(), other in instants()) {
+        prop_assert_eq!(instant.cmp(&other), instant.to_bits().cmp(&other.to_bits()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn bits_known_values((instant, bits) in prop_oneof![
+        Just((Instant::MIN, 0)),
+        Just((Instant::EPOCH, 1 << 127)),
+        Just((Instant::MAX, u128::MAX << 64 | 999_999_999)),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 5), (u128::from(u64::MAX >> 1) << 64) | 5)),
+    ]) {
+        prop_assert_eq!(bits, instant.to_bits());
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_bits_invalid_padding(padding in 1..=u32::MAX) {
+        let bits = Instant::EPOCH.to_bits() | u128::from(padding) << 32;
+
+        prop_assert_eq!(Err(DecodeError::InvalidPadding), Instant::from_bits(bits));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_bits_invalid_nanos(nanos in 1_000_000_000..=u32::MAX) {
+        let bits = Instant::EPOCH.to_bits() | u128::from(nanos);
+
+        prop_assert_eq!(Err(DecodeError::InvalidNanos(nanos)), Instant::from_bits(bits));
+    }
+}
+
+// Packed values are as written into the local file headers by Info-ZIP's `zip` 3.0, for files with the given UTC time.
+proptest! {
+    #[test]
+    fn dos_datetime_vectors((date, time, text) in prop_oneof![
+        Just((0x0021, 0x0000, "1980-01-01T00:00:00Z")),
+        Just((0x3A4D, 0xBBEF, "2009-02-13T23:31:30Z")),
+        Just((0x285D, 0x6000, "2000-02-29T12:00:00Z")),
+        Just((0xFF9F, 0xBF7D, "2107-12-31T23:59:58Z")),
+    ]) {
+        let instant = Instant::parse_rfc3339(text).unwrap();
+
+        prop_assert_eq!(Ok(instant), Instant::from_dos_datetime(date, time, 0));
+        prop_assert_eq!(Ok((date, time)), instant.to_dos_datetime(0));
+    }
+}
+
+// Info-ZIP rounds odd seconds up, so these decode to a later second than the files had,
+// while encoding here truncates them to the earlier even second.
+proptest! {
+    #[test]
+    fn dos_datetime_odd_seconds((date, time, source, decoded, truncated) in prop_oneof![
+        Just((0x58CF, 0x43D7, "2024-06-15T08:30:45Z", "2024-06-15T08:30:46Z", (0x58CF, 0x43D6))),
+        Just((0x585D, 0x6800, "2024-02-29T12:59:59Z", "2024-02-29T13:00:00Z", (0x585D, 0x677D))),
+    ]) {
+        let source = Instant::parse_rfc3339(source).unwrap();
+
+        prop_assert_eq!(Ok(Instant::parse_rfc3339(decoded).unwrap()), Instant::from_dos_datetime(date, time, 0));
+        prop_assert_eq!(Ok(truncated), source.to_dos_datetime(0));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_dos_datetime_truncates(seconds in 315_532_800i64..4_354_819_200, nanos in 0..1_000_000_000i64) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let (date, time) = instant.to_dos_datetime(0).unwrap();
+        let decoded = Instant::from_dos_datetime(date, time, 0).unwrap();
+
+        prop_assert_eq!(Instant::of_epoch_second(seconds - seconds % 2), decoded);
+    }
+}
+
+proptest! {
+    #[test]
+    fn dos_datetime_offset((offset_seconds, date, time) in prop_oneof![
+        Just((0, 0x3A4D, 0xBBEF)),
+        // 2009-02-14T00:31:30+01:00
+        Just((3_600, 0x3A4E, 0x03EF)),
+        // 2009-02-13T18:01:30-05:30
+        Just((-19_800, 0x3A4D, 0x902F)),
+    ]) {
+        let instant = Instant::of_epoch_second(1_234_567_890);
+
+        prop_assert_eq!(Ok((date, time)), instant.to_dos_datetime(offset_seconds));
+        prop_assert_eq!(Ok(instant), Instant::from_dos_datetime(date, time, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_dos_datetime_errors((date, time, field, value) in prop_oneof![
+        Just((0x0001, 0x0000, CivilField::Month, 0)),
+        Just((0x01A1, 0x0000, CivilField::Month, 13)),
+        Just((0x01E1, 0x0000, CivilField::Month, 15)),
+        Just((0x0020, 0x0000, CivilField::Day, 0)),
+        Just((0x005E, 0x0000, CivilField::Day, 30)),
+        Just((0x3A5D, 0x0000, CivilField::Day, 29)),
+        Just((0x0021, 0xC000, CivilField::Hour, 24)),
+        Just((0x0021, 0x0780, CivilField::Minute, 60)),
+        Just((0x0021, 0x001E, CivilField::Second, 60)),
+        Just((0x0021, 0x001F, CivilField::Second, 62)),
+    ]) {
+        let error = Instant::from_dos_datetime(date, time, 0).unwrap_err();
+
+        prop_assert_eq!((field, value), (error.field(), error.value()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_dos_datetime_errors((text, offset_seconds, year) in prop_oneof![
+        Just(("1979-12-31T23:59:59Z", 0, 1979)),
+        Just(("1980-01-01T00:00:00Z", -1, 1979)),
+        Just(("2108-01-01T00:00:00Z", 0, 2108)),
+        Just(("2107-12-31T23:00:00Z", 3_600, 2108)),
+    ]) {
+        let error = Instant::parse_rfc3339(text).unwrap().to_dos_datetime(offset_seconds).unwrap_err();
+
+        prop_assert_eq!((CivilField::Year, year), (error.field(), error.value()));
+    }
+}
+
+// Instants within a few centuries either side of the epoch, where naive zero-padded epoch seconds misorder.
+#[cfg(feature = "std")]
+fn instants_near_epoch() -> impl Strategy<Value = Instant> {
+    (-10_000_000_000i64..10_000_000_000, 0..1_000_000_000i64)
+        .prop_map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos))
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn sortable_string_preserves_order(instant in prop_oneof![instants(), instants_near_epoch()], other in prop_oneof![instants(), instants_near_epoch()]) {
+        let (text, other_text) = (instant.to_sortable_string(), other.to_sortable_string());
+
+        prop_assert_eq!(30, text.len());
+        prop_assert_eq!(instant.cmp(&other), text.cmp(&other_text));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn sortable_string_round_trips(instant in prop_oneof![instants(), instants_near_epoch()]) {
+        prop_assert_eq!(Ok(instant), Instant::from_sortable_string(&instant.to_sortable_string()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn sortable_string_known_values((instant, text) in prop_oneof![
+        Just((Instant::MIN, "00000000000000000000.000000000")),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 500_000_000), "09223372036854775807.500000000")),
+        Just((Instant::EPOCH, "09223372036854775808.000000000")),
+        Just((Instant::of_epoch_second_and_adjustment(1_718_453_421, 123_456_789), "09223372038573229229.123456789")),
+        Just((Instant::MAX, "18446744073709551615.999999999")),
+    ]) {
+        prop_assert_eq!(Ok(instant), Instant::from_sortable_string(text));
+        #[cfg(feature = "std")]
+        prop_assert_eq!(text, instant.to_sortable_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_sortable_string_invalid((text, kind, position) in prop_oneof![
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("9223372036854775808.000000000", ParseErrorKind::InvalidCharacter, 19)),
+        Just(("09223372036854775808", ParseErrorKind::UnexpectedEnd, 20)),
+        Just(("09223372036854775808.00000000", ParseErrorKind::UnexpectedEnd, 29)),
+        Just(("09223372036854775808.0000000000", ParseErrorKind::TrailingCharacters, 30)),
+        Just(("-9223372036854775808.000000000", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("18446744073709551616.000000000", ParseErrorKind::FieldOutOfRange, 0)),
+    ]) {
+        let error = Instant::from_sortable_string(text).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use proptest::prelude::*;
+
+use crate::testing::*;
+use crate::{Calendar, Duration, Instant, TimestampUnit};
+#[cfg(feature = "std")]
+use crate::{FixedClock, RelativeTimeOptions};
+
+fn extremes() -> impl Strategy<Value = Instant> {
+    prop_oneof![
+        Just(Instant::MIN),
+        Just(Instant::of_epoch_second_and_adjustment(i64::MIN, 1)),
+        Just(Instant::of_epoch_second_and_adjustment(i64::MAX, 0)),
+        Just(Instant::MAX),
+    ]
+}
+
+fn offsets() -> impl Strategy<Value = i32> {
+    prop_oneof![Just(i32::MIN), Just(-1), Just(0), Just(1), Just(i32::MAX)]
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn formatting_round_trips(instant in extremes(), digits in 0..=9u8) {
+        prop_assert_eq!(Ok(instant), Instant::parse_unix(&instant.to_unix_string(9)));
+        prop_assert_eq!(Ok(instant), Instant::parse_unix(&instant.to_epoch_string()));
+        prop_assert!(instant.to_string().len() <= Instant::formatted_len_max());
+        prop_assert!(instant.to_rfc3339_with_precision(digits).len() <= Instant::formatted_len_max());
+        prop_assert!(Instant::parse_unix(&instant.to_unix_string(digits)).is_ok());
+    }
+}
+
+proptest! {
+    #[test]
+    fn encoding_round_trips(instant in extremes()) {
+        prop_assert_eq!(Ok(instant), Instant::from_bits(instant.to_bits()));
+        #[cfg(feature = "std")]
+        prop_assert_eq!(Ok(instant), Instant::from_versioned_bytes(&instant.to_versioned_bytes()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn civil_conversions(instant in extremes(), offset_seconds in offsets()) {
+        let (epoch_day, nano_of_day) = instant.to_epoch_day_and_nanos(offset_seconds);
+
+        prop_assert_eq!(instant, Instant::from_epoch_day_and_nanos(epoch_day, nano_of_day, offset_seconds));
+        let _day_of_week = instant.day_of_week(offset_seconds);
+        let _julian = instant.to_civil_date(offset_seconds, Calendar::Julian);
+        let _year = Instant::from_decimal_year(instant.to_decimal_year(offset_seconds), offset_seconds);
+        let _months: Vec<Instant> = Instant::iter_months(instant, offset_seconds).take(2).collect();
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn spans(first in extremes(), second in extremes(), offset_seconds in offsets()) {
+        let forward = first.business_days_until(second, offset_seconds);
+        prop_assert_eq!(-forward, second.business_days_until(first, offset_seconds));
+        let holidays: HashSet<(i32, u8, u8)> = [(2024, 12, 25), (i32::MIN, 1, 1), (i32::MAX, 12, 31)].iter().copied().collect();
+        let _excluding = first.business_days_until_excluding(second, offset_seconds, &holidays);
+
+        let _relative = first.relative_to(second);
+        let _relative = first.relative_to_with(second, &RelativeTimeOptions::default());
+        let _difference = first.abs_diff(second);
+        let _since = first.duration_since_or_zero(second);
+        let _elapsed = first.elapsed(&FixedClock::new(second));
+        let _remaining = first.remaining_until(&FixedClock::new(second));
+        let _timeout = first.timeout_from(second);
+        let _nearest = Instant::nearest(&[Instant::EPOCH, second], first);
+        let _approx = first.approx_eq(second, crate::Tolerance::seconds(1));
+        let _added = first.saturating_add(Duration::MAX);
+        let _subtracted = first.saturating_sub(Duration::MIN);
+    }
+}
+
+proptest! {
+    #[test]
+    fn checked_to_epoch_nanos(instant in extremes()) {
+        prop_assert_eq!(None, instant.checked_to_epoch_nanos());
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_epoch_nanos_bounds((text, expected) in prop_oneof![
+        Just(("1677-09-21T00:12:43.145224192Z", Some(i64::MIN))),
+        Just(("1677-09-21T00:12:43.145224191Z", None)),
+        Just(("1970-01-01T00:00:00.000000001Z", Some(1))),
+        Just(("1969-12-31T23:59:59.999999999Z", Some(-1))),
+        Just(("2262-04-11T23:47:16.854775807Z", Some(i64::MAX))),
+        Just(("2262-04-11T23:47:16.854775808Z", None)),
+    ]) {
+        let instant = Instant::parse_rfc3339(text).unwrap();
+
+        prop_assert_eq!(expected, instant.checked_to_epoch_nanos());
+        if let Some(nanos) = expected {
+            prop_assert_eq!(nanos, instant.to_epoch_nanos());
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "nanos would overflow i64")]
+    fn to_epoch_nanos_overflow(instant in extremes()) {
+        let _nanos = instant.to_epoch_nanos();
+    }
+}
+
+fn edges() -> impl Strategy<Value = i64> {
+    proptest::sample::select(I64_EDGES.to_vec())
+}
+
+proptest! {
+    #[test]
+    fn constructors_never_panic(value in edges()) {
+        let _seconds = Instant::of_epoch_second(value);
+        let _millis = Instant::of_epoch_milli(value);
+        for unit in [TimestampUnit::Second, TimestampUnit::Millisecond, TimestampUnit::Microsecond, TimestampUnit::Nanosecond] {
+            let _timestamp = Instant::from_timestamp(value, unit);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn constructors_panic_only_when_checked_is_none(value in edges(), adjustment in edges(), nano_of_day in proptest::sample::select(vec![0, 1, 86_399_999_999_999, 86_400_000_000_000, u64::MAX]), offset_seconds in offsets()) {
+        assert_panics_only_when_none(
+            || Instant::of_epoch_second_and_adjustment(value, adjustment),
+            Instant::checked_of_epoch_second_and_adjustment(value, adjustment),
+        );
+        assert_panics_only_when_none(
+            || Instant::from_epoch_day_and_nanos(value, nano_of_day, offset_seconds),
+            Instant::checked_from_epoch_day_and_nanos(value, nano_of_day, offset_seconds),
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn conversions_panic_only_when_checked_is_none(instant in extremes(), seconds in edges()) {
+        let duration = Duration::of_seconds(seconds);
+
+        assert_panics_only_when_none(|| instant.to_epoch_nanos(), instant.checked_to_epoch_nanos());
+        assert_panics_only_when_none(|| instant + duration, instant.checked_add(duration));
+        assert_panics_only_when_none(|| instant - duration, instant.checked_sub(duration));
+        for unit in [TimestampUnit::Second, TimestampUnit::Millisecond, TimestampUnit::Microsecond, TimestampUnit::Nanosecond] {
+            let _timestamp = instant.to_timestamp(unit);
+        }
+    }
+}
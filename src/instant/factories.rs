@@ -1,10 +1,8 @@
-use std::i64;
-
 use proptest::prelude::*;
 
 use crate::constants::*;
 
-use crate::Instant;
+use crate::{CivilField, Instant, TimestampUnit};
 
 proptest! {
     #[test]
@@ -90,6 +88,22 @@ proptest! {
     }
 }
 
+proptest! {
+    #[test]
+    fn of_epoch_milli_extremes((millis, seconds, nanos) in prop_oneof![
+        Just((i64::MIN, -9_223_372_036_854_776, 192_000_000)),
+        Just((i64::MIN + 1, -9_223_372_036_854_776, 193_000_000)),
+        Just((i64::MAX, 9_223_372_036_854_775, 807_000_000)),
+        Just((i64::MAX - 807, 9_223_372_036_854_775, 0)),
+        Just((-1, -1, 999_000_000)),
+    ]) {
+        let instant = Instant::of_epoch_milli(millis);
+
+        prop_assert_eq!((seconds, nanos), (instant.epoch_second(), instant.nano()));
+        prop_assert_eq!(Ok(millis), instant.to_timestamp(TimestampUnit::Millisecond));
+    }
+}
+
 proptest! {
     #[test]
     fn of_epoch_milli(millis in prop::num::i64::ANY) {
@@ -107,3 +121,107 @@ proptest! {
         prop_assert_eq!(seconds, instant.epoch_second());
     }
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn now_is_the_system_time() {
+    let before = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+    let now = Instant::now();
+    let after = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+
+    assert!(
+        now >= Instant::of_epoch_second_and_adjustment(
+            before.as_secs() as i64,
+            i64::from(before.subsec_nanos())
+        )
+    );
+    assert!(
+        now <= Instant::of_epoch_second_and_adjustment(
+            after.as_secs() as i64,
+            i64::from(after.subsec_nanos())
+        )
+    );
+}
+
+proptest! {
+    #[test]
+    fn of_utc((fields, epoch_second, nanos) in prop_oneof![
+        Just(((1970, 1, 1, 0, 0, 0, 0), 0, 0)),
+        Just(((2007, 12, 3, 10, 15, 30, 123_000_000), 1_196_676_930, 123_000_000)),
+        Just(((2024, 2, 29, 12, 0, 0, 0), 1_709_208_000, 0)),
+        Just(((2000, 2, 29, 23, 59, 59, 999_999_999), 951_868_799, 999_999_999)),
+        Just(((1969, 12, 31, 23, 59, 59, 500_000_000), -1, 500_000_000)),
+        Just(((1900, 3, 1, 0, 0, 0, 0), -2_203_891_200, 0)),
+        Just(((0, 1, 1, 0, 0, 0, 0), -62_167_219_200, 0)),
+        Just(((-1, 12, 31, 0, 0, 0, 0), -62_167_305_600, 0)),
+    ]) {
+        let (year, month, day, hour, minute, second, nano) = fields;
+
+        let instant = Instant::of_utc(year, month, day, hour, minute, second, nano);
+
+        prop_assert_eq!((epoch_second, nanos), (instant.epoch_second(), instant.nano()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_utc_matches_rfc3339(epoch_second in -62_167_219_200i64..253_402_300_800, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let expected = Instant::of_epoch_second_and_adjustment(epoch_second, nanos);
+        let text = expected.to_string();
+        let field = |start: usize, length: usize| text[start..start + length].parse::<u32>().unwrap();
+
+        let instant = Instant::of_utc(field(0, 4) as i32, field(5, 2), field(8, 2), field(11, 2), field(14, 2), field(17, 2), nanos as u32);
+
+        prop_assert_eq!(expected, instant);
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_offset((offset_seconds, epoch_second) in prop_oneof![
+        Just((0, 1_196_676_930)),
+        Just((3_600, 1_196_673_330)),
+        Just((-19_800, 1_196_696_730)),
+        Just((i32::MAX, 1_196_676_930 - i64::from(i32::MAX))),
+    ]) {
+        let instant = Instant::of_offset(2007, 12, 3, 10, 15, 30, 0, offset_seconds);
+
+        prop_assert_eq!(Instant::of_epoch_second(epoch_second), instant);
+    }
+}
+
+proptest! {
+    #[test]
+    fn try_of_utc_errors((fields, field, value) in prop_oneof![
+        Just(((2023, 0, 1, 0, 0, 0, 0), CivilField::Month, 0)),
+        Just(((2023, 13, 1, 0, 0, 0, 0), CivilField::Month, 13)),
+        Just(((2023, 1, 0, 0, 0, 0, 0), CivilField::Day, 0)),
+        Just(((2023, 1, 32, 0, 0, 0, 0), CivilField::Day, 32)),
+        Just(((2023, 4, 31, 0, 0, 0, 0), CivilField::Day, 31)),
+        Just(((2023, 2, 29, 0, 0, 0, 0), CivilField::Day, 29)),
+        Just(((1900, 2, 29, 0, 0, 0, 0), CivilField::Day, 29)),
+        Just(((2024, 2, 30, 0, 0, 0, 0), CivilField::Day, 30)),
+        Just(((2023, 1, 1, 24, 0, 0, 0), CivilField::Hour, 24)),
+        Just(((2023, 1, 1, 0, 60, 0, 0), CivilField::Minute, 60)),
+        Just(((2016, 12, 31, 23, 59, 60, 0), CivilField::Second, 60)),
+        Just(((2023, 1, 1, 0, 0, 0, 1_000_000_000), CivilField::Nanosecond, 1_000_000_000)),
+        // The first field out of range is reported.
+        Just(((2023, 13, 32, 24, 60, 60, 1_000_000_000), CivilField::Month, 13)),
+    ]) {
+        let (year, month, day, hour, minute, second, nano) = fields;
+
+        let error = Instant::try_of_utc(year, month, day, hour, minute, second, nano).unwrap_err();
+
+        prop_assert_eq!((field, value), (error.field(), error.value()));
+    }
+}
+
+#[test]
+#[should_panic(expected = "day of month 29 is out of range")]
+fn of_utc_rejects_invalid_leap_day() {
+    Instant::of_utc(2023, 2, 29, 0, 0, 0, 0);
+}
@@ -0,0 +1,439 @@
+use std::fmt;
+
+use proptest::prelude::*;
+
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::testing::FixedWriter;
+
+use crate::Instant;
+
+proptest! {
+    #[test]
+    fn sign_and_magnitude_whole_seconds(seconds in prop::num::i64::ANY) {
+        let instant = Instant::of_epoch_second(seconds);
+
+        let (negative, magnitude, nanos) = sign_and_magnitude(instant.epoch_second(), instant.nano());
+
+        prop_assert_eq!(seconds < 0, negative);
+        prop_assert_eq!(seconds.unsigned_abs(), magnitude);
+        prop_assert_eq!(0, nanos);
+    }
+}
+
+proptest! {
+    #[test]
+    fn sign_and_magnitude_before_epoch(seconds in i64::MIN..0, nanos in 1..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let (negative, magnitude, magnitude_nanos) = sign_and_magnitude(instant.epoch_second(), instant.nano());
+
+        prop_assert!(negative);
+        prop_assert_eq!((seconds + 1).unsigned_abs(), magnitude);
+        prop_assert_eq!(NANOSECONDS_IN_SECOND - nanos, magnitude_nanos as i64);
+    }
+}
+
+proptest! {
+    #[test]
+    fn sign_and_magnitude_half_second_before_epoch(nanos in Just(-NANOSECONDS_IN_SECOND / 2)) {
+        let instant = Instant::of_epoch_second_and_adjustment(0, nanos);
+
+        prop_assert_eq!((true, 0, 500_000_000), sign_and_magnitude(instant.epoch_second(), instant.nano()));
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn implements_defmt_format() {
+    fn assert_format<T: defmt::Format>() {}
+
+    assert_format::<Instant>();
+}
+
+proptest! {
+    #[test]
+    fn defmt_rendering((instant, expected) in prop_oneof![
+        Just((Instant::EPOCH, "0.000000000")),
+        Just((Instant::of_epoch_second_and_adjustment(0, -NANOSECONDS_IN_SECOND / 2), "-0.500000000")),
+        Just((Instant::of_epoch_second_and_adjustment(1_196_676_930, 123_000_000), "1196676930.123000000")),
+        Just((Instant::of_epoch_second_and_adjustment(-2, 1), "-1.999999999")),
+        Just((Instant::MIN, "-9223372036854775808.000000000")),
+    ]) {
+        let digits = instant.defmt_digits();
+
+        prop_assert_eq!(expected, digits.as_str());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_epoch_string_whole_seconds(seconds in prop::num::i64::ANY) {
+        let instant = Instant::of_epoch_second(seconds);
+
+        prop_assert_eq!(seconds.to_string(), instant.to_epoch_string());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_epoch_string_fraction((seconds, nanos, expected) in prop_oneof![
+        Just((1_234_567_890, 123_456_789, "1234567890.123456789")),
+        Just((1_234_567_890, 500_000_000, "1234567890.5")),
+        Just((0, 1, "0.000000001")),
+        Just((0, 10, "0.00000001")),
+        Just((-1, 500_000_000, "-0.5")),
+        Just((-1, 999_999_999, "-0.000000001")),
+        Just((-2, 250_000_000, "-1.75")),
+        Just((i64::MIN, 1, "-9223372036854775807.999999999")),
+        Just((i64::MAX, 999_999_999, "9223372036854775807.999999999")),
+    ]) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, instant.to_epoch_string());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_unix_string((seconds, nanos, digits, expected) in prop_oneof![
+        Just((1_718_453_421, 123_456_789, 9, "1718453421.123456789")),
+        Just((1_718_453_421, 123_456_789, 6, "1718453421.123457")),
+        Just((1_718_453_421, 123_456_789, 3, "1718453421.123")),
+        Just((1_718_453_421, 123_456_789, 0, "1718453421")),
+        Just((1_718_453_421, 0, 3, "1718453421.000")),
+        Just((0, 0, 0, "0")),
+        Just((-1, 500_000_000, 1, "-0.5")),
+        Just((-1, 500_000_000, 3, "-0.500")),
+        Just((-1, 500_000_000, 0, "0")),
+        Just((-2, 250_000_000, 2, "-1.75")),
+        Just((-1, 999_999_999, 9, "-0.000000001")),
+        Just((-1, 999_999_999, 3, "0.000")),
+        Just((0, 999_999_950, 6, "1.000000")),
+        Just((i64::MIN, 0, 9, "-9223372036854775808.000000000")),
+        Just((i64::MIN, 1, 3, "-9223372036854775808.000")),
+        Just((i64::MAX, 999_999_999, 3, "9223372036854775807.999")),
+    ]) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, instant.to_unix_string(digits));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_unix_string_round_trips(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(instant), Instant::parse_unix(&instant.to_unix_string(9)));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "fraction digits must be at most 9")]
+fn to_unix_string_rejects_too_many_digits() {
+    Instant::EPOCH.to_unix_string(10);
+}
+
+// The original rendering through `core::fmt`'s integer formatting, as a reference for the digit buffer.
+#[cfg(feature = "std")]
+fn reference_epoch_string(instant: Instant) -> String {
+    let (negative, seconds, nanos) = sign_and_magnitude(instant.epoch_second(), instant.nano());
+    let sign = if negative { "-" } else { "" };
+    if nanos == 0 {
+        format!("{}{}", sign, seconds)
+    } else {
+        format!(
+            "{}{}.{}",
+            sign,
+            seconds,
+            format!("{:09}", nanos).trim_end_matches('0')
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn to_epoch_string_matches_reference() {
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    let spread = (0..200_000).map(|index| {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        // Spread the magnitudes across every digit count, and the nanos across every trailing zero count.
+        let seconds = (state as i64) >> (index % 64);
+        let scale = 10i64.pow((index % 10) as u32);
+        Instant::of_epoch_second_and_adjustment(
+            seconds,
+            (state >> 32) as i64 % NANOSECONDS_IN_SECOND / scale * scale,
+        )
+    });
+    for instant in spread.chain(vec![Instant::MIN, Instant::EPOCH, Instant::MAX]) {
+        assert_eq!(reference_epoch_string(instant), instant.to_epoch_string());
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_epoch_string_matches_reference_for_any(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(reference_epoch_string(instant), instant.to_epoch_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn display((seconds, nanos, expected) in prop_oneof![
+        // From `java.time.Instant.toString()`.
+        Just((0, 0, "1970-01-01T00:00:00Z")),
+        Just((1196676930, 123000000, "2007-12-03T10:15:30.123Z")),
+        Just((1196676930, 123400000, "2007-12-03T10:15:30.123400Z")),
+        Just((1196676930, 123450000, "2007-12-03T10:15:30.123450Z")),
+        Just((1196676930, 1, "2007-12-03T10:15:30.000000001Z")),
+        Just((-1, 500000000, "1969-12-31T23:59:59.500Z")),
+        Just((-62167219200, 0, "0000-01-01T00:00:00Z")),
+        Just((-62167219201, 0, "-0001-12-31T23:59:59Z")),
+        Just((-62198755200, 0, "-0001-01-01T00:00:00Z")),
+        Just((253402300799, 999999999, "9999-12-31T23:59:59.999999999Z")),
+        Just((253402300800, 0, "+10000-01-01T00:00:00Z")),
+        Just((-31557014167219200, 0, "-1000000000-01-01T00:00:00Z")),
+        Just((31556889864403199, 999999999, "+1000000000-12-31T23:59:59.999999999Z")),
+        Just((951782400, 10, "2000-02-29T00:00:00.000000010Z")),
+        Just((-12220243200, 100000000, "1582-10-04T00:00:00.100Z")),
+    ]) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(expected, instant.to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_parse_round_trip(seconds in -62_167_219_200i64..253_402_300_800, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(Ok(instant), instant.to_string().parse());
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_into_fixed_buffer(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let mut writer = FixedWriter::<40>::new();
+
+        prop_assert_eq!(Ok(()), instant.format_into(&mut writer));
+        prop_assert_eq!(instant.to_string(), writer.as_str());
+        prop_assert!(writer.as_str().len() <= Instant::formatted_len_max());
+    }
+}
+
+proptest! {
+    #[test]
+    fn format_into_overflow_writes_nothing(instant in Just(Instant::of_epoch_second_and_adjustment(1196676930, 123000000))) {
+        let mut writer = FixedWriter::<20>::new();
+
+        prop_assert_eq!(Err(fmt::Error), instant.format_into(&mut writer));
+        prop_assert_eq!("", writer.as_str());
+    }
+}
+
+proptest! {
+    #[test]
+    fn formatted_len_max_is_reached(instant in prop_oneof![
+        Just(Instant::MIN),
+        Just(Instant::of_epoch_second_and_adjustment(i64::MIN, 1)),
+        Just(Instant::MAX),
+    ]) {
+        prop_assert!(instant.to_string().len() <= Instant::formatted_len_max());
+    }
+}
+
+#[test]
+fn formatted_len_max_is_tight() {
+    let longest = Instant::of_epoch_second_and_adjustment(i64::MIN, 1);
+
+    assert_eq!(
+        Instant::formatted_len_max(),
+        longest.to_string().len(),
+        "{}",
+        longest
+    );
+}
+
+proptest! {
+    #[test]
+    fn display_padding((format, expected) in prop_oneof![
+        Just((format!("{:>24}", Instant::EPOCH), "    1970-01-01T00:00:00Z")),
+        Just((format!("{:24}", Instant::EPOCH), "1970-01-01T00:00:00Z    ")),
+        Just((format!("{:<24}", Instant::EPOCH), "1970-01-01T00:00:00Z    ")),
+        Just((format!("{:0>24}", Instant::EPOCH), "00001970-01-01T00:00:00Z")),
+        Just((format!("{:>4}", Instant::EPOCH), "1970-01-01T00:00:00Z")),
+        Just((format!("{:>28.3}", Instant::EPOCH), "    1970-01-01T00:00:00.000Z")),
+        Just((format!("{:^26.1}", Instant::EPOCH), "  1970-01-01T00:00:00.0Z  ")),
+    ]) {
+        prop_assert_eq!(expected, format);
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_without_flags_matches_format_into(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let mut writer = FixedWriter::<39>::new();
+        instant.format_into(&mut writer).unwrap();
+
+        prop_assert_eq!(writer.as_str(), format!("{}", instant));
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_precision((format, expected) in prop_oneof![
+        Just((format!("{:.0}", Instant::of_epoch_second_and_adjustment(0, 123_456_789)), "1970-01-01T00:00:00Z")),
+        Just((format!("{:.1}", Instant::of_epoch_second_and_adjustment(0, 123_456_789)), "1970-01-01T00:00:00.1Z")),
+        Just((format!("{:.3}", Instant::of_epoch_second_and_adjustment(0, 123_456_789)), "1970-01-01T00:00:00.123Z")),
+        Just((format!("{:.6}", Instant::of_epoch_second_and_adjustment(0, 123_456_789)), "1970-01-01T00:00:00.123457Z")),
+        Just((format!("{:.9}", Instant::of_epoch_second_and_adjustment(0, 123_456_789)), "1970-01-01T00:00:00.123456789Z")),
+        Just((format!("{:.12}", Instant::of_epoch_second_and_adjustment(0, 123_456_789)), "1970-01-01T00:00:00.123456789Z")),
+        Just((format!("{:.6}", Instant::EPOCH), "1970-01-01T00:00:00.000000Z")),
+        Just((format!("{:.3}", Instant::of_epoch_second_and_adjustment(0, 120_000_000)), "1970-01-01T00:00:00.120Z")),
+        Just((format!("{:.3}", Instant::of_epoch_second_and_adjustment(0, 1_499_999)), "1970-01-01T00:00:00.001Z")),
+        Just((format!("{:.3}", Instant::of_epoch_second_and_adjustment(0, 1_500_000)), "1970-01-01T00:00:00.002Z")),
+        Just((format!("{:.0}", Instant::of_epoch_second_and_adjustment(0, 500_000_000)), "1970-01-01T00:00:01Z")),
+        Just((format!("{:.3}", Instant::of_epoch_second_and_adjustment(-1, 999_600_000)), "1970-01-01T00:00:00.000Z")),
+    ]) {
+        prop_assert_eq!(expected, format);
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_precision_carries_across_day_boundary((instant, precision, expected) in prop_oneof![
+        // 2023-12-31T23:59:59.999999950Z
+        Just((Instant::of_epoch_second_and_adjustment(1_704_067_199, 999_999_950), 6, "2024-01-01T00:00:00.000000Z")),
+        Just((Instant::of_epoch_second_and_adjustment(1_704_067_199, 999_999_950), 7, "2024-01-01T00:00:00.0000000Z")),
+        Just((Instant::of_epoch_second_and_adjustment(1_704_067_199, 999_999_950), 8, "2023-12-31T23:59:59.99999995Z")),
+        Just((Instant::of_epoch_second_and_adjustment(1_704_067_199, 999_999_949), 7, "2023-12-31T23:59:59.9999999Z")),
+        Just((Instant::of_epoch_second_and_adjustment(1_704_067_199, 999_999_950), 0, "2024-01-01T00:00:00Z")),
+        // 1969-12-31T23:59:59.9995Z
+        Just((Instant::of_epoch_second_and_adjustment(-1, 999_500_000), 3, "1970-01-01T00:00:00.000Z")),
+        // Nothing to carry into.
+        Just((Instant::MAX, 6, "+292277026596-12-04T15:30:07.999999Z")),
+    ]) {
+        prop_assert_eq!(expected, format!("{:.*}", precision, instant));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_rfc3339_with_precision_matches_display(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, digits in 0..=9u8) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(format!("{:.*}", digits as usize, instant), instant.to_rfc3339_with_precision(digits));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn to_rfc3339_with_precision_rounds_to_nearest(seconds in -(1i64 << 35)..(1i64 << 37), nanos in 0..NANOSECONDS_IN_SECOND, digits in 0..=9u8) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        let parsed: Instant = instant.to_rfc3339_with_precision(digits).parse().unwrap();
+
+        let unit = 10i128.pow(9 - u32::from(digits));
+        let total_nanos = |instant: Instant| i128::from(instant.epoch_second()) * 1_000_000_000 + i128::from(instant.nano());
+        let error = total_nanos(parsed) - total_nanos(instant);
+        prop_assert!(-unit < 2 * error && 2 * error <= unit, "{} {}", instant, parsed);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "fraction digits must be at most 9")]
+fn to_rfc3339_with_precision_rejects_too_many_digits() {
+    Instant::EPOCH.to_rfc3339_with_precision(10);
+}
+
+proptest! {
+    #[test]
+    fn display_at_utc_matches_display(seconds in prop::num::i64::ANY, nanos in 0..NANOSECONDS_IN_SECOND, digits in 0..=9usize) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(format!("{}", instant), format!("{}", instant.display(0)));
+        prop_assert_eq!(format!("{:.*}", digits, instant), format!("{:.*}", digits, instant.display(0)));
+        prop_assert_eq!(format!("{:>48.*}", digits, instant), format!("{:>48.*}", digits, instant.display(0)));
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_at_offset((format, expected) in prop_oneof![
+        Just((format!("{}", Instant::EPOCH.display(3_600)), "1970-01-01T01:00:00+01:00")),
+        Just((format!("{}", Instant::EPOCH.display(-19_800)), "1969-12-31T18:30:00-05:30")),
+        Just((format!("{}", Instant::EPOCH.display(3_661)), "1970-01-01T01:01:01+01:01:01")),
+        Just((format!("{}", Instant::EPOCH.display(-1)), "1969-12-31T23:59:59-00:00:01")),
+        Just((format!("{}", Instant::EPOCH.display(64_800)), "1970-01-01T18:00:00+18:00")),
+        Just((format!("{}", Instant::EPOCH.display(-64_800)), "1969-12-31T06:00:00-18:00")),
+        Just((format!("{}", Instant::of_epoch_second_and_adjustment(1_196_676_930, 123_000_000).display(3_600)), "2007-12-03T11:15:30.123+01:00")),
+        Just((format!("{:.0}", Instant::of_epoch_second_and_adjustment(1_196_679_599, 500_000_000).display(3_600)), "2007-12-03T12:00:00+01:00")),
+        Just((format!("{:>28}", Instant::EPOCH.display(3_600)), "   1970-01-01T01:00:00+01:00")),
+    ]) {
+        prop_assert_eq!(expected, format);
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_at_offset_matches_shifted_instant(seconds in -(1i64 << 40)..(1i64 << 40), nanos in 0..NANOSECONDS_IN_SECOND, offset_seconds in -64_800..=64_800i32) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+        let shifted = Instant::of_epoch_second_and_adjustment(seconds + i64::from(offset_seconds), nanos).to_string();
+
+        let rendered = instant.display(offset_seconds).to_string();
+
+        prop_assert_eq!(&shifted[..shifted.len() - 1], &rendered[..shifted.len() - 1]);
+    }
+}
+
+#[test]
+#[should_panic(expected = "offset must be within 18 hours of UTC")]
+fn display_rejects_offset_out_of_range() {
+    Instant::EPOCH.display(64_801);
+}
+
+proptest! {
+    #[test]
+    fn debug((instant, expected) in prop_oneof![
+        Just((Instant::EPOCH, "Instant(1970-01-01T00:00:00Z, epoch_second=0, nano=0)")),
+        Just((Instant::of_epoch_second(1_718_453_421), "Instant(2024-06-15T12:10:21Z, epoch_second=1718453421, nano=0)")),
+        Just((Instant::of_epoch_milli(-1), "Instant(1969-12-31T23:59:59.999Z, epoch_second=-1, nano=999000000)")),
+    ]) {
+        prop_assert_eq!(expected, format!("{:?}", instant));
+    }
+}
+
+#[test]
+fn debug_alternate() {
+    assert_eq!(
+        "Instant(\n    2024-06-15T12:10:21Z,\n    epoch_second=1718453421,\n    nano=0,\n)",
+        format!("{:#?}", Instant::of_epoch_second(1_718_453_421))
+    );
+}
+
+#[test]
+fn debug_extremes() {
+    for instant in [Instant::MIN, Instant::MAX] {
+        let debug = format!("{:?}", instant);
+
+        assert!(debug.starts_with(&format!("Instant({}, ", instant)));
+    }
+}
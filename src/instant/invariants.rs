@@ -0,0 +1,111 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::testing::I64_EDGES;
+use crate::{Duration, Instant, TimestampUnit};
+
+// Instants and durations weighted towards the edges of the range, where a carry or borrow is most likely to escape.
+fn seconds_and_nanos() -> impl Strategy<Value = (i64, i64)> {
+    prop_oneof![
+        (
+            prop::sample::select(I64_EDGES.to_vec()),
+            prop_oneof![Just(0), Just(1), Just(999_999_999)]
+        ),
+        (prop::num::i64::ANY, 0..NANOSECONDS_IN_SECOND),
+        (-1_000_000i64..1_000_000, 0..NANOSECONDS_IN_SECOND),
+    ]
+}
+
+fn instants() -> impl Strategy<Value = Instant> {
+    seconds_and_nanos()
+        .prop_map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos))
+}
+
+fn durations() -> impl Strategy<Value = Duration> {
+    seconds_and_nanos()
+        .prop_map(|(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos))
+}
+
+fn is_valid(instant: Instant) -> bool {
+    instant.nano() < NANOSECONDS_IN_SECOND as u32
+}
+
+proptest! {
+    #[test]
+    fn constructors_are_valid(amount in prop::num::i64::ANY, adjustment in prop::num::i64::ANY, nano_of_day in 0..(NANOSECONDS_IN_DAY as u64), offset_seconds in -64_800..=64_800i32) {
+        let constructed = [
+            Some(Instant::of_epoch_second(amount)),
+            Instant::checked_of_epoch_second_and_adjustment(amount, adjustment),
+            Some(Instant::of_epoch_milli(amount)),
+            Some(Instant::from_timestamp(amount, TimestampUnit::Second)),
+            Some(Instant::from_timestamp(amount, TimestampUnit::Millisecond)),
+            Some(Instant::from_timestamp(amount, TimestampUnit::Microsecond)),
+            Some(Instant::from_timestamp(amount, TimestampUnit::Nanosecond)),
+            Instant::checked_from_epoch_day_and_nanos(amount / 86_400, nano_of_day, offset_seconds),
+        ];
+        for instant in constructed.iter().flatten() {
+            prop_assert!(is_valid(*instant), "{:?}", instant);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn arithmetic_is_valid(instant in instants(), duration in durations(), other in instants()) {
+        let results = [
+            instant.checked_add(duration),
+            Some(instant.saturating_add(duration)),
+            instant.checked_sub(duration),
+            Some(instant.saturating_sub(duration)),
+            Some(instant.floor_to_second()),
+            Some(instant).filter(|instant| *instant < Instant::MAX.floor_to_second()).map(|instant| instant.round_to_nearest_second()),
+            instant.with_nanos_of_second(other.nano()).ok(),
+        ];
+        for result in results.iter().flatten() {
+            prop_assert!(is_valid(*result), "{:?}", result);
+        }
+        prop_assert!(instant.abs_diff(other).nano() < NANOSECONDS_IN_SECOND as u32);
+        if duration > Duration::ZERO {
+            if let Some(tick) = instant.checked_next_tick_after(other, duration) {
+                prop_assert!(is_valid(tick), "{:?}", tick);
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn conversions_round_trip_valid(instant in instants()) {
+        prop_assert_eq!(Ok(instant), Instant::from_bits(instant.to_bits()));
+        prop_assert_eq!(Ok(instant), Instant::from_versioned_bytes(&crate::wire::encode(instant.epoch_second(), instant.nano())));
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_then_sub_is_identity(instant in instants(), duration in durations()) {
+        if let Some(later) = instant.checked_add(duration) {
+            prop_assert_eq!(Some(instant), later.checked_sub(duration));
+            if let Some(difference) = duration.checked_neg() {
+                prop_assert_eq!(Some(instant), later.checked_add(difference));
+            }
+        }
+        if let Some(earlier) = instant.checked_sub(duration) {
+            prop_assert_eq!(Some(instant), earlier.checked_add(duration));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn difference_then_add_is_identity(instant in instants(), other in instants()) {
+        // The difference of two instants overflows a duration when they are more than half the range apart.
+        let difference = total_nanos(other.epoch_second(), other.nano()) - total_nanos(instant.epoch_second(), instant.nano());
+        if of_total_nanos_checked(difference).is_some() {
+            let difference = other - instant;
+            prop_assert_eq!(Some(other), instant.checked_add(difference));
+            prop_assert_eq!(Some(instant), other.checked_sub(difference));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+use crate::{Instant, JsMillisError};
+
+proptest! {
+    #[test]
+    fn from_js_millis((millis, seconds, nanos) in prop_oneof![
+        Just((0.0, 0, 0)),
+        Just((-0.0, 0, 0)),
+        Just((1_500.0, 1, 500_000_000)),
+        Just((-1_500.0, -2, 500_000_000)),
+        Just((0.5, 0, 500_000)),
+        Just((-0.5, -1, 999_500_000)),
+        Just((-0.25, -1, 999_750_000)),
+        Just((1_700_000_000_123.0, 1_700_000_000, 123_000_000)),
+        Just((8.64e15, 8_640_000_000_000, 0)),
+        Just((-8.64e15, -8_640_000_000_000, 0)),
+        Just((JS_MAX_SAFE_INTEGER as f64, 9_007_199_254_740, 991_000_000)),
+        Just((-JS_MAX_SAFE_INTEGER as f64, -9_007_199_254_741, 9_000_000)),
+    ]) {
+        prop_assert_eq!(Ok(Instant::of_epoch_second_and_adjustment(seconds, nanos)), Instant::from_js_millis(millis));
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_js_millis_invalid((millis, error) in prop_oneof![
+        Just((f64::NAN, JsMillisError::NotFinite)),
+        Just((f64::INFINITY, JsMillisError::NotFinite)),
+        Just((f64::NEG_INFINITY, JsMillisError::NotFinite)),
+        Just(((JS_MAX_SAFE_INTEGER + 1) as f64, JsMillisError::OutOfRange)),
+        Just((-(JS_MAX_SAFE_INTEGER + 1) as f64, JsMillisError::OutOfRange)),
+        Just((f64::MAX, JsMillisError::OutOfRange)),
+    ]) {
+        prop_assert_eq!(Err(error), Instant::from_js_millis(millis));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_js_millis((seconds, nanos, millis) in prop_oneof![
+        Just((0, 0, 0.0)),
+        Just((1, 500_000_000, 1_500.0)),
+        Just((0, 999_999, 0.0)),
+        // Before the epoch, the fraction of a millisecond floors away from zero.
+        Just((-1, 999_500_000, -1.0)),
+        Just((-1, 999_999_999, -1.0)),
+        Just((-2, 500_000_000, -1_500.0)),
+        Just((1_700_000_000, 123_456_789, 1_700_000_000_123.0)),
+        Just((i64::MIN, 0, -9.223_372_036_854_776e21)),
+    ]) {
+        prop_assert_eq!(millis, Instant::of_epoch_second_and_adjustment(seconds, nanos).to_js_millis());
+    }
+}
+
+proptest! {
+    #[test]
+    fn js_millis_round_trip(millis in -JS_MAX_SAFE_INTEGER..=JS_MAX_SAFE_INTEGER) {
+        let instant = Instant::from_js_millis(millis as f64).unwrap();
+
+        prop_assert_eq!(millis as f64, instant.to_js_millis());
+    }
+}
+
+proptest! {
+    #[test]
+    fn js_millis_keeps_order(first in -1_000_000_000i64..1_000_000_000, second in -1_000_000_000i64..1_000_000_000) {
+        let (first, second) = (Instant::of_epoch_second_and_adjustment(0, first), Instant::of_epoch_second_and_adjustment(0, second));
+
+        prop_assert!(first > second || first.to_js_millis() <= second.to_js_millis());
+    }
+}
@@ -0,0 +1,207 @@
+use proptest::prelude::*;
+
+use crate::{Instant, ParseErrorKind};
+
+proptest! {
+    #[test]
+    fn parse_rfc3339_vectors((text, epoch_second, nanos) in prop_oneof![
+        Just(("1970-01-01T00:00:00Z", 0, 0)),
+        Just(("2007-12-03T10:15:30.123Z", 1_196_676_930, 123_000_000)),
+        Just(("2000-02-29T23:59:59.999999999-05:30", 951_888_599, 999_999_999)),
+        Just(("0000-01-01T00:00:00Z", -62_167_219_200, 0)),
+        Just(("9999-12-31T23:59:59.999999999Z", 253_402_300_799, 999_999_999)),
+        Just(("1969-12-31T23:59:59.5+00:00", -1, 500_000_000)),
+        Just(("1900-03-01T00:00:00+23:59", -2_203_977_540, 0)),
+        Just(("2024-02-29T12:00:00-23:59", 1_709_294_340, 0)),
+        Just(("1582-10-04t00:00:00z", -12_220_243_200, 0)),
+        Just(("2038-01-19 03:14:07.000000001-00:00", 2_147_483_647, 1)),
+    ]) {
+        let expected = Instant::of_epoch_second_and_adjustment(epoch_second, nanos);
+
+        prop_assert_eq!(Ok(expected), Instant::parse_rfc3339(text));
+        prop_assert_eq!(Ok(expected), Instant::parse_rfc3339_bytes(text.as_bytes()));
+        prop_assert_eq!(Ok(expected), text.parse::<Instant>());
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_rfc3339_round_trips_epoch_seconds(epoch_second in -62_167_219_200i64..253_402_300_800, nanos in 0..1_000_000_000u32) {
+        let days = epoch_second.div_euclid(86_400);
+        let second_of_day = epoch_second.rem_euclid(86_400);
+        // Civil from days, independently of the parser's own conversion.
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        let text = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+            year, month, day, second_of_day / 3_600, second_of_day % 3_600 / 60, second_of_day % 60, nanos
+        );
+
+        prop_assert_eq!(
+            Ok(Instant::of_epoch_second_and_adjustment(epoch_second, i64::from(nanos))),
+            Instant::parse_rfc3339(&text)
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_rfc3339_errors((text, kind, position) in prop_oneof![
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("2007-12-03T10:15", ParseErrorKind::UnexpectedEnd, 16)),
+        Just(("2007-12-03T10:15:30", ParseErrorKind::UnexpectedEnd, 19)),
+        Just(("2007-12-03T10:15:30.", ParseErrorKind::UnexpectedEnd, 20)),
+        Just(("2007-12-03T10:15:30+01", ParseErrorKind::UnexpectedEnd, 22)),
+        Just(("207-12-03T10:15:30Z", ParseErrorKind::InvalidCharacter, 3)),
+        Just(("2007/12/03T10:15:30Z", ParseErrorKind::InvalidCharacter, 4)),
+        Just(("2007-12-03X10:15:30Z", ParseErrorKind::InvalidCharacter, 10)),
+        Just(("2007-12-03T10:1a:30Z", ParseErrorKind::InvalidCharacter, 15)),
+        Just(("2007-12-03T10:15:30.Z", ParseErrorKind::InvalidCharacter, 20)),
+        Just(("2007-12-03T10:15:30", ParseErrorKind::UnexpectedEnd, 19)),
+        Just(("2007-12-03T10:15:30+0100", ParseErrorKind::InvalidCharacter, 22)),
+        Just(("2007-12-03T10:15:30UTC", ParseErrorKind::InvalidCharacter, 19)),
+        Just(("2007-12-03T10:15:30Z ", ParseErrorKind::TrailingCharacters, 20)),
+        Just(("2007-00-03T10:15:30Z", ParseErrorKind::FieldOutOfRange, 5)),
+        Just(("2007-13-03T10:15:30Z", ParseErrorKind::FieldOutOfRange, 5)),
+        Just(("2007-12-00T10:15:30Z", ParseErrorKind::FieldOutOfRange, 8)),
+        Just(("2007-02-29T10:15:30Z", ParseErrorKind::FieldOutOfRange, 8)),
+        Just(("1900-02-29T10:15:30Z", ParseErrorKind::FieldOutOfRange, 8)),
+        Just(("2007-04-31T10:15:30Z", ParseErrorKind::FieldOutOfRange, 8)),
+        Just(("2007-12-03T24:00:00Z", ParseErrorKind::FieldOutOfRange, 11)),
+        Just(("2007-12-03T10:60:30Z", ParseErrorKind::FieldOutOfRange, 14)),
+        Just(("2016-12-31T23:59:60Z", ParseErrorKind::FieldOutOfRange, 17)),
+        Just(("2007-12-03T10:15:30.1234567890Z", ParseErrorKind::FieldOutOfRange, 29)),
+        Just(("2007-12-03T10:15:30+24:00", ParseErrorKind::FieldOutOfRange, 20)),
+        Just(("2007-12-03T10:15:30-01:60", ParseErrorKind::FieldOutOfRange, 23)),
+    ]) {
+        let error = Instant::parse_rfc3339(text).unwrap_err();
+
+        prop_assert_eq!(kind, error.kind());
+        prop_assert_eq!(position, error.position());
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_rfc3339_bytes_rejects_non_ascii(bytes in Just(b"2007-12-03T10:15:30\xFFZ")) {
+        let error = Instant::parse_rfc3339_bytes(bytes).unwrap_err();
+
+        prop_assert_eq!(ParseErrorKind::InvalidCharacter, error.kind());
+        prop_assert_eq!(19, error.position());
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_unix_vectors((text, epoch_second, nanos) in prop_oneof![
+        Just(("0", 0, 0)),
+        Just(("-0", 0, 0)),
+        Just(("+0.0", 0, 0)),
+        Just(("1718453421.123456789", 1_718_453_421, 123_456_789)),
+        Just(("1718453421.5", 1_718_453_421, 500_000_000)),
+        Just(("0001718453421", 1_718_453_421, 0)),
+        Just(("+12.25", 12, 250_000_000)),
+        Just(("-0.5", -1, 500_000_000)),
+        Just(("-1.75", -2, 250_000_000)),
+        Just(("-0.000000001", -1, 999_999_999)),
+        Just(("-12", -12, 0)),
+        Just(("-12.0", -12, 0)),
+        Just(("9223372036854775807.999999999", i64::MAX, 999_999_999)),
+        Just(("-9223372036854775808", i64::MIN, 0)),
+        Just(("-9223372036854775807.999999999", i64::MIN, 1)),
+    ]) {
+        prop_assert_eq!(Ok(Instant::of_epoch_second_and_adjustment(epoch_second, nanos)), Instant::parse_unix(text));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_unix_errors((text, kind, position) in prop_oneof![
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("-", ParseErrorKind::UnexpectedEnd, 1)),
+        Just(("12.", ParseErrorKind::UnexpectedEnd, 3)),
+        Just((".5", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("--1", ParseErrorKind::InvalidCharacter, 1)),
+        Just(("1e9", ParseErrorKind::TrailingCharacters, 1)),
+        Just(("12.5s", ParseErrorKind::TrailingCharacters, 4)),
+        Just((" 12", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("12.1234567890", ParseErrorKind::FieldOutOfRange, 12)),
+        Just(("9223372036854775808", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("-9223372036854775809", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("-9223372036854775808.5", ParseErrorKind::FieldOutOfRange, 0)),
+        Just(("123456789012345678901234567890", ParseErrorKind::FieldOutOfRange, 0)),
+    ]) {
+        let error = Instant::parse_unix(text).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn parse_unix_round_trips_text(seconds in prop::num::i64::ANY, fraction in 0..1_000_000_000u32, digits in 1..=9usize) {
+        let fraction = fraction / 10u32.pow(9 - digits as u32);
+        prop_assume!(seconds != 0 || fraction != 0);
+        let sign = if seconds < 0 { "-" } else { "" };
+        let text = format!("{}{}.{:0width$}", sign, seconds.unsigned_abs(), fraction, width = digits);
+        let instant = Instant::parse_unix(&text);
+        prop_assume!(instant.is_ok(), "below the minimum instant");
+
+        prop_assert_eq!(text, instant.unwrap().to_unix_string(digits as u8));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_flexible_vectors((text, offset_seconds, epoch_second, nanos) in prop_oneof![
+        // ISO-8601 with an offset, which takes priority over the given offset.
+        Just(("2006-01-02T15:04:05Z", -25_200, 1_136_214_245, 0)),
+        Just(("2006-01-02T15:04:05.123-07:00", 0, 1_136_239_445, 123_000_000)),
+        // ISO-8601 without an offset.
+        Just(("2006-01-02 15:04:05", 0, 1_136_214_245, 0)),
+        Just(("2006-01-02 15:04:05", -25_200, 1_136_239_445, 0)),
+        Just(("2006-01-02T15:04:05.5", 3_600, 1_136_210_645, 500_000_000)),
+        // `asctime` output.
+        Just(("Mon Jan  2 15:04:05 2006", 0, 1_136_214_245, 0)),
+        Just(("Mon Jan 02 15:04:05 2006", -25_200, 1_136_239_445, 0)),
+        Just(("Mon Jan 2 15:04:05 2006", 0, 1_136_214_245, 0)),
+        Just(("Thu Feb 29 00:00:00 2024", 0, 1_709_164_800, 0)),
+        Just(("Wed Dec 31 23:59:59.25 1969", 0, -1, 250_000_000)),
+        // `date` output in UTC, which takes priority over the given offset.
+        Just(("Mon Jan  2 15:04:05 UTC 2006", -25_200, 1_136_214_245, 0)),
+        Just(("Mon Jan  2 15:04:05 GMT 2006", 3_600, 1_136_214_245, 0)),
+    ]) {
+        prop_assert_eq!(Ok(Instant::of_epoch_second_and_adjustment(epoch_second, nanos)), Instant::parse_flexible(text, offset_seconds));
+    }
+}
+
+proptest! {
+    #[test]
+    fn parse_flexible_errors((text, kind, position) in prop_oneof![
+        Just(("", ParseErrorKind::UnexpectedEnd, 0)),
+        Just(("2006-01-02", ParseErrorKind::UnexpectedEnd, 10)),
+        Just(("2006-01-02 15:04:05 ", ParseErrorKind::InvalidCharacter, 19)),
+        Just(("2006-01-02 15:04:05+01", ParseErrorKind::UnexpectedEnd, 22)),
+        Just(("Mon Jan  2 15:04:05", ParseErrorKind::UnexpectedEnd, 19)),
+        Just(("Mon Jan  2 15:04:05 2006 ", ParseErrorKind::TrailingCharacters, 24)),
+        Just(("mon Jan  2 15:04:05 2006", ParseErrorKind::InvalidCharacter, 0)),
+        Just(("Mon January  2 15:04:05 2006", ParseErrorKind::InvalidCharacter, 7)),
+        Just(("Mon Jam  2 15:04:05 2006", ParseErrorKind::InvalidCharacter, 4)),
+        Just(("Mon Jan  2 15:04:05 MST 2006", ParseErrorKind::InvalidCharacter, 20)),
+        Just(("Mon Feb 30 15:04:05 2006", ParseErrorKind::FieldOutOfRange, 8)),
+        Just(("Mon Jan  0 15:04:05 2006", ParseErrorKind::FieldOutOfRange, 9)),
+        Just(("Mon Jan  2 24:04:05 2006", ParseErrorKind::FieldOutOfRange, 11)),
+    ]) {
+        let error = Instant::parse_flexible(text, 0).unwrap_err();
+
+        prop_assert_eq!((kind, position), (error.kind(), error.position()));
+    }
+}
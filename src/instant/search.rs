@@ -0,0 +1,189 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::{Duration, Instant};
+
+fn index() -> Vec<Instant> {
+    vec![
+        Instant::of_epoch_second(10),
+        Instant::of_epoch_second(20),
+        Instant::of_epoch_second(20),
+        Instant::of_epoch_second_and_adjustment(25, 500_000_000),
+        Instant::of_epoch_second(40),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn nearest((query, expected) in prop_oneof![
+        // Before the first.
+        Just((Instant::of_epoch_second(0), (0, Duration::of_seconds(10)))),
+        Just((Instant::MIN, (0, Duration::MAX))),
+        // Exactly on an instant, including the first of duplicates.
+        Just((Instant::of_epoch_second(10), (0, Duration::ZERO))),
+        Just((Instant::of_epoch_second(20), (1, Duration::ZERO))),
+        Just((Instant::of_epoch_second(40), (4, Duration::ZERO))),
+        // Between two instants.
+        Just((Instant::of_epoch_second(12), (0, Duration::of_seconds(2)))),
+        Just((Instant::of_epoch_second(18), (1, Duration::of_seconds(2)))),
+        Just((Instant::of_epoch_second(23), (3, Duration::of_millis(2_500)))),
+        Just((Instant::of_epoch_second_and_adjustment(25, 499_999_999), (3, Duration::of_nanos(1)))),
+        Just((Instant::of_epoch_second(33), (4, Duration::of_seconds(7)))),
+        // Equidistant between two instants chooses the earlier.
+        Just((Instant::of_epoch_second(15), (0, Duration::of_seconds(5)))),
+        Just((Instant::of_epoch_second_and_adjustment(22, 750_000_000), (1, Duration::of_millis(2_750)))),
+        Just((Instant::of_epoch_second_and_adjustment(32, 750_000_000), (3, Duration::of_millis(7_250)))),
+        // After the last.
+        Just((Instant::of_epoch_second(100), (4, Duration::of_seconds(60)))),
+        Just((Instant::MAX, (4, Duration::of_seconds_and_adjustment(i64::MAX - 40, 999_999_999)))),
+    ]) {
+        prop_assert_eq!(Some(expected), Instant::nearest(&index(), query));
+    }
+}
+
+proptest! {
+    #[test]
+    fn nearest_in_empty_index(query in Just(Instant::EPOCH)) {
+        prop_assert_eq!(None, Instant::nearest(&[], query));
+    }
+}
+
+proptest! {
+    #[test]
+    fn nearest_matches_linear_scan(mut index in prop::collection::vec((-1_000i64..1_000, 0..NANOSECONDS_IN_SECOND), 1..20), query_seconds in -1_100i64..1_100, query_nanos in 0..NANOSECONDS_IN_SECOND) {
+        let mut index: Vec<Instant> = index.drain(..).map(|(seconds, nanos)| Instant::of_epoch_second_and_adjustment(seconds, nanos)).collect();
+        index.sort();
+        let query = Instant::of_epoch_second_and_adjustment(query_seconds, query_nanos);
+
+        let expected = index
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, instant)| instant.abs_diff(query))
+            .map(|(position, instant)| (position, instant.abs_diff(query)));
+
+        prop_assert_eq!(expected, Instant::nearest(&index, query));
+    }
+}
+
+proptest! {
+    #[test]
+    fn detect_uniform_stride((sorted, expected) in prop_oneof![
+        Just((vec![], None)),
+        Just((vec![Instant::of_epoch_second(10)], None)),
+        Just((vec![Instant::of_epoch_second(10), Instant::of_epoch_second(15)], Some(Duration::of_seconds(5)))),
+        Just(((0..100).map(|index| Instant::of_epoch_milli(1_718_453_421_000 + index * 250)).collect(), Some(Duration::of_millis(250)))),
+        Just((vec![Instant::of_epoch_second(-1); 3], Some(Duration::ZERO))),
+        Just((
+            vec![Instant::of_epoch_second(0), Instant::of_epoch_second(-2), Instant::of_epoch_second(-4)],
+            Some(Duration::of_seconds(-2)),
+        )),
+        // Off by a single nanosecond.
+        Just((
+            vec![
+                Instant::of_epoch_second(0),
+                Instant::of_epoch_second(1),
+                Instant::of_epoch_second_and_adjustment(2, 1),
+                Instant::of_epoch_second(3),
+            ],
+            None,
+        )),
+        Just((
+            vec![Instant::of_epoch_second(0), Instant::of_epoch_second(1), Instant::of_epoch_second(2), Instant::of_epoch_second(4)],
+            None,
+        )),
+        Just((vec![Instant::MIN, Instant::MAX], None)),
+        Just((vec![Instant::MIN, Instant::of_epoch_second(0), Instant::MAX], None)),
+    ]) {
+        prop_assert_eq!(expected, Instant::detect_uniform_stride(&sorted));
+    }
+}
+
+proptest! {
+    #[test]
+    fn earliest((deadlines, expected) in prop_oneof![
+        Just((vec![], None)),
+        Just((vec![Instant::of_epoch_second(30)], Some((0, Instant::of_epoch_second(30))))),
+        Just((vec![Instant::of_epoch_second(30), Instant::of_epoch_second(10), Instant::of_epoch_second(20)], Some((1, Instant::of_epoch_second(10))))),
+        // Of duplicates, the first position wins.
+        Just((vec![Instant::of_epoch_second(30), Instant::of_epoch_second(10), Instant::of_epoch_second(20), Instant::of_epoch_second(10)], Some((1, Instant::of_epoch_second(10))))),
+        Just((vec![Instant::MAX, Instant::MAX], Some((0, Instant::MAX)))),
+        Just((vec![Instant::of_epoch_second_and_adjustment(10, 1), Instant::of_epoch_second(10), Instant::MIN], Some((2, Instant::MIN)))),
+    ]) {
+        prop_assert_eq!(expected, Instant::earliest(&deadlines));
+    }
+}
+
+proptest! {
+    #[test]
+    fn earliest_is_minimum(seconds in prop::collection::vec(-100i64..100, 1..20)) {
+        let deadlines: Vec<Instant> = seconds.iter().map(|&second| Instant::of_epoch_second(second)).collect();
+
+        let (position, earliest) = Instant::earliest(&deadlines).unwrap();
+        prop_assert_eq!(deadlines[position], earliest);
+        prop_assert_eq!(Some(&earliest), deadlines.iter().min());
+        prop_assert!(deadlines[..position].iter().all(|&deadline| deadline > earliest));
+    }
+}
+
+#[cfg(feature = "std")]
+fn series(len: i64) -> Vec<Instant> {
+    (0..len).map(Instant::of_epoch_second).collect()
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn downsample_grid((len, factor, expected) in prop_oneof![
+        Just((0, 3, vec![])),
+        Just((1, 3, vec![0])),
+        Just((2, 3, vec![0])),
+        Just((7, 3, vec![0, 3, 6])),
+        Just((6, 3, vec![0, 3])),
+        Just((4, 1, vec![0, 1, 2, 3])),
+    ]) {
+        let expected: Vec<Instant> = expected.into_iter().map(Instant::of_epoch_second).collect();
+
+        prop_assert_eq!(expected, Instant::downsample_grid(&series(len), factor));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn build_pyramid((len, levels, factor, expected) in prop_oneof![
+        Just((100, 2, 2, vec![100, 50])),
+        Just((100, 4, 10, vec![100, 10, 1, 1])),
+        Just((100, 3, 3, vec![100, 34, 12])),
+        Just((0, 2, 2, vec![0, 0])),
+        Just((5, 0, 2, vec![])),
+    ]) {
+        let base = series(len);
+        let pyramid = Instant::build_pyramid(&base, levels, factor);
+
+        let lengths: Vec<usize> = pyramid.iter().map(Vec::len).collect();
+        prop_assert_eq!(expected, lengths);
+        for (level, instants) in pyramid.iter().enumerate() {
+            let stride = i64::from(factor).pow(level as u32);
+            prop_assert!(instants.iter().enumerate().all(|(index, instant)| instant.epoch_second() == index as i64 * stride));
+        }
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "downsampling factor must be positive")]
+    fn downsample_grid_zero_factor(len in 0..10i64) {
+        let _downsampled = Instant::downsample_grid(&series(len), 0);
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic(expected = "downsampling factor must be positive")]
+    fn build_pyramid_zero_factor(levels in 0..4usize) {
+        let _pyramid = Instant::build_pyramid(&series(10), levels, 0);
+    }
+}
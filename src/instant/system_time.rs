@@ -0,0 +1,101 @@
+use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+
+use proptest::prelude::*;
+
+use crate::constants::*;
+
+use crate::Instant;
+
+fn system_time(seconds: i64, nanos: u32) -> SystemTime {
+    let instant = Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos));
+    if seconds >= 0 {
+        UNIX_EPOCH + StdDuration::new(seconds as u64, nanos)
+    } else {
+        let (_, magnitude_seconds, magnitude_nanos) =
+            crate::seconds_nanos::sign_and_magnitude(instant.epoch_second(), instant.nano());
+        UNIX_EPOCH - StdDuration::new(magnitude_seconds, magnitude_nanos)
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_system_time_lossy(seconds in -(1i64 << 40)..(1i64 << 40), nanos in 0..NANOSECONDS_IN_SECOND as u32) {
+        let instant = Instant::from_system_time_lossy(system_time(seconds, nanos));
+
+        prop_assert_eq!(Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos)), instant);
+    }
+}
+
+proptest! {
+    #[test]
+    fn compare_either_side_of_epoch((seconds, nanos) in prop_oneof![
+        Just((0, 0)),
+        Just((0, 1)),
+        Just((-1, 999_999_999)),
+        Just((-1, 0)),
+        Just((1_718_453_421, 123_456_789)),
+        Just((-1_718_453_421, 123_456_789)),
+    ]) {
+        let time = system_time(seconds, nanos);
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos));
+        let earlier = Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos) - 1);
+        let later = Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos) + 1);
+
+        prop_assert!(instant == time);
+        prop_assert!(earlier != time && later != time);
+        prop_assert!(earlier < time && earlier <= time);
+        prop_assert!(later > time && later >= time);
+        prop_assert!(instant.partial_cmp(&time) == Some(core::cmp::Ordering::Equal));
+    }
+}
+
+// The range of `SystemTime` on Linux (a `timespec`) is exactly that of instants.
+proptest! {
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn compare_at_platform_extremes((time, instant) in prop_oneof![
+        Just((UNIX_EPOCH + StdDuration::new(i64::MAX as u64, 999_999_999), Instant::MAX)),
+        Just((UNIX_EPOCH - StdDuration::new(1 << 63, 0), Instant::MIN)),
+    ]) {
+        prop_assert_eq!(instant, Instant::from_system_time_lossy(time));
+        prop_assert!(instant == time);
+        prop_assert!(Instant::EPOCH != time);
+    }
+}
+
+proptest! {
+    #[test]
+    fn compare_beyond_range(time in prop_oneof![
+        Just(UNIX_EPOCH.checked_add(StdDuration::new(u64::MAX, 999_999_999))),
+        Just(UNIX_EPOCH.checked_add(StdDuration::new(1 << 63, 0))),
+        Just(UNIX_EPOCH.checked_sub(StdDuration::new(1 << 63, 1))),
+        Just(UNIX_EPOCH.checked_sub(StdDuration::new(u64::MAX, 0))),
+    ]) {
+        // Only platforms with a wider `SystemTime` than instants can represent these.
+        if let Some(time) = time {
+            let after_epoch = time > UNIX_EPOCH;
+
+            prop_assert!(Instant::MAX != time && Instant::MIN != time);
+            prop_assert_eq!(after_epoch, Instant::MAX < time);
+            prop_assert_eq!(!after_epoch, Instant::MIN > time);
+            let saturated = if after_epoch { Instant::MAX } else { Instant::MIN };
+            prop_assert_eq!(saturated, Instant::from_system_time_lossy(time));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn of_total_nanos_saturating((total, expected) in prop_oneof![
+        Just((0, Instant::EPOCH)),
+        Just((-1, Instant::of_epoch_second_and_adjustment(-1, 999_999_999))),
+        Just((i128::from(i64::MAX) * 1_000_000_000 + 999_999_999, Instant::MAX)),
+        Just((i128::from(i64::MAX) * 1_000_000_000 + 1_000_000_000, Instant::MAX)),
+        Just((i128::from(u64::MAX) * 1_000_000_000, Instant::MAX)),
+        Just((i128::from(i64::MIN) * 1_000_000_000, Instant::MIN)),
+        Just((i128::from(i64::MIN) * 1_000_000_000 - 1, Instant::MIN)),
+        Just((-i128::from(u64::MAX) * 1_000_000_000, Instant::MIN)),
+    ]) {
+        prop_assert_eq!(expected, Instant::of_total_nanos_saturating(total));
+    }
+}
@@ -0,0 +1,178 @@
+use proptest::prelude::*;
+
+#[cfg(feature = "std")]
+use crate::{Duration, SliceOverflowError};
+use crate::{Instant, TimestampOverflowError, TimestampUnit};
+
+fn units() -> impl Strategy<Value = TimestampUnit> {
+    prop_oneof![
+        Just(TimestampUnit::Second),
+        Just(TimestampUnit::Millisecond),
+        Just(TimestampUnit::Microsecond),
+        Just(TimestampUnit::Nanosecond),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn from_timestamp((value, unit, seconds, nanos) in prop_oneof![
+        Just((0, TimestampUnit::Second, 0, 0)),
+        Just((-1, TimestampUnit::Second, -1, 0)),
+        Just((i64::MIN, TimestampUnit::Second, i64::MIN, 0)),
+        Just((i64::MAX, TimestampUnit::Second, i64::MAX, 0)),
+        Just((1_500, TimestampUnit::Millisecond, 1, 500_000_000)),
+        Just((-1, TimestampUnit::Millisecond, -1, 999_000_000)),
+        Just((-1_500, TimestampUnit::Microsecond, -1, 998_500_000)),
+        Just((1_718_453_421_123_456, TimestampUnit::Microsecond, 1_718_453_421, 123_456_000)),
+        Just((-1, TimestampUnit::Nanosecond, -1, 999_999_999)),
+        Just((i64::MIN, TimestampUnit::Nanosecond, -9_223_372_037, 145_224_192)),
+        Just((i64::MAX, TimestampUnit::Nanosecond, 9_223_372_036, 854_775_807)),
+    ]) {
+        prop_assert_eq!(Instant::of_epoch_second_and_adjustment(seconds, nanos), Instant::from_timestamp(value, unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_timestamp((instant, unit, expected) in prop_oneof![
+        Just((Instant::of_epoch_second_and_adjustment(0, 999_999_999), TimestampUnit::Second, Ok(0))),
+        Just((Instant::of_epoch_second_and_adjustment(0, 999_999_999), TimestampUnit::Millisecond, Ok(999))),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 999_999_999), TimestampUnit::Second, Ok(-1))),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 999_999_999), TimestampUnit::Millisecond, Ok(-1))),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 999_999_999), TimestampUnit::Microsecond, Ok(-1))),
+        Just((Instant::of_epoch_second_and_adjustment(-1, 999_999_999), TimestampUnit::Nanosecond, Ok(-1))),
+        Just((Instant::of_epoch_second_and_adjustment(-2, 1), TimestampUnit::Millisecond, Ok(-2_000))),
+        Just((Instant::MIN, TimestampUnit::Second, Ok(i64::MIN))),
+        Just((Instant::MAX, TimestampUnit::Second, Ok(i64::MAX))),
+        Just((Instant::MIN, TimestampUnit::Millisecond, Err(TimestampOverflowError::new(TimestampUnit::Millisecond)))),
+        Just((Instant::MAX, TimestampUnit::Microsecond, Err(TimestampOverflowError::new(TimestampUnit::Microsecond)))),
+        Just((Instant::of_epoch_second_and_adjustment(0, i64::MIN), TimestampUnit::Nanosecond, Ok(i64::MIN))),
+        Just((Instant::of_epoch_second_and_adjustment(-1, i64::MIN), TimestampUnit::Nanosecond, Err(TimestampOverflowError::new(TimestampUnit::Nanosecond)))),
+        Just((Instant::of_epoch_second_and_adjustment(0, i64::MAX), TimestampUnit::Nanosecond, Ok(i64::MAX))),
+        Just((Instant::of_epoch_second_and_adjustment(1, i64::MAX), TimestampUnit::Nanosecond, Err(TimestampOverflowError::new(TimestampUnit::Nanosecond)))),
+    ]) {
+        prop_assert_eq!(expected, instant.to_timestamp(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn timestamp_round_trips(value in prop::num::i64::ANY, unit in units()) {
+        prop_assert_eq!(Ok(value), Instant::from_timestamp(value, unit).to_timestamp(unit));
+    }
+}
+
+proptest! {
+    #[test]
+    fn truncation_preserves_order(first in prop::num::i64::ANY, second in prop::num::i64::ANY, unit in units()) {
+        let (first, second) = (Instant::from_timestamp(first, TimestampUnit::Nanosecond), Instant::from_timestamp(second, TimestampUnit::Nanosecond));
+        let (first_units, second_units) = (first.to_timestamp(unit).unwrap(), second.to_timestamp(unit).unwrap());
+        prop_assert!(first > second || first_units <= second_units);
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_timestamp_slice(values in prop::collection::vec(prop::num::i64::ANY, 0..8), unit in units()) {
+        let expected: Vec<Instant> = values.iter().map(|&value| Instant::from_timestamp(value, unit)).collect();
+        prop_assert_eq!(expected, Instant::from_timestamp_slice(&values, unit));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn delta_round_trips(start in 1_600_000_000i64..1_700_000_000, gaps in prop::collection::vec(0..60_000_000_000i64, 0..100)) {
+        // A sensor sampled roughly once a second, with jitter and occasional gaps of up to a minute.
+        let mut instants = vec![Instant::of_epoch_second(start)];
+        for gap in gaps {
+            let next = *instants.last().unwrap() + Duration::of_nanos(gap);
+            instants.push(next);
+        }
+
+        let (first, deltas) = Instant::delta_encode(&instants).unwrap().unwrap();
+        prop_assert_eq!(instants[0], first);
+        prop_assert_eq!(instants.len() - 1, deltas.len());
+        prop_assert!(deltas.iter().all(|&delta| delta >= 0));
+        prop_assert_eq!(Ok(instants), Instant::delta_decode(first, &deltas));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn delta_known_values((instants, deltas) in Just((
+        vec![
+            Instant::of_epoch_second_and_adjustment(10, 500_000_000),
+            Instant::of_epoch_second(11),
+            Instant::of_epoch_second_and_adjustment(9, 999_999_999),
+        ],
+        vec![500_000_000, -1_000_000_001],
+    ))) {
+        prop_assert_eq!(Ok(Some((instants[0], deltas.clone()))), Instant::delta_encode(&instants));
+        prop_assert_eq!(Ok(instants.clone()), Instant::delta_decode(instants[0], &deltas));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn delta_encode_overflow((instants, index) in prop_oneof![
+        Just((vec![Instant::EPOCH, Instant::of_epoch_second(1_000_000_000_000)], 1)),
+        Just((vec![Instant::EPOCH, Instant::EPOCH, Instant::MIN, Instant::MAX], 2)),
+    ]) {
+        prop_assert_eq!(Err(SliceOverflowError::new(index)), Instant::delta_encode(&instants));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn delta_decode_overflow((first, deltas, index) in prop_oneof![
+        Just((Instant::MAX, vec![1], 0)),
+        Just((Instant::MIN, vec![i64::MAX, i64::MIN + 1, -1], 2)),
+    ]) {
+        prop_assert_eq!(Err(SliceOverflowError::new(index)), Instant::delta_decode(first, &deltas));
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn delta_encode_empty(instants in Just(Vec::<Instant>::new())) {
+        prop_assert_eq!(Ok(None), Instant::delta_encode(&instants));
+    }
+}
+
+proptest! {
+    #[test]
+    fn to_timespec((instant, expected) in prop_oneof![
+        Just((Instant::EPOCH, (0, 0))),
+        // Before the epoch, the nanoseconds stay non-negative by borrowing a second.
+        Just((Instant::of_epoch_second_and_adjustment(-1, 500_000_000), (-1, 500_000_000))),
+        Just((Instant::of_epoch_milli(-1), (-1, 999_000_000))),
+        Just((Instant::of_epoch_second_and_adjustment(1_718_453_421, 123_456_789), (1_718_453_421, 123_456_789))),
+        Just((Instant::MIN, (i64::MIN, 0))),
+        Just((Instant::MAX, (i64::MAX, 999_999_999))),
+    ]) {
+        prop_assert_eq!(expected, instant.to_timespec());
+        prop_assert_eq!(Some(instant), Instant::from_timespec(expected.0, expected.1));
+    }
+}
+
+proptest! {
+    #[test]
+    fn timespec_round_trips(tv_sec in prop::num::i64::ANY, tv_nsec in 0..1_000_000_000i64) {
+        let instant = Instant::from_timespec(tv_sec, tv_nsec).unwrap();
+
+        prop_assert_eq!((tv_sec, tv_nsec), instant.to_timespec());
+    }
+}
+
+proptest! {
+    #[test]
+    fn from_timespec_invalid_nanos(tv_nsec in prop_oneof![Just(-1), Just(i64::MIN), Just(1_000_000_000), Just(i64::MAX), Just(1i64 << 32)]) {
+        prop_assert_eq!(None, Instant::from_timespec(0, tv_nsec));
+    }
+}
@@ -1,5 +1,97 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "rkyv")]
+mod archive;
+mod calendar;
+mod civil;
+mod clock;
+mod clock_format;
+mod compact;
 mod constants;
+mod cpu_time;
+mod day_of_week;
+#[cfg(feature = "std")]
+mod default_timeout;
+mod digits;
+mod display;
+mod duration;
+#[cfg(feature = "std")]
+mod duration_stats;
+mod duration_sum;
+mod error;
 mod instant;
+mod macros;
+#[cfg(feature = "std")]
+mod monotonic;
+mod parse;
+mod parts;
+#[cfg(feature = "sqlx-postgres")]
+mod postgres;
+mod range;
+mod rate;
+mod recurrence;
+mod relative;
+mod rounding;
+mod saturating;
 mod seconds_nanos;
+#[cfg(feature = "serde")]
+mod serialization;
+mod split;
+mod temporal;
+#[cfg(feature = "std")]
+pub mod test_util;
+#[cfg(test)]
+mod testing;
+#[cfg(feature = "std")]
+mod time_series;
+mod timestamp_unit;
+mod tolerance;
+mod unit;
+pub mod wire;
 
+pub use crate::calendar::{Calendar, MonthStarts};
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub use crate::clock::JsClock;
+#[cfg(feature = "std")]
+pub use crate::clock::SystemClock;
+pub use crate::clock::{Clock, FixedClock};
+pub use crate::clock_format::ClockFormat;
+pub use crate::compact::CompactBytes;
+pub use crate::cpu_time::CpuTime;
+pub use crate::day_of_week::DayOfWeek;
+#[cfg(feature = "std")]
+pub use crate::default_timeout::{default_timeout, DefaultTimeout};
+pub use crate::display::{DurationDisplay, DurationStyle, InstantDisplay};
+pub use crate::duration::Duration;
+#[cfg(feature = "std")]
+pub use crate::duration_stats::DurationStats;
+pub use crate::duration_sum::DurationSum;
+#[cfg(feature = "wasm")]
+pub use crate::error::JsMillisError;
+pub use crate::error::{
+    CivilField, CivilFieldError, DecodeError, DecomposeError, OutOfRangeError, ParseError,
+    ParseErrorKind, SliceOverflowError, TemporalError, TimestampOverflowError,
+};
 pub use crate::instant::Instant;
+#[cfg(feature = "std")]
+pub use crate::monotonic::MonotonicInstantSource;
+pub use crate::parts::Parts;
+pub use crate::range::DurationRange;
+pub use crate::rate::Rate;
+pub use crate::recurrence::{
+    LeapDayPolicy, MissingDayPolicy, MonthlyOccurrences, MonthlyRecurrence, YearlyOccurrences,
+    YearlyRecurrence,
+};
+pub use crate::relative::{RelativeTime, RelativeTimeOptions};
+pub use crate::rounding::RoundingMode;
+pub use crate::saturating::Saturating;
+pub use crate::split::SplitEvenly;
+pub use crate::temporal::{Temporal, TemporalAmount};
+#[cfg(feature = "std")]
+pub use crate::time_series::TimeSeries;
+pub use crate::timestamp_unit::TimestampUnit;
+pub use crate::tolerance::Tolerance;
+pub use crate::unit::{EstimatedUnit, Unit};
+
+#[cfg(feature = "rkyv")]
+pub use crate::archive::{ArchivedDuration, ArchivedInstant, InvalidNanosError};
@@ -0,0 +1,58 @@
+/// Creates a [`Duration`](struct.Duration.html) from terms of whole units, such as `duration!(2 h, 30 min)`,
+/// evaluated at compile time.
+///
+/// Each term is an integer literal followed by a unit:
+/// `d` (standard 24-hour days), `h`, `min`, `s`, `ms`, `us`, or `ns`.
+/// The terms are added, and a leading `-` negates the whole duration, so `duration!(-2 h, 30 min)` is
+/// minus two and a half hours, as for the ISO-8601 `-PT2H30M`. Terms may not have their own signs.
+///
+/// The duration is computed in a const item, so it may be used to initialize constants,
+/// and a duration that would overflow, or an unknown unit, fails to compile.
+#[macro_export]
+macro_rules! duration {
+    (- $($value:tt $unit:ident),+ $(,)?) => {{
+        const DURATION: $crate::Duration =
+            $crate::Duration::__of_literal_nanos(-(0 $(+ $value * $crate::__duration_unit_nanos!($unit))+));
+        DURATION
+    }};
+    ($($value:tt $unit:ident),+ $(,)?) => {{
+        const DURATION: $crate::Duration =
+            $crate::Duration::__of_literal_nanos(0 $(+ $value * $crate::__duration_unit_nanos!($unit))+);
+        DURATION
+    }};
+}
+
+// The nanoseconds in each unit accepted by `duration!`, as an `i128` so that the terms do not overflow before
+// the total is checked.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __duration_unit_nanos {
+    (d) => {
+        86_400_000_000_000i128
+    };
+    (h) => {
+        3_600_000_000_000i128
+    };
+    (min) => {
+        60_000_000_000i128
+    };
+    (s) => {
+        1_000_000_000i128
+    };
+    (ms) => {
+        1_000_000i128
+    };
+    (us) => {
+        1_000i128
+    };
+    (ns) => {
+        1i128
+    };
+    ($unit:ident) => {
+        compile_error!(concat!(
+            "unknown duration unit `",
+            stringify!($unit),
+            "`; expected d, h, min, s, ms, us, or ns"
+        ))
+    };
+}
@@ -0,0 +1,72 @@
+use core::convert::TryFrom;
+use core::sync::atomic::{AtomicI64, Ordering};
+
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::Instant;
+
+#[cfg(test)]
+pub mod ordering;
+
+/// A source of strictly increasing instants, such as for deriving unique, ordered identifiers from the time.
+///
+/// Each call returns the later of the current time and one nanosecond after the previous result,
+/// so the results keep increasing even when the system clock is coarse, stalls, or is set backwards.
+/// During bursts of calls (or after the clock is set backwards) the results may run ahead of the wall clock,
+/// rejoining it once the clock catches up.
+///
+/// The previous result is held in a single atomic count of nanoseconds since the epoch, so one source may be
+/// shared between threads without locking. This limits the results to the years 1677 to 2262.
+#[derive(Debug)]
+pub struct MonotonicInstantSource {
+    last: AtomicI64,
+}
+
+impl MonotonicInstantSource {
+    /// Creates a source that has not yet returned any instant.
+    pub const fn new() -> MonotonicInstantSource {
+        MonotonicInstantSource {
+            last: AtomicI64::new(i64::MIN),
+        }
+    }
+
+    /// Gets the current instant, or one nanosecond after the previous result if that is later.
+    ///
+    /// # Panics
+    /// - if the result would be outside the years 1677 to 2262.
+    pub fn now(&self) -> Instant {
+        self.next_after(Instant::now())
+    }
+
+    pub(crate) fn next_after(&self, now: Instant) -> Instant {
+        let now = i64::try_from(total_nanos(now.epoch_second(), now.nano()))
+            .expect("instant is outside the range of a monotonic source");
+        let mut last = self.last.load(Ordering::Relaxed);
+        loop {
+            let next = if now > last {
+                now
+            } else {
+                last.checked_add(1)
+                    .expect("instant is outside the range of a monotonic source")
+            };
+            match self
+                .last
+                .compare_exchange_weak(last, next, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                Ok(_) => {
+                    return Instant::of_epoch_second_and_adjustment(
+                        next.div_euclid(NANOSECONDS_IN_SECOND),
+                        next.rem_euclid(NANOSECONDS_IN_SECOND),
+                    )
+                }
+                Err(current) => last = current,
+            }
+        }
+    }
+}
+
+impl Default for MonotonicInstantSource {
+    fn default() -> MonotonicInstantSource {
+        MonotonicInstantSource::new()
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+use proptest::prelude::*;
+
+use crate::{Duration, Instant, MonotonicInstantSource};
+
+#[test]
+fn rapid_calls_strictly_increase() {
+    let source = MonotonicInstantSource::new();
+    let mut previous = source.now();
+
+    for _ in 0..100_000 {
+        let next = source.now();
+        assert!(next > previous, "{} {}", previous, next);
+        previous = next;
+    }
+}
+
+proptest! {
+    #[test]
+    fn stalled_or_backwards_clock_still_increases(steps in prop::collection::vec(-1_000_000i64..1_000_000, 1..100)) {
+        let source = MonotonicInstantSource::new();
+        let mut clock = Instant::of_epoch_second(1_700_000_000);
+        let mut previous = source.next_after(clock);
+
+        for step in steps {
+            clock += Duration::of_nanos(step);
+            let next = source.next_after(clock);
+
+            prop_assert!(next > previous);
+            prop_assert_eq!(next, clock.max(previous + Duration::of_nanos(1)));
+            previous = next;
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn first_call_is_the_current_time(seconds in -9_000_000_000i64..9_000_000_000, nanos in 0..1_000_000_000i64) {
+        let now = Instant::of_epoch_second_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(now, MonotonicInstantSource::new().next_after(now));
+    }
+}
+
+#[test]
+fn concurrent_calls_are_unique() {
+    let source = Arc::new(MonotonicInstantSource::new());
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let source = Arc::clone(&source);
+            thread::spawn(move || {
+                let results: Vec<Instant> = (0..10_000).map(|_| source.now()).collect();
+                assert!(results.windows(2).all(|pair| pair[0] < pair[1]));
+                results
+            })
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    for thread in threads {
+        for instant in thread.join().unwrap() {
+            assert!(seen.insert(instant), "{}", instant);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "instant is outside the range of a monotonic source")]
+fn out_of_range() {
+    MonotonicInstantSource::new().next_after(Instant::of_epoch_second(10_000_000_000));
+}
@@ -0,0 +1,521 @@
+//! Byte-level parsing shared by the text formats.
+//!
+//! Parsing works directly on bytes, without allocating, so callers holding byte buffers can skip UTF-8 validation.
+
+use core::convert::TryFrom;
+
+use crate::civil::*;
+use crate::constants::*;
+use crate::error::{ParseError, ParseErrorKind};
+use crate::seconds_nanos::*;
+
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(bytes: &'a [u8]) -> Cursor<'a> {
+        Cursor { bytes, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn error_at(&self, kind: ParseErrorKind, position: usize) -> ParseError {
+        ParseError::new(kind, position)
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    pub fn next(&mut self) -> Result<u8, ParseError> {
+        let byte = self.peek().ok_or_else(|| self.end())?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    // Consumes the byte if it is next, returning whether it was.
+    pub fn accept(&mut self, byte: u8) -> bool {
+        let accepted = self.peek() == Some(byte);
+        self.position += usize::from(accepted);
+        accepted
+    }
+
+    pub fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        self.expect_any(&[byte]).map(|_| ())
+    }
+
+    pub fn expect_any(&mut self, options: &[u8]) -> Result<u8, ParseError> {
+        let byte = self.next()?;
+        if options.contains(&byte) {
+            Ok(byte)
+        } else {
+            Err(self.error_at(ParseErrorKind::InvalidCharacter, self.position - 1))
+        }
+    }
+
+    pub fn digit(&mut self) -> Result<u32, ParseError> {
+        let byte = self.next()?;
+        let digit = byte.wrapping_sub(b'0');
+        if digit < 10 {
+            Ok(u32::from(digit))
+        } else {
+            Err(self.error_at(ParseErrorKind::InvalidCharacter, self.position - 1))
+        }
+    }
+
+    // Reads a fixed-width field of two digits.
+    pub fn digits2(&mut self) -> Result<u32, ParseError> {
+        match self.bytes.get(self.position..self.position + 2) {
+            Some(&[tens, ones]) => {
+                let (tens, ones) = (tens.wrapping_sub(b'0'), ones.wrapping_sub(b'0'));
+                if tens >= 10 {
+                    Err(self.error_at(ParseErrorKind::InvalidCharacter, self.position))
+                } else if ones >= 10 {
+                    Err(self.error_at(ParseErrorKind::InvalidCharacter, self.position + 1))
+                } else {
+                    self.position += 2;
+                    Ok(u32::from(tens) * 10 + u32::from(ones))
+                }
+            }
+            _ => self.digit().and_then(|_| self.digit()),
+        }
+    }
+
+    // Reads a fixed-width field of four digits.
+    pub fn digits4(&mut self) -> Result<u32, ParseError> {
+        let high = self.digits2()?;
+        let low = self.digits2()?;
+        Ok(high * 100 + low)
+    }
+
+    // Reads a fixed-width field of up to 19 digits.
+    pub fn digits_n(&mut self, count: usize) -> Result<u64, ParseError> {
+        debug_assert!(count <= 19);
+        let mut value = 0;
+        for _ in 0..count {
+            value = value * 10 + u64::from(self.digit()?);
+        }
+        Ok(value)
+    }
+
+    // Reads the digits of a fraction of a second (after the decimal point) as nanoseconds,
+    // requiring between one and nine digits.
+    pub fn fraction_nanos(&mut self) -> Result<u32, ParseError> {
+        const SCALE: [u32; 10] = [
+            1_000_000_000,
+            100_000_000,
+            10_000_000,
+            1_000_000,
+            100_000,
+            10_000,
+            1_000,
+            100,
+            10,
+            1,
+        ];
+        let start = self.position;
+        let mut nanos = self.digit()?;
+        while let Some(digit) = self.peek().map(|byte| byte.wrapping_sub(b'0')) {
+            if digit >= 10 {
+                break;
+            }
+            if self.position - start == 9 {
+                return Err(self.error_at(ParseErrorKind::FieldOutOfRange, self.position));
+            }
+            nanos = nanos * 10 + u32::from(digit);
+            self.position += 1;
+        }
+        Ok(nanos * SCALE[self.position - start])
+    }
+
+    // Reads like `fraction_nanos`, but allows no digits at all (as java.time does for `PT1.S`).
+    pub fn fraction_nanos_or_empty(&mut self) -> Result<u32, ParseError> {
+        match self.peek() {
+            Some(byte) if byte.is_ascii_digit() => self.fraction_nanos(),
+            _ => Ok(0),
+        }
+    }
+
+    // Reads an optionally signed number of up to 19 digits that fits in an `i64`,
+    // also returning whether it had a minus sign (so that `-0` can be told apart from `0`).
+    pub fn signed_number(&mut self) -> Result<(i128, bool), ParseError> {
+        let start = self.position;
+        let negative = match self.peek() {
+            Some(b'-') => self.next().map(|_| true)?,
+            Some(b'+') => self.next().map(|_| false)?,
+            _ => false,
+        };
+        let mut value = i128::from(self.digit()?);
+        while let Some(digit) = self.peek().map(|byte| byte.wrapping_sub(b'0')) {
+            if digit >= 10 {
+                break;
+            }
+            value = value * 10 + i128::from(digit);
+            if value > i128::from(i64::MAX) + 1 {
+                return Err(self.error_at(ParseErrorKind::FieldOutOfRange, start));
+            }
+            self.position += 1;
+        }
+        if negative {
+            Ok((-value, true))
+        } else if value > i128::from(i64::MAX) {
+            Err(self.error_at(ParseErrorKind::FieldOutOfRange, start))
+        } else {
+            Ok((value, false))
+        }
+    }
+
+    // Reads a two digit field, checking it is no larger than the maximum.
+    pub fn field2(&mut self, max: u32) -> Result<u32, ParseError> {
+        let start = self.position;
+        let value = self.digits2()?;
+        if value > max {
+            Err(self.error_at(ParseErrorKind::FieldOutOfRange, start))
+        } else {
+            Ok(value)
+        }
+    }
+
+    pub fn finish(&self) -> Result<(), ParseError> {
+        if self.position == self.bytes.len() {
+            Ok(())
+        } else {
+            Err(self.error_at(ParseErrorKind::TrailingCharacters, self.position))
+        }
+    }
+
+    fn end(&self) -> ParseError {
+        self.error_at(ParseErrorKind::UnexpectedEnd, self.bytes.len())
+    }
+}
+
+// Parses an RFC 3339 timestamp, such as `2007-12-03T10:15:30.123+01:00`, into epoch seconds and nanoseconds.
+pub fn parse_rfc3339(bytes: &[u8]) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let (local_second, nanos) = date_time(&mut cursor)?;
+    let offset_seconds = offset(&mut cursor)?;
+    cursor.finish()?;
+
+    Ok((local_second - offset_seconds, nanos))
+}
+
+// Parses a date and time in one of the common formats listed on `Instant::parse_flexible`,
+// such as `2006-01-02 15:04:05` or `Mon Jan  2 15:04:05 2006`, into epoch seconds and nanoseconds.
+// Text that starts with a letter is read as `asctime` or `date` output, and anything else as ISO-8601.
+pub fn parse_flexible(bytes: &[u8], offset_seconds: i32) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let (local_second, nanos, offset_seconds) = if matches!(cursor.peek(), Some(byte) if byte.is_ascii_alphabetic())
+    {
+        asctime(&mut cursor, i64::from(offset_seconds))?
+    } else {
+        // `2006-01-02 15:04:05`, with an offset if the text has one.
+        let (local_second, nanos) = date_time(&mut cursor)?;
+        let offset_seconds = if cursor.peek().is_some() {
+            offset(&mut cursor)?
+        } else {
+            i64::from(offset_seconds)
+        };
+        (local_second, nanos, offset_seconds)
+    };
+    cursor.finish()?;
+
+    Ok((local_second - offset_seconds, nanos))
+}
+
+const WEEKDAY_NAMES: [[u8; 3]; 7] = [
+    *b"Mon", *b"Tue", *b"Wed", *b"Thu", *b"Fri", *b"Sat", *b"Sun",
+];
+
+const MONTH_NAMES: [[u8; 3]; 12] = [
+    *b"Jan", *b"Feb", *b"Mar", *b"Apr", *b"May", *b"Jun", *b"Jul", *b"Aug", *b"Sep", *b"Oct",
+    *b"Nov", *b"Dec",
+];
+
+// Reads `asctime` or `date` output, such as `Mon Jan  2 15:04:05 2006`, optionally with a zone of `UTC` or `GMT`
+// before the year, into local epoch seconds, nanoseconds, and the offset in seconds.
+fn asctime(cursor: &mut Cursor, default_offset: i64) -> Result<(i64, u32, i64), ParseError> {
+    let weekday_position = cursor.position();
+    if name_index(cursor, &WEEKDAY_NAMES).is_none() {
+        return Err(cursor.error_at(ParseErrorKind::InvalidCharacter, weekday_position));
+    }
+    cursor.expect(b' ')?;
+    let month_position = cursor.position();
+    let month = name_index(cursor, &MONTH_NAMES)
+        .ok_or_else(|| cursor.error_at(ParseErrorKind::InvalidCharacter, month_position))?
+        as u32
+        + 1;
+    cursor.expect(b' ')?;
+    // The day is padded with a space, as by `asctime`, but a zero or no padding is also accepted.
+    cursor.accept(b' ');
+    let day_position = cursor.position();
+    let mut day = cursor.digit()?;
+    if let Some(digit) = cursor.peek().filter(u8::is_ascii_digit) {
+        cursor.next()?;
+        day = day * 10 + u32::from(digit - b'0');
+    }
+    cursor.expect(b' ')?;
+    let (hour, minute, second, nanos) = time(cursor)?;
+    cursor.expect(b' ')?;
+    let offset_seconds = if matches!(cursor.peek(), Some(byte) if byte.is_ascii_alphabetic()) {
+        let zone_position = cursor.position();
+        if name_index(cursor, &[*b"UTC", *b"GMT"]).is_none() {
+            return Err(cursor.error_at(ParseErrorKind::InvalidCharacter, zone_position));
+        }
+        cursor.expect(b' ')?;
+        0
+    } else {
+        default_offset
+    };
+    let year = i64::from(cursor.digits4()?);
+    if day == 0 || day > days_in_month(year, month) {
+        return Err(cursor.error_at(ParseErrorKind::FieldOutOfRange, day_position));
+    }
+    let local_second = epoch_day_of(year, month, day) * SECONDS_IN_DAY
+        + i64::from(hour) * SECONDS_IN_HOUR
+        + i64::from(minute) * SECONDS_IN_MINUTE
+        + i64::from(second);
+    Ok((local_second, nanos, offset_seconds))
+}
+
+// Reads one of the three-letter names, returning its index.
+fn name_index(cursor: &mut Cursor, names: &[[u8; 3]]) -> Option<usize> {
+    let start = cursor.position();
+    let index = names
+        .iter()
+        .position(|name| cursor.bytes.get(start..start + 3) == Some(&name[..]))?;
+    cursor.position += 3;
+    Some(index)
+}
+
+// Reads an ISO-8601 date and time, such as `2007-12-03T10:15:30.123`, into local epoch seconds and nanoseconds.
+fn date_time(cursor: &mut Cursor) -> Result<(i64, u32), ParseError> {
+    let year = i64::from(cursor.digits4()?);
+    cursor.expect(b'-')?;
+    let month_position = cursor.position();
+    let month = cursor.digits2()?;
+    if month == 0 || month > 12 {
+        return Err(cursor.error_at(ParseErrorKind::FieldOutOfRange, month_position));
+    }
+    cursor.expect(b'-')?;
+    let day_position = cursor.position();
+    let day = cursor.digits2()?;
+    if day == 0 || day > days_in_month(year, month) {
+        return Err(cursor.error_at(ParseErrorKind::FieldOutOfRange, day_position));
+    }
+
+    cursor.expect_any(b"Tt ")?;
+    let (hour, minute, second, nanos) = time(cursor)?;
+
+    let local_second = epoch_day_of(year, month, day) * SECONDS_IN_DAY
+        + i64::from(hour) * SECONDS_IN_HOUR
+        + i64::from(minute) * SECONDS_IN_MINUTE
+        + i64::from(second);
+    Ok((local_second, nanos))
+}
+
+// Reads a time of day, such as `10:15:30.123`, with an optional fraction of the second.
+fn time(cursor: &mut Cursor) -> Result<(u32, u32, u32, u32), ParseError> {
+    let hour = cursor.field2(23)?;
+    cursor.expect(b':')?;
+    let minute = cursor.field2(59)?;
+    cursor.expect(b':')?;
+    let second = cursor.field2(59)?;
+    let nanos = if cursor.accept(b'.') {
+        cursor.fraction_nanos()?
+    } else {
+        0
+    };
+    Ok((hour, minute, second, nanos))
+}
+
+// Reads an RFC 3339 offset of `Z`, `z`, or `±HH:MM`, into seconds.
+fn offset(cursor: &mut Cursor) -> Result<i64, ParseError> {
+    match cursor.expect_any(b"Zz+-")? {
+        b'Z' | b'z' => Ok(0),
+        sign => {
+            let offset_hours = cursor.field2(23)?;
+            cursor.expect(b':')?;
+            let offset_minutes = cursor.field2(59)?;
+            let offset = i64::from(offset_hours) * SECONDS_IN_HOUR
+                + i64::from(offset_minutes) * SECONDS_IN_MINUTE;
+            Ok(if sign == b'-' { -offset } else { offset })
+        }
+    }
+}
+
+// Parses plain decimal seconds, such as `1718453421.123456789` or `-0.5`, into (floored) seconds and nanoseconds.
+pub fn parse_decimal_seconds(bytes: &[u8]) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let (seconds, negative) = cursor.signed_number()?;
+    let nanos = if cursor.accept(b'.') {
+        i128::from(cursor.fraction_nanos()?)
+    } else {
+        0
+    };
+    cursor.finish()?;
+
+    // The fraction carries the sign of the whole value, so `-0.5` is half a second before the epoch.
+    let total = seconds * i128::from(NANOSECONDS_IN_SECOND) + if negative { -nanos } else { nanos };
+    of_total_nanos_checked(total).ok_or_else(|| cursor.error_at(ParseErrorKind::FieldOutOfRange, 0))
+}
+
+// Parses a clock-time duration, such as `01:23:45.678` or `-05:30`, into seconds and nanoseconds.
+// Two fields are minutes and seconds; three fields are hours, minutes, and seconds, with unbounded hours.
+pub fn parse_clock(bytes: &[u8]) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let negative = cursor.accept(b'-');
+    let leading_position = cursor.position();
+    let mut leading = u64::from(cursor.digit()?);
+    while let Some(digit) = cursor.peek().map(|byte| byte.wrapping_sub(b'0')) {
+        if digit >= 10 {
+            break;
+        }
+        leading = leading
+            .checked_mul(10)
+            .and_then(|leading| leading.checked_add(u64::from(digit)))
+            .ok_or_else(|| cursor.error_at(ParseErrorKind::FieldOutOfRange, leading_position))?;
+        cursor.next()?;
+    }
+    let leading_digits = cursor.position() - leading_position;
+    cursor.expect(b':')?;
+    let middle = cursor.field2(59)?;
+    let (hours, minutes, seconds) = if cursor.accept(b':') {
+        (leading, middle, cursor.field2(59)?)
+    } else if leading_digits > 2 || leading > 59 {
+        return Err(cursor.error_at(ParseErrorKind::FieldOutOfRange, leading_position));
+    } else {
+        (0, leading as u32, middle)
+    };
+    let nanos = if cursor.accept(b'.') {
+        cursor.fraction_nanos()?
+    } else {
+        0
+    };
+    cursor.finish()?;
+
+    let magnitude = i128::from(hours) * i128::from(NANOSECONDS_IN_HOUR)
+        + i128::from(minutes) * i128::from(NANOSECONDS_IN_MINUTE)
+        + i128::from(seconds) * i128::from(NANOSECONDS_IN_SECOND)
+        + i128::from(nanos);
+    of_total_nanos_checked(if negative { -magnitude } else { magnitude })
+        .ok_or_else(|| cursor.error_at(ParseErrorKind::FieldOutOfRange, leading_position))
+}
+
+// Parses the fixed-width ISO-8601 duration rendering, such as `PT0000000000000001H05M03.250000000S`,
+// into seconds and nanoseconds.
+pub fn parse_fixed_iso(bytes: &[u8]) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let negative = cursor.accept(b'-');
+    cursor.expect(b'P')?;
+    cursor.expect(b'T')?;
+    let hours_position = cursor.position();
+    let hours = cursor.digits_n(FIXED_ISO_HOUR_DIGITS)?;
+    cursor.expect(b'H')?;
+    let minutes = cursor.field2(59)?;
+    cursor.expect(b'M')?;
+    let seconds = cursor.field2(59)?;
+    cursor.expect(b'.')?;
+    let nanos = cursor.digits_n(9)?;
+    cursor.expect(b'S')?;
+    cursor.finish()?;
+
+    let magnitude = i128::from(hours) * i128::from(NANOSECONDS_IN_HOUR)
+        + i128::from(minutes) * i128::from(NANOSECONDS_IN_MINUTE)
+        + i128::from(seconds) * i128::from(NANOSECONDS_IN_SECOND)
+        + i128::from(nanos);
+    of_total_nanos_checked(if negative { -magnitude } else { magnitude })
+        .ok_or_else(|| cursor.error_at(ParseErrorKind::FieldOutOfRange, hours_position))
+}
+
+// Parses the sortable instant key, such as `09223372038573229229.123456789`,
+// of the epoch second biased by 2^63 and the nanosecond of second, into seconds and nanoseconds.
+pub fn parse_sortable(bytes: &[u8]) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    // Twenty digits are more than `digits_n` reads, so the leading digit is read on its own.
+    let leading = cursor.digit()?;
+    let biased_seconds = u64::try_from(
+        u128::from(leading) * 10u128.pow(SORTABLE_SECONDS_DIGITS as u32 - 1)
+            + u128::from(cursor.digits_n(SORTABLE_SECONDS_DIGITS - 1)?),
+    )
+    .map_err(|_| cursor.error_at(ParseErrorKind::FieldOutOfRange, 0))?;
+    cursor.expect(b'.')?;
+    let nanos = cursor.digits_n(9)? as u32;
+    cursor.finish()?;
+
+    Ok((of_biased_seconds(biased_seconds), nanos))
+}
+
+// Parses an ISO-8601 duration in the form accepted by java.time's `Duration.parse`, such as `PT8M20.345S`,
+// `P2DT3H`, or `-PT-1M-0.5S`, into seconds and nanoseconds.
+pub fn parse_iso_duration(bytes: &[u8]) -> Result<(i64, u32), ParseError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let negate = match cursor.peek() {
+        Some(b'-') => cursor.next().map(|_| true)?,
+        Some(b'+') => cursor.next().map(|_| false)?,
+        _ => false,
+    };
+    cursor.expect_any(b"Pp")?;
+
+    // Days are required unless a time follows.
+    let mut total = 0;
+    if !matches!(cursor.peek(), Some(b'T') | Some(b't')) {
+        let (days, _) = cursor.signed_number()?;
+        if let Some(b'Y') | Some(b'y') | Some(b'M') | Some(b'm') = cursor.peek() {
+            return Err(cursor.error_at(ParseErrorKind::CalendarDesignator, cursor.position()));
+        }
+        cursor.expect_any(b"Dd")?;
+        total += days * i128::from(NANOSECONDS_IN_DAY);
+    }
+
+    if cursor.accept(b'T') || cursor.accept(b't') {
+        // Each of hours, minutes, and seconds may appear at most once, in that order.
+        let mut next_allowed = 0;
+        loop {
+            let (amount, negative) = cursor.signed_number()?;
+            let fraction = if cursor.accept(b'.') || cursor.accept(b',') {
+                Some(cursor.fraction_nanos_or_empty()?)
+            } else {
+                None
+            };
+            let designator_position = cursor.position();
+            let (order, unit_nanos) = match (cursor.expect_any(b"HhMmSs")?, fraction) {
+                (b'H', None) | (b'h', None) => (0, NANOSECONDS_IN_HOUR),
+                (b'M', None) | (b'm', None) => (1, NANOSECONDS_IN_MINUTE),
+                (b'S', _) | (b's', _) => (2, NANOSECONDS_IN_SECOND),
+                _ => {
+                    return Err(
+                        cursor.error_at(ParseErrorKind::InvalidCharacter, designator_position)
+                    )
+                }
+            };
+            if order < next_allowed {
+                return Err(cursor.error_at(ParseErrorKind::InvalidCharacter, designator_position));
+            }
+            next_allowed = order + 1;
+
+            total += amount * i128::from(unit_nanos);
+            // The fraction takes the sign of the seconds, so `-0.5S` is negative.
+            let fraction = i128::from(fraction.unwrap_or(0));
+            total += if negative { -fraction } else { fraction };
+
+            if cursor.peek().is_none() || next_allowed > 2 {
+                break;
+            }
+        }
+    }
+    cursor.finish()?;
+
+    of_total_nanos_checked(if negate { -total } else { total })
+        .ok_or_else(|| cursor.error_at(ParseErrorKind::FieldOutOfRange, 0))
+}
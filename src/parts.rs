@@ -0,0 +1,62 @@
+use core::iter::FusedIterator;
+
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::{Duration, Unit};
+
+const UNITS: [Unit; 7] = [
+    Unit::Days,
+    Unit::Hours,
+    Unit::Minutes,
+    Unit::Seconds,
+    Unit::Millis,
+    Unit::Micros,
+    Unit::Nanos,
+];
+
+/// An iterator over the non-zero components of the magnitude of a duration, from largest to smallest unit.
+///
+/// This is created by [`Duration::parts`](struct.Duration.html#method.parts).
+/// Each component is the amount of its unit left over from the larger units, so that the sub-second components
+/// are disjoint: `PT1.0023S` yields 1 second, 2 milliseconds, and 300 microseconds.
+#[derive(Clone, Debug)]
+pub struct Parts {
+    amounts: [u64; 7],
+    index: usize,
+}
+
+impl Parts {
+    pub(crate) fn of(duration: Duration) -> Parts {
+        let (_, seconds, nanos) = sign_and_magnitude(duration.seconds(), duration.nano());
+        let nanos = u64::from(nanos);
+        Parts {
+            amounts: [
+                seconds / SECONDS_IN_DAY as u64,
+                seconds % SECONDS_IN_DAY as u64 / SECONDS_IN_HOUR as u64,
+                seconds % SECONDS_IN_HOUR as u64 / SECONDS_IN_MINUTE as u64,
+                seconds % SECONDS_IN_MINUTE as u64,
+                nanos / NANOSECONDS_IN_MILLISECOND as u64,
+                nanos % NANOSECONDS_IN_MILLISECOND as u64 / NANOSECONDS_IN_MICROSECOND as u64,
+                nanos % NANOSECONDS_IN_MICROSECOND as u64,
+            ],
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for Parts {
+    type Item = (Unit, u64);
+
+    fn next(&mut self) -> Option<(Unit, u64)> {
+        while self.index < UNITS.len() {
+            let index = self.index;
+            self.index += 1;
+            if self.amounts[index] != 0 {
+                return Some((UNITS[index], self.amounts[index]));
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for Parts {}
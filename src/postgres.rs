@@ -0,0 +1,191 @@
+//! [`sqlx`](https://docs.rs/sqlx) support for Postgres.
+//!
+//! - `Duration` maps to `INTERVAL`.
+//!   Durations are always encoded as microseconds, with zero days and months, as Postgres treats interval days as
+//!   calendar days (which are not always 24 hours long when added to a `TIMESTAMPTZ`).
+//!   When decoding, days are treated as exactly 24 hours, and intervals with months are rejected, as months have no fixed length.
+//! - `Instant` maps to `TIMESTAMPTZ`.
+//!   The conversion uses the raw epoch seconds, and performs no leap-second adjustment.
+//!   Postgres' `infinity` and `-infinity` decode to `Instant::MAX` and `Instant::MIN`,
+//!   and any instant too large (or small) for a `TIMESTAMPTZ` encodes to the respective sentinel.
+//!
+//! Postgres only stores microseconds, so encoding truncates any sub-microsecond nanoseconds
+//! toward negative infinity, consistently for both types: the encoded value is never later than the original.
+
+use sqlx_core::decode::Decode;
+use sqlx_core::encode::{Encode, IsNull};
+use sqlx_core::error::BoxDynError;
+use sqlx_core::types::Type;
+use sqlx_postgres::types::{Oid, PgInterval};
+use sqlx_postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+
+use crate::constants::*;
+use crate::{Duration, Instant};
+
+#[cfg(test)]
+pub mod wire;
+
+const TIMESTAMPTZ: PgTypeInfo = PgTypeInfo::with_oid(Oid(1184));
+const TIMESTAMPTZ_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(1185));
+const INTERVAL: PgTypeInfo = PgTypeInfo::with_oid(Oid(1186));
+const INTERVAL_ARRAY: PgTypeInfo = PgTypeInfo::with_oid(Oid(1187));
+
+// Seconds from 1970-01-01 (the epoch of this crate) to 2000-01-01 (the epoch of Postgres).
+const POSTGRES_EPOCH_OFFSET_SECONDS: i64 = 30 * DAYS_IN_YEAR * SECONDS_IN_DAY
+    // Leap days from 1970 to 2000:
+    // 1972, 1976, 1980, 1984, 1988, 1992, 1996
+    + 7 * SECONDS_IN_DAY;
+
+fn binary_bytes<'r>(value: PgValueRef<'r>, type_name: &str) -> Result<&'r [u8], BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => value.as_bytes(),
+        PgValueFormat::Text => {
+            Err(format!("decoding `{}` in text mode is not supported", type_name).into())
+        }
+    }
+}
+
+fn floor_micros(seconds: i64, nanos: u32) -> i128 {
+    i128::from(seconds) * i128::from(MICROSECONDS_IN_SECOND)
+        + i128::from(i64::from(nanos) / NANOSECONDS_IN_MICROSECOND)
+}
+
+fn duration_to_interval(duration: Duration) -> Option<PgInterval> {
+    let microseconds = floor_micros(duration.seconds(), duration.nano());
+    if microseconds < i128::from(i64::MIN) || microseconds > i128::from(i64::MAX) {
+        None
+    } else {
+        Some(PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: microseconds as i64,
+        })
+    }
+}
+
+fn interval_to_duration(interval: PgInterval) -> Result<Duration, BoxDynError> {
+    if interval.months != 0 {
+        return Err(format!(
+            "interval with {} months cannot be represented as an exact duration",
+            interval.months
+        )
+        .into());
+    }
+    Ok(Duration::of_seconds_and_adjustment(
+        i64::from(interval.days) * SECONDS_IN_DAY
+            + interval.microseconds.div_euclid(MICROSECONDS_IN_SECOND),
+        interval.microseconds.rem_euclid(MICROSECONDS_IN_SECOND) * NANOSECONDS_IN_MICROSECOND,
+    ))
+}
+
+fn decode_interval(bytes: &[u8]) -> Result<Duration, BoxDynError> {
+    if bytes.len() != 16 {
+        return Err(format!("expected 16 bytes for `INTERVAL`, got {}", bytes.len()).into());
+    }
+    let mut microseconds = [0; 8];
+    let mut days = [0; 4];
+    let mut months = [0; 4];
+    microseconds.copy_from_slice(&bytes[0..8]);
+    days.copy_from_slice(&bytes[8..12]);
+    months.copy_from_slice(&bytes[12..16]);
+    interval_to_duration(PgInterval {
+        months: i32::from_be_bytes(months),
+        days: i32::from_be_bytes(days),
+        microseconds: i64::from_be_bytes(microseconds),
+    })
+}
+
+fn instant_to_timestamp(instant: Instant) -> i64 {
+    let microseconds = floor_micros(instant.epoch_second(), instant.nano())
+        - i128::from(POSTGRES_EPOCH_OFFSET_SECONDS) * i128::from(MICROSECONDS_IN_SECOND);
+    if microseconds >= i128::from(i64::MAX) {
+        i64::MAX
+    } else if microseconds <= i128::from(i64::MIN) {
+        i64::MIN
+    } else {
+        microseconds as i64
+    }
+}
+
+fn timestamp_to_instant(microseconds: i64) -> Instant {
+    match microseconds {
+        i64::MAX => Instant::MAX,
+        i64::MIN => Instant::MIN,
+        _ => Instant::of_epoch_second_and_adjustment(
+            microseconds.div_euclid(MICROSECONDS_IN_SECOND) + POSTGRES_EPOCH_OFFSET_SECONDS,
+            microseconds.rem_euclid(MICROSECONDS_IN_SECOND) * NANOSECONDS_IN_MICROSECOND,
+        ),
+    }
+}
+
+fn decode_timestamp(bytes: &[u8]) -> Result<Instant, BoxDynError> {
+    if bytes.len() != 8 {
+        return Err(format!("expected 8 bytes for `TIMESTAMPTZ`, got {}", bytes.len()).into());
+    }
+    let mut microseconds = [0; 8];
+    microseconds.copy_from_slice(bytes);
+    Ok(timestamp_to_instant(i64::from_be_bytes(microseconds)))
+}
+
+impl Type<Postgres> for Duration {
+    fn type_info() -> PgTypeInfo {
+        INTERVAL
+    }
+}
+
+impl PgHasArrayType for Duration {
+    fn array_type_info() -> PgTypeInfo {
+        INTERVAL_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for Duration {
+    /// # Errors
+    /// - if the duration is larger than the ~292,000 years of microseconds a Postgres interval can hold.
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        duration_to_interval(*self)
+            .ok_or_else(|| format!("duration {} is out of range for a Postgres interval", self))?
+            .encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Duration {
+    fn decode(value: PgValueRef<'r>) -> Result<Duration, BoxDynError> {
+        decode_interval(binary_bytes(value, "INTERVAL")?)
+    }
+}
+
+impl Type<Postgres> for Instant {
+    fn type_info() -> PgTypeInfo {
+        TIMESTAMPTZ
+    }
+}
+
+impl PgHasArrayType for Instant {
+    fn array_type_info() -> PgTypeInfo {
+        TIMESTAMPTZ_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for Instant {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&instant_to_timestamp(*self).to_be_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for Instant {
+    fn decode(value: PgValueRef<'r>) -> Result<Instant, BoxDynError> {
+        decode_timestamp(binary_bytes(value, "TIMESTAMPTZ")?)
+    }
+}
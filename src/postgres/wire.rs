@@ -0,0 +1,130 @@
+use proptest::prelude::*;
+use sqlx_core::encode::Encode;
+use sqlx_postgres::PgArgumentBuffer;
+
+use super::*;
+
+fn encoded<'q, T: Encode<'q, Postgres>>(value: T) -> Vec<u8> {
+    let mut buffer = PgArgumentBuffer::default();
+    let is_null = value.encode_by_ref(&mut buffer).expect("value encodes");
+    assert!(matches!(is_null, IsNull::No));
+    buffer.to_vec()
+}
+
+fn interval_bytes(microseconds: i64, days: i32, months: i32) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16);
+    bytes.extend_from_slice(&microseconds.to_be_bytes());
+    bytes.extend_from_slice(&days.to_be_bytes());
+    bytes.extend_from_slice(&months.to_be_bytes());
+    bytes
+}
+
+proptest! {
+    #[test]
+    fn decode_interval_days_and_microseconds((microseconds, days, expected) in prop_oneof![
+        Just((1_500_000, 0, Duration::of_millis(1_500))),
+        Just((1_500_000, 1, Duration::of_millis(86_401_500))),
+        Just((-500_000, 0, Duration::of_millis(-500))),
+        Just((-1, 0, Duration::of_nanos(-1_000))),
+        Just((0, -2, Duration::of_days(-2))),
+        Just((i64::MAX, i32::MAX, Duration::of_seconds_and_adjustment(i64::from(i32::MAX) * SECONDS_IN_DAY + i64::MAX / 1_000_000, 775_807_000))),
+    ]) {
+        let duration = decode_interval(&interval_bytes(microseconds, days, 0)).unwrap();
+
+        prop_assert_eq!(expected, duration);
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_interval_rejects_months(months in prop::num::i32::ANY.prop_filter("months", |months| *months != 0)) {
+        prop_assert!(decode_interval(&interval_bytes(0, 0, months)).is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_interval_rejects_truncated(length in 0usize..16) {
+        prop_assert!(decode_interval(&interval_bytes(0, 0, 0)[..length]).is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn encode_interval((duration, microseconds) in prop_oneof![
+        Just((Duration::ZERO, 0)),
+        Just((Duration::of_seconds_and_adjustment(1, 500_000_999), 1_500_000)),
+        Just((Duration::of_nanos(-1), -1)),
+        Just((Duration::of_nanos(-1_000), -1)),
+        Just((Duration::of_nanos(-1_001), -2)),
+        Just((Duration::of_days(1), 86_400_000_000)),
+    ]) {
+        prop_assert_eq!(interval_bytes(microseconds, 0, 0), encoded(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn interval_round_trip(microseconds in prop::num::i64::ANY) {
+        let duration = decode_interval(&interval_bytes(microseconds, 0, 0)).unwrap();
+
+        prop_assert_eq!(interval_bytes(microseconds, 0, 0), encoded(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn encode_interval_overflow(duration in prop_oneof![Just(Duration::MAX), Just(Duration::MIN)]) {
+        let mut buffer = PgArgumentBuffer::default();
+
+        let message = duration.encode_by_ref(&mut buffer).err().map(|error| error.to_string());
+
+        prop_assert!(message.as_deref().is_some_and(|message| message.ends_with("is out of range for a Postgres interval")), "{:?}", message);
+        prop_assert!(buffer.is_empty());
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_timestamp_vectors((microseconds, expected) in prop_oneof![
+        Just((0, Instant::of_epoch_second(946_684_800))),
+        Just((-946_684_800_000_000, Instant::EPOCH)),
+        Just((-1, Instant::of_epoch_second_and_adjustment(946_684_799, 999_999_000))),
+        Just((1_500_000, Instant::of_epoch_second_and_adjustment(946_684_801, 500_000_000))),
+        Just((i64::MAX, Instant::MAX)),
+        Just((i64::MIN, Instant::MIN)),
+    ]) {
+        prop_assert_eq!(expected, decode_timestamp(&microseconds.to_be_bytes()).unwrap());
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_timestamp_rejects_truncated(length in 0usize..8) {
+        prop_assert!(decode_timestamp(&[0; 8][..length]).is_err());
+    }
+}
+
+proptest! {
+    #[test]
+    fn encode_timestamp((instant, microseconds) in prop_oneof![
+        Just((Instant::EPOCH, -946_684_800_000_000)),
+        Just((Instant::of_epoch_second(946_684_800), 0)),
+        Just((Instant::of_epoch_second_and_adjustment(946_684_799, 999_999_999), -1)),
+        Just((Instant::MAX, i64::MAX)),
+        Just((Instant::MIN, i64::MIN)),
+        Just((Instant::of_epoch_second(i64::MAX / 1_000_000 + 946_684_801), i64::MAX)),
+        Just((Instant::of_epoch_second(i64::MIN / 1_000_000 + 946_684_799), i64::MIN)),
+    ]) {
+        prop_assert_eq!(microseconds.to_be_bytes().to_vec(), encoded(instant));
+    }
+}
+
+proptest! {
+    #[test]
+    fn timestamp_round_trip(microseconds in (i64::MIN + 1)..i64::MAX) {
+        let instant = decode_timestamp(&microseconds.to_be_bytes()).unwrap();
+
+        prop_assert_eq!(microseconds.to_be_bytes().to_vec(), encoded(instant));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{Duration, OutOfRangeError};
+
+#[cfg(test)]
+pub mod bounds;
+
+/// An inclusive range of durations, such as the bounds allowed for a configured timeout.
+///
+/// Both the minimum and the maximum are within the range, so `DurationRange::inclusive(PT1S, PT30S)`
+/// accepts exactly one second and exactly 30 seconds.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DurationRange {
+    min: Duration,
+    max: Duration,
+}
+
+impl DurationRange {
+    /// Obtains the range from the minimum to the maximum, both inclusive, or `None` if the bounds are inverted.
+    ///
+    /// Equal bounds give a range containing exactly one duration.
+    ///
+    /// # Parameters
+    ///  - `min`: the smallest duration in the range.
+    ///  - `max`: the largest duration in the range.
+    pub fn inclusive(min: Duration, max: Duration) -> Option<DurationRange> {
+        if min <= max {
+            Some(DurationRange { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Gets the smallest duration in the range.
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    /// Gets the largest duration in the range.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Checks if the duration is within the range, including the bounds.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to check.
+    pub fn contains(&self, duration: Duration) -> bool {
+        self.min <= duration && duration <= self.max
+    }
+
+    /// Returns the duration limited to the range, so the minimum if it is smaller, or the maximum if it is larger.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to limit.
+    pub fn clamp(&self, duration: Duration) -> Duration {
+        duration.clamp(self.min, self.max)
+    }
+
+    /// Checks that the duration is within the range, returning it unchanged if so.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to check.
+    ///
+    /// # Errors
+    /// - if the duration is outside the range, with the duration and the range for rendering a message.
+    pub fn validate(&self, duration: Duration) -> Result<Duration, OutOfRangeError> {
+        if self.contains(duration) {
+            Ok(duration)
+        } else {
+            Err(OutOfRangeError::new(duration, *self))
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use proptest::prelude::*;
+
+use crate::{Duration, DurationRange};
+
+fn seconds_range(min: i64, max: i64) -> DurationRange {
+    DurationRange::inclusive(Duration::of_seconds(min), Duration::of_seconds(max)).unwrap()
+}
+
+proptest! {
+    #[test]
+    fn inclusive_rejects_inverted((min, max, valid) in prop_oneof![
+        Just((Duration::of_seconds(1), Duration::of_seconds(30), true)),
+        Just((Duration::of_seconds(1), Duration::of_seconds(1), true)),
+        Just((Duration::of_seconds(1), Duration::of_nanos(999_999_999), false)),
+        Just((Duration::MAX, Duration::MIN, false)),
+        Just((Duration::MIN, Duration::MAX, true)),
+    ]) {
+        prop_assert_eq!(valid, DurationRange::inclusive(min, max).is_some());
+    }
+}
+
+proptest! {
+    #[test]
+    fn bounds_are_inclusive((duration, contained, clamped) in prop_oneof![
+        Just((Duration::of_nanos(999_999_999), false, Duration::of_seconds(1))),
+        Just((Duration::of_seconds(1), true, Duration::of_seconds(1))),
+        Just((Duration::of_seconds(15), true, Duration::of_seconds(15))),
+        Just((Duration::of_seconds(30), true, Duration::of_seconds(30))),
+        Just((Duration::of_seconds_and_adjustment(30, 1), false, Duration::of_seconds(30))),
+        Just((Duration::MIN, false, Duration::of_seconds(1))),
+        Just((Duration::MAX, false, Duration::of_seconds(30))),
+    ]) {
+        let range = seconds_range(1, 30);
+
+        prop_assert_eq!(contained, range.contains(duration));
+        prop_assert_eq!(clamped, range.clamp(duration));
+        prop_assert_eq!(contained, range.validate(duration).is_ok());
+    }
+}
+
+proptest! {
+    #[test]
+    fn validate_error(duration in Just(Duration::of_seconds(45))) {
+        let range = seconds_range(1, 30);
+
+        let error = range.validate(duration).unwrap_err();
+
+        prop_assert_eq!(duration, error.value());
+        prop_assert_eq!(range, error.range());
+        prop_assert_eq!(
+            "duration PT45S is outside the range PT1S to PT30S (inclusive)",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::rounding::divide_rounding;
+use crate::seconds_nanos::*;
+use crate::{Duration, RoundingMode};
+
+#[cfg(test)]
+pub mod throughput;
+
+/// A number of items per interval of time, such as for rate limiting or for estimating the time remaining.
+///
+/// Rates are compared by their value, so `2` per second is equal to `120` per minute.
+/// Calculations are exact, over 128-bit nanoseconds and wider, so even very high rates over long intervals do not overflow.
+#[derive(Clone, Copy, Debug)]
+pub struct Rate {
+    count: u64,
+    interval: Duration,
+}
+
+impl Rate {
+    /// Obtains a rate of a number of items per interval.
+    ///
+    /// # Parameters
+    ///  - `count`: the number of items.
+    ///  - `interval`: the interval the items occur in.
+    ///
+    /// # Panics
+    /// - if `count` is zero.
+    /// - if `interval` is zero or negative.
+    pub fn per(count: u64, interval: Duration) -> Rate {
+        Rate::checked_per(count, interval).expect("rate must have a positive count and interval")
+    }
+
+    /// Obtains a rate of a number of items per interval,
+    /// or `None` if the count is zero or the interval is zero or negative.
+    ///
+    /// # Parameters
+    ///  - `count`: the number of items.
+    ///  - `interval`: the interval the items occur in.
+    pub fn checked_per(count: u64, interval: Duration) -> Option<Rate> {
+        if count == 0 || interval.is_zero() || interval.is_negative() {
+            None
+        } else {
+            Some(Rate { count, interval })
+        }
+    }
+
+    /// Gets the number of items, as the rate was obtained.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Gets the interval, as the rate was obtained.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Gets the spacing between items at this rate, such as the delay between requests for rate limiting.
+    ///
+    /// When the interval does not divide evenly, the spacing is rounded to the nanosecond as specified;
+    /// for example, 3 items per second are `333,333,333` nanoseconds apart when rounded down,
+    /// leaving a nanosecond of the second unused, or `333,333,334` nanoseconds apart when rounded up.
+    /// Rounding up never exceeds the rate; rounding down never falls behind it.
+    ///
+    /// # Parameters
+    ///  - `mode`: how to round to the nanosecond.
+    pub fn interval_per_item(&self, mode: RoundingMode) -> Duration {
+        let spacing = divide_rounding(
+            unsigned_nanos(self.interval) as i128,
+            i128::from(self.count),
+            mode,
+        );
+        duration_of_nanos(spacing)
+    }
+
+    /// Gets the number of whole items that occur in a duration at this rate, such as the progress expected so far.
+    ///
+    /// Any partial item is truncated, and a negative duration has no items.
+    /// The result saturates at `u64::MAX`.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to count items in.
+    pub fn items_in(&self, duration: Duration) -> u64 {
+        if duration.is_negative() {
+            return 0;
+        }
+        let (duration, interval) = (unsigned_nanos(duration), unsigned_nanos(self.interval));
+        let (whole, remainder) = (duration / interval, duration % interval);
+        u64::try_from(whole)
+            .ok()
+            .and_then(|whole| whole.checked_mul(self.count))
+            .and_then(|items| items.checked_add(mul_div_floor(self.count, remainder, interval)))
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Returns a copy of this rate, multiplied by a factor, such as `2.0` for twice as many items in the same time.
+    ///
+    /// The count is kept, and the interval is divided by the factor, rounded to the nearest nanosecond.
+    ///
+    /// # Parameters
+    ///  - `factor`: the factor to multiply the rate by.
+    ///
+    /// # Panics
+    /// - if `factor` is not positive and finite.
+    /// - if the scaled interval would be less than half a nanosecond, or would overflow a duration.
+    pub fn scale(&self, factor: f64) -> Rate {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "factor must be positive and finite"
+        );
+        // Adding half a nanosecond before truncating rounds to the nearest nanosecond.
+        let interval = unsigned_nanos(self.interval) as f64 / factor + 0.5;
+        // The largest duration is just under 2^63 seconds, whose nanoseconds are exactly representable.
+        assert!(
+            (1.0..9_223_372_036_854_775_808.0 * 1_000_000_000.0).contains(&interval),
+            "scaled interval would be out of range"
+        );
+        Rate::per(self.count, duration_of_nanos(interval as i128))
+    }
+}
+
+impl PartialEq for Rate {
+    fn eq(&self, other: &Rate) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Rate {}
+
+impl PartialOrd for Rate {
+    fn partial_cmp(&self, other: &Rate) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rate {
+    /// Compares the rates by value, where a higher rate has more items per unit of time.
+    fn cmp(&self, other: &Rate) -> Ordering {
+        // Cross-multiplying keeps the comparison exact: a/b < c/d exactly when a*d < c*b.
+        wide_mul(self.count, unsigned_nanos(other.interval))
+            .cmp(&wide_mul(other.count, unsigned_nanos(self.interval)))
+    }
+}
+
+impl fmt::Display for Rate {
+    /// Formats this rate as the count per interval, such as `250/s`.
+    ///
+    /// Intervals of exactly one nanosecond, microsecond, millisecond, second, minute, hour, or day
+    /// use the unit's symbol (`ns`, `us`, `ms`, `s`, `min`, `h`, or `d`); other intervals are formatted as
+    /// an ISO-8601 duration, such as `3/PT2.5S`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match unsigned_nanos(self.interval) {
+            1 => "ns",
+            1_000 => "us",
+            1_000_000 => "ms",
+            1_000_000_000 => "s",
+            60_000_000_000 => "min",
+            3_600_000_000_000 => "h",
+            86_400_000_000_000 => "d",
+            _ => return write!(f, "{}/{}", self.count, self.interval),
+        };
+        write!(f, "{}/{}", self.count, symbol)
+    }
+}
+
+// The nanoseconds in a non-negative duration.
+fn unsigned_nanos(duration: Duration) -> u128 {
+    total_nanos(duration.seconds(), duration.nano()) as u128
+}
+
+// The duration of a number of nanoseconds, which must be in range.
+fn duration_of_nanos(nanos: i128) -> Duration {
+    let (seconds, nanos) = of_total_nanos_checked(nanos).expect("nanoseconds are in range");
+    Duration::of_seconds_and_adjustment(seconds, i64::from(nanos))
+}
+
+// Multiplies out to 192 bits, as the high 128 bits and the low 64 bits, so that the products compare in order.
+fn wide_mul(count: u64, nanos: u128) -> (u128, u64) {
+    let count = u128::from(count);
+    let low = count * (nanos as u64 as u128);
+    let high = count * (nanos >> 64) + (low >> 64);
+    (high, low as u64)
+}
+
+// Computes `multiplier * numerator / divisor`, rounded down, without overflow, where the numerator is less than
+// the divisor (so the result is less than the multiplier) and the divisor is less than 2^127.
+fn mul_div_floor(multiplier: u64, numerator: u128, divisor: u128) -> u64 {
+    // Long multiplication over the bits of the multiplier, keeping the product as a quotient and remainder,
+    // each step of which has at most one carry into the quotient.
+    let (mut quotient, mut remainder) = (0u64, 0u128);
+    for bit in (0..64).rev() {
+        quotient <<= 1;
+        remainder <<= 1;
+        if remainder >= divisor {
+            quotient += 1;
+            remainder -= divisor;
+        }
+        if (multiplier >> bit) & 1 == 1 {
+            remainder += numerator;
+            if remainder >= divisor {
+                quotient += 1;
+                remainder -= divisor;
+            }
+        }
+    }
+    quotient
+}
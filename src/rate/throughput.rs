@@ -0,0 +1,122 @@
+use proptest::prelude::*;
+
+use crate::{Duration, Rate, RoundingMode};
+
+proptest! {
+    #[test]
+    fn interval_per_item((count, interval, mode, expected) in prop_oneof![
+        Just((1, Duration::of_seconds(1), RoundingMode::Down, Duration::of_seconds(1))),
+        Just((250, Duration::of_seconds(1), RoundingMode::Down, Duration::of_millis(4))),
+        // 3 items per second do not divide evenly, leaving a nanosecond over (or short).
+        Just((3, Duration::of_seconds(1), RoundingMode::Down, Duration::of_nanos(333_333_333))),
+        Just((3, Duration::of_seconds(1), RoundingMode::HalfEven, Duration::of_nanos(333_333_333))),
+        Just((3, Duration::of_seconds(1), RoundingMode::Up, Duration::of_nanos(333_333_334))),
+        Just((2, Duration::of_nanos(3), RoundingMode::HalfDown, Duration::of_nanos(1))),
+        Just((2, Duration::of_nanos(3), RoundingMode::HalfEven, Duration::of_nanos(2))),
+        Just((u64::MAX, Duration::of_seconds(1), RoundingMode::Down, Duration::ZERO)),
+        Just((u64::MAX, Duration::of_seconds(1), RoundingMode::Ceiling, Duration::of_nanos(1))),
+        Just((1, Duration::MAX, RoundingMode::Down, Duration::MAX)),
+        Just((u64::MAX, Duration::MAX, RoundingMode::HalfUp, Duration::of_millis(500))),
+        Just((u64::MAX, Duration::MAX, RoundingMode::Up, Duration::of_nanos(500_000_001))),
+    ]) {
+        prop_assert_eq!(expected, Rate::per(count, interval).interval_per_item(mode));
+    }
+}
+
+proptest! {
+    #[test]
+    fn items_in((count, interval, duration, expected) in prop_oneof![
+        Just((3, Duration::of_seconds(1), Duration::of_seconds(1), 3)),
+        Just((3, Duration::of_seconds(1), Duration::of_nanos(999_999_999), 2)),
+        Just((3, Duration::of_seconds(1), Duration::of_millis(500), 1)),
+        Just((3, Duration::of_seconds(1), Duration::ZERO, 0)),
+        Just((3, Duration::of_seconds(1), Duration::of_nanos(-1), 0)),
+        Just((250, Duration::of_seconds(1), Duration::of_minutes(2), 30_000)),
+        // These would overflow 128 bits if multiplied out directly.
+        Just((u64::MAX, Duration::MAX, Duration::of_days(1), 172_799)),
+        Just((u64::MAX, Duration::MAX, Duration::MAX, u64::MAX)),
+        Just((1_000_000_007, Duration::MAX, Duration::MAX - Duration::of_nanos(1), 1_000_000_006)),
+        Just((u64::MAX, Duration::of_seconds(i64::MAX), Duration::of_seconds(i64::MAX / 2), 9_223_372_036_854_775_806)),
+        // Saturates.
+        Just((u64::MAX, Duration::of_nanos(1), Duration::of_nanos(2), u64::MAX)),
+        Just((2, Duration::of_nanos(1), Duration::MAX, u64::MAX)),
+    ]) {
+        prop_assert_eq!(expected, Rate::per(count, interval).items_in(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn compare((first, second, expected) in prop_oneof![
+        Just((Rate::per(2, Duration::of_seconds(1)), Rate::per(120, Duration::of_minutes(1)), core::cmp::Ordering::Equal)),
+        Just((Rate::per(3, Duration::of_seconds(1)), Rate::per(2, Duration::of_seconds(1)), core::cmp::Ordering::Greater)),
+        Just((Rate::per(1, Duration::of_seconds(1)), Rate::per(1, Duration::of_nanos(999_999_999)), core::cmp::Ordering::Less)),
+        Just((Rate::per(u64::MAX, Duration::MAX), Rate::per(u64::MAX - 1, Duration::MAX), core::cmp::Ordering::Greater)),
+        Just((Rate::per(u64::MAX, Duration::MAX), Rate::per(u64::MAX, Duration::MAX - Duration::of_nanos(1)), core::cmp::Ordering::Less)),
+    ]) {
+        prop_assert_eq!(expected, first.cmp(&second));
+        prop_assert_eq!(expected.reverse(), second.cmp(&first));
+        prop_assert_eq!(expected == core::cmp::Ordering::Equal, first == second);
+    }
+}
+
+proptest! {
+    #[test]
+    fn display((rate, expected) in prop_oneof![
+        Just((Rate::per(250, Duration::of_seconds(1)), "250/s")),
+        Just((Rate::per(1, Duration::of_nanos(1)), "1/ns")),
+        Just((Rate::per(3, Duration::of_micros(1)), "3/us")),
+        Just((Rate::per(3, Duration::of_millis(1)), "3/ms")),
+        Just((Rate::per(60, Duration::of_minutes(1)), "60/min")),
+        Just((Rate::per(5, Duration::of_hours(1)), "5/h")),
+        Just((Rate::per(1_000, Duration::of_days(1)), "1000/d")),
+        Just((Rate::per(3, Duration::of_millis(2_500)), "3/PT2.5S")),
+    ]) {
+        prop_assert_eq!(expected, rate.to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn scale((rate, factor, expected) in prop_oneof![
+        Just((Rate::per(3, Duration::of_seconds(1)), 2.0, Rate::per(6, Duration::of_seconds(1)))),
+        Just((Rate::per(3, Duration::of_seconds(1)), 0.5, Rate::per(3, Duration::of_seconds(2)))),
+        Just((Rate::per(1, Duration::of_nanos(3)), 2.0, Rate::per(1, Duration::of_nanos(2)))),
+    ]) {
+        let scaled = rate.scale(factor);
+
+        prop_assert_eq!(expected, scaled);
+        prop_assert_eq!(rate.count(), scaled.count());
+    }
+}
+
+proptest! {
+    #[test]
+    fn checked_per_rejects((count, interval) in prop_oneof![
+        Just((0, Duration::of_seconds(1))),
+        Just((1, Duration::ZERO)),
+        Just((1, Duration::of_nanos(-1))),
+        Just((u64::MAX, Duration::MIN)),
+    ]) {
+        prop_assert_eq!(None, Rate::checked_per(count, interval));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "rate must have a positive count and interval")]
+    fn per_rejects(count in Just(0)) {
+        Rate::per(count, Duration::of_seconds(1));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "scaled interval would be out of range")]
+    fn scale_out_of_range((rate, factor) in prop_oneof![
+        Just((Rate::per(1, Duration::of_nanos(1)), 3.0)),
+        Just((Rate::per(1, Duration::MAX), 0.5)),
+    ]) {
+        rate.scale(factor);
+    }
+}
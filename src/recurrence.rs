@@ -0,0 +1,264 @@
+use core::iter::FusedIterator;
+
+use crate::civil::*;
+
+#[cfg(test)]
+pub mod occurrences;
+
+/// What a [`MonthlyRecurrence`](struct.MonthlyRecurrence.html) does in a month that is too short for its day,
+/// such as for the 31st in April.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MissingDayPolicy {
+    /// Occurs on the last day of the month instead, such as April 30th for the 31st.
+    Clamp,
+    /// Does not occur in the month at all.
+    Skip,
+}
+
+/// What a [`YearlyRecurrence`](struct.YearlyRecurrence.html) on February 29th does in a common year.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LeapDayPolicy {
+    /// Occurs on February 28th instead.
+    Clamp,
+    /// Does not occur in the year at all, so it only occurs in leap years.
+    Skip,
+    /// Occurs on March 1st instead, the day after February 28th.
+    MarchFirst,
+}
+
+/// A date that recurs on the same day of every month, such as for billing on the 31st.
+///
+/// Dates are proleptic Gregorian `(year, month, day)` tuples, as returned by
+/// [`Instant::to_civil_date`](struct.Instant.html#method.to_civil_date).
+/// Days past the end of a shorter month are handled by the [`MissingDayPolicy`](enum.MissingDayPolicy.html).
+/// Clamping is done afresh each month, so the 31st is February 28th (or 29th), then March 31st again.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MonthlyRecurrence {
+    day: u32,
+    policy: MissingDayPolicy,
+}
+
+impl MonthlyRecurrence {
+    /// Obtains a recurrence on the specified day of every month.
+    ///
+    /// # Parameters
+    ///  - `day`: the day of the month, from 1 to 31.
+    ///  - `policy`: what to do in months that are too short for the day.
+    ///
+    /// # Panics
+    /// - if `day` is not from 1 to 31.
+    pub fn new(day: u32, policy: MissingDayPolicy) -> MonthlyRecurrence {
+        assert!((1..=31).contains(&day), "day of month out of range");
+        MonthlyRecurrence { day, policy }
+    }
+
+    /// Gets the first occurrence on or after the date, or `None` if it would be after the last representable year.
+    ///
+    /// # Parameters
+    ///  - `date`: the `(year, month, day)` to search from, inclusive.
+    ///
+    /// # Panics
+    /// - if `date` is not a valid date.
+    pub fn on_or_after(&self, date: (i64, u32, u32)) -> Option<(i64, u32, u32)> {
+        assert_valid_date(date);
+        let (mut year, mut month, _) = date;
+        loop {
+            if let Some(occurrence) = self.occurrence_in(year, month) {
+                if occurrence >= date {
+                    return Some(occurrence);
+                }
+            }
+            if month == 12 {
+                year = year.checked_add(1)?;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+    }
+
+    /// Gets the first occurrence strictly after the date, or `None` if it would be after the last representable year.
+    ///
+    /// # Parameters
+    ///  - `date`: the `(year, month, day)` to search from, exclusive.
+    ///
+    /// # Panics
+    /// - if `date` is not a valid date.
+    pub fn next_after(&self, date: (i64, u32, u32)) -> Option<(i64, u32, u32)> {
+        self.on_or_after(next_date(date)?)
+    }
+
+    /// Iterates over the occurrences on or after the date, in order.
+    ///
+    /// # Parameters
+    ///  - `date`: the `(year, month, day)` to start from, inclusive.
+    ///
+    /// # Panics
+    /// - if `date` is not a valid date.
+    pub fn occurrences(&self, date: (i64, u32, u32)) -> MonthlyOccurrences {
+        MonthlyOccurrences {
+            recurrence: *self,
+            next: self.on_or_after(date),
+        }
+    }
+
+    fn occurrence_in(&self, year: i64, month: u32) -> Option<(i64, u32, u32)> {
+        let length = days_in_month(year, month);
+        match self.policy {
+            _ if self.day <= length => Some((year, month, self.day)),
+            MissingDayPolicy::Clamp => Some((year, month, length)),
+            MissingDayPolicy::Skip => None,
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`MonthlyRecurrence`](struct.MonthlyRecurrence.html).
+///
+/// This is created by [`MonthlyRecurrence::occurrences`](struct.MonthlyRecurrence.html#method.occurrences).
+#[derive(Clone, Debug)]
+pub struct MonthlyOccurrences {
+    recurrence: MonthlyRecurrence,
+    next: Option<(i64, u32, u32)>,
+}
+
+impl Iterator for MonthlyOccurrences {
+    type Item = (i64, u32, u32);
+
+    fn next(&mut self) -> Option<(i64, u32, u32)> {
+        let occurrence = self.next?;
+        self.next = self.recurrence.next_after(occurrence);
+        Some(occurrence)
+    }
+}
+
+impl FusedIterator for MonthlyOccurrences {}
+
+/// A date that recurs on the same month and day of every year, such as for an anniversary.
+///
+/// Dates are proleptic Gregorian `(year, month, day)` tuples, as returned by
+/// [`Instant::to_civil_date`](struct.Instant.html#method.to_civil_date).
+/// A recurrence on February 29th is handled in common years by the [`LeapDayPolicy`](enum.LeapDayPolicy.html);
+/// every other month and day occurs every year.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct YearlyRecurrence {
+    month: u32,
+    day: u32,
+    policy: LeapDayPolicy,
+}
+
+impl YearlyRecurrence {
+    /// Obtains a recurrence on the specified month and day of every year.
+    ///
+    /// # Parameters
+    ///  - `month`: the month of the year, from 1 to 12.
+    ///  - `day`: the day of the month, from 1 to the length of the month in a leap year.
+    ///  - `policy`: what to do in common years, if the date is February 29th.
+    ///
+    /// # Panics
+    /// - if `month` is not from 1 to 12, or `day` is not in the month in a leap year.
+    pub fn new(month: u32, day: u32, policy: LeapDayPolicy) -> YearlyRecurrence {
+        assert!((1..=12).contains(&month), "month out of range");
+        // 2000 is a leap year, so this allows February 29th.
+        assert!(
+            (1..=days_in_month(2000, month)).contains(&day),
+            "day of month out of range"
+        );
+        YearlyRecurrence { month, day, policy }
+    }
+
+    /// Gets the first occurrence on or after the date, or `None` if it would be after the last representable year.
+    ///
+    /// # Parameters
+    ///  - `date`: the `(year, month, day)` to search from, inclusive.
+    ///
+    /// # Panics
+    /// - if `date` is not a valid date.
+    pub fn on_or_after(&self, date: (i64, u32, u32)) -> Option<(i64, u32, u32)> {
+        assert_valid_date(date);
+        let mut year = date.0;
+        loop {
+            if let Some(occurrence) = self.occurrence_in(year) {
+                if occurrence >= date {
+                    return Some(occurrence);
+                }
+            }
+            year = year.checked_add(1)?;
+        }
+    }
+
+    /// Gets the first occurrence strictly after the date, or `None` if it would be after the last representable year.
+    ///
+    /// # Parameters
+    ///  - `date`: the `(year, month, day)` to search from, exclusive.
+    ///
+    /// # Panics
+    /// - if `date` is not a valid date.
+    pub fn next_after(&self, date: (i64, u32, u32)) -> Option<(i64, u32, u32)> {
+        self.on_or_after(next_date(date)?)
+    }
+
+    /// Iterates over the occurrences on or after the date, in order.
+    ///
+    /// # Parameters
+    ///  - `date`: the `(year, month, day)` to start from, inclusive.
+    ///
+    /// # Panics
+    /// - if `date` is not a valid date.
+    pub fn occurrences(&self, date: (i64, u32, u32)) -> YearlyOccurrences {
+        YearlyOccurrences {
+            recurrence: *self,
+            next: self.on_or_after(date),
+        }
+    }
+
+    fn occurrence_in(&self, year: i64) -> Option<(i64, u32, u32)> {
+        if self.month != 2 || self.day != 29 || is_leap_year(year) {
+            return Some((year, self.month, self.day));
+        }
+        match self.policy {
+            LeapDayPolicy::Clamp => Some((year, 2, 28)),
+            LeapDayPolicy::Skip => None,
+            LeapDayPolicy::MarchFirst => Some((year, 3, 1)),
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`YearlyRecurrence`](struct.YearlyRecurrence.html).
+///
+/// This is created by [`YearlyRecurrence::occurrences`](struct.YearlyRecurrence.html#method.occurrences).
+#[derive(Clone, Debug)]
+pub struct YearlyOccurrences {
+    recurrence: YearlyRecurrence,
+    next: Option<(i64, u32, u32)>,
+}
+
+impl Iterator for YearlyOccurrences {
+    type Item = (i64, u32, u32);
+
+    fn next(&mut self) -> Option<(i64, u32, u32)> {
+        let occurrence = self.next?;
+        self.next = self.recurrence.next_after(occurrence);
+        Some(occurrence)
+    }
+}
+
+impl FusedIterator for YearlyOccurrences {}
+
+fn assert_valid_date((year, month, day): (i64, u32, u32)) {
+    assert!(
+        (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day),
+        "invalid date"
+    );
+}
+
+// The day after the date, or `None` after the last day of the last representable year.
+fn next_date((year, month, day): (i64, u32, u32)) -> Option<(i64, u32, u32)> {
+    assert_valid_date((year, month, day));
+    if day < days_in_month(year, month) {
+        Some((year, month, day + 1))
+    } else if month < 12 {
+        Some((year, month + 1, 1))
+    } else {
+        year.checked_add(1).map(|year| (year, 1, 1))
+    }
+}
@@ -0,0 +1,105 @@
+use proptest::prelude::*;
+
+use crate::{LeapDayPolicy, MissingDayPolicy, MonthlyRecurrence, YearlyRecurrence};
+
+proptest! {
+    #[test]
+    fn monthly_31st((policy, start, expected) in prop_oneof![
+        // Through the leap-year February, April, and back to a 31-day month.
+        Just((MissingDayPolicy::Clamp, (2023, 12, 1), [(2023, 12, 31), (2024, 1, 31), (2024, 2, 29), (2024, 3, 31), (2024, 4, 30), (2024, 5, 31)])),
+        Just((MissingDayPolicy::Skip, (2023, 12, 1), [(2023, 12, 31), (2024, 1, 31), (2024, 3, 31), (2024, 5, 31), (2024, 7, 31), (2024, 8, 31)])),
+        // Through a common-year February.
+        Just((MissingDayPolicy::Clamp, (2025, 1, 1), [(2025, 1, 31), (2025, 2, 28), (2025, 3, 31), (2025, 4, 30), (2025, 5, 31), (2025, 6, 30)])),
+        Just((MissingDayPolicy::Skip, (2025, 1, 1), [(2025, 1, 31), (2025, 3, 31), (2025, 5, 31), (2025, 7, 31), (2025, 8, 31), (2025, 10, 31)])),
+    ]) {
+        let recurrence = MonthlyRecurrence::new(31, policy);
+
+        let occurrences: Vec<(i64, u32, u32)> = recurrence.occurrences(start).take(expected.len()).collect();
+        prop_assert_eq!(&expected[..], &occurrences[..]);
+    }
+}
+
+proptest! {
+    #[test]
+    fn monthly_search((day, policy, date, on_or_after, next_after) in prop_oneof![
+        Just((31, MissingDayPolicy::Clamp, (2024, 2, 29), (2024, 2, 29), (2024, 3, 31))),
+        Just((31, MissingDayPolicy::Skip, (2024, 2, 29), (2024, 3, 31), (2024, 3, 31))),
+        Just((30, MissingDayPolicy::Clamp, (2023, 2, 28), (2023, 2, 28), (2023, 3, 30))),
+        Just((15, MissingDayPolicy::Skip, (2023, 12, 15), (2023, 12, 15), (2024, 1, 15))),
+        Just((1, MissingDayPolicy::Skip, (2023, 12, 2), (2024, 1, 1), (2024, 1, 1))),
+    ]) {
+        let recurrence = MonthlyRecurrence::new(day, policy);
+
+        prop_assert_eq!(Some(on_or_after), recurrence.on_or_after(date));
+        prop_assert_eq!(Some(next_after), recurrence.next_after(date));
+    }
+}
+
+proptest! {
+    #[test]
+    fn yearly_leap_day((policy, start, expected) in prop_oneof![
+        Just((LeapDayPolicy::Clamp, (2023, 1, 1), [(2023, 2, 28), (2024, 2, 29), (2025, 2, 28), (2026, 2, 28), (2027, 2, 28), (2028, 2, 29)])),
+        Just((LeapDayPolicy::Skip, (2023, 1, 1), [(2024, 2, 29), (2028, 2, 29), (2032, 2, 29), (2036, 2, 29), (2040, 2, 29), (2044, 2, 29)])),
+        Just((LeapDayPolicy::MarchFirst, (2023, 1, 1), [(2023, 3, 1), (2024, 2, 29), (2025, 3, 1), (2026, 3, 1), (2027, 3, 1), (2028, 2, 29)])),
+        // 2100 is not a leap year, as it is a century not divisible by 400.
+        Just((LeapDayPolicy::Clamp, (2096, 3, 1), [(2097, 2, 28), (2098, 2, 28), (2099, 2, 28), (2100, 2, 28), (2101, 2, 28), (2102, 2, 28)])),
+        Just((LeapDayPolicy::Skip, (2092, 3, 1), [(2096, 2, 29), (2104, 2, 29), (2108, 2, 29), (2112, 2, 29), (2116, 2, 29), (2120, 2, 29)])),
+        Just((LeapDayPolicy::MarchFirst, (2099, 2, 28), [(2099, 3, 1), (2100, 3, 1), (2101, 3, 1), (2102, 3, 1), (2103, 3, 1), (2104, 2, 29)])),
+    ]) {
+        let recurrence = YearlyRecurrence::new(2, 29, policy);
+
+        let occurrences: Vec<(i64, u32, u32)> = recurrence.occurrences(start).take(expected.len()).collect();
+        prop_assert_eq!(&expected[..], &occurrences[..]);
+    }
+}
+
+proptest! {
+    #[test]
+    fn yearly_search((month, day, policy, date, on_or_after, next_after) in prop_oneof![
+        Just((2, 29, LeapDayPolicy::Clamp, (2023, 2, 28), (2023, 2, 28), (2024, 2, 29))),
+        Just((2, 29, LeapDayPolicy::Skip, (2023, 2, 28), (2024, 2, 29), (2024, 2, 29))),
+        Just((2, 29, LeapDayPolicy::MarchFirst, (2023, 2, 28), (2023, 3, 1), (2023, 3, 1))),
+        Just((2, 29, LeapDayPolicy::MarchFirst, (2024, 2, 29), (2024, 2, 29), (2025, 3, 1))),
+        Just((12, 25, LeapDayPolicy::Skip, (2023, 12, 26), (2024, 12, 25), (2024, 12, 25))),
+        Just((12, 25, LeapDayPolicy::Skip, (2023, 12, 31), (2024, 12, 25), (2024, 12, 25))),
+    ]) {
+        let recurrence = YearlyRecurrence::new(month, day, policy);
+
+        prop_assert_eq!(Some(on_or_after), recurrence.on_or_after(date));
+        prop_assert_eq!(Some(next_after), recurrence.next_after(date));
+    }
+}
+
+proptest! {
+    #[test]
+    fn occurrences_end_at_last_year(recurrence in Just(YearlyRecurrence::new(12, 31, LeapDayPolicy::Skip))) {
+        let occurrences: Vec<(i64, u32, u32)> = recurrence.occurrences((i64::MAX - 1, 1, 1)).collect();
+
+        prop_assert_eq!(vec![(i64::MAX - 1, 12, 31), (i64::MAX, 12, 31)], occurrences);
+        prop_assert_eq!(None, MonthlyRecurrence::new(31, MissingDayPolicy::Skip).next_after((i64::MAX, 12, 31)));
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "day of month out of range")]
+    fn monthly_day_out_of_range(day in prop_oneof![Just(0), Just(32)]) {
+        let _recurrence = MonthlyRecurrence::new(day, MissingDayPolicy::Clamp);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "day of month out of range")]
+    fn yearly_day_out_of_range((month, day) in prop_oneof![Just((2, 30)), Just((4, 31)), Just((1, 0))]) {
+        let _recurrence = YearlyRecurrence::new(month, day, LeapDayPolicy::Clamp);
+    }
+}
+
+proptest! {
+    #[test]
+    #[should_panic(expected = "invalid date")]
+    fn search_invalid_date(date in prop_oneof![Just((2023, 2, 29)), Just((2023, 13, 1)), Just((2023, 4, 31))]) {
+        let _occurrence = MonthlyRecurrence::new(1, MissingDayPolicy::Clamp).on_or_after(date);
+    }
+}
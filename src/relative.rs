@@ -0,0 +1,143 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::rounding::*;
+use crate::seconds_nanos::*;
+use crate::{Duration, Instant, Unit};
+
+#[cfg(test)]
+pub mod phrases;
+
+/// The thresholds used to choose how a [`RelativeTime`](struct.RelativeTime.html) is phrased.
+///
+/// Each threshold is compared against the absolute difference between the two instants.
+/// The defaults switch units before the rounded amount would reach the next unit,
+/// so phrases such as "60 minutes ago" are never produced.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RelativeTimeOptions {
+    /// Differences shorter than this are "just now". Defaults to 5 seconds.
+    pub just_now: Duration,
+    /// Differences at least this long are phrased in minutes. Defaults to 45 seconds.
+    pub minutes: Duration,
+    /// Differences at least this long are phrased in hours. Defaults to 45 minutes.
+    pub hours: Duration,
+    /// Differences at least this long are phrased in days. Defaults to 22 hours.
+    pub days: Duration,
+}
+
+impl Default for RelativeTimeOptions {
+    fn default() -> RelativeTimeOptions {
+        RelativeTimeOptions {
+            just_now: Duration::of_seconds(5),
+            minutes: Duration::of_seconds(45),
+            hours: Duration::of_minutes(45),
+            days: Duration::of_hours(22),
+        }
+    }
+}
+
+/// The time between an instant and a reference instant, phrased for people, such as "3 minutes ago" or "in 2 days".
+///
+/// This is created by [`Instant::relative_to`](struct.Instant.html#method.relative_to),
+/// or [`Duration::humanize`](struct.Duration.html#method.humanize) for a signed offset from the present.
+/// `Display` produces English phrases; the [`unit`](#method.unit) and [`amount`](#method.amount)
+/// are available for callers who localize the phrase themselves.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RelativeTime {
+    amount: i64,
+    unit: Unit,
+}
+
+impl RelativeTime {
+    pub(crate) fn between(
+        instant: Instant,
+        reference: Instant,
+        options: &RelativeTimeOptions,
+    ) -> RelativeTime {
+        RelativeTime::of_total_nanos(
+            total_nanos(instant.epoch_second(), instant.nano())
+                - total_nanos(reference.epoch_second(), reference.nano()),
+            options,
+        )
+    }
+
+    pub(crate) fn of_duration(duration: Duration, options: &RelativeTimeOptions) -> RelativeTime {
+        RelativeTime::of_total_nanos(total_nanos(duration.seconds(), duration.nano()), options)
+    }
+
+    // The difference is widened, as the difference between two instants may not fit in a duration.
+    fn of_total_nanos(difference: i128, options: &RelativeTimeOptions) -> RelativeTime {
+        let magnitude = difference.abs();
+        let exceeds =
+            |threshold: Duration| magnitude >= total_nanos(threshold.seconds(), threshold.nano());
+
+        if !exceeds(options.just_now) {
+            return RelativeTime {
+                amount: 0,
+                unit: Unit::Seconds,
+            };
+        }
+        let unit = if exceeds(options.days) {
+            Unit::Days
+        } else if exceeds(options.hours) {
+            Unit::Hours
+        } else if exceeds(options.minutes) {
+            Unit::Minutes
+        } else {
+            Unit::Seconds
+        };
+        // Only thresholds beyond the default units can leave more seconds than fit, so saturate rather than wrap.
+        let amount = i64::try_from(divide_rounding(
+            magnitude,
+            i128::from(unit.nanos()),
+            RoundingMode::HalfUp,
+        ))
+        .unwrap_or(i64::MAX);
+        RelativeTime {
+            amount: if difference < 0 { -amount } else { amount },
+            unit,
+        }
+    }
+
+    /// Checks if the instants are close enough to be "just now".
+    pub fn is_just_now(&self) -> bool {
+        self.amount == 0
+    }
+
+    /// Gets the unit the time is phrased in, one of seconds, minutes, hours, or days.
+    ///
+    /// This is `Unit::Seconds` for "just now".
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    /// Gets the rounded number of units, negative when the instant is before the reference ("ago"),
+    /// positive when it is after ("in"), and zero for "just now".
+    ///
+    /// The magnitude saturates at `i64::MAX`, which only the largest differences phrased in seconds can reach.
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+}
+
+impl fmt::Display for RelativeTime {
+    /// Formats the relative time as an English phrase, such as "just now", "1 minute ago", or "in 2 days".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_just_now() {
+            return f.write_str("just now");
+        }
+        let magnitude = self.amount.unsigned_abs();
+        let unit = match self.unit {
+            Unit::Days => "day",
+            Unit::Hours => "hour",
+            Unit::Minutes => "minute",
+            _ => "second",
+        };
+        let plural = if magnitude == 1 { "" } else { "s" };
+        if self.amount < 0 {
+            write!(f, "{} {}{} ago", magnitude, unit, plural)
+        } else {
+            write!(f, "in {} {}{}", magnitude, unit, plural)
+        }
+    }
+}
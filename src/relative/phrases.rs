@@ -0,0 +1,137 @@
+use proptest::prelude::*;
+
+use crate::{Duration, Instant, RelativeTimeOptions, Unit};
+
+fn after_reference(difference: Duration) -> (String, String) {
+    let reference = Instant::of_epoch_second(1_700_000_000);
+    (
+        (reference + difference).relative_to(reference).to_string(),
+        (reference - difference).relative_to(reference).to_string(),
+    )
+}
+
+proptest! {
+    #[test]
+    fn threshold_boundaries((difference, future, past) in prop_oneof![
+        Just((Duration::ZERO, "just now", "just now")),
+        Just((Duration::of_nanos(4_999_999_999), "just now", "just now")),
+        Just((Duration::of_seconds(5), "in 5 seconds", "5 seconds ago")),
+        Just((Duration::of_millis(44_499), "in 44 seconds", "44 seconds ago")),
+        Just((Duration::of_millis(44_999), "in 45 seconds", "45 seconds ago")),
+        Just((Duration::of_seconds(45), "in 1 minute", "1 minute ago")),
+        Just((Duration::of_millis(89_999), "in 1 minute", "1 minute ago")),
+        Just((Duration::of_seconds(90), "in 2 minutes", "2 minutes ago")),
+        Just((Duration::of_seconds(44 * 60 + 29), "in 44 minutes", "44 minutes ago")),
+        Just((Duration::of_nanos(45 * 60 * 1_000_000_000 - 1), "in 45 minutes", "45 minutes ago")),
+        Just((Duration::of_minutes(45), "in 1 hour", "1 hour ago")),
+        Just((Duration::of_minutes(89), "in 1 hour", "1 hour ago")),
+        Just((Duration::of_minutes(90), "in 2 hours", "2 hours ago")),
+        Just((Duration::of_nanos(22 * 3_600 * 1_000_000_000 - 1), "in 22 hours", "22 hours ago")),
+        Just((Duration::of_hours(22), "in 1 day", "1 day ago")),
+        Just((Duration::of_hours(35), "in 1 day", "1 day ago")),
+        Just((Duration::of_hours(36), "in 2 days", "2 days ago")),
+        Just((Duration::of_days(400), "in 400 days", "400 days ago")),
+    ]) {
+        prop_assert_eq!((future.to_owned(), past.to_owned()), after_reference(difference));
+    }
+}
+
+proptest! {
+    #[test]
+    fn singular_and_plural((difference, future, past) in prop_oneof![
+        Just((Duration::of_seconds(1), "in 1 second", "1 second ago")),
+        Just((Duration::of_seconds(2), "in 2 seconds", "2 seconds ago")),
+        Just((Duration::of_minutes(1), "in 1 minute", "1 minute ago")),
+        Just((Duration::of_hours(1), "in 1 hour", "1 hour ago")),
+        Just((Duration::of_days(1), "in 1 day", "1 day ago")),
+    ]) {
+        let options = RelativeTimeOptions {
+            just_now: Duration::of_seconds(1),
+            minutes: Duration::of_minutes(1),
+            hours: Duration::of_hours(1),
+            days: Duration::of_days(1),
+        };
+        let reference = Instant::EPOCH;
+
+        prop_assert_eq!(future, (reference + difference).relative_to_with(reference, &options).to_string());
+        prop_assert_eq!(past, (reference - difference).relative_to_with(reference, &options).to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn accessors((difference, unit, amount) in prop_oneof![
+        Just((Duration::of_seconds(-3), Unit::Seconds, 0)),
+        Just((Duration::of_seconds(-30), Unit::Seconds, -30)),
+        Just((Duration::of_minutes(3), Unit::Minutes, 3)),
+        Just((Duration::of_hours(-5), Unit::Hours, -5)),
+        Just((Duration::of_days(2), Unit::Days, 2)),
+    ]) {
+        let relative = (Instant::EPOCH + difference).relative_to(Instant::EPOCH);
+
+        prop_assert_eq!(unit, relative.unit());
+        prop_assert_eq!(amount, relative.amount());
+        prop_assert_eq!(amount == 0, relative.is_just_now());
+    }
+}
+
+proptest! {
+    #[test]
+    fn extremes_do_not_overflow((instant, reference, days) in prop_oneof![
+        Just((Instant::MIN, Instant::MAX, -213_503_982_334_601)),
+        Just((Instant::MAX, Instant::MIN, 213_503_982_334_601)),
+    ]) {
+        let relative = instant.relative_to(reference);
+
+        prop_assert_eq!(Unit::Days, relative.unit());
+        prop_assert_eq!(days, relative.amount());
+    }
+}
+
+proptest! {
+    #[test]
+    fn extremes_in_seconds_saturate((duration, amount) in prop_oneof![
+        Just((Duration::of_seconds_and_adjustment(i64::MAX, 499_999_999), i64::MAX)),
+        Just((Duration::of_seconds_and_adjustment(i64::MAX, 500_000_000), i64::MAX)),
+        Just((Duration::of_seconds_and_adjustment(i64::MIN, 500_000_000), -i64::MAX)),
+    ]) {
+        // Only a threshold beyond every smaller difference can leave them phrased in seconds.
+        let options = RelativeTimeOptions {
+            minutes: Duration::MAX,
+            hours: Duration::MAX,
+            days: Duration::MAX,
+            ..RelativeTimeOptions::default()
+        };
+        let reference = Instant::EPOCH;
+
+        let relative = (reference + duration).relative_to_with(reference, &options);
+
+        prop_assert_eq!(Unit::Seconds, relative.unit());
+        prop_assert_eq!(amount, relative.amount());
+        prop_assert_eq!(relative, duration.humanize_with(&options));
+    }
+}
+
+proptest! {
+    #[test]
+    fn relative_to_past_and_future((offset, expected) in prop_oneof![
+        Just((Duration::of_minutes(5), "in 5 minutes")),
+        Just((Duration::of_hours(-3), "3 hours ago")),
+        Just((Duration::of_days(-1), "1 day ago")),
+        Just((Duration::of_seconds(2), "just now")),
+    ]) {
+        let now = Instant::of_epoch_second(1_700_000_000);
+
+        prop_assert_eq!(expected, (now + offset).relative_to(now).to_string());
+    }
+}
+
+proptest! {
+    #[test]
+    fn relative_to_matches_humanize(seconds in -1_000_000_000i64..1_000_000_000, nanos in 0..1_000_000_000i64) {
+        let now = Instant::of_epoch_second(1_700_000_000);
+        let offset = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        prop_assert_eq!(offset.humanize(), (now + offset).relative_to(now));
+    }
+}
@@ -0,0 +1,51 @@
+use core::cmp::Ordering;
+
+/// How to round a value that falls between two representable results, matching `java.math.RoundingMode`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Rounds away from zero.
+    Up,
+    /// Rounds toward zero, truncating.
+    Down,
+    /// Rounds toward positive infinity.
+    Ceiling,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds toward the nearest neighbor, with ties rounding away from zero.
+    HalfUp,
+    /// Rounds toward the nearest neighbor, with ties rounding toward zero.
+    HalfDown,
+    /// Rounds toward the nearest neighbor, with ties rounding toward the even neighbor.
+    ///
+    /// This is "banker's rounding", which does not accumulate a bias when summing many rounded values.
+    HalfEven,
+}
+
+// Divides by a positive divisor, rounding the quotient as specified.
+pub fn divide_rounding(dividend: i128, divisor: i128, mode: RoundingMode) -> i128 {
+    let (quotient, remainder) = (dividend.div_euclid(divisor), dividend.rem_euclid(divisor));
+    if remainder == 0 {
+        return quotient;
+    }
+    // The exact value lies strictly between `quotient` and `quotient + 1`,
+    // so it is negative exactly when the (floored) quotient is.
+    let negative = quotient < 0;
+    let round_up = match mode {
+        RoundingMode::Up => !negative,
+        RoundingMode::Down => negative,
+        RoundingMode::Ceiling => true,
+        RoundingMode::Floor => false,
+        RoundingMode::HalfUp | RoundingMode::HalfDown | RoundingMode::HalfEven => {
+            match (remainder * 2).cmp(&divisor) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => match mode {
+                    RoundingMode::HalfUp => !negative,
+                    RoundingMode::HalfDown => negative,
+                    _ => quotient & 1 == 1,
+                },
+            }
+        }
+    };
+    quotient + i128::from(round_up)
+}
@@ -0,0 +1,109 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Duration, Instant};
+
+#[cfg(test)]
+pub mod arithmetic;
+
+/// Provides intentionally-saturating arithmetic on `Duration` and `Instant`.
+///
+/// Like [`std::num::Saturating`](https://doc.rust-lang.org/std/num/struct.Saturating.html),
+/// wrapping a value opts a whole computation into a single overflow policy:
+/// any operation that would overflow instead clamps to the minimum or maximum value,
+/// and the results of operations stay wrapped so they can be chained.
+///
+/// Adding or subtracting a `Saturating<Duration>` to or from a `Saturating<Instant>` produces a `Saturating<Instant>`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Saturating<T>(pub T);
+
+impl<T> Saturating<T> {
+    /// Unwraps the value, leaving the saturating policy behind.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Add for Saturating<Duration> {
+    type Output = Saturating<Duration>;
+
+    fn add(self, other: Saturating<Duration>) -> Saturating<Duration> {
+        Saturating(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for Saturating<Duration> {
+    fn add_assign(&mut self, other: Saturating<Duration>) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Saturating<Duration> {
+    type Output = Saturating<Duration>;
+
+    fn sub(self, other: Saturating<Duration>) -> Saturating<Duration> {
+        Saturating(self.0.saturating_sub(other.0))
+    }
+}
+
+impl SubAssign for Saturating<Duration> {
+    fn sub_assign(&mut self, other: Saturating<Duration>) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Saturating<Duration> {
+    type Output = Saturating<Duration>;
+
+    fn mul(self, scalar: i64) -> Saturating<Duration> {
+        Saturating(self.0.saturating_mul(scalar))
+    }
+}
+
+impl MulAssign<i64> for Saturating<Duration> {
+    fn mul_assign(&mut self, scalar: i64) {
+        *self = *self * scalar;
+    }
+}
+
+impl Neg for Saturating<Duration> {
+    type Output = Saturating<Duration>;
+
+    fn neg(self) -> Saturating<Duration> {
+        Saturating(self.0.saturating_neg())
+    }
+}
+
+impl Add<Saturating<Duration>> for Saturating<Instant> {
+    type Output = Saturating<Instant>;
+
+    fn add(self, duration: Saturating<Duration>) -> Saturating<Instant> {
+        Saturating(self.0.saturating_add(duration.0))
+    }
+}
+
+impl AddAssign<Saturating<Duration>> for Saturating<Instant> {
+    fn add_assign(&mut self, duration: Saturating<Duration>) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Saturating<Duration>> for Saturating<Instant> {
+    type Output = Saturating<Instant>;
+
+    fn sub(self, duration: Saturating<Duration>) -> Saturating<Instant> {
+        Saturating(self.0.saturating_sub(duration.0))
+    }
+}
+
+impl SubAssign<Saturating<Duration>> for Saturating<Instant> {
+    fn sub_assign(&mut self, duration: Saturating<Duration>) {
+        *self = *self - duration;
+    }
+}
@@ -0,0 +1,101 @@
+use proptest::prelude::*;
+
+use crate::{Duration, Instant, Saturating};
+
+proptest! {
+    #[test]
+    fn add_duration((first, second, expected) in prop_oneof![
+        Just((Duration::of_seconds(1), Duration::of_seconds(2), Duration::of_seconds(3))),
+        Just((Duration::MAX, Duration::of_nanos(1), Duration::MAX)),
+        Just((Duration::MAX, Duration::MAX, Duration::MAX)),
+        Just((Duration::MIN, Duration::of_nanos(-1), Duration::MIN)),
+        Just((Duration::MIN, Duration::MIN, Duration::MIN)),
+        Just((Duration::MAX, Duration::MIN, Duration::of_nanos(-1))),
+    ]) {
+        prop_assert_eq!(Saturating(expected), Saturating(first) + Saturating(second));
+    }
+}
+
+proptest! {
+    #[test]
+    fn sub_duration((first, second, expected) in prop_oneof![
+        Just((Duration::of_seconds(1), Duration::of_seconds(2), Duration::of_seconds(-1))),
+        Just((Duration::MIN, Duration::of_nanos(1), Duration::MIN)),
+        Just((Duration::MAX, Duration::of_nanos(-1), Duration::MAX)),
+        Just((Duration::ZERO, Duration::MIN, Duration::MAX)),
+        Just((Duration::of_nanos(-1), Duration::MAX, Duration::MIN)),
+    ]) {
+        prop_assert_eq!(Saturating(expected), Saturating(first) - Saturating(second));
+    }
+}
+
+proptest! {
+    #[test]
+    fn mul_duration((duration, scalar, expected) in prop_oneof![
+        Just((Duration::of_seconds(2), 3, Duration::of_seconds(6))),
+        Just((Duration::MAX, 2, Duration::MAX)),
+        Just((Duration::MAX, -2, Duration::MIN)),
+        Just((Duration::MIN, 2, Duration::MIN)),
+        Just((Duration::MIN, -1, Duration::MAX)),
+        Just((Duration::ZERO, i64::MIN, Duration::ZERO)),
+    ]) {
+        prop_assert_eq!(Saturating(expected), Saturating(duration) * scalar);
+    }
+}
+
+proptest! {
+    #[test]
+    fn neg_duration((duration, expected) in prop_oneof![
+        Just((Duration::of_seconds(2), Duration::of_seconds(-2))),
+        Just((Duration::MAX, Duration::of_seconds_and_adjustment(i64::MIN, 1))),
+        Just((Duration::MIN, Duration::MAX)),
+    ]) {
+        prop_assert_eq!(Saturating(expected), -Saturating(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn add_duration_to_instant((instant, duration, expected) in prop_oneof![
+        Just((Instant::EPOCH, Duration::of_seconds(1), Instant::of_epoch_second(1))),
+        Just((Instant::MAX, Duration::of_nanos(1), Instant::MAX)),
+        Just((Instant::MIN, Duration::of_nanos(-1), Instant::MIN)),
+        Just((Instant::MIN, Duration::MAX, Instant::of_epoch_second_and_adjustment(-1, 999_999_999))),
+    ]) {
+        prop_assert_eq!(Saturating(expected), Saturating(instant) + Saturating(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn sub_duration_from_instant((instant, duration, expected) in prop_oneof![
+        Just((Instant::EPOCH, Duration::of_seconds(1), Instant::of_epoch_second(-1))),
+        Just((Instant::MIN, Duration::of_nanos(1), Instant::MIN)),
+        Just((Instant::MAX, Duration::of_nanos(-1), Instant::MAX)),
+        Just((Instant::EPOCH, Duration::MIN, Instant::MAX)),
+    ]) {
+        prop_assert_eq!(Saturating(expected), Saturating(instant) - Saturating(duration));
+    }
+}
+
+proptest! {
+    #[test]
+    fn chained_operations_stay_saturating(seconds in prop::num::i64::ANY, scalar in prop::num::i64::ANY) {
+        let mut instant = Saturating(Instant::of_epoch_second(seconds));
+        let duration: Saturating<Duration> = -(Saturating(Duration::of_seconds(seconds)) * scalar) + Saturating(Duration::MAX);
+        instant += duration;
+        instant -= duration * 2;
+
+        prop_assert!(instant.into_inner() >= Instant::MIN);
+        prop_assert!(duration.into_inner() <= Duration::MAX);
+    }
+}
+
+proptest! {
+    #[test]
+    fn display_forwards(seconds in prop::num::i64::ANY) {
+        let duration = Duration::of_seconds(seconds);
+
+        prop_assert_eq!(duration.to_string(), Saturating(duration).to_string());
+    }
+}
@@ -1,6 +1,12 @@
-use std::i64;
+use core::convert::TryFrom;
 
 use crate::constants::*;
+use crate::{DecodeError, SliceOverflowError};
+
+#[cfg(test)]
+pub mod sweeps;
+
+const SECONDS_BIAS: u64 = 1 << 63;
 
 pub fn of_seconds_and_adjustment_checked(seconds: i64, nano_adjustment: i64) -> Option<(i64, u32)> {
     let (second_adjustment, nanos) = seconds_and_nanos(nano_adjustment);
@@ -9,17 +15,26 @@ pub fn of_seconds_and_adjustment_checked(seconds: i64, nano_adjustment: i64) ->
         .map(|total_seconds| (total_seconds, nanos))
 }
 
+// Splits nanoseconds into whole seconds and the nanosecond of second, for every `i64`.
+// Euclidean division keeps the remainder non-negative without a separate borrow, so nothing can overflow.
 pub fn seconds_and_nanos(nanoseconds: i64) -> (i64, u32) {
-    let (base_adjustment, base_nanos) = (
-        nanoseconds / NANOSECONDS_IN_SECOND,
-        nanoseconds % NANOSECONDS_IN_SECOND,
-    );
+    (
+        nanoseconds.div_euclid(NANOSECONDS_IN_SECOND),
+        nanoseconds.rem_euclid(NANOSECONDS_IN_SECOND) as u32,
+    )
+}
 
-    let (extra_adjustment, final_nanos) = carry_and_nanos(base_nanos);
-    (base_adjustment + extra_adjustment, final_nanos)
+// Splits an amount of a sub-second unit into whole seconds and the nanosecond of second, for every `i64`.
+pub fn seconds_and_nanos_of_units(amount: i64, units_in_second: i64) -> (i64, u32) {
+    (
+        amount.div_euclid(units_in_second),
+        (amount.rem_euclid(units_in_second) * (NANOSECONDS_IN_SECOND / units_in_second)) as u32,
+    )
 }
 
 // A second adjustment for when nanoseconds are within 1 step, instead of unbounded.
+// The step toward zero cannot overflow, but a value more than a second outside the range would be truncated,
+// so callers only pass sums or differences of two nanoseconds of second.
 pub fn carry_and_nanos(nanoseconds: i64) -> (i64, u32) {
     if nanoseconds < 0 {
         (-1, (nanoseconds + NANOSECONDS_IN_SECOND) as u32)
@@ -29,3 +44,145 @@ pub fn carry_and_nanos(nanoseconds: i64) -> (i64, u32) {
         (0, nanoseconds as u32)
     }
 }
+
+// Adds two normalized seconds/nanos pairs, widening the seconds so that a nanosecond carry
+// can bring a sum of near-extreme values back into range.
+pub fn add_seconds_and_nanos_checked(
+    (seconds, nanos): (i64, u32),
+    (other_seconds, other_nanos): (i64, u32),
+) -> Option<(i64, u32)> {
+    let (carry, nanos) = carry_and_nanos(i64::from(nanos) + i64::from(other_nanos));
+    i64::try_from(i128::from(seconds) + i128::from(other_seconds) + i128::from(carry))
+        .ok()
+        .map(|seconds| (seconds, nanos))
+}
+
+// Adds two normalized seconds/nanos pairs with wrapping seconds, also returning whether the true sum overflowed.
+// The nanosecond carry is at most one, so the two steps can only both overflow when they cancel out
+// (as for `i64::MIN + -1 + 1`), meaning the sum is out of range exactly when one of them overflows.
+pub fn add_seconds_and_nanos_overflowing(
+    (seconds, nanos): (i64, u32),
+    (other_seconds, other_nanos): (i64, u32),
+) -> ((i64, u32), bool) {
+    let nanos = nanos + other_nanos;
+    let carry = u32::from(nanos >= NANOSECONDS_IN_SECOND as u32);
+    let (seconds, overflow) = seconds.overflowing_add(other_seconds);
+    let (seconds, carry_overflow) = seconds.overflowing_add(i64::from(carry));
+    (
+        (seconds, nanos - carry * NANOSECONDS_IN_SECOND as u32),
+        overflow != carry_overflow,
+    )
+}
+
+// Subtracts a normalized seconds/nanos pair with wrapping seconds,
+// exactly undoing `add_seconds_and_nanos_overflowing` even when that overflowed.
+fn sub_seconds_and_nanos_wrapping(
+    (seconds, nanos): (i64, u32),
+    (other_seconds, other_nanos): (i64, u32),
+) -> (i64, u32) {
+    let (borrow, nanos) = carry_and_nanos(i64::from(nanos) - i64::from(other_nanos));
+    (
+        seconds.wrapping_sub(other_seconds).wrapping_add(borrow),
+        nanos,
+    )
+}
+
+// Adds the offset to every element of the slice, or to none of them if any would overflow.
+// The update is a single pass without per-element branching on overflow, only accumulating whether any element
+// overflowed; in that (rare) case the wrapped elements are restored, and the first overflowing element found.
+pub fn add_to_slice_checked<T: Copy>(
+    values: &mut [T],
+    offset: (i64, u32),
+    to_seconds_and_nanos: impl Fn(T) -> (i64, u32),
+    of_seconds_and_nanos: impl Fn((i64, u32)) -> T,
+) -> Result<(), SliceOverflowError> {
+    let mut any_overflow = false;
+    for value in values.iter_mut() {
+        let (sum, overflow) =
+            add_seconds_and_nanos_overflowing(to_seconds_and_nanos(*value), offset);
+        any_overflow |= overflow;
+        *value = of_seconds_and_nanos(sum);
+    }
+    if !any_overflow {
+        return Ok(());
+    }
+
+    let mut first_overflow = None;
+    for (index, value) in values.iter_mut().enumerate() {
+        let original = sub_seconds_and_nanos_wrapping(to_seconds_and_nanos(*value), offset);
+        if first_overflow.is_none() && add_seconds_and_nanos_checked(original, offset).is_none() {
+            first_overflow = Some(index);
+        }
+        *value = of_seconds_and_nanos(original);
+    }
+    Err(SliceOverflowError::new(
+        first_overflow.expect("an element overflowed"),
+    ))
+}
+
+pub fn sub_seconds_and_nanos_checked(
+    (seconds, nanos): (i64, u32),
+    (other_seconds, other_nanos): (i64, u32),
+) -> Option<(i64, u32)> {
+    let (carry, nanos) = carry_and_nanos(i64::from(nanos) - i64::from(other_nanos));
+    i64::try_from(i128::from(seconds) - i128::from(other_seconds) + i128::from(carry))
+        .ok()
+        .map(|seconds| (seconds, nanos))
+}
+
+pub fn total_nanos(seconds: i64, nanos: u32) -> i128 {
+    i128::from(seconds) * i128::from(NANOSECONDS_IN_SECOND) + i128::from(nanos)
+}
+
+pub fn of_total_nanos_checked(total_nanos: i128) -> Option<(i64, u32)> {
+    let nanoseconds_in_second = i128::from(NANOSECONDS_IN_SECOND);
+    i64::try_from(total_nanos.div_euclid(nanoseconds_in_second))
+        .ok()
+        .map(|seconds| {
+            (
+                seconds,
+                total_nanos.rem_euclid(nanoseconds_in_second) as u32,
+            )
+        })
+}
+
+// Splits a normalized seconds/nanos pair into a sign and the magnitude of both parts,
+// so that (for example) -1 seconds + 500_000_000 nanos becomes (true, 0, 500_000_000).
+pub fn sign_and_magnitude(seconds: i64, nanos: u32) -> (bool, u64, u32) {
+    if seconds < 0 && nanos > 0 {
+        (
+            true,
+            (seconds + 1).unsigned_abs(),
+            NANOSECONDS_IN_SECOND as u32 - nanos,
+        )
+    } else {
+        (seconds < 0, seconds.unsigned_abs(), nanos)
+    }
+}
+
+// Packs a normalized seconds/nanos pair into a u128, with the seconds biased by 2^63 in the high 64 bits,
+// zero padding, and the nanos in the low 32 bits.
+// The bias means unsigned comparison of the packed values matches the ordering of the pairs.
+pub const fn to_bits(seconds: i64, nanos: u32) -> u128 {
+    (to_biased_seconds(seconds) as u128) << 64 | nanos as u128
+}
+
+pub const fn from_bits(bits: u128) -> Result<(i64, u32), DecodeError> {
+    let nanos = bits as u32;
+    if (bits >> 32) as u32 != 0 {
+        Err(DecodeError::InvalidPadding)
+    } else if nanos as i64 >= NANOSECONDS_IN_SECOND {
+        Err(DecodeError::InvalidNanos(nanos))
+    } else {
+        Ok((of_biased_seconds((bits >> 64) as u64), nanos))
+    }
+}
+
+// Offsets seconds by 2^63 into a `u64`, so that unsigned order matches signed order, with `i64::MIN` at zero.
+pub const fn to_biased_seconds(seconds: i64) -> u64 {
+    (seconds as u64) ^ SECONDS_BIAS
+}
+
+pub const fn of_biased_seconds(biased_seconds: u64) -> i64 {
+    (biased_seconds ^ SECONDS_BIAS) as i64
+}
@@ -0,0 +1,64 @@
+use proptest::prelude::*;
+
+use crate::constants::*;
+use crate::seconds_nanos::*;
+use crate::testing::I64_EDGES;
+
+// Every value within this distance of a boundary is checked, rather than a sample of them.
+const WINDOW: i64 = 10_000;
+
+proptest! {
+    #[test]
+    fn seconds_and_nanos_sweep(boundary in prop_oneof![
+        prop::sample::select(I64_EDGES.to_vec()),
+        Just(-NANOSECONDS_IN_SECOND),
+        Just(NANOSECONDS_IN_SECOND),
+        Just(i64::MIN / NANOSECONDS_IN_SECOND * NANOSECONDS_IN_SECOND),
+        Just(i64::MAX / NANOSECONDS_IN_SECOND * NANOSECONDS_IN_SECOND),
+        (-1_000_000i64..1_000_000).prop_map(|seconds| seconds * NANOSECONDS_IN_SECOND),
+    ]) {
+        let start = boundary.saturating_sub(WINDOW);
+        let end = boundary.saturating_add(WINDOW);
+        for nanoseconds in start..=end {
+            let (seconds, nanos) = seconds_and_nanos(nanoseconds);
+            prop_assert!(nanos < NANOSECONDS_IN_SECOND as u32, "{}", nanoseconds);
+            prop_assert_eq!(i128::from(nanoseconds), total_nanos(seconds, nanos));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn seconds_and_nanos_of_units_sweep(units_in_second in prop_oneof![Just(1_000i64), Just(1_000_000), Just(NANOSECONDS_IN_SECOND)], boundary in prop::sample::select(I64_EDGES.to_vec())) {
+        let start = boundary.saturating_sub(WINDOW);
+        let end = boundary.saturating_add(WINDOW);
+        let nanos_per_unit = NANOSECONDS_IN_SECOND / units_in_second;
+        for amount in start..=end {
+            let (seconds, nanos) = seconds_and_nanos_of_units(amount, units_in_second);
+            prop_assert!(nanos < NANOSECONDS_IN_SECOND as u32, "{}", amount);
+            prop_assert_eq!(i128::from(amount) * i128::from(nanos_per_unit), total_nanos(seconds, nanos));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn carry_and_nanos_sweep(boundary in prop_oneof![
+        // The domain is the sums and differences of two nanoseconds of second.
+        Just(-(NANOSECONDS_IN_SECOND - 1)),
+        Just(0),
+        Just(NANOSECONDS_IN_SECOND),
+        Just(2 * (NANOSECONDS_IN_SECOND - 1)),
+        -(NANOSECONDS_IN_SECOND - 1)..=2 * (NANOSECONDS_IN_SECOND - 1),
+    ]) {
+        let start = (boundary - WINDOW).max(-(NANOSECONDS_IN_SECOND - 1));
+        let end = (boundary + WINDOW).min(2 * (NANOSECONDS_IN_SECOND - 1));
+        for nanoseconds in start..=end {
+            let (carry, nanos) = carry_and_nanos(nanoseconds);
+            prop_assert!(nanos < NANOSECONDS_IN_SECOND as u32, "{}", nanoseconds);
+            prop_assert!((-1..=1).contains(&carry), "{}", nanoseconds);
+            prop_assert_eq!(i128::from(nanoseconds), total_nanos(carry, nanos));
+            prop_assert_eq!(seconds_and_nanos(nanoseconds), (carry, nanos));
+        }
+    }
+}
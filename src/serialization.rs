@@ -0,0 +1,110 @@
+//! [`serde`](https://serde.rs) support.
+//!
+//! - `Duration` is a string in ISO-8601 form, as produced by `Display` and accepted by `Duration::parse`,
+//!   such as `"PT8M20.345S"`.
+//! - `DurationRange` is a struct of its `min` and `max` durations, such as `{"min": "PT1S", "max": "PT30S"}`.
+//!   Deserializing rejects inverted bounds.
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::{Duration, DurationRange};
+
+#[cfg(test)]
+pub mod round_trip;
+
+const RANGE_FIELDS: &[&str] = &["min", "max"];
+
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserializer.deserialize_str(DurationVisitor)
+    }
+}
+
+struct DurationVisitor;
+
+impl<'de> Visitor<'de> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an ISO-8601 duration, such as \"PT8M20.345S\"")
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Duration, E> {
+        Duration::parse(text)
+            .map_err(|error| E::custom(format_args!("invalid duration {:?}: {}", text, error)))
+    }
+}
+
+impl Serialize for DurationRange {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut range = serializer.serialize_struct("DurationRange", RANGE_FIELDS.len())?;
+        range.serialize_field("min", &self.min())?;
+        range.serialize_field("max", &self.max())?;
+        range.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationRange {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<DurationRange, D::Error> {
+        deserializer.deserialize_struct("DurationRange", RANGE_FIELDS, DurationRangeVisitor)
+    }
+}
+
+struct DurationRangeVisitor;
+
+impl DurationRangeVisitor {
+    fn range<E: de::Error>(min: Duration, max: Duration) -> Result<DurationRange, E> {
+        DurationRange::inclusive(min, max).ok_or_else(|| {
+            E::custom(format_args!(
+                "duration range minimum {} is greater than maximum {}",
+                min, max
+            ))
+        })
+    }
+}
+
+impl<'de> Visitor<'de> for DurationRangeVisitor {
+    type Value = DurationRange;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a duration range with \"min\" and \"max\"")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<DurationRange, A::Error> {
+        let min = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let max = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        DurationRangeVisitor::range(min, max)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<DurationRange, A::Error> {
+        let (mut min, mut max) = (None, None);
+        while let Some(key) = map.next_key::<&str>()? {
+            let slot = match key {
+                "min" => &mut min,
+                "max" => &mut max,
+                _ => return Err(de::Error::unknown_field(key, RANGE_FIELDS)),
+            };
+            if slot.is_some() {
+                return Err(de::Error::custom(format_args!("duplicate field `{}`", key)));
+            }
+            *slot = Some(map.next_value()?);
+        }
+        DurationRangeVisitor::range(
+            min.ok_or_else(|| de::Error::missing_field("min"))?,
+            max.ok_or_else(|| de::Error::missing_field("max"))?,
+        )
+    }
+}
@@ -0,0 +1,57 @@
+use proptest::prelude::*;
+
+use crate::{Duration, DurationRange};
+
+proptest! {
+    #[test]
+    fn duration_round_trip(seconds in prop::num::i64::ANY, nanos in 0..1_000_000_000i64) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, nanos);
+
+        let json = serde_json::to_string(&duration).unwrap();
+
+        prop_assert_eq!(format!("\"{}\"", duration), json.clone());
+        prop_assert_eq!(duration, serde_json::from_str::<Duration>(&json).unwrap());
+    }
+}
+
+#[test]
+fn duration_invalid() {
+    let error = serde_json::from_str::<Duration>("\"PT1X\"").unwrap_err();
+
+    assert!(
+        error.to_string().starts_with("invalid duration \"PT1X\""),
+        "{}",
+        error
+    );
+    assert!(serde_json::from_str::<Duration>("5").is_err());
+}
+
+#[test]
+fn range_round_trip() {
+    let range =
+        DurationRange::inclusive(Duration::of_seconds(1), Duration::of_seconds(30)).unwrap();
+
+    let json = serde_json::to_string(&range).unwrap();
+
+    assert_eq!(r#"{"min":"PT1S","max":"PT30S"}"#, json);
+    assert_eq!(range, serde_json::from_str::<DurationRange>(&json).unwrap());
+    assert_eq!(
+        range,
+        serde_json::from_str::<DurationRange>(r#"{"max": "PT30S", "min": "PT1S"}"#).unwrap()
+    );
+}
+
+proptest! {
+    #[test]
+    fn range_invalid((json, message) in prop_oneof![
+        Just((r#"{"min": "PT30S", "max": "PT1S"}"#, "duration range minimum PT30S is greater than maximum PT1S")),
+        Just((r#"{"min": "PT1S"}"#, "missing field `max`")),
+        Just((r#"{"max": "PT1S"}"#, "missing field `min`")),
+        Just((r#"{"min": "PT1S", "max": "PT2S", "step": "PT1S"}"#, "unknown field `step`")),
+        Just((r#"{"min": "PT1S", "min": "PT2S", "max": "PT3S"}"#, "duplicate field `min`")),
+    ]) {
+        let error = serde_json::from_str::<DurationRange>(json).unwrap_err();
+
+        prop_assert!(error.to_string().starts_with(message), "{}", error);
+    }
+}
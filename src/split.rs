@@ -0,0 +1,63 @@
+use core::iter::FusedIterator;
+
+use crate::seconds_nanos::*;
+use crate::Duration;
+
+/// An iterator over equal chunks of a duration, which sum exactly to the original.
+///
+/// This is created by [`Duration::split_evenly`](struct.Duration.html#method.split_evenly).
+/// The nanoseconds that do not divide evenly are spread one each over the first chunks,
+/// so the chunks differ by at most one nanosecond, and the larger chunks come first.
+/// A negative duration splits into the negations of the chunks of its magnitude.
+#[derive(Clone, Debug)]
+pub struct SplitEvenly {
+    quotient: i128,
+    remainder: i128,
+    index: u32,
+    parts: u32,
+}
+
+impl SplitEvenly {
+    pub(crate) fn of(duration: Duration, parts: u32) -> SplitEvenly {
+        let total = total_nanos(duration.seconds(), duration.nano());
+        let divisor = i128::from(parts);
+        SplitEvenly {
+            quotient: total / divisor,
+            // Like the quotient, this is truncated towards zero, so it takes the sign of the duration.
+            remainder: total % divisor,
+            index: 0,
+            parts,
+        }
+    }
+}
+
+impl Iterator for SplitEvenly {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if self.index == self.parts {
+            return None;
+        }
+        let chunk = if i128::from(self.index) < self.remainder.abs() {
+            self.quotient + self.remainder.signum()
+        } else {
+            self.quotient
+        };
+        self.index += 1;
+        // Every chunk is no larger in magnitude than the duration, so it always fits.
+        let (seconds, nanos) = of_total_nanos_checked(chunk).expect("chunk is in range");
+        Some(Duration::of_seconds_and_adjustment(
+            seconds,
+            i64::from(nanos),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.parts - self.index) as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for SplitEvenly {}
+
+impl FusedIterator for SplitEvenly {}
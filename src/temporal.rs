@@ -0,0 +1,86 @@
+use crate::{Duration, Instant, TemporalError};
+
+#[cfg(test)]
+pub mod consistency;
+
+/// A point in time that supports adding a duration and measuring the duration until another such point,
+/// so that generic code, such as for scheduling, can be written once over any of them.
+///
+/// Like `java.time.temporal.Temporal`, every failure is reported as a [`TemporalError`](enum.TemporalError.html),
+/// rather than panicking as the operators do.
+pub trait Temporal: Sized {
+    /// Returns a copy of this value with the specified duration added.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to add, positive or negative.
+    ///
+    /// # Errors
+    /// - if the result would be outside the range of the type.
+    fn plus_duration(&self, duration: Duration) -> Result<Self, TemporalError>;
+
+    /// Returns a copy of this value with the specified duration subtracted.
+    ///
+    /// # Parameters
+    ///  - `duration`: the duration to subtract, positive or negative.
+    ///
+    /// # Errors
+    /// - if the result would be outside the range of the type.
+    fn minus_duration(&self, duration: Duration) -> Result<Self, TemporalError>;
+
+    /// Gets the signed duration from this value until the other,
+    /// which is negative if the other is earlier.
+    ///
+    /// # Parameters
+    ///  - `end`: the value to measure until.
+    ///
+    /// # Errors
+    /// - if the duration would be outside the range of `Duration`.
+    fn until(&self, end: &Self) -> Result<Duration, TemporalError>;
+}
+
+/// An amount of time that can be added to (or subtracted from) a [`Temporal`](trait.Temporal.html),
+/// as with `java.time.temporal.TemporalAmount`.
+pub trait TemporalAmount {
+    /// Adds this amount to the temporal value.
+    ///
+    /// # Parameters
+    ///  - `temporal`: the value to add to.
+    ///
+    /// # Errors
+    /// - if the amount cannot be added to this type of value, or the result would be out of range.
+    fn add_to<T: Temporal>(&self, temporal: &T) -> Result<T, TemporalError>;
+
+    /// Subtracts this amount from the temporal value.
+    ///
+    /// # Parameters
+    ///  - `temporal`: the value to subtract from.
+    ///
+    /// # Errors
+    /// - if the amount cannot be subtracted from this type of value, or the result would be out of range.
+    fn subtract_from<T: Temporal>(&self, temporal: &T) -> Result<T, TemporalError>;
+}
+
+impl Temporal for Instant {
+    fn plus_duration(&self, duration: Duration) -> Result<Instant, TemporalError> {
+        self.checked_add(duration).ok_or(TemporalError::Overflow)
+    }
+
+    fn minus_duration(&self, duration: Duration) -> Result<Instant, TemporalError> {
+        self.checked_sub(duration).ok_or(TemporalError::Overflow)
+    }
+
+    fn until(&self, end: &Instant) -> Result<Duration, TemporalError> {
+        end.checked_duration_since(*self)
+            .ok_or(TemporalError::Overflow)
+    }
+}
+
+impl TemporalAmount for Duration {
+    fn add_to<T: Temporal>(&self, temporal: &T) -> Result<T, TemporalError> {
+        temporal.plus_duration(*self)
+    }
+
+    fn subtract_from<T: Temporal>(&self, temporal: &T) -> Result<T, TemporalError> {
+        temporal.minus_duration(*self)
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt::Debug;
+
+use proptest::prelude::*;
+
+use crate::{Duration, Instant, Temporal, TemporalAmount, TemporalError};
+
+// Checks the laws every implementor must satisfy, for a start value and a duration that keep it in range.
+fn check_consistent<T: Temporal + Clone + Debug + PartialEq>(
+    start: T,
+    duration: Duration,
+) -> Result<(), TestCaseError> {
+    let end = start.plus_duration(duration).unwrap();
+
+    prop_assert_eq!(Ok(duration), start.until(&end));
+    prop_assert_eq!(Ok(-duration), end.until(&start));
+    prop_assert_eq!(Ok(Duration::ZERO), start.until(&start));
+    prop_assert_eq!(Ok(start.clone()), end.minus_duration(duration));
+    prop_assert_eq!(Ok(end.clone()), duration.add_to(&start));
+    prop_assert_eq!(Ok(start), duration.subtract_from(&end));
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn instant_is_consistent(
+        seconds in -(1i64 << 60)..(1i64 << 60),
+        nanos in 0..1_000_000_000i64,
+        duration_seconds in -(1i64 << 60)..(1i64 << 60),
+        duration_nanos in 0..1_000_000_000i64,
+    ) {
+        check_consistent(
+            Instant::of_epoch_second_and_adjustment(seconds, nanos),
+            Duration::of_seconds_and_adjustment(duration_seconds, duration_nanos),
+        )?;
+    }
+}
+
+proptest! {
+    #[test]
+    fn instant_overflow((result, expected) in prop_oneof![
+        Just((Instant::MAX.plus_duration(Duration::of_nanos(1)), Err(TemporalError::Overflow))),
+        Just((Instant::MIN.minus_duration(Duration::of_nanos(1)), Err(TemporalError::Overflow))),
+        Just((Duration::MIN.add_to(&Instant::MIN), Err(TemporalError::Overflow))),
+        Just((Duration::MAX.add_to(&Instant::MIN), Ok(Instant::of_epoch_second_and_adjustment(-1, 999_999_999)))),
+    ]) {
+        prop_assert_eq!(expected, result);
+    }
+}
+
+proptest! {
+    #[test]
+    fn instant_until_overflow((start, end, expected) in prop_oneof![
+        Just((Instant::MIN, Instant::MAX, Err(TemporalError::Overflow))),
+        Just((Instant::MAX, Instant::MIN, Err(TemporalError::Overflow))),
+        Just((Instant::EPOCH, Instant::MAX, Ok(Duration::MAX))),
+    ]) {
+        prop_assert_eq!(expected, start.until(&end));
+    }
+}
@@ -0,0 +1,107 @@
+//! Helpers for the tests of code using this crate, such as [`assert_duration_eq!`](../macro.assert_duration_eq.html).
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use crate::{Duration, Tolerance};
+
+#[cfg(test)]
+pub mod assertions;
+
+/// A value that [`assert_duration_eq!`](../macro.assert_duration_eq.html) can compare,
+/// either a [`Duration`](../struct.Duration.html) or a standard library duration.
+pub trait ComparableDuration: fmt::Debug {
+    /// Converts this value to a duration, or `None` if it is too long for one.
+    fn to_duration(&self) -> Option<Duration>;
+}
+
+impl ComparableDuration for Duration {
+    fn to_duration(&self) -> Option<Duration> {
+        Some(*self)
+    }
+}
+
+impl ComparableDuration for core::time::Duration {
+    fn to_duration(&self) -> Option<Duration> {
+        i64::try_from(self.as_secs()).ok().map(|seconds| {
+            Duration::of_seconds_and_adjustment(seconds, i64::from(self.subsec_nanos()))
+        })
+    }
+}
+
+impl<T: ComparableDuration + ?Sized> ComparableDuration for &T {
+    fn to_duration(&self) -> Option<Duration> {
+        (**self).to_duration()
+    }
+}
+
+/// Asserts that two durations are equal, or within a tolerance, where either may be a standard library duration,
+/// such as `assert_duration_eq!(elapsed, std::time::Duration::from_millis(1_500), Tolerance::millis(1))`.
+///
+/// On failure, both values are printed as ISO-8601 durations, such as `PT1.5S`, along with their difference,
+/// rather than in the `Debug` form of the standard library, as `1.5s`, or of this crate.
+/// A standard library duration too long for a [`Duration`](struct.Duration.html) is printed in its `Debug` form,
+/// and is never equal to anything.
+///
+/// # Parameters
+///  - `left`, `right`: the durations to compare, each a `Duration` or a `std::time::Duration`.
+///  - `tolerance`: optionally, the largest difference allowed, as a [`Tolerance`](struct.Tolerance.html).
+///    Without it the durations must be exactly equal.
+#[macro_export]
+macro_rules! assert_duration_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::test_util::__assert_duration_eq(&$left, &$right, $crate::Tolerance::EXACT)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        $crate::test_util::__assert_duration_eq(&$left, &$right, $tolerance)
+    };
+}
+
+struct Iso<'a>(&'a dyn ComparableDuration);
+
+impl fmt::Display for Iso<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.to_duration() {
+            Some(duration) => write!(f, "{}", duration),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_duration_eq(
+    left: &dyn ComparableDuration,
+    right: &dyn ComparableDuration,
+    tolerance: Tolerance,
+) {
+    let (left_duration, right_duration) = (left.to_duration(), right.to_duration());
+    if let (Some(left_duration), Some(right_duration)) = (left_duration, right_duration) {
+        if left_duration.approx_eq(right_duration, tolerance) {
+            return;
+        }
+    }
+    let difference = left_duration
+        .zip(right_duration)
+        .and_then(|(left, right)| left.checked_sub(right));
+    let difference = match difference {
+        Some(difference) => difference.to_string(),
+        None => "too long for a duration".to_owned(),
+    };
+    if tolerance == Tolerance::EXACT {
+        panic!(
+            "assertion failed: `left == right`\n      left: {}\n     right: {}\ndifference: {}",
+            Iso(left),
+            Iso(right),
+            difference
+        );
+    } else {
+        panic!(
+            "assertion failed: `left` is within {} of `right`\n      left: {}\n     right: {}\ndifference: {}",
+            tolerance.duration(),
+            Iso(left),
+            Iso(right),
+            difference
+        );
+    }
+}
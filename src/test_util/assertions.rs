@@ -0,0 +1,73 @@
+use proptest::prelude::*;
+
+use crate::{assert_duration_eq, Duration, Tolerance};
+
+fn panic_message(assertion: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = std::panic::catch_unwind(assertion).unwrap_err();
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .expect("assertion panics with a formatted message")
+}
+
+proptest! {
+    #[test]
+    fn assert_duration_eq_passes((left, right, tolerance) in prop_oneof![
+        Just((Duration::of_millis(1_500), std::time::Duration::from_millis(1_500), Tolerance::EXACT)),
+        Just((Duration::ZERO, std::time::Duration::ZERO, Tolerance::EXACT)),
+        Just((Duration::of_millis(1_500), std::time::Duration::from_micros(1_500_900), Tolerance::millis(1))),
+        Just((Duration::MAX, std::time::Duration::new(i64::MAX as u64, 999_999_999), Tolerance::EXACT)),
+    ]) {
+        assert_duration_eq!(left, right, tolerance);
+        assert_duration_eq!(right, left, tolerance);
+        assert_duration_eq!(left, left);
+    }
+}
+
+proptest! {
+    #[test]
+    fn assert_duration_eq_message((left, right, expected) in prop_oneof![
+        Just((
+            Duration::of_millis(1_500),
+            std::time::Duration::from_secs(2),
+            "assertion failed: `left == right`\n      left: PT1.5S\n     right: PT2S\ndifference: PT-0.5S",
+        )),
+        Just((
+            Duration::of_seconds(-90),
+            std::time::Duration::from_secs(90),
+            "assertion failed: `left == right`\n      left: PT-1M-30S\n     right: PT1M30S\ndifference: PT-3M",
+        )),
+        Just((
+            Duration::ZERO,
+            std::time::Duration::MAX,
+            "assertion failed: `left == right`\n      left: PT0S\n     right: 18446744073709551615.999999999s\ndifference: too long for a duration",
+        )),
+    ]) {
+        prop_assert_eq!(expected, panic_message(move || assert_duration_eq!(left, right)));
+    }
+}
+
+#[test]
+fn assert_duration_eq_message_with_tolerance() {
+    let message = panic_message(|| {
+        assert_duration_eq!(
+            Duration::of_millis(1_500),
+            Duration::of_millis(1_502),
+            Tolerance::millis(1)
+        )
+    });
+
+    assert_eq!(
+        "assertion failed: `left` is within PT0.001S of `right`\n      left: PT1.5S\n     right: PT1.502S\ndifference: PT-0.002S",
+        message
+    );
+}
+
+#[test]
+#[should_panic(expected = "left: PT1H\n     right: PT59M59S")]
+fn assert_duration_eq_fails_between_std_durations() {
+    assert_duration_eq!(
+        std::time::Duration::from_secs(3_600),
+        std::time::Duration::from_secs(3_599)
+    );
+}
@@ -0,0 +1,51 @@
+//! Helpers shared by the tests.
+
+use core::fmt;
+
+// A writer over a fixed-capacity buffer, which fails (without writing anything) when a write would overflow it.
+pub struct FixedWriter<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedWriter<N> {
+    pub fn new() -> FixedWriter<N> {
+        FixedWriter {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Write for FixedWriter<N> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        let end = self.len + text.len();
+        if end > N {
+            return Err(fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(text.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+// The edges of the `i64` range, and around zero, where conversions of untrusted values are most likely to overflow.
+pub const I64_EDGES: [i64; 7] = [i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+
+// Asserts that the panicking form of a conversion panics exactly when its checked counterpart gives `None`,
+// and otherwise agrees with it.
+pub fn assert_panics_only_when_none<T: fmt::Debug + PartialEq>(
+    panicking: impl FnOnce() -> T + std::panic::UnwindSafe,
+    checked: Option<T>,
+) {
+    match (std::panic::catch_unwind(panicking), checked) {
+        (Ok(value), Some(expected)) => assert_eq!(expected, value),
+        (Err(_), None) => {}
+        (Ok(value), None) => panic!("{:?} was returned, but the checked form gave None", value),
+        (Err(_), Some(expected)) => panic!("panicked, but the checked form gave {:?}", expected),
+    }
+}
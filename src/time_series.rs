@@ -0,0 +1,147 @@
+use std::collections::btree_map::{BTreeMap, Iter, Range};
+use std::iter::FromIterator;
+use std::ops::{Bound, RangeBounds};
+
+use crate::seconds_nanos::*;
+use crate::Instant;
+
+#[cfg(test)]
+pub mod lookups;
+
+// An entry of a time series, as returned by its lookups.
+type Entry<'a, V> = (&'a Instant, &'a V);
+
+/// A table of values keyed by instant, with lookups of the entries around an instant, such as for interpolating ephemerides.
+///
+/// This is a thin wrapper over a `BTreeMap<Instant, V>`, so every lookup is `O(log n)`.
+/// Each instant holds at most one value; inserting at an existing instant replaces its value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeSeries<V> {
+    entries: BTreeMap<Instant, V>,
+}
+
+impl<V> TimeSeries<V> {
+    /// Obtains an empty time series.
+    pub fn new() -> TimeSeries<V> {
+        TimeSeries {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Inserts a value at the instant, returning the value it replaced, if any.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to key the value by.
+    ///  - `value`: the value to store.
+    pub fn insert(&mut self, instant: Instant, value: V) -> Option<V> {
+        self.entries.insert(instant, value)
+    }
+
+    /// Gets the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over the entries in order of their instants.
+    pub fn iter(&self) -> Iter<'_, Instant, V> {
+        self.entries.iter()
+    }
+
+    /// Iterates over the entries with instants in the range, in order, such as `start..end` or `start..`.
+    ///
+    /// # Parameters
+    ///  - `range`: the instants to include.
+    ///
+    /// # Panics
+    /// - if the range starts after it ends, or starts and ends at the same excluded instant, as for `BTreeMap::range`.
+    pub fn range<R: RangeBounds<Instant>>(&self, range: R) -> Range<'_, Instant, V> {
+        self.entries.range(range)
+    }
+
+    /// Gets the latest entry at or before the instant, or `None` if every entry is after it.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to look up.
+    pub fn at_or_before(&self, instant: Instant) -> Option<Entry<'_, V>> {
+        self.entries.range(..=instant).next_back()
+    }
+
+    /// Gets the earliest entry at or after the instant, or `None` if every entry is before it.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to look up.
+    pub fn at_or_after(&self, instant: Instant) -> Option<Entry<'_, V>> {
+        self.entries.range(instant..).next()
+    }
+
+    /// Gets the entries either side of the instant, such as for interpolating between them,
+    /// or `None` if the instant is before the first entry or after the last.
+    ///
+    /// An entry exactly at the instant is returned as both sides, so the pair is never more than one step apart;
+    /// otherwise the first of the pair is the latest entry before the instant, and the second the earliest entry after it.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to look up.
+    pub fn bracketing(&self, instant: Instant) -> Option<(Entry<'_, V>, Entry<'_, V>)> {
+        let before = self.at_or_before(instant)?;
+        if *before.0 == instant {
+            return Some((before, before));
+        }
+        let after = self
+            .entries
+            .range((Bound::Excluded(instant), Bound::Unbounded))
+            .next()?;
+        Some((before, after))
+    }
+
+    /// Gets the entry closest to the instant, or `None` if there are no entries.
+    ///
+    /// When the instant is equally distant from two entries, the earlier one is chosen.
+    ///
+    /// # Parameters
+    ///  - `instant`: the instant to look up.
+    pub fn nearest(&self, instant: Instant) -> Option<Entry<'_, V>> {
+        match (self.at_or_before(instant), self.at_or_after(instant)) {
+            (Some(before), Some(after)) => {
+                let query = total_nanos(instant.epoch_second(), instant.nano());
+                let before_distance = query - total_nanos(before.0.epoch_second(), before.0.nano());
+                let after_distance = total_nanos(after.0.epoch_second(), after.0.nano()) - query;
+                if before_distance <= after_distance {
+                    Some(before)
+                } else {
+                    Some(after)
+                }
+            }
+            (before, after) => before.or(after),
+        }
+    }
+}
+
+impl<V> Default for TimeSeries<V> {
+    fn default() -> TimeSeries<V> {
+        TimeSeries::new()
+    }
+}
+
+impl<V> FromIterator<(Instant, V)> for TimeSeries<V> {
+    /// Collects the entries into a time series; of entries at the same instant, the last value is kept.
+    fn from_iter<I: IntoIterator<Item = (Instant, V)>>(entries: I) -> TimeSeries<V> {
+        TimeSeries {
+            entries: entries.into_iter().collect(),
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a TimeSeries<V> {
+    type Item = (&'a Instant, &'a V);
+    type IntoIter = Iter<'a, Instant, V>;
+
+    fn into_iter(self) -> Iter<'a, Instant, V> {
+        self.iter()
+    }
+}
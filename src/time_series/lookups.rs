@@ -0,0 +1,146 @@
+use proptest::prelude::*;
+
+use crate::{Instant, TimeSeries};
+
+fn series() -> TimeSeries<&'static str> {
+    vec![
+        (Instant::of_epoch_second(10), "a"),
+        (Instant::of_epoch_second(20), "b"),
+        (Instant::of_epoch_second(40), "c"),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn at(second: i64) -> Instant {
+    Instant::of_epoch_second(second)
+}
+
+proptest! {
+    #[test]
+    fn at_or_before_and_after((query, before, after) in prop_oneof![
+        Just((Instant::MIN, None, Some((10, "a")))),
+        Just((at(9), None, Some((10, "a")))),
+        Just((at(10), Some((10, "a")), Some((10, "a")))),
+        Just((at(15), Some((10, "a")), Some((20, "b")))),
+        Just((at(20), Some((20, "b")), Some((20, "b")))),
+        Just((at(39), Some((20, "b")), Some((40, "c")))),
+        Just((at(41), Some((40, "c")), None)),
+        Just((Instant::MAX, Some((40, "c")), None)),
+    ]) {
+        let series = series();
+        let (before_key, after_key) = (before.map(|(second, _)| at(second)), after.map(|(second, _)| at(second)));
+
+        prop_assert_eq!(before_key.as_ref().zip(before.as_ref().map(|(_, value)| value)), series.at_or_before(query));
+        prop_assert_eq!(after_key.as_ref().zip(after.as_ref().map(|(_, value)| value)), series.at_or_after(query));
+    }
+}
+
+proptest! {
+    #[test]
+    fn bracketing((query, expected) in prop_oneof![
+        Just((at(9), None)),
+        // An exact hit is both sides.
+        Just((at(10), Some((10, 10)))),
+        Just((at(11), Some((10, 20)))),
+        Just((at(20), Some((20, 20)))),
+        Just((Instant::of_epoch_second_and_adjustment(39, 999_999_999), Some((20, 40)))),
+        Just((at(40), Some((40, 40)))),
+        Just((Instant::of_epoch_second_and_adjustment(40, 1), None)),
+    ]) {
+        let series = series();
+
+        let bracket = series.bracketing(query).map(|((before, _), (after, _))| (before.epoch_second(), after.epoch_second()));
+        prop_assert_eq!(expected, bracket);
+    }
+}
+
+proptest! {
+    #[test]
+    fn nearest((query, expected) in prop_oneof![
+        Just((Instant::MIN, 10)),
+        Just((at(10), 10)),
+        Just((at(14), 10)),
+        // Equidistant chooses the earlier.
+        Just((at(15), 10)),
+        Just((Instant::of_epoch_second_and_adjustment(15, 1), 20)),
+        Just((at(30), 20)),
+        Just((at(31), 40)),
+        Just((Instant::MAX, 40)),
+    ]) {
+        prop_assert_eq!(Some(at(expected)), series().nearest(query).map(|(instant, _)| *instant));
+    }
+}
+
+proptest! {
+    #[test]
+    fn empty(query in prop_oneof![Just(Instant::MIN), Just(Instant::EPOCH), Just(Instant::MAX)]) {
+        let series = TimeSeries::<()>::new();
+
+        prop_assert!(series.is_empty());
+        prop_assert_eq!(None, series.at_or_before(query));
+        prop_assert_eq!(None, series.at_or_after(query));
+        prop_assert_eq!(None, series.bracketing(query));
+        prop_assert_eq!(None, series.nearest(query));
+        prop_assert_eq!(0, series.range(..).count());
+    }
+}
+
+proptest! {
+    #[test]
+    fn single_entry((query, before, after, bracketed) in prop_oneof![
+        Just((at(9), false, true, false)),
+        Just((at(10), true, true, true)),
+        Just((at(11), true, false, false)),
+    ]) {
+        let mut series = TimeSeries::new();
+        prop_assert_eq!(None, series.insert(at(10), "a"));
+        let entry = Some((&at(10), &"a"));
+
+        prop_assert_eq!(1, series.len());
+        prop_assert_eq!(if before { entry } else { None }, series.at_or_before(query));
+        prop_assert_eq!(if after { entry } else { None }, series.at_or_after(query));
+        prop_assert_eq!(if bracketed { entry.zip(entry) } else { None }, series.bracketing(query));
+        prop_assert_eq!(entry, series.nearest(query));
+    }
+}
+
+proptest! {
+    #[test]
+    fn insert_replaces(value in prop::num::u32::ANY) {
+        let mut series = TimeSeries::new();
+
+        prop_assert_eq!(None, series.insert(at(10), 0));
+        prop_assert_eq!(Some(0), series.insert(at(10), value));
+        prop_assert_eq!(1, series.len());
+        prop_assert_eq!(Some((&at(10), &value)), series.at_or_after(Instant::MIN));
+    }
+}
+
+proptest! {
+    #[test]
+    fn range((start, end, expected) in prop_oneof![
+        Just((at(0), at(100), vec!["a", "b", "c"])),
+        Just((at(10), at(40), vec!["a", "b"])),
+        Just((at(11), at(41), vec!["b", "c"])),
+        Just((at(21), at(39), vec![])),
+    ]) {
+        let series = series();
+
+        let values: Vec<&str> = series.range(start..end).map(|(_, value)| *value).collect();
+        prop_assert_eq!(expected, values);
+    }
+}
+
+proptest! {
+    #[test]
+    fn lookups_match_linear_scan(seconds in prop::collection::vec(-100i64..100, 0..20), query in -110i64..110) {
+        let series: TimeSeries<()> = seconds.iter().map(|&second| (at(second), ())).collect();
+        let query = at(query);
+
+        prop_assert_eq!(series.iter().rev().find(|(instant, _)| **instant <= query), series.at_or_before(query));
+        prop_assert_eq!(series.iter().find(|(instant, _)| **instant >= query), series.at_or_after(query));
+        let nearest = series.iter().min_by_key(|(instant, _)| instant.abs_diff(query));
+        prop_assert_eq!(nearest, series.nearest(query));
+    }
+}
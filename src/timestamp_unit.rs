@@ -0,0 +1,25 @@
+use crate::constants::*;
+
+/// The unit of an integer timestamp, counted from the epoch, such as in Apache Arrow and Parquet columns.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum TimestampUnit {
+    /// Whole seconds.
+    Second,
+    /// Milliseconds, 1,000 per second.
+    Millisecond,
+    /// Microseconds, 1,000,000 per second.
+    Microsecond,
+    /// Nanoseconds, 1,000,000,000 per second.
+    Nanosecond,
+}
+
+impl TimestampUnit {
+    pub(crate) fn per_second(self) -> i64 {
+        match self {
+            TimestampUnit::Second => 1,
+            TimestampUnit::Millisecond => MILLISECONDS_IN_SECOND,
+            TimestampUnit::Microsecond => MICROSECONDS_IN_SECOND,
+            TimestampUnit::Nanosecond => NANOSECONDS_IN_SECOND,
+        }
+    }
+}
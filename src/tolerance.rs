@@ -0,0 +1,83 @@
+use crate::seconds_nanos::*;
+use crate::Duration;
+
+#[cfg(test)]
+pub mod approx;
+
+/// The largest difference allowed between two approximately equal values, such as for tolerance-based tests.
+///
+/// This is a thin, named wrapper over a non-negative [`Duration`](struct.Duration.html),
+/// used by [`Duration::approx_eq`](struct.Duration.html#method.approx_eq)
+/// and [`Instant::approx_eq`](struct.Instant.html#method.approx_eq),
+/// so that assertions read as `assert!(a.approx_eq(b, Tolerance::millis(1)))`.
+/// Values whose difference is exactly the tolerance are approximately equal.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Tolerance {
+    duration: Duration,
+}
+
+impl Tolerance {
+    /// A tolerance of zero, for which only equal values are approximately equal.
+    pub const EXACT: Tolerance = Tolerance {
+        duration: Duration::ZERO,
+    };
+
+    /// Obtains a tolerance of the specified duration.
+    ///
+    /// # Parameters
+    ///  - `duration`: the largest difference allowed.
+    ///
+    /// # Panics
+    /// - if `duration` is negative.
+    pub fn of(duration: Duration) -> Tolerance {
+        assert!(!duration.is_negative(), "tolerance must not be negative");
+        Tolerance { duration }
+    }
+
+    /// Obtains a tolerance of a number of nanoseconds.
+    ///
+    /// # Parameters
+    ///  - `nanoseconds`: the largest difference allowed, in nanoseconds.
+    pub fn nanos(nanoseconds: u32) -> Tolerance {
+        Tolerance::of(Duration::of_nanos(i64::from(nanoseconds)))
+    }
+
+    /// Obtains a tolerance of a number of microseconds.
+    ///
+    /// # Parameters
+    ///  - `microseconds`: the largest difference allowed, in microseconds.
+    pub fn micros(microseconds: u32) -> Tolerance {
+        Tolerance::of(Duration::of_micros(i64::from(microseconds)))
+    }
+
+    /// Obtains a tolerance of a number of milliseconds.
+    ///
+    /// # Parameters
+    ///  - `milliseconds`: the largest difference allowed, in milliseconds.
+    pub fn millis(milliseconds: u32) -> Tolerance {
+        Tolerance::of(Duration::of_millis(i64::from(milliseconds)))
+    }
+
+    /// Obtains a tolerance of a number of seconds.
+    ///
+    /// # Parameters
+    ///  - `seconds`: the largest difference allowed, in seconds.
+    pub fn seconds(seconds: u32) -> Tolerance {
+        Tolerance::of(Duration::of_seconds(i64::from(seconds)))
+    }
+
+    /// Gets the largest difference allowed, as a duration.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    // Compares two normalized seconds/nanos pairs, widening so that the difference of any two values fits.
+    pub(crate) fn allows(
+        &self,
+        (seconds, nanos): (i64, u32),
+        (other_seconds, other_nanos): (i64, u32),
+    ) -> bool {
+        (total_nanos(seconds, nanos) - total_nanos(other_seconds, other_nanos)).abs()
+            <= total_nanos(self.duration.seconds(), self.duration.nano())
+    }
+}
@@ -0,0 +1,55 @@
+use proptest::prelude::*;
+
+use crate::{Duration, Instant, Tolerance};
+
+proptest! {
+    #[test]
+    fn millis_tolerance((difference_nanos, expected) in prop_oneof![
+        Just((0, true)),
+        Just((500_000, true)),
+        Just((-500_000, true)),
+        Just((1_000_000, true)),
+        Just((1_000_001, false)),
+        Just((2_000_000, false)),
+        Just((-2_000_000, false)),
+    ]) {
+        let duration = Duration::of_seconds(10);
+        let instant = Instant::of_epoch_second(1_700_000_000);
+        let difference = Duration::of_nanos(difference_nanos);
+
+        prop_assert_eq!(expected, duration.approx_eq(duration + difference, Tolerance::millis(1)));
+        prop_assert_eq!(expected, (instant + difference).approx_eq(instant, Tolerance::millis(1)));
+    }
+}
+
+proptest! {
+    #[test]
+    fn constructors((tolerance, expected) in prop_oneof![
+        Just((Tolerance::EXACT, Duration::ZERO)),
+        Just((Tolerance::nanos(7), Duration::of_nanos(7))),
+        Just((Tolerance::micros(7), Duration::of_nanos(7_000))),
+        Just((Tolerance::millis(7), Duration::of_nanos(7_000_000))),
+        Just((Tolerance::seconds(7), Duration::of_seconds(7))),
+        Just((Tolerance::of(Duration::of_minutes(1)), Duration::of_seconds(60))),
+    ]) {
+        prop_assert_eq!(expected, tolerance.duration());
+    }
+}
+
+proptest! {
+    #[test]
+    fn extremes_do_not_overflow((first, second, tolerance, expected) in prop_oneof![
+        Just((Duration::MIN, Duration::MAX, Tolerance::of(Duration::MAX), false)),
+        Just((Duration::MAX, Duration::MAX, Tolerance::EXACT, true)),
+        Just((Duration::MIN, Duration::MIN + Duration::of_nanos(1), Tolerance::nanos(1), true)),
+    ]) {
+        prop_assert_eq!(expected, first.approx_eq(second, tolerance));
+        prop_assert_eq!(expected, second.approx_eq(first, tolerance));
+    }
+}
+
+#[test]
+#[should_panic(expected = "tolerance must not be negative")]
+fn negative_tolerance() {
+    Tolerance::of(Duration::of_nanos(-1));
+}
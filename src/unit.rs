@@ -0,0 +1,69 @@
+use crate::constants::*;
+use crate::Duration;
+
+#[cfg(test)]
+pub mod lengths;
+
+/// A unit of time with an exact length, such as for conversions and rounding.
+///
+/// Like `java.time.temporal.ChronoUnit`, days are treated as exactly 24 hours.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Unit {
+    /// A nanosecond, the smallest unit of time in this library.
+    Nanos,
+    /// A microsecond, 1,000 nanoseconds.
+    Micros,
+    /// A millisecond, 1,000 microseconds.
+    Millis,
+    /// A second.
+    Seconds,
+    /// A minute, 60 seconds.
+    Minutes,
+    /// An hour, 60 minutes.
+    Hours,
+    /// A standard day, 24 hours.
+    Days,
+}
+
+impl Unit {
+    /// Gets the length of this unit as a duration.
+    pub fn duration(self) -> Duration {
+        Duration::of_nanos(self.nanos())
+    }
+
+    pub(crate) fn nanos(self) -> i64 {
+        match self {
+            Unit::Nanos => 1,
+            Unit::Micros => NANOSECONDS_IN_MICROSECOND,
+            Unit::Millis => NANOSECONDS_IN_MILLISECOND,
+            Unit::Seconds => NANOSECONDS_IN_SECOND,
+            Unit::Minutes => NANOSECONDS_IN_MINUTE,
+            Unit::Hours => NANOSECONDS_IN_HOUR,
+            Unit::Days => NANOSECONDS_IN_DAY,
+        }
+    }
+}
+
+/// A unit of time whose length varies on the calendar, with an estimated length, such as for a cache lifetime of "1 month".
+///
+/// These are kept apart from [`Unit`](enum.Unit.html), so that conversions and rounding never use an estimate
+/// unless asked for one. The lengths are those of `java.time.temporal.ChronoUnit`,
+/// as for [`Duration::of_months_estimated`](struct.Duration.html#method.of_months_estimated)
+/// and [`Duration::of_years_estimated`](struct.Duration.html#method.of_years_estimated).
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum EstimatedUnit {
+    /// An estimated month, a twelfth of an estimated year, 2,629,746 seconds.
+    Months,
+    /// An estimated year, the average Gregorian year of 365.2425 days, 31,556,952 seconds.
+    Years,
+}
+
+impl EstimatedUnit {
+    /// Gets the estimated length of this unit as a duration.
+    pub fn duration_estimate(self) -> Duration {
+        match self {
+            EstimatedUnit::Months => Duration::of_months_estimated(1),
+            EstimatedUnit::Years => Duration::of_years_estimated(1),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use proptest::prelude::*;
+
+use crate::{Duration, EstimatedUnit, Unit};
+
+proptest! {
+    #[test]
+    fn duration((unit, expected) in prop_oneof![
+        Just((Unit::Nanos, Duration::of_nanos(1))),
+        Just((Unit::Micros, Duration::of_nanos(1_000))),
+        Just((Unit::Millis, Duration::of_millis(1))),
+        Just((Unit::Seconds, Duration::of_seconds(1))),
+        Just((Unit::Minutes, Duration::of_minutes(1))),
+        Just((Unit::Hours, Duration::of_hours(1))),
+        Just((Unit::Days, Duration::of_days(1))),
+    ]) {
+        prop_assert_eq!(expected, unit.duration());
+    }
+}
+
+proptest! {
+    #[test]
+    fn ordered_by_length((unit, larger) in prop_oneof![
+        Just((Unit::Nanos, Unit::Micros)),
+        Just((Unit::Micros, Unit::Millis)),
+        Just((Unit::Millis, Unit::Seconds)),
+        Just((Unit::Seconds, Unit::Minutes)),
+        Just((Unit::Minutes, Unit::Hours)),
+        Just((Unit::Hours, Unit::Days)),
+    ]) {
+        prop_assert!(unit < larger);
+        prop_assert!(unit.duration() < larger.duration());
+    }
+}
+
+proptest! {
+    #[test]
+    fn duration_estimate((unit, expected) in prop_oneof![
+        Just((EstimatedUnit::Months, Duration::of_seconds(2_629_746))),
+        Just((EstimatedUnit::Years, Duration::of_seconds(31_556_952))),
+    ]) {
+        prop_assert_eq!(expected, unit.duration_estimate());
+    }
+}
+
+proptest! {
+    #[test]
+    fn estimated_ordered_by_length((unit, larger) in prop_oneof![
+        Just((EstimatedUnit::Months, EstimatedUnit::Years)),
+    ]) {
+        prop_assert!(unit < larger);
+        prop_assert!(Unit::Days.duration() < unit.duration_estimate());
+        prop_assert!(unit.duration_estimate() < larger.duration_estimate());
+    }
+}
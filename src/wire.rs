@@ -0,0 +1,135 @@
+//! A canonical, versioned binary encoding of durations and instants, for exchanging and checksumming values across languages.
+//!
+//! Every value is encoded as a one-byte format version, currently `1`,
+//! followed by the seconds as a little-endian `i64`, then the nanosecond of second as a little-endian `u32`, for 13 bytes in total.
+//! For a duration the seconds are the [`seconds`](../struct.Duration.html#method.seconds) of the duration,
+//! and for an instant they are the [`epoch_second`](../struct.Instant.html#method.epoch_second),
+//! so the layout is the same as [`Instant::to_versioned_bytes`](../struct.Instant.html#method.to_versioned_bytes).
+//! The nanosecond of second is always less than one second, so each value has exactly one encoding.
+//! In Java, this is `ByteBuffer.allocate(13).order(ByteOrder.LITTLE_ENDIAN).put((byte) 1).putLong(seconds).putInt(nanos)`,
+//! using `Duration.getSeconds()` and `Duration.getNano()`, or `Instant.getEpochSecond()` and `Instant.getNano()`.
+//!
+//! The canonical hash of a value is the 64-bit FNV-1a hash of its 13 encoded bytes:
+//! starting from the offset basis `0xCBF29CE484222325`,
+//! each byte is XORed into the hash, which is then multiplied by the prime `0x100000001B3`, wrapping on overflow.
+//!
+//! The test vectors in this module's tests are the contract for the format; they must not change for version `1`.
+
+use crate::constants::NANOSECONDS_IN_SECOND;
+use crate::{DecodeError, Duration, Instant};
+
+#[cfg(test)]
+pub mod golden;
+
+/// The current format version, written as the first byte of every encoding.
+pub const VERSION: u8 = 1;
+
+/// The length, in bytes, of an encoding in the current format version.
+pub const LENGTH: usize = 13;
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// Appends the encoding of the duration to the buffer.
+///
+/// # Parameters
+///  - `duration`: the duration to encode.
+///  - `buffer`: the buffer to append the 13 encoded bytes to.
+#[cfg(feature = "std")]
+pub fn encode_duration(duration: &Duration, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&encode(duration.seconds(), duration.nano()));
+}
+
+/// Decodes a duration from the start of the bytes, returning it along with the number of bytes read.
+///
+/// Any bytes after the encoding are ignored, so a batch of values can be decoded one after another.
+///
+/// # Parameters
+///  - `bytes`: the bytes to decode, starting with the version byte.
+///
+/// # Errors
+/// - if the version byte is missing, or is not a known version.
+/// - if the input is shorter than the length the version requires.
+/// - if the nanosecond of second is not less than one second.
+pub fn decode_duration(bytes: &[u8]) -> Result<(Duration, usize), DecodeError> {
+    decode(bytes).map(|(seconds, nanos)| {
+        (
+            Duration::of_seconds_and_adjustment(seconds, i64::from(nanos)),
+            LENGTH,
+        )
+    })
+}
+
+/// Appends the encoding of the instant to the buffer.
+///
+/// # Parameters
+///  - `instant`: the instant to encode.
+///  - `buffer`: the buffer to append the 13 encoded bytes to.
+#[cfg(feature = "std")]
+pub fn encode_instant(instant: &Instant, buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(&encode(instant.epoch_second(), instant.nano()));
+}
+
+/// Decodes an instant from the start of the bytes, returning it along with the number of bytes read.
+///
+/// Any bytes after the encoding are ignored, so a batch of values can be decoded one after another.
+///
+/// # Parameters
+///  - `bytes`: the bytes to decode, starting with the version byte.
+///
+/// # Errors
+/// - if the version byte is missing, or is not a known version.
+/// - if the input is shorter than the length the version requires.
+/// - if the nanosecond of second is not less than one second.
+pub fn decode_instant(bytes: &[u8]) -> Result<(Instant, usize), DecodeError> {
+    decode(bytes).map(|(seconds, nanos)| {
+        (
+            Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos)),
+            LENGTH,
+        )
+    })
+}
+
+pub(crate) fn encode(seconds: i64, nanos: u32) -> [u8; LENGTH] {
+    let mut bytes = [0; LENGTH];
+    bytes[0] = VERSION;
+    bytes[1..9].copy_from_slice(&seconds.to_le_bytes());
+    bytes[9..13].copy_from_slice(&nanos.to_le_bytes());
+    bytes
+}
+
+fn decode(bytes: &[u8]) -> Result<(i64, u32), DecodeError> {
+    match bytes.first() {
+        None => Err(DecodeError::InvalidLength {
+            expected: LENGTH,
+            actual: 0,
+        }),
+        Some(&VERSION) => {
+            if bytes.len() < LENGTH {
+                return Err(DecodeError::InvalidLength {
+                    expected: LENGTH,
+                    actual: bytes.len(),
+                });
+            }
+            let mut seconds = [0; 8];
+            let mut nanos = [0; 4];
+            seconds.copy_from_slice(&bytes[1..9]);
+            nanos.copy_from_slice(&bytes[9..13]);
+            let nanos = u32::from_le_bytes(nanos);
+            if i64::from(nanos) >= NANOSECONDS_IN_SECOND {
+                return Err(DecodeError::InvalidNanos(nanos));
+            }
+            Ok((i64::from_le_bytes(seconds), nanos))
+        }
+        Some(&version) => Err(DecodeError::UnknownVersion(version)),
+    }
+}
+
+// The 64-bit FNV-1a hash of the canonical encoding.
+pub(crate) fn canonical_hash(seconds: i64, nanos: u32) -> u64 {
+    encode(seconds, nanos)
+        .iter()
+        .fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
@@ -0,0 +1,147 @@
+use proptest::prelude::*;
+
+use crate::wire::*;
+use crate::{DecodeError, Duration, Instant};
+
+// The contract for version 1 of the format: the seconds, nanos, encoded bytes, and canonical hash of each value.
+// These must never change, as other services reproduce them independently.
+const GOLDEN: [(i64, u32, [u8; LENGTH], u64); 6] = [
+    (
+        0,
+        0,
+        [
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        0xECA4_BD25_1670_946C,
+    ),
+    (
+        1,
+        500_000_000,
+        [
+            0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x65, 0xCD, 0x1D,
+        ],
+        0x9EEE_12C4_B99B_3D54,
+    ),
+    (
+        -1,
+        999_999_999,
+        [
+            0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xC9, 0x9A, 0x3B,
+        ],
+        0x546C_89AC_3D4A_2767,
+    ),
+    (
+        1_234_567_890,
+        123_456_789,
+        [
+            0x01, 0xD2, 0x02, 0x96, 0x49, 0x00, 0x00, 0x00, 0x00, 0x15, 0xCD, 0x5B, 0x07,
+        ],
+        0xE222_BC36_D750_73B5,
+    ),
+    (
+        i64::MAX,
+        999_999_999,
+        [
+            0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xC9, 0x9A, 0x3B,
+        ],
+        0x0451_168F_C460_F3E7,
+    ),
+    (
+        i64::MIN,
+        0,
+        [
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00,
+        ],
+        0x9533_27E6_6EBB_62EC,
+    ),
+];
+
+fn golden() -> impl Strategy<Value = (i64, u32, [u8; LENGTH], u64)> {
+    (0..GOLDEN.len()).prop_map(|index| GOLDEN[index])
+}
+
+proptest! {
+    #[test]
+    fn duration_golden((seconds, nanos, bytes, hash) in golden()) {
+        let duration = Duration::of_seconds_and_adjustment(seconds, i64::from(nanos));
+
+        prop_assert_eq!(Ok((duration, LENGTH)), decode_duration(&bytes));
+        prop_assert_eq!(hash, duration.canonical_hash());
+        #[cfg(feature = "std")]
+        {
+            let mut buffer = Vec::new();
+            encode_duration(&duration, &mut buffer);
+            prop_assert_eq!(&bytes[..], &buffer[..]);
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn instant_golden((seconds, nanos, bytes, hash) in golden()) {
+        let instant = Instant::of_epoch_second_and_adjustment(seconds, i64::from(nanos));
+
+        prop_assert_eq!(Ok((instant, LENGTH)), decode_instant(&bytes));
+        prop_assert_eq!(hash, instant.canonical_hash());
+        #[cfg(feature = "std")]
+        {
+            let mut buffer = Vec::new();
+            encode_instant(&instant, &mut buffer);
+            prop_assert_eq!(&bytes[..], &buffer[..]);
+        }
+    }
+}
+
+proptest! {
+    #[cfg(feature = "std")]
+    #[test]
+    fn batch_round_trip(values in prop::collection::vec((prop::num::i64::ANY, 0..1_000_000_000i64), 0..10)) {
+        let durations: Vec<Duration> = values.iter().map(|&(seconds, nanos)| Duration::of_seconds_and_adjustment(seconds, nanos)).collect();
+        let mut buffer = Vec::new();
+        for duration in &durations {
+            encode_duration(duration, &mut buffer);
+        }
+
+        let mut decoded = Vec::new();
+        let mut remaining = &buffer[..];
+        while !remaining.is_empty() {
+            let (duration, read) = decode_duration(remaining).unwrap();
+            decoded.push(duration);
+            remaining = &remaining[read..];
+        }
+        prop_assert_eq!(durations, decoded);
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_unknown_version(version in prop::num::u8::ANY) {
+        prop_assume!(version != VERSION);
+        let mut bytes = GOLDEN[0].2;
+        bytes[0] = version;
+
+        prop_assert_eq!(Err(DecodeError::UnknownVersion(version)), decode_duration(&bytes));
+        prop_assert_eq!(Err(DecodeError::UnknownVersion(version)), decode_instant(&bytes));
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_invalid_nanos(nanos in 1_000_000_000..=u32::MAX) {
+        let mut bytes = GOLDEN[0].2;
+        bytes[9..13].copy_from_slice(&nanos.to_le_bytes());
+
+        prop_assert_eq!(Err(DecodeError::InvalidNanos(nanos)), decode_duration(&bytes));
+        prop_assert_eq!(Err(DecodeError::InvalidNanos(nanos)), decode_instant(&bytes));
+    }
+}
+
+proptest! {
+    #[test]
+    fn decode_too_short(length in 0..LENGTH) {
+        let bytes = &GOLDEN[0].2[..length];
+
+        prop_assert_eq!(Err(DecodeError::InvalidLength { expected: LENGTH, actual: length }), decode_duration(bytes));
+        prop_assert_eq!(Err(DecodeError::InvalidLength { expected: LENGTH, actual: length }), decode_instant(bytes));
+    }
+}
@@ -0,0 +1,215 @@
+// Checks this crate against vectors generated from java.time, for code ported from Java.
+//
+// The vectors are in `compat/java_time.json`, produced by `compat/Generate.java`.
+// Where this crate deliberately differs, the difference is documented on the method,
+// and the case is checked against this crate's documented behavior instead.
+#![cfg(feature = "std")]
+
+use ephemeris::{Duration, Instant, Unit};
+use serde_json::Value;
+
+const FIXTURE: &str = include_str!("compat/java_time.json");
+
+fn cases(section: &str) -> Vec<Value> {
+    let fixture: Value = serde_json::from_str(FIXTURE).expect("fixture is valid JSON");
+    fixture[section]
+        .as_array()
+        .unwrap_or_else(|| panic!("fixture has no {} section", section))
+        .clone()
+}
+
+fn seconds_and_nanos(value: &Value) -> (i64, i64) {
+    (
+        value["seconds"].as_i64().expect("seconds"),
+        value["nanos"].as_i64().expect("nanos"),
+    )
+}
+
+fn duration(value: &Value) -> Duration {
+    let (seconds, nanos) = seconds_and_nanos(value);
+    Duration::of_seconds_and_adjustment(seconds, nanos)
+}
+
+fn instant(value: &Value) -> Instant {
+    let (seconds, nanos) = seconds_and_nanos(value);
+    Instant::of_epoch_second_and_adjustment(seconds, nanos)
+}
+
+fn text(value: &Value) -> &str {
+    value["text"].as_str().expect("text")
+}
+
+fn unit(value: &Value) -> Unit {
+    match value["unit"].as_str().expect("unit") {
+        "NANOS" => Unit::Nanos,
+        "MICROS" => Unit::Micros,
+        "MILLIS" => Unit::Millis,
+        "SECONDS" => Unit::Seconds,
+        "MINUTES" => Unit::Minutes,
+        "HOURS" => Unit::Hours,
+        "DAYS" => Unit::Days,
+        other => panic!("unknown unit {}", other),
+    }
+}
+
+// Runs the check over every case of the section, and fails with all of the mismatches at once.
+fn check(section: &str, mut mismatch: impl FnMut(&Value) -> Option<String>) {
+    let mismatches: Vec<String> = cases(section)
+        .iter()
+        .filter_map(|case| mismatch(case).map(|message| format!("{}: {}", case, message)))
+        .collect();
+    assert!(
+        mismatches.is_empty(),
+        "{} of the {} cases differ from java.time:\n{}",
+        mismatches.len(),
+        section,
+        mismatches.join("\n")
+    );
+}
+
+fn differ<T: PartialEq + std::fmt::Debug>(expected: T, actual: T) -> Option<String> {
+    if expected == actual {
+        None
+    } else {
+        Some(format!("expected {:?}, got {:?}", expected, actual))
+    }
+}
+
+#[test]
+fn duration_parse() {
+    check("duration_parse", |case| {
+        let expected = if case["error"].as_bool() == Some(true) {
+            None
+        } else {
+            Some(duration(case))
+        };
+        differ(expected, Duration::parse(text(case)).ok())
+    });
+}
+
+#[test]
+fn duration_format() {
+    check("duration_format", |case| {
+        differ(text(case), &duration(case).to_string())
+    });
+}
+
+#[test]
+fn duration_plus() {
+    check("duration_plus", |case| {
+        let expected = Some(&case["result"])
+            .filter(|result| !result.is_null())
+            .map(duration);
+        differ(
+            expected,
+            duration(&case["first"]).checked_add(duration(&case["second"])),
+        )
+    });
+}
+
+#[test]
+fn duration_minus() {
+    check("duration_minus", |case| {
+        let expected = Some(&case["result"])
+            .filter(|result| !result.is_null())
+            .map(duration);
+        differ(
+            expected,
+            duration(&case["first"]).checked_sub(duration(&case["second"])),
+        )
+    });
+}
+
+#[test]
+fn duration_truncate() {
+    check("duration_truncate", |case| {
+        let (seconds, nanos) = seconds_and_nanos(&case["duration"]);
+        let (original, expected) = (duration(&case["duration"]), duration(&case["result"]));
+        let unit = unit(case);
+        let actual = original.truncated_to(unit);
+        // java.time truncates toward negative infinity when the seconds are a negative whole number of days,
+        // as documented on `truncated_to`, where this crate truncates toward zero: one unit closer to zero,
+        // unless the duration was already a whole number of the unit.
+        if seconds < 0 && seconds % 86_400 == 0 && nanos > 0 && expected != original {
+            return differ(Some(actual), expected.checked_add(unit.duration()));
+        }
+        differ(expected, actual)
+    });
+}
+
+// Whether this crate accepts a text that java.time's `Instant.parse` rejects, or the reverse,
+// for the differences documented on `parse_rfc3339`; `None` where the two should agree.
+fn accepted_where_java_differs(text: &str) -> Option<bool> {
+    // A second of `60` is rejected, although RFC 3339 allows it for a leap second.
+    let leap_second = text.get(17..19) == Some("60");
+    // Stricter than java.time: years of exactly four digits, hours up to 23, and digits after a `.`.
+    let expanded_year = text.starts_with('+') || text.starts_with('-');
+    let hour_24 = text.get(11..13) == Some("24");
+    let empty_fraction = text.contains(".Z");
+    // Looser than java.time: a space between the date and time, and offsets beyond ±18:00.
+    let space = text.get(10..11) == Some(" ");
+    let offset_beyond_18 = text.ends_with("+18:01");
+    if leap_second || expanded_year || hour_24 || empty_fraction {
+        Some(false)
+    } else if space || offset_beyond_18 {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn instant_parse() {
+    check("instant_parse", |case| {
+        let expected = if case["error"].as_bool() == Some(true) {
+            None
+        } else {
+            Some(instant(case))
+        };
+        let actual = Instant::parse_rfc3339(text(case)).ok();
+        if let Some(accepted) = accepted_where_java_differs(text(case)) {
+            return differ(
+                (!accepted, accepted),
+                (expected.is_some(), actual.is_some()),
+            );
+        }
+        differ(expected, actual)
+    });
+}
+
+#[test]
+fn instant_format() {
+    check("instant_format", |case| {
+        differ(text(case), &instant(case).to_string())
+    });
+}
+
+#[test]
+fn instant_plus() {
+    check("instant_plus", |case| {
+        differ(
+            Some(instant(&case["result"])),
+            instant(&case["instant"]).checked_add(duration(&case["duration"])),
+        )
+    });
+}
+
+#[test]
+fn instant_between() {
+    check("instant_between", |case| {
+        differ(
+            duration(&case["result"]),
+            instant(&case["end"]) - instant(&case["start"]),
+        )
+    });
+}
+
+#[test]
+fn instant_truncate() {
+    check("instant_truncate", |case| {
+        differ(
+            instant(&case["result"]),
+            instant(&case["instant"]).truncated_key(unit(case).duration()),
+        )
+    });
+}
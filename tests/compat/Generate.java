@@ -0,0 +1,293 @@
+// Generates java_time.json, the java.time vectors for the compat test suite.
+//
+// Run with a JDK 11 or later from this directory:
+//
+//     java Generate.java > java_time.json
+//
+// The cases are drawn from a fixed seed, so the output only changes when this file (or java.time) does.
+// Instants are limited to the range of java.time.Instant, years -1,000,000,000 to 1,000,000,000,
+// which is narrower than the range of this crate; cases whose results would leave that range are skipped.
+
+import java.time.Duration;
+import java.time.Instant;
+import java.time.temporal.ChronoUnit;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Random;
+import java.util.function.Supplier;
+
+public class Generate {
+    private static final Random RANDOM = new Random(205);
+    private static final int RANDOM_CASES = 250;
+
+    private static final long INSTANT_MIN_SECOND = Instant.MIN.getEpochSecond();
+    private static final long INSTANT_MAX_SECOND = Instant.MAX.getEpochSecond();
+
+    private static final ChronoUnit[] UNITS = {
+        ChronoUnit.NANOS, ChronoUnit.MICROS, ChronoUnit.MILLIS, ChronoUnit.SECONDS,
+        ChronoUnit.MINUTES, ChronoUnit.HOURS, ChronoUnit.DAYS,
+    };
+
+    // Hand-picked durations, which precede the random ones.
+    private static final List<Duration> DURATION_EDGES = List.of(
+        Duration.ZERO, Duration.ofNanos(1), Duration.ofNanos(-1), Duration.ofMillis(500), Duration.ofMillis(-500),
+        Duration.ofMillis(-1500), Duration.ofSeconds(59), Duration.ofSeconds(-61), Duration.ofSeconds(86_399, 500_000_000),
+        Duration.ofSeconds(-86_400, 500_000_000), Duration.ofSeconds(-86_401, 500_000_000), Duration.ofDays(2),
+        Duration.ofDays(-2).plusNanos(1), Duration.ofSeconds(Long.MAX_VALUE, 999_999_999), Duration.ofSeconds(Long.MIN_VALUE),
+        Duration.ofSeconds(Long.MIN_VALUE, 1), Duration.ofSeconds(Long.MAX_VALUE), Duration.ofSeconds(Long.MIN_VALUE, 999_999_999));
+
+    // Hand-picked instants, which precede the random ones.
+    private static final List<Instant> INSTANT_EDGES = List.of(
+        Instant.EPOCH, Instant.ofEpochSecond(-1), Instant.ofEpochSecond(-1, 999_999_999), Instant.ofEpochSecond(-1, 1),
+        Instant.ofEpochSecond(0, 1), Instant.ofEpochMilli(-1), Instant.ofEpochMilli(1_196_676_930_123L),
+        Instant.ofEpochSecond(253_402_300_799L, 999_999_999), Instant.ofEpochSecond(253_402_300_800L),
+        Instant.ofEpochSecond(-62_167_219_200L), Instant.ofEpochSecond(-62_167_219_201L), Instant.ofEpochSecond(-86_401, 500_000_000),
+        Instant.MIN, Instant.MAX, Instant.MIN.plusNanos(1), Instant.MAX.minusNanos(1));
+
+    private static final String[] DURATION_TEXTS = {
+        "PT0S", "PT20.345S", "PT15M", "PT10H", "P2D", "P2DT3H4M", "PT36H", "P1DT2H3M4.5S",
+        "PT-6H3M", "-PT6H3M", "-PT-6H+3M", "+PT1S", "PT+1S", "P+1D", "P-1D", "pt1s", "p1dt1h1m1s",
+        "PT1,5S", "PT1.S", "PT-0.5S", "PT-1.5S", "-PT0.5S", "PT0.999999999S", "PT-0.000000001S",
+        "PT1.0000000001S", "PT.5S", "PT9223372036854775807S", "PT9223372036854775808S",
+        "PT-9223372036854775808S", "-PT-9223372036854775808S", "PT-9223372036854775808.5S",
+        "PT2562047788015215H30M7.999999999S", "PT2562047788015216H", "P106751991167300D",
+        "P106751991167301D", "P1Y", "P1M", "P1W", "P1Y2M3DT4H", "PT", "P", "", "P1DT", "PT1H1H",
+        "PT1M1H", "PT1S1M", "PT1.5M", "PT1.5H", " PT1S", "PT1S ", "PT1S2", "--PT1S", "PT 1S",
+        "PT1D", "P1H", "PT0000000001S", "PT-0S", "-PT0S", "PT1H-60M", "P0DT0H0M0S",
+    };
+
+    private static final String[] INSTANT_TEXTS = {
+        "1970-01-01T00:00:00Z", "2007-12-03T10:15:30Z", "2007-12-03T10:15:30.123Z", "2007-12-03T10:15:30.1Z",
+        "2007-12-03T10:15:30.123456789Z", "2007-12-03T10:15:30.000000001Z", "2007-12-03T10:15:30.1234567891Z",
+        "2007-12-03T10:15:30.Z", "2007-12-03T10:15Z", "2007-12-03T10:15:30+01:00", "2007-12-03T10:15:30-05:30",
+        "2007-12-03T10:15:30+00:00", "2007-12-03t10:15:30z", "2007-12-03 10:15:30Z", "2007-12-03T10:15:30",
+        "2016-12-31T23:59:60Z", "2016-12-31T23:59:60.5Z", "2015-06-30T23:59:60Z", "2007-12-03T24:00:00Z",
+        "2007-02-29T00:00:00Z", "2008-02-29T00:00:00Z", "2007-13-01T00:00:00Z", "2007-12-32T00:00:00Z",
+        "0000-01-01T00:00:00Z", "-0001-01-01T00:00:00Z", "+10000-01-01T00:00:00Z", "10000-01-01T00:00:00Z",
+        "+1000000000-12-31T23:59:59.999999999Z", "-1000000000-01-01T00:00:00Z", "1969-12-31T23:59:59.999999999Z",
+        "2007-12-03T10:15:30.123+01:30", "2007-12-03T10:15:30+18:00", "2007-12-03T10:15:30+18:01",
+        "2007-12-03T10:15:30+0100", "2007-12-03T10:15:30+01", "", "2007-12-03", "2007-12-03T10:15:30ZZ",
+    };
+
+    public static void main(String[] args) {
+        StringBuilder out = new StringBuilder();
+        out.append("{\n");
+        out.append("  \"java_version\": ").append(quote(System.getProperty("java.version"))).append(",\n");
+        section(out, "duration_parse", durationParse(), false);
+        section(out, "duration_format", durationFormat(), false);
+        section(out, "duration_plus", durationArithmetic(true), false);
+        section(out, "duration_minus", durationArithmetic(false), false);
+        section(out, "duration_truncate", durationTruncate(), false);
+        section(out, "instant_parse", instantParse(), false);
+        section(out, "instant_format", instantFormat(), false);
+        section(out, "instant_plus", instantPlus(), false);
+        section(out, "instant_between", instantBetween(), false);
+        section(out, "instant_truncate", instantTruncate(), true);
+        out.append("}\n");
+        System.out.print(out);
+    }
+
+    private static List<String> durationParse() {
+        List<String> cases = new ArrayList<>();
+        List<String> texts = new ArrayList<>(List.of(DURATION_TEXTS));
+        for (int i = 0; i < RANDOM_CASES; i++) {
+            texts.add(randomDuration().toString());
+        }
+        for (String text : texts) {
+            try {
+                Duration duration = Duration.parse(text);
+                cases.add(object("text", quote(text), "seconds", duration.getSeconds(), "nanos", duration.getNano()));
+            } catch (RuntimeException e) {
+                cases.add(object("text", quote(text), "error", true));
+            }
+        }
+        return cases;
+    }
+
+    private static List<String> durationFormat() {
+        List<String> cases = new ArrayList<>();
+        for (Duration duration : durations()) {
+            cases.add(object("seconds", duration.getSeconds(), "nanos", duration.getNano(), "text", quote(duration.toString())));
+        }
+        return cases;
+    }
+
+    private static List<String> durationArithmetic(boolean plus) {
+        List<String> cases = new ArrayList<>();
+        List<Duration> durations = durations();
+        for (int i = 0; i < durations.size(); i++) {
+            Duration first = durations.get(i);
+            Duration second = i % 3 == 0 ? durations.get(durations.size() - 1 - i) : randomDuration();
+            String result;
+            try {
+                Duration sum = plus ? first.plus(second) : first.minus(second);
+                result = object("seconds", sum.getSeconds(), "nanos", sum.getNano());
+            } catch (ArithmeticException e) {
+                result = "null";
+            }
+            cases.add(object("first", durationObject(first), "second", durationObject(second), "result", result));
+        }
+        return cases;
+    }
+
+    private static List<String> durationTruncate() {
+        List<String> cases = new ArrayList<>();
+        List<Duration> durations = durations();
+        for (int i = 0; i < durations.size(); i++) {
+            // Every unit for the edge cases at the start, then one unit for each random case.
+            for (ChronoUnit unit : i < DURATION_EDGES.size() ? UNITS : new ChronoUnit[] {UNITS[i % UNITS.length]}) {
+                Duration truncated = durations.get(i).truncatedTo(unit);
+                cases.add(object("duration", durationObject(durations.get(i)), "unit", quote(unit.name()), "result", durationObject(truncated)));
+            }
+        }
+        return cases;
+    }
+
+    private static List<String> instantParse() {
+        List<String> cases = new ArrayList<>();
+        List<String> texts = new ArrayList<>(List.of(INSTANT_TEXTS));
+        for (int i = 0; i < RANDOM_CASES; i++) {
+            texts.add(randomInstant().toString());
+        }
+        for (String text : texts) {
+            try {
+                Instant instant = Instant.parse(text);
+                cases.add(object("text", quote(text), "seconds", instant.getEpochSecond(), "nanos", instant.getNano()));
+            } catch (RuntimeException e) {
+                cases.add(object("text", quote(text), "error", true));
+            }
+        }
+        return cases;
+    }
+
+    private static List<String> instantFormat() {
+        List<String> cases = new ArrayList<>();
+        for (Instant instant : instants()) {
+            cases.add(object("seconds", instant.getEpochSecond(), "nanos", instant.getNano(), "text", quote(instant.toString())));
+        }
+        return cases;
+    }
+
+    private static List<String> instantPlus() {
+        List<String> cases = new ArrayList<>();
+        for (Instant instant : instants()) {
+            Duration duration = Duration.ofSeconds(randomSeconds(1_000_000_000_000L), randomNanos());
+            try {
+                Instant sum = instant.plus(duration);
+                cases.add(object("instant", instantObject(instant), "duration", durationObject(duration), "result", instantObject(sum)));
+            } catch (RuntimeException e) {
+                // Outside the range of java.time.Instant, which is narrower than this crate's.
+            }
+        }
+        return cases;
+    }
+
+    private static List<String> instantBetween() {
+        List<String> cases = new ArrayList<>();
+        List<Instant> instants = instants();
+        for (int i = 0; i < instants.size(); i++) {
+            Instant start = instants.get(i);
+            Instant end = i % 2 == 0 ? instants.get(instants.size() - 1 - i) : randomInstant();
+            Duration between = Duration.between(start, end);
+            cases.add(object("start", instantObject(start), "end", instantObject(end), "result", durationObject(between)));
+        }
+        return cases;
+    }
+
+    private static List<String> instantTruncate() {
+        List<String> cases = new ArrayList<>();
+        List<Instant> instants = instants();
+        for (int i = 0; i < instants.size(); i++) {
+            Instant instant = instants.get(i);
+            // Every unit for the edge cases at the start, then one unit for each random case.
+            for (ChronoUnit unit : i < INSTANT_EDGES.size() ? UNITS : new ChronoUnit[] {UNITS[i % UNITS.length]}) {
+                try {
+                    Instant truncated = instant.truncatedTo(unit);
+                    cases.add(object("instant", instantObject(instant), "unit", quote(unit.name()), "result", instantObject(truncated)));
+                } catch (RuntimeException e) {
+                    // Outside the range of java.time.Instant, which is narrower than this crate's.
+                }
+            }
+        }
+        return cases;
+    }
+
+    private static List<Duration> durations() {
+        List<Duration> durations = new ArrayList<>(DURATION_EDGES);
+        for (int i = 0; i < RANDOM_CASES; i++) {
+            durations.add(randomDuration());
+        }
+        return durations;
+    }
+
+    private static List<Instant> instants() {
+        List<Instant> instants = new ArrayList<>(INSTANT_EDGES);
+        for (int i = 0; i < RANDOM_CASES; i++) {
+            instants.add(randomInstant());
+        }
+        return instants;
+    }
+
+    private static Duration randomDuration() {
+        long bound = pick(() -> 100L, () -> 100_000L, () -> 10_000_000_000L, () -> Long.MAX_VALUE);
+        return Duration.ofSeconds(randomSeconds(bound), randomNanos());
+    }
+
+    private static Instant randomInstant() {
+        long bound = pick(() -> 100L, () -> 100_000L, () -> 10_000_000_000L, () -> INSTANT_MAX_SECOND);
+        long seconds = Math.max(INSTANT_MIN_SECOND, Math.min(INSTANT_MAX_SECOND, randomSeconds(bound)));
+        return Instant.ofEpochSecond(seconds, randomNanos());
+    }
+
+    // A random number of seconds, positive or negative, with a magnitude up to the bound.
+    private static long randomSeconds(long bound) {
+        long magnitude = Math.floorMod(RANDOM.nextLong(), bound);
+        return RANDOM.nextBoolean() ? magnitude : -magnitude;
+    }
+
+    // Random nanoseconds, biased to the whole and round fractions that show differences in formatting.
+    private static int randomNanos() {
+        return pick(
+            () -> 0,
+            () -> RANDOM.nextInt(10) * 100_000_000,
+            () -> RANDOM.nextInt(1_000) * 1_000_000,
+            () -> RANDOM.nextInt(1_000_000) * 1_000,
+            () -> RANDOM.nextInt(1_000_000_000));
+    }
+
+    @SafeVarargs
+    private static <T> T pick(Supplier<T>... options) {
+        return options[RANDOM.nextInt(options.length)].get();
+    }
+
+    private static String durationObject(Duration duration) {
+        return object("seconds", duration.getSeconds(), "nanos", duration.getNano());
+    }
+
+    private static String instantObject(Instant instant) {
+        return object("seconds", instant.getEpochSecond(), "nanos", instant.getNano());
+    }
+
+    private static String object(Object... pairs) {
+        StringBuilder out = new StringBuilder("{");
+        for (int i = 0; i < pairs.length; i += 2) {
+            if (i > 0) {
+                out.append(", ");
+            }
+            out.append(quote((String) pairs[i])).append(": ").append(pairs[i + 1]);
+        }
+        return out.append("}").toString();
+    }
+
+    private static String quote(String text) {
+        return "\"" + text.replace("\\", "\\\\").replace("\"", "\\\"") + "\"";
+    }
+
+    private static void section(StringBuilder out, String name, List<String> cases, boolean last) {
+        out.append("  ").append(quote(name)).append(": [\n");
+        for (int i = 0; i < cases.size(); i++) {
+            out.append("    ").append(cases.get(i)).append(i + 1 < cases.size() ? ",\n" : "\n");
+        }
+        out.append("  ]").append(last ? "\n" : ",\n");
+    }
+}
@@ -0,0 +1,8 @@
+// The expected messages include the location of the `panic!` behind a failed const evaluation,
+// which is in `core` rather than `std` without the `std` feature.
+#[cfg(feature = "std")]
+#[test]
+fn duration_misuse_fails_to_compile() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/duration_*.rs");
+}
@@ -0,0 +1,5 @@
+use ephemeris::duration;
+
+fn main() {
+    let _ = duration!();
+}
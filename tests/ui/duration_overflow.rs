@@ -0,0 +1,7 @@
+use ephemeris::{duration, Duration};
+
+const TOO_LONG: Duration = duration!(9_223_372_036_854_775_807 s, 1 s);
+
+fn main() {
+    let _ = TOO_LONG;
+}
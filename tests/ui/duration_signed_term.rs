@@ -0,0 +1,5 @@
+use ephemeris::duration;
+
+fn main() {
+    let _ = duration!(2 h, -30 min);
+}
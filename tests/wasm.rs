@@ -0,0 +1,32 @@
+// Checks the JavaScript interop in a JavaScript engine. Run with `cargo test --target wasm32-unknown-unknown --features wasm --test wasm`,
+// with `wasm-bindgen-test-runner` as the runner for the target.
+// The conversion arithmetic is tested on every target, alongside `Instant`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use ephemeris::{Clock, Instant, JsClock};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn date_round_trip() {
+    let date = js_sys::Date::new(&"2024-02-29T12:34:56.789Z".into());
+    let instant = Instant::from_js_millis(date.get_time()).unwrap();
+
+    assert_eq!(Ok(instant), "2024-02-29T12:34:56.789Z".parse());
+    assert_eq!(date.get_time(), instant.to_js_millis());
+}
+
+#[wasm_bindgen_test]
+fn invalid_date() {
+    let date = js_sys::Date::new(&"not a date".into());
+
+    assert!(Instant::from_js_millis(date.get_time()).is_err());
+}
+
+#[wasm_bindgen_test]
+fn js_clock_reads_date_now() {
+    let before = js_sys::Date::now();
+    let instant = JsClock.instant();
+    let after = js_sys::Date::now();
+
+    assert!(before <= instant.to_js_millis() && instant.to_js_millis() <= after);
+}